@@ -16,10 +16,11 @@ fn main() {
     loop {
         turtle.forward(1.0);
 
-        while let Some(event) = drawing.poll_event() {
+        for event in drawing.poll_events() {
             use Key::{LeftArrow, RightArrow};
             match event {
-                Event::Key(key, PressedState::Pressed) => match key {
+                // Keep turning for as long as the key is held down
+                Event::Key(key, PressedState::Pressed) | Event::KeyHeld {key, ..} => match key {
                     LeftArrow => {
                         turtle.set_speed(8);
                         for _ in 0..20 {
@@ -23,8 +23,10 @@ fn main() {
         turtle.turn_towards(target);
         turtle.set_speed(8);
 
-        while let Some(MouseMove(next_target)) = drawing.poll_event() {
-            target = next_target;
+        for event in drawing.poll_events() {
+            if let MouseMove(next_target) = event {
+                target = next_target;
+            }
         }
     }
 }
@@ -1,7 +1,4 @@
 //! A hangman game to guess the hidden word.
-// To run this example, use the command: cargo run --features unstable --example hangman
-#[cfg(all(not(feature = "unstable")))]
-compile_error!("This example relies on unstable features. Run with `--features unstable`");
 
 use std::io::{Error, Write};
 
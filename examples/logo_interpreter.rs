@@ -2,9 +2,9 @@
 //!     cargo run --example logo_interpreter -- my_logo_program.txt
 
 use std::env;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-use turtle::Turtle;
+use std::fs;
+use std::io::{self, BufRead};
+use turtle::{Turtle, logo::run_logo};
 
 fn main() {
     let mut turtle = Turtle::new();
@@ -14,91 +14,39 @@ fn main() {
     if args.len() != 2 {
         println!("Interactive LOGO Mode.\nType the commands in stdin.\nPress '^C' (cmd/ctrl+C) to exit");
         let stdin = io::stdin();
-        interpret(&mut turtle, stdin)
-    } else {
-        let path = &args[1];
-        let file = File::open(path).expect("Could not open provided program");
-        interpret(&mut turtle, file)
-    }
-}
-
-fn interpret<R: io::Read>(turtle: &mut Turtle, input: R) {
-    let mut reader = BufReader::new(input);
-    loop {
-        let mut buffer = String::new();
-        let read_bytes = reader.read_line(&mut buffer).expect("Unable read input");
-        if read_bytes == 0 {
-            // Reached EOF, break loop
-            break;
-        }
-        let cmd_args = buffer.split_whitespace();
-        handle_command(turtle, cmd_args);
-    }
-}
-
-fn handle_command<'a, A: Iterator<Item = &'a str>>(turtle: &mut Turtle, mut args: A) {
-    while let Some(command) = args.next() {
-        match command {
-            "fd" | "forward" => {
-                let distance = parse_distance(args.next().expect("Expected a distance value after fd/forward command"));
-                turtle.forward(distance);
-            }
-            "bk" | "back" => {
-                let distance = parse_distance(args.next().expect("Expect a distance value after bk/back command"));
-                turtle.backward(distance);
-            }
-            "lt" | "left" => {
-                let distance = parse_distance(args.next().expect("Expect a distance value after lt/left command"));
-                turtle.left(distance);
-            }
-            "rt" | "right" => {
-                let distance = parse_distance(args.next().expect("Expect a distance value after rt/right command"));
-                turtle.right(distance);
-            }
-            "home" => {
-                turtle.home();
+        // Lines are collected until every bracket is closed, so that a `REPEAT` block can be
+        // spread over several lines
+        let mut program = String::new();
+        for line in stdin.lock().lines() {
+            let line = line.expect("Unable read input");
+            program.push_str(&line);
+            program.push('\n');
+            if open_brackets(&program) > 0 {
+                continue;
             }
-            "setx" => {
-                let x_pos = parse_distance(args.next().expect("No expression found"));
-                turtle.set_x(x_pos);
-            }
-            "sety" => {
-                let y_pos = parse_distance(args.next().expect("No expression found"));
-                turtle.set_y(y_pos);
-            }
-            "setheading" | "seth" => {
-                let expr = parse_distance(args.next().expect("No expression found"));
-                turtle.set_heading(expr);
-            }
-            "showturtle" | "st" => {
-                turtle.show();
-            }
-            "hideturtle" | "ht" => {
-                turtle.hide();
-            }
-            "clean" => {
-                turtle.clear();
-            }
-            "clearscreen" | "cs" => {
-                turtle.clear();
-                turtle.home();
-            }
-            "pendown" | "pd" => {
-                turtle.pen_down();
-            }
-            "penup" | "pu" => {
-                turtle.pen_up();
-            }
-            "setpensize" | "setwidth" | "setpw" => {
-                let value = parse_distance(args.next().expect("No value found"));
-                turtle.set_pen_size(value);
+
+            if let Err(err) = run_logo(&mut turtle, &program) {
+                eprintln!("{}", err);
             }
-            _ => unimplemented!("Use of invalid or unsupported LOGO command"),
+            program.clear();
+        }
+    } else {
+        let path = &args[1];
+        let program = fs::read_to_string(path).expect("Could not open provided program");
+        if let Err(err) = run_logo(&mut turtle, &program) {
+            eprintln!("{}", err);
         }
     }
 }
 
-fn parse_distance(s: &str) -> f64 {
-    let dist: f64 = s.parse().expect("Not a valid number");
-    dist
+/// Returns the number of brackets in `program` that have not been closed yet, ignoring comments
+fn open_brackets(program: &str) -> isize {
+    program.lines()
+        .flat_map(|line| line.split(';').next().unwrap_or("").chars())
+        .map(|c| match c {
+            '[' => 1,
+            ']' => -1,
+            _ => 0,
+        })
+        .sum()
 }
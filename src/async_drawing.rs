@@ -2,10 +2,14 @@ use std::fmt::Debug;
 use std::path::Path;
 
 use serde::{Serialize, Deserialize};
+use thiserror::Error;
+use futures_util::stream::{self, Stream};
 
 use crate::ipc_protocol::ProtocolClient;
+use crate::renderer_server::{WindowConfig, DisplayListData};
 use crate::async_turtle::AsyncTurtle;
-use crate::{Drawing, Point, Color, Event, ExportError};
+use crate::event::Key;
+use crate::{Drawing, Point, Rect, Color, Event, ExportError, Recording, CoordinateSystem};
 
 /// Represents a size
 ///
@@ -54,6 +58,48 @@ impl From<[u32; 2]> for Size {
     }
 }
 
+/// The error returned by the [`import_json()` method] when the given JSON can't be imported
+///
+/// [`import_json()` method]: struct.Drawing.html#method.import_json
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ImportJsonError {
+    /// The string is not JSON in the format returned by [`export_json()`]. Contains a description
+    /// of the problem.
+    ///
+    /// [`export_json()`]: struct.Drawing.html#method.export_json
+    #[error("the JSON is not a drawing exported by export_json: {0}")]
+    Parse(String),
+    /// The drawing contains a value that can't be drawn (e.g. an invalid color or a fill pattern
+    /// with a spacing that is too small)
+    #[error("the drawing contains an invalid point, color, thickness, or fill pattern")]
+    InvalidDrawing,
+}
+
+/// The async version of [`Drawing`], for use in programs that already run async code
+///
+/// Like [`AsyncTurtle`], this supports the same methods as its blocking counterpart. See the
+/// [`Drawing`] documentation for what each method does. The turtles created by
+/// [`add_turtle()`] are `AsyncTurtle`s. Convert between `AsyncDrawing` and [`Drawing`] with
+/// `From`/`Into` or [`into_sync()`].
+///
+/// The methods of `AsyncDrawing` must be run within a multi-threaded tokio 0.2 runtime.
+///
+/// ```rust,no_run
+/// use turtle::AsyncDrawing;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut drawing = AsyncDrawing::new().await;
+///     drawing.set_background_color("light grey");
+///     let mut turtle = drawing.add_turtle().await;
+///     turtle.forward(100.0).await;
+/// }
+/// ```
+///
+/// [`Drawing`]: struct.Drawing.html
+/// [`AsyncTurtle`]: struct.AsyncTurtle.html
+/// [`add_turtle()`]: #method.add_turtle
+/// [`into_sync()`]: #method.into_sync
 pub struct AsyncDrawing {
     client: ProtocolClient,
 }
@@ -66,16 +112,28 @@ impl From<Drawing> for AsyncDrawing {
 
 impl AsyncDrawing {
     pub async fn new() -> Self {
+        Self::with_config(WindowConfig::default()).await
+    }
+
+    pub(crate) async fn with_config(config: WindowConfig) -> Self {
         // This needs to be called as close to the start of the program as possible. We call it
         // here since Drawing::new() or AsyncDrawing::new() are commonly called at the beginning
         // of many programs that use the turtle crate.
         crate::start();
 
-        let client = ProtocolClient::new().await
+        let client = ProtocolClient::new(config).await
             .expect("unable to create renderer client");
         Self {client}
     }
 
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.client.coordinate_system()
+    }
+
+    pub async fn set_coordinate_system(&mut self, system: CoordinateSystem) {
+        self.client.set_coordinate_system(system).await
+    }
+
     pub async fn add_turtle(&mut self) -> AsyncTurtle {
         let client = self.client.split().await;
         AsyncTurtle::with_client(client).await
@@ -107,8 +165,24 @@ impl AsyncDrawing {
         self.client.drawing_set_background(bg_color)
     }
 
+    pub async fn set_background_color_animated<C: Into<Color> + Copy + Debug>(&mut self, color: C, secs: f64) {
+        let bg_color = color.into();
+        assert!(
+            bg_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            color
+        );
+        assert!(
+            secs.is_finite(),
+            "Invalid duration: {}. The duration of an animation must be a finite number of seconds",
+            secs
+        );
+        self.client.drawing_animate_background(bg_color, secs).await
+    }
+
     pub async fn center(&self) -> Point {
-        self.client.drawing_center().await
+        let center = self.client.drawing_center().await;
+        self.client.to_user(center)
     }
 
     pub fn set_center<P: Into<Point>>(&mut self, center: P) {
@@ -116,13 +190,46 @@ impl AsyncDrawing {
         if !center.is_finite() {
             return;
         }
+        let center = self.client.to_logical(center);
         self.client.drawing_set_center(center)
     }
 
+    pub async fn set_center_animated<P: Into<Point>>(&mut self, center: P, secs: f64) {
+        assert!(
+            secs.is_finite(),
+            "Invalid duration: {}. The duration of an animation must be a finite number of seconds",
+            secs
+        );
+        let center = center.into();
+        if !center.is_finite() {
+            return;
+        }
+        let center = self.client.to_logical(center);
+        self.client.drawing_animate_center(center, secs).await
+    }
+
     pub fn reset_center(&mut self) {
         self.client.drawing_reset_center()
     }
 
+    pub async fn scale(&self) -> f64 {
+        self.client.drawing_scale().await
+    }
+
+    pub fn set_scale(&mut self, scale: f64) {
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "Invalid scale: {}. The scale must be greater than zero",
+            scale
+        );
+
+        self.client.drawing_set_scale(scale)
+    }
+
+    pub fn reset_scale(&mut self) {
+        self.client.drawing_reset_scale()
+    }
+
     pub async fn size(&self) -> Size {
         self.client.drawing_size().await
     }
@@ -138,6 +245,34 @@ impl AsyncDrawing {
         self.client.drawing_reset_size()
     }
 
+    pub async fn bounds(&self) -> Option<Rect> {
+        let bounds = self.client.drawing_bounds().await?;
+        Some(self.client.rect_to_user(bounds))
+    }
+
+    pub async fn fit_to_window(&mut self, padding: f64) {
+        assert!(
+            padding >= 0.0 && padding.is_finite(),
+            "Invalid padding: {}. The padding must be zero or greater",
+            padding
+        );
+
+        // Works in logical coordinates so that the scale can be compared to the window size
+        let bounds = match self.client.drawing_bounds().await {
+            Some(bounds) => bounds,
+            None => return,
+        };
+        let size = self.client.drawing_size().await;
+
+        // A drawing with no width or height (e.g. a single dot) is treated as being one unit wide
+        // and high so that the scale stays finite
+        let width = (bounds.width() + 2.0 * padding).max(1.0);
+        let height = (bounds.height() + 2.0 * padding).max(1.0);
+        let scale = (size.width as f64 / width).min(size.height as f64 / height);
+        self.client.drawing_set_center(bounds.center());
+        self.set_scale(scale);
+    }
+
     pub async fn is_maximized(&self) -> bool {
         self.client.drawing_is_maximized().await
     }
@@ -162,18 +297,231 @@ impl AsyncDrawing {
         self.client.drawing_set_is_fullscreen(false)
     }
 
+    pub async fn window_position(&self) -> (i32, i32) {
+        self.client.drawing_window_position().await
+    }
+
+    pub fn set_window_position(&mut self, x: i32, y: i32) {
+        self.client.drawing_set_window_position(x, y)
+    }
+
+    pub async fn set_icon<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ExportError> {
+        self.client.drawing_set_icon(path.as_ref().to_path_buf()).await
+    }
+
+    pub async fn grid(&self) -> Option<f64> {
+        self.client.drawing_grid().await
+    }
+
+    pub fn set_grid(&mut self, spacing: Option<f64>) {
+        if let Some(spacing) = spacing {
+            assert!(
+                spacing > 0.0 && spacing.is_finite(),
+                "Invalid grid spacing: {}. The grid spacing must be greater than zero",
+                spacing
+            );
+        }
+
+        self.client.drawing_set_grid(spacing)
+    }
+
+    pub async fn is_showing_axes(&self) -> bool {
+        self.client.drawing_show_axes().await
+    }
+
+    pub fn show_axes(&mut self, show: bool) {
+        self.client.drawing_set_show_axes(show)
+    }
+
+    pub async fn is_showing_fps(&self) -> bool {
+        self.client.drawing_show_fps().await
+    }
+
+    pub fn show_fps(&mut self, show: bool) {
+        self.client.drawing_set_show_fps(show)
+    }
+
+    pub async fn antialiasing(&self) -> bool {
+        self.client.drawing_antialiasing().await
+    }
+
+    pub fn set_antialiasing(&mut self, enabled: bool) {
+        self.client.drawing_set_antialiasing(enabled)
+    }
+
+    pub async fn exit_on_escape(&self) -> bool {
+        self.client.drawing_exit_on_escape().await
+    }
+
+    pub fn set_exit_on_escape(&mut self, enabled: bool) {
+        self.client.drawing_set_exit_on_escape(enabled)
+    }
+
+    pub async fn exit_on_close(&self) -> bool {
+        self.client.drawing_exit_on_close().await
+    }
+
+    pub fn set_exit_on_close(&mut self, enabled: bool) {
+        self.client.drawing_set_exit_on_close(enabled)
+    }
+
+    pub async fn tick_events(&self) -> bool {
+        self.client.drawing_tick_events().await
+    }
+
+    pub fn set_tick_events(&mut self, enabled: bool) {
+        self.client.drawing_set_tick_events(enabled)
+    }
+
     pub fn clear(&mut self) {
         self.client.clear_all()
     }
 
+    pub async fn update(&mut self) {
+        self.client.drawing_update().await
+    }
+
+    pub fn set_track_history(&mut self, turtle: &AsyncTurtle, capacity: usize) {
+        self.client.turtle_set_track_history(turtle.id(), capacity)
+    }
+
+    pub async fn position_history(&self, turtle: &AsyncTurtle) -> Vec<Point> {
+        let positions = self.client.turtle_position_history(turtle.id()).await;
+        self.client.points_to_user(positions)
+    }
+
+    pub fn start_recording(&mut self) {
+        self.client.start_recording()
+    }
+
+    pub fn stop_recording(&mut self) -> Recording {
+        self.client.stop_recording()
+    }
+
+    pub async fn replay(&mut self, recording: &Recording) {
+        self.client.replay(recording).await
+    }
+
     pub async fn poll_event(&mut self) -> Option<Event> {
-        self.client.poll_event().await
+        let event = self.client.poll_event().await?;
+        Some(self.client.event_to_user(event))
+    }
+
+    pub async fn poll_events(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        while let Some(event) = self.poll_event().await {
+            events.push(event);
+        }
+        events
+    }
+
+    /// Returns a stream that yields each event as soon as it arrives
+    ///
+    /// The stream ends once no more events will ever be sent (e.g. if the window is closed).
+    /// Events are shared with `poll_event`, so each event is only returned by one of them.
+    pub fn events(&self) -> impl Stream<Item=Event> + '_ {
+        // Waiting for an event blocks the client until the event arrives, so the stream gets its
+        // own client to keep the rest of the drawing usable in the meantime
+        stream::unfold(None, move |events_client: Option<ProtocolClient>| async move {
+            let events_client = match events_client {
+                Some(events_client) => events_client,
+                None => self.client.split().await,
+            };
+            let event = events_client.next_event().await?;
+            let event = events_client.event_to_user(event);
+            Some((event, Some(events_client)))
+        })
+    }
+
+    pub async fn mouse_position(&self) -> Point {
+        let position = self.client.mouse_position().await;
+        self.client.to_user(position)
+    }
+
+    pub async fn pressed_keys(&self) -> Vec<Key> {
+        self.client.pressed_keys().await
+    }
+
+    pub async fn is_key_pressed(&self, key: Key) -> bool {
+        self.pressed_keys().await.contains(&key)
+    }
+
+    pub async fn poll_event_matching<F: Fn(&Event) -> bool>(&mut self, predicate: F) -> Option<Event> {
+        while let Some(event) = self.poll_event().await {
+            if predicate(&event) {
+                return Some(event);
+            }
+        }
+
+        None
     }
 
     pub async fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
         self.client.export_svg(path.as_ref().to_path_buf()).await
     }
 
+    pub async fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
+        self.save_png_scaled(path, 1.0).await
+    }
+
+    pub async fn save_png_scaled<P: AsRef<Path>>(&self, path: P, scale: f64) -> Result<(), ExportError> {
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "Invalid scale: {}. The scale must be greater than zero",
+            scale
+        );
+
+        self.client.export_png(path.as_ref().to_path_buf(), scale).await
+    }
+
+    pub async fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
+        self.client.export_pdf(path.as_ref().to_path_buf()).await
+    }
+
+    #[cfg(feature = "embedded")]
+    pub async fn render_to(&self, buffer: &mut Vec<u8>) -> Result<Size, ExportError> {
+        self.client.render_to(buffer).await
+    }
+
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    pub fn send_event(&mut self, event: Event) {
+        self.client.send_event(event)
+    }
+
+    pub async fn screenshot(&self) -> Result<(Size, Vec<u8>), ExportError> {
+        self.client.screenshot().await
+    }
+
+    pub fn start_gif_recording<P: AsRef<Path>>(&mut self, path: P, fps: f64) {
+        assert!(
+            fps > 0.0 && fps <= 50.0,
+            "Invalid frame rate: {}. The frame rate of a GIF must be greater than zero and at most 50",
+            fps
+        );
+
+        self.client.start_gif_recording(path.as_ref().to_path_buf(), fps)
+    }
+
+    pub async fn stop_gif_recording(&mut self) -> Result<(), ExportError> {
+        self.client.stop_gif_recording().await
+    }
+
+    pub async fn export_json(&self) -> String {
+        let data = self.client.export_display_list().await;
+        serde_json::to_string(&data).expect("bug: unable to serialize the display list")
+    }
+
+    pub fn import_json(&mut self, json: &str) -> Result<(), ImportJsonError> {
+        let data: DisplayListData = serde_json::from_str(json)
+            .map_err(|err| ImportJsonError::Parse(err.to_string()))?;
+        if !data.is_valid() {
+            return Err(ImportJsonError::InvalidDrawing);
+        }
+
+        self.client.import_display_list(data);
+        Ok(())
+    }
+
     //TODO: If we move to a shared memory architecture, we wouldn't need to make
     // any request here and thus would not need this method at all. We should
     // think things through before making this method public.
@@ -1,11 +1,20 @@
 use std::fmt::Debug;
+use std::time::Duration;
 
 use tokio::time;
 
 use crate::radians::{self, Radians};
-use crate::ipc_protocol::{ProtocolClient, RotationDirection};
-use crate::renderer_server::TurtleId;
-use crate::{Turtle, Color, Point, Speed};
+use crate::ipc_protocol::{ProtocolClient, RotationDirection, BatchCommand};
+use crate::renderer_server::{TurtleId, WindowConfig};
+use crate::text::DEFAULT_FONT_SIZE;
+use crate::event::Key;
+use crate::fill;
+use crate::{Turtle, Color, Point, Rect, Size, Speed, TextAlign, LineCap, LineJoin, PenState, StampId, TurtleShape, FillRule, FillPattern, FillId, UnknownFillError, PathRecorder, TurtleBatch};
+
+/// The largest number of turns that a spiral can make in either direction
+///
+/// Each turn takes hundreds of steps, so this keeps a spiral from running (almost) forever.
+pub(crate) const MAX_SPIRAL_TURNS: f64 = 100.0;
 
 /// Any distance value (positive or negative)
 pub type Distance = f64;
@@ -24,7 +33,7 @@ pub enum AngleUnit {
 }
 
 impl AngleUnit {
-    fn to_radians(self, angle: Angle) -> Radians {
+    pub(crate) fn to_radians(self, angle: Angle) -> Radians {
         match self {
             AngleUnit::Degrees => Radians::from_degrees_value(angle),
             AngleUnit::Radians => Radians::from_radians_value(angle),
@@ -37,12 +46,63 @@ impl AngleUnit {
             AngleUnit::Radians => angle.to_radians(),
         }
     }
+
+    /// Returns the angle in this unit that is equivalent to `angle` and between 0 (inclusive) and
+    /// one full turn (exclusive)
+    ///
+    /// This is done without converting to radians so that whole numbers of degrees stay exact.
+    fn normalize(self, angle: Angle) -> Angle {
+        let full_turn = match self {
+            AngleUnit::Degrees => 360.0,
+            AngleUnit::Radians => radians::TWO_PI.to_radians(),
+        };
+
+        let angle = angle.rem_euclid(full_turn);
+        // Tiny negative angles can round up to exactly one full turn
+        if angle >= full_turn { 0.0 } else { angle }
+    }
+}
+
+/// The state of a turtle saved by `AsyncTurtle::save`
+#[derive(Debug, Clone, Copy)]
+struct SavedState {
+    position: Point,
+    heading: Radians,
+    pen: PenState,
+    fill_color: Color,
 }
 
+/// The async version of [`Turtle`], for use in programs that already run async code
+///
+/// Each method of [`Turtle`] blocks until the same method of `AsyncTurtle` completes, so the two
+/// types support the same commands. See the [`Turtle`] documentation for what each method does.
+/// Using `AsyncTurtle` directly avoids blocking, so it can be used to control turtles from async
+/// code without starting another runtime just for the turtle crate. Convert between the two
+/// types with `From`/`Into` or [`into_sync()`].
+///
+/// The methods of `AsyncTurtle` must be run within a multi-threaded tokio 0.2 runtime.
+///
+/// ```rust,no_run
+/// use turtle::AsyncTurtle;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut turtle = AsyncTurtle::new().await;
+///     for _ in 0..4 {
+///         turtle.forward(100.0).await;
+///         turtle.right(90.0).await;
+///     }
+/// }
+/// ```
+///
+/// [`Turtle`]: struct.Turtle.html
+/// [`into_sync()`]: #method.into_sync
 pub struct AsyncTurtle {
     client: ProtocolClient,
     id: TurtleId,
     angle_unit: AngleUnit,
+    /// The states saved with `save` that have not been restored yet, most recent last
+    saved_states: Vec<SavedState>,
 }
 
 impl From<Turtle> for AsyncTurtle {
@@ -58,7 +118,7 @@ impl AsyncTurtle {
         // of many programs that use the turtle crate.
         crate::start();
 
-        let client = ProtocolClient::new().await
+        let client = ProtocolClient::new(WindowConfig::default()).await
             .expect("unable to create renderer client");
         Self::with_client(client).await
     }
@@ -68,26 +128,88 @@ impl AsyncTurtle {
         let id = client.create_turtle().await;
         let angle_unit = AngleUnit::Degrees;
 
-        Self {client, id, angle_unit}
+        Self {client, id, angle_unit, saved_states: Vec::new()}
+    }
+
+    pub async fn clone_turtle(&self) -> Self {
+        let client = self.client.split().await;
+        let id = client.clone_turtle(self.id).await;
+
+        // The clone starts with none of the saved states, since they belong to this turtle
+        Self {client, id, angle_unit: self.angle_unit, saved_states: Vec::new()}
     }
 
     pub async fn forward(&mut self, distance: Distance) {
-        self.client.move_forward(self.id, distance).await
+        self.client.move_forward(self.id, distance).await;
+    }
+
+    pub async fn forward_returning(&mut self, distance: Distance) -> Point {
+        match self.client.move_forward(self.id, distance).await {
+            Some(position) => self.client.to_user(position),
+            // The turtle did not move, so its position has to be asked for
+            None => self.position().await,
+        }
+    }
+
+    pub async fn forward_in(&mut self, distance: Distance, duration: Duration) {
+        self.client.move_forward_in(self.id, distance, duration).await
     }
 
     pub async fn backward(&mut self, distance: Distance) {
         // Moving backwards is essentially moving forwards with a negative distance
-        self.client.move_forward(self.id, -distance).await
+        self.client.move_forward(self.id, -distance).await;
+    }
+
+    pub async fn backward_returning(&mut self, distance: Distance) -> Point {
+        self.forward_returning(-distance).await
+    }
+
+    pub async fn forward_bounded(&mut self, distance: Distance) -> Distance {
+        self.client.move_forward_bounded(self.id, distance).await
     }
 
     pub async fn right(&mut self, angle: Angle) {
         let angle = self.angle_unit.to_radians(angle);
-        self.client.rotate_in_place(self.id, angle, RotationDirection::Clockwise).await
+        self.client.rotate_in_place(self.id, angle, RotationDirection::Clockwise).await;
+    }
+
+    pub async fn right_returning(&mut self, angle: Angle) -> Angle {
+        self.rotate_returning(angle, RotationDirection::Clockwise).await
     }
 
     pub async fn left(&mut self, angle: Angle) {
         let angle = self.angle_unit.to_radians(angle);
-        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await
+        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await;
+    }
+
+    pub async fn left_returning(&mut self, angle: Angle) -> Angle {
+        self.rotate_returning(angle, RotationDirection::Counterclockwise).await
+    }
+
+    async fn rotate_returning(&mut self, angle: Angle, direction: RotationDirection) -> Angle {
+        let angle = self.angle_unit.to_radians(angle);
+        match self.client.rotate_in_place(self.id, angle, direction).await {
+            Some(heading) => self.angle_unit.to_angle(heading),
+            // The turtle did not rotate, so its heading has to be asked for
+            None => self.heading().await,
+        }
+    }
+
+    pub async fn batch<F: FnOnce(&mut TurtleBatch)>(&mut self, commands: F) {
+        let mut batch = TurtleBatch::new(self.angle_unit);
+        commands(&mut batch);
+
+        let mut commands = batch.into_commands();
+        for command in &mut commands {
+            if let BatchCommand::MoveTo(position) = command {
+                *position = self.client.to_logical(*position);
+            }
+        }
+        self.client.batch(self.id, commands).await
+    }
+
+    pub async fn sync(&mut self) {
+        self.client.wait_for_animation(self.id).await
     }
 
     pub async fn wait(&mut self, secs: f64) {
@@ -123,10 +245,80 @@ impl AsyncTurtle {
             .await
     }
 
+    pub async fn arc_around<P: Into<Point>>(&mut self, center: P, angle: Angle) {
+        let center = self.client.to_logical(center.into());
+        self.client.circular_arc_around(self.id, center, self.angle_unit.to_radians(angle)).await
+    }
+
+    pub async fn spiral(&mut self, start_radius: Distance, growth: Distance, turns: f64) {
+        assert!(
+            start_radius >= 0.0 && start_radius.is_finite(),
+            "Invalid start radius: {}. The start radius must be greater than or equal to zero",
+            start_radius
+        );
+        assert!(growth.is_finite(), "Invalid growth: {}. The growth must be a finite number", growth);
+        assert!(
+            turns.abs() <= MAX_SPIRAL_TURNS,
+            "Invalid number of turns: {}. The number of turns must be between -{max} and {max}",
+            turns,
+            max = MAX_SPIRAL_TURNS
+        );
+
+        self.client.spiral(self.id, start_radius, growth, turns).await
+    }
+
+    pub async fn bezier<C1, C2, E>(&mut self, control1: C1, control2: C2, end: E)
+        where C1: Into<Point>,
+              C2: Into<Point>,
+              E: Into<Point>,
+    {
+        let control1 = self.client.to_logical(control1.into());
+        let control2 = self.client.to_logical(control2.into());
+        let end = self.client.to_logical(end.into());
+        self.client.cubic_bezier(self.id, control1, control2, end).await
+    }
+
+    pub async fn quadratic_bezier<C, E>(&mut self, control: C, end: E)
+        where C: Into<Point>,
+              E: Into<Point>,
+    {
+        let control = self.client.to_logical(control.into());
+        let end = self.client.to_logical(end.into());
+        self.client.quadratic_bezier(self.id, control, end).await
+    }
+
+    pub async fn polygon(&mut self, sides: u32, side_length: Distance) {
+        assert!(sides >= 3, "Invalid number of sides: {}. A polygon must have at least 3 sides", sides);
+
+        // Turning by the exterior angle after every side adds up to a full turn, so the turtle
+        // ends up back where it started
+        let exterior_angle = radians::TWO_PI / sides as f64;
+        for _ in 0..sides {
+            self.forward(side_length).await;
+            self.client.rotate_in_place(self.id, exterior_angle, RotationDirection::Clockwise).await;
+        }
+    }
+
+    pub async fn filled_polygon<C: Into<Color> + Copy + Debug>(&mut self, sides: u32, side_length: Distance, color: C) {
+        // Check the number of sides before anything is changed
+        assert!(sides >= 3, "Invalid number of sides: {}. A polygon must have at least 3 sides", sides);
+
+        let fill_color = self.fill_color().await;
+        self.set_fill_color(color);
+        self.begin_fill();
+        self.polygon(sides, side_length).await;
+        self.end_fill().await;
+        self.set_fill_color(fill_color);
+    }
+
     pub fn into_sync(self) -> Turtle {
         self.into()
     }
 
+    pub(crate) fn id(&self) -> TurtleId {
+        self.id
+    }
+
     pub async fn speed(&self) -> Speed {
         self.client.turtle_speed(self.id).await
     }
@@ -135,12 +327,82 @@ impl AsyncTurtle {
         self.client.turtle_set_speed(self.id, speed.into())
     }
 
+    pub async fn speed_px_per_sec(&self) -> Distance {
+        match self.client.turtle_movement_rate(self.id).await {
+            Some(rate) => rate,
+            None => self.speed().await.to_px_per_sec(),
+        }
+    }
+
+    pub fn set_speed_px_per_sec(&mut self, px: Distance) {
+        assert!(
+            px > 0.0 && px.is_finite(),
+            "Invalid speed: {} px/sec. The speed must be a finite number greater than zero",
+            px
+        );
+
+        self.client.turtle_set_movement_rate(self.id, Some(px))
+    }
+
+    pub async fn turn_speed_deg_per_sec(&self) -> f64 {
+        let rate = match self.client.turtle_rotation_rate(self.id).await {
+            Some(rate) => rate,
+            None => self.speed().await.to_rad_per_sec(),
+        };
+        rate.to_degrees()
+    }
+
+    pub fn set_turn_speed_deg_per_sec(&mut self, deg: f64) {
+        assert!(
+            deg > 0.0 && deg.is_finite(),
+            "Invalid turn speed: {} deg/sec. The turn speed must be a finite number greater than zero",
+            deg
+        );
+
+        self.client.turtle_set_rotation_rate(self.id, Some(Radians::from_degrees_value(deg)))
+    }
+
+    pub async fn is_tracer_enabled(&self) -> bool {
+        self.client.turtle_tracer(self.id).await
+    }
+
+    pub fn set_tracer(&mut self, enabled: bool) {
+        self.client.turtle_set_tracer(self.id, enabled)
+    }
+
     pub async fn position(&self) -> Point {
-        self.client.turtle_position(self.id).await
+        let position = self.client.turtle_position(self.id).await;
+        self.client.to_user(position)
     }
 
     pub async fn go_to<P: Into<Point>>(&mut self, position: P) {
-        self.client.move_to(self.id, position.into()).await
+        let position = self.client.to_logical(position.into());
+        self.client.move_to(self.id, position).await
+    }
+
+    pub async fn move_by<P: Into<Point>>(&mut self, offset: P) {
+        let position = self.position().await;
+        self.go_to(position + offset.into()).await
+    }
+
+    pub async fn go_to_polar(&mut self, radius: Distance, angle: Angle) {
+        // Angles keep their usual meaning in every coordinate system (counterclockwise is
+        // positive), so the offset is added in logical coordinates
+        let origin = self.client.to_logical(Point::origin());
+        let position = origin + self.polar_to_point(radius, angle);
+        self.client.move_to(self.id, position).await
+    }
+
+    pub async fn move_by_polar(&mut self, radius: Distance, angle: Angle) {
+        let position = self.client.turtle_position(self.id).await;
+        let position = position + self.polar_to_point(radius, angle);
+        self.client.move_to(self.id, position).await
+    }
+
+    /// Returns the point at the given distance from the origin in the direction of the given angle
+    fn polar_to_point(&self, radius: Distance, angle: Angle) -> Point {
+        let angle = self.angle_unit.to_radians(angle);
+        Point {x: radius * angle.cos(), y: radius * angle.sin()}
     }
 
     pub async fn set_x(&mut self, x: f64) {
@@ -177,7 +439,32 @@ impl AsyncTurtle {
         // Formula from: https://stackoverflow.com/a/24234924/551904
         let angle = angle - radians::TWO_PI * ((angle + radians::PI) / radians::TWO_PI).floor();
 
-        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await
+        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await;
+    }
+
+    pub fn teleport<P: Into<Point>>(&mut self, position: P, heading: Angle) {
+        let position = self.client.to_logical(position.into());
+        let heading = self.angle_unit.to_radians(heading);
+        self.client.teleport(self.id, position, heading)
+    }
+
+    pub async fn save(&mut self) {
+        let state = SavedState {
+            position: self.client.turtle_position(self.id).await,
+            heading: self.client.turtle_heading(self.id).await,
+            pen: self.client.turtle_pen_state(self.id).await,
+            fill_color: self.client.turtle_fill_color(self.id).await,
+        };
+        self.saved_states.push(state);
+    }
+
+    pub fn restore(&mut self) {
+        let SavedState {position, heading, pen, fill_color} = self.saved_states.pop()
+            .expect("Unable to restore the turtle: no state was saved. Every call to restore() must come after a call to save()");
+
+        self.client.teleport(self.id, position, heading);
+        self.client.turtle_pen_set_state(self.id, pen);
+        self.client.turtle_set_fill_color(self.id, fill_color);
     }
 
     pub fn is_using_degrees(&self) -> bool {
@@ -196,6 +483,18 @@ impl AsyncTurtle {
         self.angle_unit = AngleUnit::Radians;
     }
 
+    pub fn normalize_angle(&self, angle: Angle) -> Angle {
+        self.angle_unit.normalize(angle)
+    }
+
+    pub fn to_radians(&self, angle: Angle) -> f64 {
+        self.angle_unit.to_radians(angle).to_radians()
+    }
+
+    pub fn to_degrees(&self, angle: Angle) -> f64 {
+        self.angle_unit.to_radians(angle).to_degrees()
+    }
+
     pub async fn is_pen_down(&self) -> bool {
         self.client.turtle_pen_is_enabled(self.id).await
     }
@@ -222,6 +521,41 @@ impl AsyncTurtle {
         self.client.turtle_pen_set_thickness(self.id, thickness)
     }
 
+    pub fn set_pen_size_animated(&mut self, thickness: f64, over_distance: Distance) {
+        assert!(
+            thickness >= 0.0 && thickness.is_finite(),
+            "Invalid thickness: {}. The pen thickness must be greater than or equal to zero",
+            thickness
+        );
+        assert!(
+            over_distance >= 0.0 && over_distance.is_finite(),
+            "Invalid distance: {}. The distance must be greater than or equal to zero",
+            over_distance
+        );
+
+        if over_distance == 0.0 {
+            self.set_pen_size(thickness);
+        } else {
+            self.client.turtle_pen_set_thickness_change(self.id, thickness, over_distance)
+        }
+    }
+
+    pub async fn pen_cap(&self) -> LineCap {
+        self.client.turtle_pen_cap(self.id).await
+    }
+
+    pub fn set_pen_cap<C: Into<LineCap>>(&mut self, cap: C) {
+        self.client.turtle_pen_set_cap(self.id, cap.into())
+    }
+
+    pub async fn pen_join(&self) -> LineJoin {
+        self.client.turtle_pen_join(self.id).await
+    }
+
+    pub fn set_pen_join<J: Into<LineJoin>>(&mut self, join: J) {
+        self.client.turtle_pen_set_join(self.id, join.into())
+    }
+
     pub async fn pen_color(&self) -> Color {
         self.client.turtle_pen_color(self.id).await
     }
@@ -236,6 +570,50 @@ impl AsyncTurtle {
         self.client.turtle_pen_set_color(self.id, pen_color)
     }
 
+    pub async fn pen_gradient(&self) -> Option<(Color, Color)> {
+        self.client.turtle_pen_gradient(self.id).await
+    }
+
+    pub fn set_pen_gradient<S, E>(&mut self, start: S, end: E)
+        where S: Into<Color> + Copy + Debug,
+              E: Into<Color> + Copy + Debug,
+    {
+        let start_color = start.into();
+        assert!(
+            start_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            start
+        );
+        let end_color = end.into();
+        assert!(
+            end_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            end
+        );
+        self.client.turtle_pen_set_gradient(self.id, start_color, end_color)
+    }
+
+    pub async fn pen_state(&self) -> PenState {
+        self.client.turtle_pen_state(self.id).await
+    }
+
+    pub fn set_pen_state(&mut self, state: PenState) {
+        assert!(
+            state.size >= 0.0 && state.size.is_finite(),
+            "Invalid thickness: {}. The pen thickness must be greater than or equal to zero",
+            state.size
+        );
+        for color in Some(state.color).iter().chain(&state.end_color) {
+            assert!(
+                color.is_valid(),
+                "Invalid color: {:?}. See the color module documentation for more information.",
+                color
+            );
+        }
+
+        self.client.turtle_pen_set_state(self.id, state)
+    }
+
     pub async fn fill_color(&self) -> Color {
         self.client.turtle_fill_color(self.id).await
     }
@@ -250,6 +628,49 @@ impl AsyncTurtle {
         self.client.turtle_set_fill_color(self.id, fill_color)
     }
 
+    pub async fn fill_rule(&self) -> FillRule {
+        self.client.turtle_fill_rule(self.id).await
+    }
+
+    pub fn set_fill_rule<R: Into<FillRule>>(&mut self, rule: R) {
+        self.client.turtle_set_fill_rule(self.id, rule.into())
+    }
+
+    pub async fn fill_pattern(&self) -> FillPattern {
+        let pattern = self.client.turtle_fill_pattern(self.id).await;
+        pattern.map_angle(|angle| self.angle_unit.to_angle(Radians::from_radians_value(angle)))
+    }
+
+    pub fn set_fill_pattern(&mut self, pattern: FillPattern) {
+        match pattern {
+            FillPattern::Solid => {},
+            FillPattern::Hatch {angle, spacing} => {
+                assert!(angle.is_finite(), "Invalid angle: {}. The angle of a fill pattern must be a finite number", angle);
+                assert_valid_pattern_spacing(spacing);
+            },
+            FillPattern::CrossHatch {spacing} | FillPattern::Dots {spacing} => {
+                assert_valid_pattern_spacing(spacing);
+            },
+        }
+
+        let pattern = pattern.map_angle(|angle| self.angle_unit.to_radians(angle).to_radians());
+        self.client.turtle_set_fill_pattern(self.id, pattern)
+    }
+
+    pub async fn group_opacity(&self) -> f64 {
+        self.client.turtle_group_opacity(self.id).await
+    }
+
+    pub fn set_group_opacity(&mut self, alpha: f64) {
+        assert!(
+            (0.0..=1.0).contains(&alpha),
+            "Invalid opacity: {}. The opacity must be between 0.0 and 1.0",
+            alpha
+        );
+
+        self.client.turtle_set_group_opacity(self.id, alpha)
+    }
+
     pub async fn is_filling(&self) -> bool {
         self.client.turtle_is_filling(self.id).await
     }
@@ -258,8 +679,63 @@ impl AsyncTurtle {
         self.client.begin_fill(self.id)
     }
 
-    pub fn end_fill(&mut self) {
-        self.client.end_fill(self.id)
+    pub async fn end_fill(&mut self) -> FillId {
+        self.client.end_fill(self.id).await
+    }
+
+    pub async fn set_fill_color_of<C: Into<Color> + Copy + Debug>(&mut self, fill_id: FillId, color: C) -> Result<(), UnknownFillError> {
+        let fill_color = color.into();
+        assert!(
+            fill_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            color
+        );
+        self.client.set_fill_color_of(self.id, fill_id, fill_color).await
+    }
+
+    pub async fn animate_fill_color<F, T>(&mut self, from: F, to: T, secs: f64)
+        where F: Into<Color> + Copy + Debug,
+              T: Into<Color> + Copy + Debug,
+    {
+        let (from_color, to_color) = (from.into(), to.into());
+        assert!(
+            from_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            from
+        );
+        assert!(
+            to_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            to
+        );
+        assert!(
+            secs.is_finite(),
+            "Invalid duration: {}. The duration of an animation must be a finite number of seconds",
+            secs
+        );
+        self.client.turtle_animate_fill_color(self.id, from_color, to_color, secs).await
+    }
+
+    pub fn begin_poly(&mut self) {
+        self.client.turtle_begin_poly(self.id)
+    }
+
+    pub async fn end_poly(&mut self) -> Vec<Point> {
+        let points = self.client.turtle_end_poly(self.id).await;
+        self.client.points_to_user(points)
+    }
+
+    /// Not public since `PathRecorder` blocks, so it can't be used from async code
+    pub(crate) async fn record_path(&mut self) -> PathRecorder {
+        // The recorder needs its own client since it can be used while this turtle is moving
+        let client = self.client.split().await;
+        PathRecorder::start(client, self.id).await
+    }
+
+    pub async fn draw_path(&mut self, path: &[Point]) {
+        for &point in path {
+            self.go_to(point).await;
+        }
     }
 
     pub async fn is_visible(&self) -> bool {
@@ -274,6 +750,63 @@ impl AsyncTurtle {
         self.client.turtle_set_is_visible(self.id, true)
     }
 
+    pub async fn shape(&self) -> TurtleShape {
+        self.client.turtle_shape(self.id).await
+    }
+
+    pub fn set_shape<S: Into<TurtleShape>>(&mut self, shape: S) {
+        let shape = shape.into();
+        if let TurtleShape::Polygon(points) = &shape {
+            assert!(
+                points.len() >= 3,
+                "Invalid turtle shape: a polygon must have at least 3 points, but {} were given",
+                points.len()
+            );
+            assert!(
+                points.iter().all(|p| p.is_finite()),
+                "Invalid turtle shape: all of the points in a polygon must be finite"
+            );
+        }
+
+        self.client.turtle_set_shape(self.id, shape)
+    }
+
+    pub async fn shape_scale(&self) -> f64 {
+        self.client.turtle_shape_scale(self.id).await
+    }
+
+    pub fn set_shape_scale(&mut self, scale: f64) {
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "Invalid shape scale: {}. The scale must be greater than zero",
+            scale
+        );
+        self.client.turtle_set_shape_scale(self.id, scale)
+    }
+
+    pub async fn turtle_color(&self) -> (Color, Color) {
+        self.client.turtle_shape_colors(self.id).await
+    }
+
+    pub fn set_turtle_color<O, F>(&mut self, outline: O, fill: F)
+        where O: Into<Color> + Copy + Debug,
+              F: Into<Color> + Copy + Debug,
+    {
+        let outline_color = outline.into();
+        assert!(
+            outline_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            outline
+        );
+        let fill_color = fill.into();
+        assert!(
+            fill_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            fill
+        );
+        self.client.turtle_set_shape_colors(self.id, outline_color, fill_color)
+    }
+
     pub fn reset(&mut self) {
         self.clear();
         self.client.reset_turtle(self.id);
@@ -283,13 +816,80 @@ impl AsyncTurtle {
         self.client.clear_turtle(self.id)
     }
 
+    pub fn undo(&mut self) {
+        self.client.undo(self.id)
+    }
+
+    pub async fn stamp(&mut self) -> StampId {
+        self.client.stamp(self.id).await
+    }
+
+    pub fn clear_stamp(&mut self, stamp_id: StampId) {
+        self.client.clear_stamp(self.id, stamp_id)
+    }
+
+    pub fn clear_stamps(&mut self) {
+        self.client.clear_stamps(self.id)
+    }
+
+    pub fn write(&mut self, text: &str) {
+        self.write_with(text, DEFAULT_FONT_SIZE, TextAlign::Left)
+    }
+
+    pub fn write_with<A: Into<TextAlign>>(&mut self, text: &str, font_size: f64, align: A) {
+        assert!(
+            font_size > 0.0 && font_size.is_finite(),
+            "Invalid font size: {}. The font size must be greater than zero",
+            font_size
+        );
+
+        self.client.write_text(self.id, text.to_string(), font_size, align.into())
+    }
+
+    pub async fn distance<P: Into<Point>>(&self, target: P) -> Distance {
+        let position = self.position().await;
+        position.distance(target.into())
+    }
+
+    pub async fn within_rect<P: Into<Point>, S: Into<Size>>(&self, top_left: P, size: S) -> bool {
+        let top_left = self.client.to_logical(top_left.into());
+        let Size {width, height} = size.into();
+        // The y-axis points up in logical coordinates, so the rest of the rectangle is below its
+        // top left corner
+        let rect = Rect {
+            min: Point {x: top_left.x, y: top_left.y - height as f64},
+            max: Point {x: top_left.x + width as f64, y: top_left.y},
+        };
+
+        let position = self.client.turtle_position(self.id).await;
+        rect.contains(position)
+    }
+
+    pub async fn within_circle<P: Into<Point>>(&self, center: P, radius: Distance) -> bool {
+        self.distance(center).await <= radius
+    }
+
+    pub async fn towards<P: Into<Point>>(&self, target: P) -> Angle {
+        let target = self.client.to_logical(target.into());
+        let position = self.client.turtle_position(self.id).await;
+
+        // Any heading already points at the turtle's own position, so keep the current one
+        if !target.is_finite() || (target - position).is_not_normal() {
+            return self.heading().await;
+        }
+
+        // Normalize the angle to be between 0 and 360 degrees, just like `heading()`
+        let angle = Radians::from_radians_value((target - position).atan2()).normalized();
+        self.angle_unit.to_angle(angle)
+    }
+
     pub async fn turn_towards<P: Into<Point>>(&mut self, target: P) {
-        let target: Point = target.into();
+        let target = self.client.to_logical(target.into());
         if !target.is_finite() {
             return;
         }
 
-        let position = self.position().await;
+        let position = self.client.turtle_position(self.id).await;
 
         // If the target is (approximately) on the turtle don't turn
         if (target - position).is_not_normal() {
@@ -313,7 +913,18 @@ impl AsyncTurtle {
             angle
         };
 
-        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await
+        self.client.rotate_in_place(self.id, angle, RotationDirection::Counterclockwise).await;
+    }
+
+    pub async fn heading_to_home(&self) -> Angle {
+        // Home is always the center of the drawing, no matter which coordinate system is used
+        let home = self.client.to_user(Point::origin());
+        self.towards(home).await
+    }
+
+    pub async fn face_home(&mut self) {
+        let home = self.client.to_user(Point::origin());
+        self.turn_towards(home).await
     }
 
     pub async fn wait_for_click(&mut self) {
@@ -322,6 +933,11 @@ impl AsyncTurtle {
             event::{PressedState::Pressed, MouseButton::LeftButton},
         };
 
+        // Without `Drawing::send_event()`, nothing can ever send a click to an embedded drawing
+        if cfg!(all(feature = "embedded", not(feature = "unstable"))) {
+            panic!("Cannot wait for a click: events can only be sent to an embedded drawing with `Drawing::send_event()`, which requires the \"unstable\" feature");
+        }
+
         loop {
             if let Some(MouseButton(LeftButton, Pressed)) = self.client.poll_event().await {
                 break;
@@ -332,6 +948,23 @@ impl AsyncTurtle {
         }
     }
 
+    pub async fn wait_for_key(&mut self) -> Key {
+        use crate::{Event, event::PressedState::Pressed};
+
+        loop {
+            if let Some(Event::Key(key, Pressed)) = self.client.poll_event().await {
+                break key;
+            }
+
+            // Sleep for ~1 frame (at 120fps) to avoid pegging the CPU.
+            self.wait(1.0 / 120.0).await;
+        }
+    }
+
+    pub async fn wait_for_key_pressed(&mut self, key: Key) {
+        while self.wait_for_key().await != key {}
+    }
+
     //TODO: If we move to a shared memory architecture, we wouldn't need to make
     // any request here and thus would not need this method at all. We should
     // think things through before making this method public.
@@ -346,3 +979,13 @@ impl AsyncTurtle {
         self.client.debug_turtle(self.id, self.angle_unit).await
     }
 }
+
+/// Panics if the given spacing cannot be used for the lines or dots of a fill pattern
+fn assert_valid_pattern_spacing(spacing: Distance) {
+    assert!(
+        fill::is_valid_pattern_spacing(spacing),
+        "Invalid spacing: {}. The spacing of a fill pattern must be a finite number of at least {}",
+        spacing,
+        fill::MIN_PATTERN_SPACING
+    );
+}
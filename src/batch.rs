@@ -0,0 +1,88 @@
+use crate::{Point, Distance, Angle};
+use crate::async_turtle::AngleUnit;
+use crate::ipc_protocol::{BatchCommand, RotationDirection};
+
+/// Commands collected by [`Turtle::batch()`] so that they can all be run at once
+///
+/// Each method works just like the `Turtle` method with the same name, except that nothing
+/// happens until the closure passed to [`Turtle::batch()`] returns. Angles are in the same units
+/// as the turtle that the batch is for.
+///
+/// [`Turtle::batch()`]: struct.Turtle.html#method.batch
+#[derive(Debug)]
+pub struct TurtleBatch {
+    angle_unit: AngleUnit,
+    commands: Vec<BatchCommand>,
+}
+
+impl TurtleBatch {
+    pub(crate) fn new(angle_unit: AngleUnit) -> Self {
+        Self {angle_unit, commands: Vec::new()}
+    }
+
+    pub(crate) fn into_commands(self) -> Vec<BatchCommand> {
+        self.commands
+    }
+
+    /// Adds a command that moves the turtle forward by the given amount of `distance`
+    ///
+    /// See [`Turtle::forward()`](struct.Turtle.html#method.forward) for more information.
+    pub fn forward(&mut self, distance: Distance) {
+        if distance.is_normal() {
+            self.commands.push(BatchCommand::MoveForward(distance));
+        }
+    }
+
+    /// Adds a command that moves the turtle backwards by the given amount of `distance`
+    ///
+    /// See [`Turtle::backward()`](struct.Turtle.html#method.backward) for more information.
+    pub fn backward(&mut self, distance: Distance) {
+        // Moving backwards is essentially moving forwards with a negative distance
+        self.forward(-distance)
+    }
+
+    /// Adds a command that turns the turtle right (clockwise) by the given angle
+    ///
+    /// See [`Turtle::right()`](struct.Turtle.html#method.right) for more information.
+    pub fn right(&mut self, angle: Angle) {
+        self.rotate(angle, RotationDirection::Clockwise)
+    }
+
+    /// Adds a command that turns the turtle left (counterclockwise) by the given angle
+    ///
+    /// See [`Turtle::left()`](struct.Turtle.html#method.left) for more information.
+    pub fn left(&mut self, angle: Angle) {
+        self.rotate(angle, RotationDirection::Counterclockwise)
+    }
+
+    /// Adds a command that moves the turtle directly to the given position
+    ///
+    /// See [`Turtle::go_to()`](struct.Turtle.html#method.go_to) for more information.
+    pub fn go_to<P: Into<Point>>(&mut self, position: P) {
+        let position = position.into();
+        if position.is_finite() {
+            self.commands.push(BatchCommand::MoveTo(position));
+        }
+    }
+
+    /// Adds a command that picks the pen up so that the turtle stops drawing as it moves
+    ///
+    /// See [`Turtle::pen_up()`](struct.Turtle.html#method.pen_up) for more information.
+    pub fn pen_up(&mut self) {
+        self.commands.push(BatchCommand::SetPenEnabled(false));
+    }
+
+    /// Adds a command that puts the pen down so that the turtle draws as it moves
+    ///
+    /// See [`Turtle::pen_down()`](struct.Turtle.html#method.pen_down) for more information.
+    pub fn pen_down(&mut self) {
+        self.commands.push(BatchCommand::SetPenEnabled(true));
+    }
+
+    fn rotate(&mut self, angle: Angle, direction: RotationDirection) {
+        let angle = self.angle_unit.to_radians(angle);
+        if angle.is_normal() {
+            self.commands.push(BatchCommand::RotateInPlace(angle, direction));
+        }
+    }
+}
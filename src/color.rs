@@ -11,10 +11,10 @@ use crate::rand::{Random, RandomRange};
 /// The maximum allowed value for RGB
 const RGB_MAX_VAL: f64 = 255.0;
 
-/// The minimum allowed value for RGB and HSL values
+/// The minimum allowed value for RGB, HSL and HSV values
 const COLOR_MIN_VALUE: f64 = 0.0;
 
-/// The maximum allowed value for saturation, alpha, or lightness
+/// The maximum allowed value for saturation, alpha, lightness, or value
 const SAL_MAX_VAL: f64 = 1.0;
 
 /// The maximum allowed value for hue
@@ -23,7 +23,7 @@ const HUE_MAX_VAL: f64 = 360.0;
 macro_rules! assert_value_in_range {
     ($name:expr, $value:expr, $min:expr, $max:expr) => {
         assert!(
-            $value >= $min && $value <= $max,
+            ($min..=$max).contains(&$value),
             "{} is not a valid value for {}, values must be between {:.1} and {:.1}.",
             $value,
             $name,
@@ -43,6 +43,26 @@ macro_rules! assert_color_valid {
     };
 }
 
+/// Returns the hue (in degrees, rounded to the nearest degree) of the given RGB values, each
+/// between 0.0 and 1.0. `max` and `min` are the largest and smallest of those values.
+///
+/// The hue is the same in both HSL and HSV. See https://en.wikipedia.org/wiki/HSL_and_HSV
+fn hue_of_rgb(r: f64, g: f64, b: f64, max: f64, min: f64) -> f64 {
+    // Shades of gray have no hue
+    if f64_eq(max, min) {
+        return 0.;
+    }
+
+    let d = max - min;
+    let hue = match max {
+        _ if f64_eq(max, r) => (g - b) / d + if g < b { 6. } else { 0. },
+        _ if f64_eq(max, g) => (b - r) / d + 2.,
+        _ => (r - g) / d + 4.,
+    } * 60.;
+
+    hue.round()
+}
+
 /// Compare f64 values for equality. Floating point numbers are not precise enough to be compared
 /// with `==` reliably. This helper function ensures that the two numbers are within EPSILON
 /// of each other.
@@ -50,6 +70,28 @@ fn f64_eq(left: f64, right: f64) -> bool {
     (left - right).abs() < EPSILON
 }
 
+/// Computes the Levenshtein edit distance between two strings: the minimum number of single
+/// character insertions, deletions, or substitutions needed to turn one string into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    // Only the previous row of the full table is needed to compute the next row
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    let mut row = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == b_char { 0 } else { 1 };
+            row[j + 1] = (prev_row[j] + substitution_cost)
+                .min(prev_row[j + 1] + 1)
+                .min(row[j] + 1);
+        }
+        std::mem::swap(&mut prev_row, &mut row);
+    }
+
+    prev_row[b.len()]
+}
+
 /// A type for representing a color
 ///
 /// # Color names and constants
@@ -71,6 +113,8 @@ fn f64_eq(left: f64, right: f64) -> bool {
 /// turtle.set_pen_color(BLACK);
 /// // You can use any of the supported color names
 /// turtle.set_pen_color("deep lilac");
+/// // Names are not case sensitive and extra spaces are ignored
+/// turtle.set_pen_color(" Deep Lilac ");
 /// ```
 ///
 /// You can also use hexadecimal color strings to get any color you want
@@ -82,6 +126,20 @@ fn f64_eq(left: f64, right: f64) -> bool {
 /// turtle.set_pen_color("#36f");
 /// ```
 ///
+/// The same hexadecimal values can also be written as numbers, which is handy when the value is
+/// calculated in your code. Only the lowest 24 bits (`0xRRGGBB`) are used. Use a tuple to specify
+/// the alpha as well.
+///
+/// ```rust
+/// use turtle::Color;
+/// # let mut turtle = turtle::Turtle::new();
+/// assert_eq!(Color::from(0x3366ff), "#3366ff".into());
+/// turtle.set_pen_color(Color::from(0x3366ff));
+///
+/// // Half transparent
+/// turtle.set_fill_color((0x3366ff, 0.5));
+/// ```
+///
 /// For your convenience, there is a static variable [`COLORS`] that contains
 /// the values of all the color constants listed in [`colors`](colors/index.html)
 /// and another static variable [`COLOR_NAMES`] with each of the color names as
@@ -166,6 +224,8 @@ fn f64_eq(left: f64, right: f64) -> bool {
 /// * [`rgba(red, green, blue, alpha)`]: Similar to `rgb` but also accepts an alpha value
 /// * [`hsl(hue, saturation, lightness)`]: Create from the given hue, saturation, and lightness values with an alpha of 1.0
 /// * [`hsla(hue, saturation, lightness, alpha)`]: Similar to `hsl` but also accepts an alpha value
+/// * [`hsv(hue, saturation, value)`]: Create from the given hue, saturation, and value (brightness) values with an alpha of 1.0
+/// * [`hsva(hue, saturation, value, alpha)`]: Similar to `hsv` but also accepts an alpha value
 ///
 /// These methods provide a concise syntax for creating a new `Color`. If the values passed in are invalid,
 /// the program will exit with an error that lets you know what happened. See the documentation for each
@@ -180,11 +240,15 @@ fn f64_eq(left: f64, right: f64) -> bool {
 /// let white_rgba = Color::rgba(255.0, 255.0, 255.0, 1.0);
 /// let white_hsl = Color::hsl(0.0, 0.0, 1.0);
 /// let white_hsla = Color::hsla(0.0, 0.0, 1.0, 1.0);
+/// let white_hsv = Color::hsv(0.0, 0.0, 1.0);
+/// let white_hsva = Color::hsva(0.0, 0.0, 1.0, 1.0);
 ///
 /// assert_eq!(white_manual, white_rgb);
 /// assert_eq!(white_rgb, white_rgba);
 /// assert_eq!(white_rgba, white_hsl);
 /// assert_eq!(white_hsl, white_hsla);
+/// assert_eq!(white_hsla, white_hsv);
+/// assert_eq!(white_hsv, white_hsva);
 /// ```
 ///
 /// So, you can incorporate these constructors into your turtle code along with
@@ -208,6 +272,8 @@ fn f64_eq(left: f64, right: f64) -> bool {
 /// [`rgba(red, green, blue, alpha)`]: ./struct.Color.html#method.rgba
 /// [`hsl(hue, saturation, lightness)`]: ./struct.Color.html#method.hsl
 /// [`hsla(hue, saturation, lightness, alpha)`]: ./struct.Color.html#method.hsla
+/// [`hsv(hue, saturation, value)`]: ./struct.Color.html#method.hsv
+/// [`hsva(hue, saturation, value, alpha)`]: ./struct.Color.html#method.hsva
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Color {
     /// Value between 0.0 and 255.0
@@ -391,6 +457,81 @@ impl Color {
         Color::rgba(red, green, blue, alpha)
     }
 
+    /// Create a new `Color` from the given [`HSV`] values with alpha set to 1.0.
+    ///
+    /// HSV (also known as HSB) describes a color by its hue, how saturated it is, and its value
+    /// (brightness). Unlike with [`hsl()`], a value of 1.0 is the brightest version of the hue
+    /// rather than white.
+    ///
+    /// The expected value ranges are:
+    ///
+    /// * 0.0 &le; `hue` &le; 360.0
+    /// * 0.0 &le; `saturation` &le; 1.0
+    /// * 0.0 &le; `value` &le; 1.0
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    /// let black: Color = "black".into();
+    /// assert_eq!(black, Color::hsv(0.0, 0.0, 0.0));
+    ///
+    /// let red: Color = "#ff0000".into();
+    /// assert_eq!(red, Color::hsv(0.0, 1.0, 1.0));
+    ///
+    /// let blue: Color = "blue".into();
+    /// assert_eq!(blue, Color::hsv(201.0, 1.0, 0.784));
+    /// ```
+    /// [`HSV`]: https://en.wikipedia.org/wiki/HSL_and_HSV
+    /// [`hsl()`]: ./struct.Color.html#method.hsl
+    pub fn hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        Color::hsva(hue, saturation, value, 1.0)
+    }
+
+    /// Create a new `Color` from the given [`HSV`] values and the given alpha value.
+    ///
+    /// The expected value ranges are:
+    ///
+    /// * 0.0 &le; `hue` &le; 360.0
+    /// * 0.0 &le; `saturation` &le; 1.0
+    /// * 0.0 &le; `value` &le; 1.0
+    /// * 0.0 &le; `alpha` &le; 1.0
+    ///
+    /// ```rust
+    /// use turtle::{Color, colors::BLUE};
+    ///
+    /// let blue = BLUE.with_alpha(0.8);
+    /// let blue_hsva = Color::hsva(201.0, 1.0, 0.784, 0.8);
+    /// assert_eq!(blue, blue_hsva);
+    /// ```
+    /// [`HSV`]: https://en.wikipedia.org/wiki/HSL_and_HSV
+    pub fn hsva(hue: f64, saturation: f64, value: f64, alpha: f64) -> Self {
+        assert_value_in_range!("hue", hue, COLOR_MIN_VALUE, HUE_MAX_VAL);
+        assert_value_in_range!("saturation", saturation, COLOR_MIN_VALUE, SAL_MAX_VAL);
+        assert_value_in_range!("value", value, COLOR_MIN_VALUE, SAL_MAX_VAL);
+        assert_value_in_range!("alpha", alpha, COLOR_MIN_VALUE, SAL_MAX_VAL);
+
+        // The formula from https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB
+        let chroma = value * saturation;
+        // A hue of 360° is the same as 0°
+        let h = (hue / 60.) % 6.;
+        let x = chroma * (1. - (h % 2. - 1.).abs());
+
+        let (r, g, b) = match h {
+            h if h < 1. => (chroma, x, 0.),
+            h if h < 2. => (x, chroma, 0.),
+            h if h < 3. => (0., chroma, x),
+            h if h < 4. => (0., x, chroma),
+            h if h < 5. => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+
+        // Add the same amount to each value to get the right brightness, then change the
+        // percentage into an actual rgb value between 0 and 255
+        let m = value - chroma;
+        let to_rgb = |c: f64| ((c + m) * 255.).round();
+
+        Color::rgba(to_rgb(r), to_rgb(g), to_rgb(b), alpha)
+    }
+
     /// Returns true if the values for each field are valid.
     ///
     /// The documentation above lists the valid range for each field.
@@ -576,6 +717,92 @@ impl Color {
         Color::rgba(r_mod, g_mod, b_mod, a_mod)
     }
 
+    /// Mixes all of the given colors together evenly by averaging each of their red, green, blue,
+    /// and alpha values.
+    ///
+    /// Returns `None` if there are no colors to mix. Unlike [`mix()`], the alpha values of the
+    /// colors do not change how much each color contributes to the result. Use
+    /// [`mix_weighted()`] to make some colors count more than others.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let palette = [Color::rgb(200.0, 0.0, 0.0), Color::rgb(0.0, 100.0, 0.0), Color::rgba(0.0, 0.0, 50.0, 0.25)];
+    /// let average = Color::mix_many(&palette).unwrap();
+    /// assert_eq!(average, Color::rgba(200.0 / 3.0, 100.0 / 3.0, 50.0 / 3.0, 0.75));
+    ///
+    /// assert_eq!(Color::mix_many(&[]), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the colors are not valid (see [`is_valid()`]).
+    ///
+    /// [`mix()`]: #method.mix
+    /// [`mix_weighted()`]: #method.mix_weighted
+    /// [`is_valid()`]: #method.is_valid
+    pub fn mix_many(colors: &[Color]) -> Option<Color> {
+        let weighted: Vec<_> = colors.iter().map(|&color| (color, 1.0)).collect();
+        Self::mix_weighted(&weighted)
+    }
+
+    /// Mixes all of the given colors together, with each color contributing to the result in
+    /// proportion to its weight.
+    ///
+    /// The weights can be any non-negative numbers. Only how they compare to each other matters,
+    /// so weights of `1.0` and `3.0` mix the same way as weights of `0.25` and `0.75`. The red,
+    /// green, blue, and alpha values of the result are each a weighted average of those values in
+    /// the colors. Returns `None` if there are no colors to mix or if all of the weights are zero.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let black = Color::rgb(0.0, 0.0, 0.0);
+    /// let white = Color::rgb(255.0, 255.0, 255.0);
+    /// // Three parts black to one part white
+    /// let gray = Color::mix_weighted(&[(black, 3.0), (white, 1.0)]).unwrap();
+    /// assert_eq!(gray, Color::rgb(63.75, 63.75, 63.75));
+    ///
+    /// assert_eq!(Color::mix_weighted(&[(black, 0.0)]), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the colors are not valid (see [`is_valid()`]) or if any of the weights are
+    /// negative, infinite, or NaN.
+    ///
+    /// [`is_valid()`]: #method.is_valid
+    pub fn mix_weighted(colors: &[(Color, f64)]) -> Option<Color> {
+        let mut total_weight = 0.0;
+        let mut sum = [0.0; 4];
+        for &(color, weight) in colors {
+            assert_color_valid!(color);
+            assert!(
+                weight >= 0.0 && weight.is_finite(),
+                "{} is not a valid value for weight, weights must be greater than or equal to 0.0",
+                weight
+            );
+
+            total_weight += weight;
+            for (total, value) in sum.iter_mut().zip([color.red, color.green, color.blue, color.alpha]) {
+                *total += value * weight;
+            }
+        }
+
+        if total_weight == 0.0 {
+            return None;
+        }
+
+        // Dividing the sums keeps each value within its valid range, except for rounding error
+        let [red, green, blue, alpha] = sum.map(|total| total / total_weight);
+        Some(Color {
+            red: red.clamp(0.0, 255.0),
+            green: green.clamp(0.0, 255.0),
+            blue: blue.clamp(0.0, 255.0),
+            alpha: alpha.clamp(0.0, 1.0),
+        })
+    }
+
     /// Retrieve the hue for this `Color`. The returned value is in degrees
     /// between 0° and 360° that represents its position on the color wheel.
     ///
@@ -615,6 +842,134 @@ impl Color {
         self.to_hsl().2
     }
 
+    /// Retrieve the [`HSV`] values for this `Color` as a `(hue, saturation, value)` tuple.
+    ///
+    /// The hue is in degrees between 0° and 360° and is the same as the one returned by
+    /// [`hue()`]. The saturation and value are between 0.0 and 1.0 (inclusive). Note that the
+    /// saturation in HSV is not the same as the one returned by [`saturation()`], which is the
+    /// saturation in HSL.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let c: Color = "blue".into();
+    /// let (hue, saturation, value) = c.to_hsv();
+    /// assert_eq!(hue, 201.0);
+    /// assert_eq!(saturation, 1.0);
+    /// assert_eq!(value, 0.7843137254901961);
+    /// ```
+    ///
+    /// [`HSV`]: https://en.wikipedia.org/wiki/HSL_and_HSV
+    /// [`hue()`]: ./struct.Color.html#method.hue
+    /// [`saturation()`]: ./struct.Color.html#method.saturation
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        assert_color_valid!(self);
+        let div_color = |c| c / 255.0;
+        let (r, g, b) = (div_color(self.red), div_color(self.green), div_color(self.blue));
+
+        let max = r.max(g.max(b));
+        let min = r.min(g.min(b));
+
+        let saturation = if max > 0. { (max - min) / max } else { 0. };
+
+        (hue_of_rgb(r, g, b, max, min), saturation, max)
+    }
+
+    /// Create a new `Color` with the same saturation, lightness, and alpha as this `Color`, but
+    /// with the given hue. The hue is in degrees between 0° and 360°.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let original = Color::hsla(120.0, 0.3, 0.9, 0.5);
+    /// assert_eq!(original.with_hue(240.0), Color::hsla(240.0, 0.3, 0.9, 0.5));
+    /// ```
+    ///
+    /// Passing a hue outside of that range will result in a panic
+    ///
+    /// ```should_panic
+    /// use turtle::Color;
+    ///
+    /// let red: Color = "red".into();
+    ///
+    /// // This will panic, as 361 is greater than the maximum hue of 360
+    /// let improper = red.with_hue(361.0);
+    /// ```
+    ///
+    /// To change the hue by some amount instead, see [`rotate_hue`].
+    ///
+    /// [`rotate_hue`]: ./struct.Color.html#method.rotate_hue
+    pub fn with_hue(self, hue: f64) -> Self {
+        assert_value_in_range!("hue", hue, COLOR_MIN_VALUE, HUE_MAX_VAL);
+
+        let (_, s, l) = self.to_hsl();
+        Color::hsla(hue, s, l, self.alpha)
+    }
+
+    /// Create a new `Color` with the same hue, lightness, and alpha as this `Color`, but with the
+    /// given saturation. The saturation is a float between 0.0 and 1.0.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let original = Color::hsl(120.0, 0.3, 0.9);
+    /// assert_eq!(original.with_saturation(0.8), Color::hsl(120.0, 0.8, 0.9));
+    /// ```
+    ///
+    /// Passing a saturation outside of that range will result in a panic
+    ///
+    /// ```should_panic
+    /// use turtle::Color;
+    ///
+    /// let original = Color::hsl(120.0, 0.3, 0.9);
+    ///
+    /// // This will panic, as a negative value is less than the minimum saturation of 0.0
+    /// let incorrect = original.with_saturation(-0.2);
+    /// ```
+    ///
+    /// To change the saturation by some amount instead, see [`saturate`] and [`desaturate`].
+    ///
+    /// [`saturate`]: ./struct.Color.html#method.saturate
+    /// [`desaturate`]: ./struct.Color.html#method.desaturate
+    pub fn with_saturation(self, saturation: f64) -> Self {
+        assert_value_in_range!("saturation", saturation, COLOR_MIN_VALUE, SAL_MAX_VAL);
+
+        let (h, _, l) = self.to_hsl();
+        Color::hsla(h, saturation, l, self.alpha)
+    }
+
+    /// Create a new `Color` with the same hue, saturation, and alpha as this `Color`, but with
+    /// the given lightness. The lightness is a float between 0.0 and 1.0.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let original = Color::hsl(158.0, 0.8, 0.3);
+    /// assert_eq!(original.with_lightness(0.6), Color::hsl(158.0, 0.8, 0.6));
+    /// ```
+    ///
+    /// Passing a lightness outside of that range will result in a panic
+    ///
+    /// ```should_panic
+    /// use turtle::Color;
+    ///
+    /// let original = Color::hsl(158.0, 0.8, 0.3);
+    ///
+    /// // This will panic, as 1.1 is greater than the maximum lightness of 1.0
+    /// let incorrect = original.with_lightness(1.1);
+    /// ```
+    ///
+    /// To change the lightness by some amount instead, see [`lighten`] and [`darken`].
+    ///
+    /// [`lighten`]: ./struct.Color.html#method.lighten
+    /// [`darken`]: ./struct.Color.html#method.darken
+    pub fn with_lightness(self, lightness: f64) -> Self {
+        assert_value_in_range!("lightness", lightness, COLOR_MIN_VALUE, SAL_MAX_VAL);
+
+        let (h, s, _) = self.to_hsl();
+        Color::hsla(h, s, lightness, self.alpha)
+    }
+
     /// Changes the hue of a color. Takes a color and a number of degrees
     /// (usually between -360° and 360°), and returns a color with the hue
     /// rotated along the color wheel by that amount.
@@ -1012,12 +1367,137 @@ impl Color {
     /// // The grayscale version simply has a saturation of 0
     /// assert_eq!(original.grayscale(), Color::hsl(200.0, 0.0, 1.0));
     /// ```
+    ///
+    /// Keeping the same lightness does not keep the same *perceived* brightness. For example, pure
+    /// blue and pure yellow both become the same shade of gray. Use [`grayscale_luminance`] if
+    /// you want the gray to look as bright as the original color.
+    ///
     /// [`desaturate`]: ./struct.Color.html#method.desaturate
     /// [achromatic]: https://en.wikipedia.org/wiki/Color_scheme#Achromatic_colors
+    /// [`grayscale_luminance`]: ./struct.Color.html#method.grayscale_luminance
     pub fn grayscale(self) -> Self {
         self.desaturate(1.0)
     }
 
+    /// Convert this `Color` to a gray that looks about as bright as the original `Color`.
+    ///
+    /// Our eyes are much more sensitive to green light than to red light, and much less sensitive
+    /// to blue light than to either of them. This method takes that into account by using the
+    /// [luma] weights from the Rec. 601 standard: `0.299 * red + 0.587 * green + 0.114 * blue`.
+    /// The alpha of the color is left unchanged.
+    ///
+    /// This is usually what you want when converting a colorful drawing to black and white. The
+    /// [`grayscale`] method uses the lightness of the color instead, which gives very different
+    /// results for some colors.
+    ///
+    /// Despite its name, the gray is not based on [`luminance`]. Luma is calculated directly from
+    /// the red, green, and blue values, while relative luminance first undoes the gamma encoding
+    /// of those values and uses different weights. That means that the red value of the gray
+    /// divided by 255.0 is usually not the same as the [`luminance`] of the original color.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// // Pure blue and pure yellow
+    /// let blue = Color::from(0x0000ff);
+    /// let yellow = Color::from(0xffff00);
+    ///
+    /// // Desaturating makes both colors the same gray...
+    /// assert_eq!(blue.grayscale(), yellow.grayscale());
+    ///
+    /// // ...but yellow looks a lot brighter than blue
+    /// assert!(blue.grayscale_luminance().red < 40.0);
+    /// assert!(yellow.grayscale_luminance().red > 220.0);
+    /// ```
+    ///
+    /// [luma]: https://en.wikipedia.org/wiki/Luma_(video)
+    /// [`grayscale`]: ./struct.Color.html#method.grayscale
+    /// [`luminance`]: ./struct.Color.html#method.luminance
+    pub fn grayscale_luminance(self) -> Self {
+        let Color {red, green, blue, alpha} = self;
+        // The weights add up to 1.0, but rounding errors could still push white slightly too high
+        let gray = (0.299 * red + 0.587 * green + 0.114 * blue).min(RGB_MAX_VAL);
+
+        Color {red: gray, green: gray, blue: gray, alpha}
+    }
+
+    /// Returns the [relative luminance] of this `Color`, which is how bright it looks to our eyes.
+    ///
+    /// The returned value is between 0.0 (black) and 1.0 (white). Unlike [`lightness`], this takes
+    /// into account that our eyes are much more sensitive to some colors than to others, so pure
+    /// yellow has a much higher luminance than pure blue. The alpha of the color is ignored.
+    ///
+    /// The luminance is calculated with the Rec. 709 weights from the linear (not gamma encoded)
+    /// red, green, and blue values. This is not the same as the luma used by
+    /// [`grayscale_luminance`], so the two methods usually disagree about how bright a color is.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// assert_eq!(Color::from("black").luminance(), 0.0);
+    /// assert_eq!(Color::from("white").luminance(), 1.0);
+    ///
+    /// let blue = Color::from(0x0000ff);
+    /// let yellow = Color::from(0xffff00);
+    /// assert_eq!(blue.lightness(), yellow.lightness());
+    /// assert!(blue.luminance() < yellow.luminance());
+    /// ```
+    ///
+    /// [relative luminance]: https://en.wikipedia.org/wiki/Relative_luminance
+    /// [`lightness`]: ./struct.Color.html#method.lightness
+    /// [`grayscale_luminance`]: ./struct.Color.html#method.grayscale_luminance
+    pub fn luminance(self) -> f64 {
+        let (r, g, b) = self.to_linear_rgb();
+        // The weights add up to 1.0, but rounding errors could still push white slightly too high
+        (0.2126 * r + 0.7152 * g + 0.0722 * b).min(1.0)
+    }
+
+    /// Returns true if this `Color` is dark enough that white text drawn on top of it is easier
+    /// to read than black text.
+    ///
+    /// This is the case when the [`luminance`] of the color is below about 0.18, the point where
+    /// white and black have the same [contrast ratio] with the color. That is lower than you might
+    /// expect because our eyes are better at telling apart dark colors than bright ones. This is
+    /// always the opposite of [`is_light`]. The alpha of the color is ignored.
+    ///
+    /// ```rust
+    /// use turtle::{Color, colors::{WHITE, BLACK}};
+    ///
+    /// let background: Color = "navy blue".into();
+    /// let text = if background.is_dark() { WHITE } else { BLACK };
+    /// assert_eq!(text, WHITE);
+    ///
+    /// assert!(!Color::from("yellow").is_dark());
+    /// ```
+    ///
+    /// [`luminance`]: ./struct.Color.html#method.luminance
+    /// [contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    /// [`is_light`]: ./struct.Color.html#method.is_light
+    pub fn is_dark(self) -> bool {
+        // The contrast ratio of two colors is (L1 + 0.05) / (L2 + 0.05). Solving for the luminance
+        // that has the same ratio with white (1.0) and black (0.0) gives this threshold.
+        let threshold = (1.05f64 * 0.05).sqrt() - 0.05;
+        self.luminance() < threshold
+    }
+
+    /// Returns true if this `Color` is light enough that black text drawn on top of it is easier
+    /// to read than white text.
+    ///
+    /// This is always the opposite of [`is_dark`], see that method for more details.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// assert!(Color::from("white").is_light());
+    /// assert!(Color::from("light grey").is_light());
+    /// assert!(!Color::from("black").is_light());
+    /// ```
+    ///
+    /// [`is_dark`]: ./struct.Color.html#method.is_dark
+    pub fn is_light(self) -> bool {
+        !self.is_dark()
+    }
+
     /// Create a new `Color` by obtaining the complement (opposite) of this `Color`.
     /// The complement of a color is 180 degrees around the color wheel. For more
     /// information on rotating the hue of a `Color` please see [`rotate_hue`].
@@ -1074,6 +1554,111 @@ impl Color {
         inv_color.mix(self, 1.0)
     }
 
+    /// Returns up to `max` color names from the [`colors`] module that are the most similar to
+    /// `input`, with the closest match first.
+    ///
+    /// This is useful for suggesting a correct color name when a name is misspelled. Names are
+    /// compared by the number of characters that would need to be added, removed, or changed to
+    /// turn `input` into each name. Names that are equally similar are returned in the order they
+    /// appear in [`COLOR_NAMES`]. Upper and lowercase letters are treated as the same.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// assert_eq!(Color::suggest_names("maroom", 1), vec!["maroon"]);
+    /// assert_eq!(Color::suggest_names("Light Bleu", 2), vec!["light blue", "night blue"]);
+    ///
+    /// // Exact matches are always returned first
+    /// assert_eq!(Color::suggest_names("red", 1), vec!["red"]);
+    /// assert!(Color::suggest_names("red", 0).is_empty());
+    /// ```
+    ///
+    /// [`colors`]: colors/index.html
+    /// [`COLOR_NAMES`]: colors/static.COLOR_NAMES.html
+    pub fn suggest_names(input: &str, max: usize) -> Vec<&'static str> {
+        let input = input.to_lowercase();
+
+        let mut names: Vec<(usize, &'static str)> = colors::COLOR_NAMES.iter()
+            .map(|&name| (edit_distance(&input, name), name))
+            .collect();
+        // Stable sort to keep equally similar names in their original order
+        names.sort_by_key(|&(distance, _)| distance);
+
+        names.into_iter().take(max).map(|(_, name)| name).collect()
+    }
+
+    /// Returns how different this `Color` looks from the other given `Color`.
+    ///
+    /// This is the [CIE76] color difference (also known as ΔE\*), which is the straight-line
+    /// distance between the two colors in the [CIELAB] color space. Unlike the distance between
+    /// RGB values, equal distances in CIELAB look roughly equally different to our eyes. A
+    /// difference of about 2.3 is just barely noticeable, and a difference of 0.0 means that the
+    /// colors are the same. The alpha of each color is ignored.
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// let red: Color = "red".into();
+    /// assert_eq!(red.delta_e(red), 0.0);
+    ///
+    /// // Orange looks more like red than blue does
+    /// assert!(red.delta_e("orange") < red.delta_e("blue"));
+    /// ```
+    ///
+    /// [CIE76]: https://en.wikipedia.org/wiki/Color_difference#CIE76
+    /// [CIELAB]: https://en.wikipedia.org/wiki/CIELAB_color_space
+    pub fn delta_e<C: Into<Color>>(self, other: C) -> f64 {
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.into().to_lab();
+
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Returns the name of the color in the [`colors`] module that looks the most like this
+    /// `Color`.
+    ///
+    /// Colors are compared using [`delta_e`], so the alpha of this color is ignored. The
+    /// `"transparent"` color is never returned since it has no color of its own. If multiple
+    /// colors are equally close, the one that appears first in [`COLOR_NAMES`] is returned.
+    ///
+    /// This is useful for snapping any color to the closest color with a name:
+    ///
+    /// ```rust
+    /// use turtle::Color;
+    ///
+    /// assert_eq!(Color::from("cherry red").nearest_named(), "cherry red");
+    /// assert_eq!(Color::rgb(255.0, 255.0, 255.0).nearest_named(), "white");
+    ///
+    /// // A color that isn't exactly one of the named colors
+    /// let snapped: Color = Color::rgb(1.0, 0.0, 1.0).nearest_named().into();
+    /// assert_eq!(snapped, "black".into());
+    /// ```
+    ///
+    /// [`colors`]: colors/index.html
+    /// [`delta_e`]: ./struct.Color.html#method.delta_e
+    /// [`COLOR_NAMES`]: colors/static.COLOR_NAMES.html
+    pub fn nearest_named(self) -> &'static str {
+        let lab = self.to_lab();
+        let distance = |other: &Color| {
+            let (l, a, b) = other.to_lab();
+            (lab.0 - l).powi(2) + (lab.1 - a).powi(2) + (lab.2 - b).powi(2)
+        };
+
+        colors::COLOR_NAMES.iter().zip(colors::COLORS)
+            .filter(|(_, color)| color.alpha > 0.0)
+            .fold(None, |nearest: Option<(&'static str, f64)>, (&name, color)| {
+                let distance = distance(color);
+                match nearest {
+                    // Only replace the nearest color if this one is strictly closer so that the
+                    // first of any equally close colors is kept
+                    Some((_, nearest_distance)) if nearest_distance <= distance => nearest,
+                    _ => Some((name, distance)),
+                }
+            })
+            .map(|(name, _)| name)
+            .expect("bug: there should be at least one named color")
+    }
+
     /// Helper to switch a given RGB `Color` to HSL values.
     ///
     /// Answer adapted from this SO answer (https://stackoverflow.com/a/9493060)
@@ -1087,6 +1672,9 @@ impl Color {
          *  - darken
          *  - lighten
          *  - rotate_hue
+         *  - with_hue
+         *  - with_saturation
+         *  - with_lightness
          *  - hue
          *  - saturation
          *  - lightness
@@ -1098,25 +1686,51 @@ impl Color {
 
         let max = r.max(g.max(b));
         let min = r.min(g.min(b));
-        let hue: f64;
-        let saturation: f64;
         let lightness = (max + min) * 0.5;
 
-        if f64_eq(max, min) {
-            hue = 0.;
-            saturation = 0.;
+        let saturation = if f64_eq(max, min) {
+            0.
         } else {
             let d = max - min;
-            saturation = if lightness > 0.5 { d / (2. - max - min) } else { d / (max + min) };
+            if lightness > 0.5 { d / (2. - max - min) } else { d / (max + min) }
+        };
 
-            hue = match max {
-                _ if f64_eq(max, r) => (g - b) / d + if g < b { 6. } else { 0. },
-                _ if f64_eq(max, g) => (b - r) / d + 2.,
-                _ => (r - g) / d + 4.,
-            } * 60.;
-        }
+        (hue_of_rgb(r, g, b, max, min), saturation, lightness)
+    }
 
-        (hue.round(), saturation, lightness)
+    /// Helper to convert a given RGB `Color` to linear RGB values between 0.0 and 1.0 by undoing
+    /// the gamma correction of sRGB.
+    ///
+    /// The formula comes from https://en.wikipedia.org/wiki/SRGB
+    fn to_linear_rgb(self) -> (f64, f64, f64) {
+        assert_color_valid!(self);
+
+        let linear = |c: f64| {
+            let c = c / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        (linear(self.red), linear(self.green), linear(self.blue))
+    }
+
+    /// Helper to convert a given RGB `Color` to CIELAB values, using the D65 white point.
+    ///
+    /// The formulas come from https://en.wikipedia.org/wiki/SRGB (to get linear RGB and then XYZ)
+    /// and https://en.wikipedia.org/wiki/CIELAB_color_space (to get from XYZ to CIELAB).
+    fn to_lab(self) -> (f64, f64, f64) {
+        let (r, g, b) = self.to_linear_rgb();
+
+        // XYZ relative to the D65 white point, so white is (1, 1, 1)
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.9505;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.0890;
+
+        let f = |t: f64| {
+            const DELTA: f64 = 6.0 / 29.0;
+            if t > DELTA.powi(3) { t.cbrt() } else { t / (3.0 * DELTA * DELTA) + 4.0 / 29.0 }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
     }
 }
 
@@ -1156,6 +1770,22 @@ impl From<[f64; 4]> for Color {
     }
 }
 
+impl From<u32> for Color {
+    fn from(hex: u32) -> Self {
+        Self::from((hex, 1.0))
+    }
+}
+
+impl From<(u32, f64)> for Color {
+    fn from((hex, alpha): (u32, f64)) -> Self {
+        let red = (hex >> 16) & 0xff;
+        let green = (hex >> 8) & 0xff;
+        let blue = hex & 0xff;
+
+        Self::rgba(red as f64, green as f64, blue as f64, alpha)
+    }
+}
+
 impl<'a> From<&'a str> for Color {
     fn from(s: &'a str) -> Self {
         if s.starts_with('#') {
@@ -1178,8 +1808,10 @@ impl<'a> From<&'a str> for Color {
 
             Self::rgb(red, green, blue)
         } else {
-            colors::from_color_name(s)
-                .unwrap_or_else(|| panic!("Unknown color name: {}", s))
+            colors::from_color_name(s).unwrap_or_else(|| match Color::suggest_names(s, 1).first() {
+                Some(suggestion) => panic!("Unknown color name: {}. Did you mean '{}'?", s, suggestion),
+                None => panic!("Unknown color name: {}", s),
+            })
         }
     }
 }
@@ -1234,6 +1866,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lab_of_reference_colors() {
+        let assert_lab = |color: Color, expected: (f64, f64, f64)| {
+            let (l, a, b) = color.to_lab();
+            assert!((l - expected.0).abs() < 0.1 && (a - expected.1).abs() < 0.1 && (b - expected.2).abs() < 0.1,
+                "expected {:?} to be {:?}", (l, a, b), expected);
+        };
+
+        assert_lab(Color::from(0xffffff), (100.0, 0.0, 0.0));
+        assert_lab(Color::from(0x000000), (0.0, 0.0, 0.0));
+        assert_lab(Color::from(0xff0000), (53.24, 80.09, 67.20));
+        assert_lab(Color::from(0x0000ff), (32.30, 79.19, -107.86));
+    }
+
+    #[test]
+    fn nearest_named_colors() {
+        // Every named color is its own nearest color, except when two names have the same color
+        for (&name, &color) in colors::COLOR_NAMES.iter().zip(colors::COLORS).skip(1) {
+            let nearest = color.nearest_named();
+            assert_eq!(Color::from(nearest), color, "{} is nearest to {}", name, nearest);
+        }
+
+        // Transparent black is still black
+        assert_eq!(Color::from("transparent").nearest_named(), "black");
+    }
+
+    #[test]
+    fn grayscale_luminance_weights() {
+        let gray = |color: Color| {
+            let gray = color.grayscale_luminance();
+            assert_eq!(gray.red, gray.green);
+            assert_eq!(gray.green, gray.blue);
+            gray
+        };
+
+        assert_eq!(gray(Color::from(0xffffff)), Color::from(0xffffff));
+        assert_eq!(gray(Color::from(0x000000)), Color::from(0x000000));
+        assert!((gray(Color::from(0xff0000)).red - 0.299 * 255.0).abs() < f64::EPSILON);
+        assert!((gray(Color::from(0x00ff00)).red - 0.587 * 255.0).abs() < f64::EPSILON);
+        assert!((gray(Color::from(0x0000ff)).red - 0.114 * 255.0).abs() < f64::EPSILON);
+        // Alpha is preserved
+        assert_eq!(gray(Color::from((0x336699, 0.3))).alpha, 0.3);
+    }
+
+    #[test]
+    fn hex_numbers_match_hex_strings() {
+        for &(hex, hex_str) in &[(0x3366ff, "#3366ff"), (0x000000, "#000000"), (0xffffff, "#ffffff"), (0xf80a01, "#f80a01")] {
+            assert_eq!(Color::from(hex), Color::from(hex_str));
+        }
+
+        // Only the lowest 24 bits are used
+        assert_eq!(Color::from(0xab3366ff), Color::from(0x3366ff));
+        assert_eq!(Color::from((0x3366ff, 0.25)), Color::from("#3366ff").with_alpha(0.25));
+    }
+
     #[test]
     #[should_panic(expected = "Invalid color literal: #fffff")]
     fn invalid_color1() {
@@ -1248,6 +1935,22 @@ mod tests {
         Color::from("#www");
     }
 
+    #[test]
+    #[should_panic(expected = "Unknown color name: chery red. Did you mean 'cherry red'?")]
+    fn unknown_color_name_suggestion() {
+        let _: Color = "chery red".into();
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("red", ""), 3);
+        assert_eq!(edit_distance("", "red"), 3);
+        assert_eq!(edit_distance("red", "red"), 0);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("maroom", "maroon"), 1);
+    }
+
     #[test]
     fn valid_colors() {
         // Test that all colors in their valid ranges are valid
@@ -1517,6 +2220,24 @@ mod tests {
         assert_eq!(expected, actual_rgba);
     }
 
+    #[test]
+    fn with_hsl_values_keep_the_others() {
+        let color = Color::hsla(200.0, 0.6, 0.4, 0.25);
+        let (h, s, l) = color.to_hsl();
+
+        let hue = color.with_hue(20.0);
+        assert_eq!(hue, Color::hsla(20.0, s, l, 0.25));
+        assert!((hue.hue() - 20.0).abs() < 1.0);
+
+        let saturation = color.with_saturation(1.0);
+        assert_eq!(saturation, Color::hsla(h, 1.0, l, 0.25));
+        assert_eq!(saturation.alpha, 0.25);
+
+        // Only the lightness is left once a color is black
+        assert_eq!(color.with_lightness(0.0), Color::rgba(0.0, 0.0, 0.0, 0.25));
+        assert_eq!(color.with_lightness(l), Color::hsla(h, s, l, 0.25));
+    }
+
     #[test]
     fn ensure_achromatic_hsl() {
         let expected = Color {
@@ -1529,6 +2250,78 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn hsv_of_reference_colors() {
+        let cases = [
+            ((0., 1., 1.), [255., 0., 0.]),
+            ((60., 1., 1.), [255., 255., 0.]),
+            ((120., 1., 0.5), [0., 128., 0.]),
+            ((180., 1., 1.), [0., 255., 255.]),
+            ((240., 0.5, 1.), [128., 128., 255.]),
+            ((300., 1., 1.), [255., 0., 255.]),
+            // 360° wraps around to red
+            ((360., 1., 1.), [255., 0., 0.]),
+            ((0., 0., 0.5), [128., 128., 128.]),
+        ];
+
+        for &((h, s, v), rgb) in &cases {
+            assert_eq!(Color::hsv(h, s, v), Color::from(rgb), "hsv({}, {}, {})", h, s, v);
+        }
+
+        assert_eq!(Color::hsva(60., 1., 1., 0.5), Color::rgba(255., 255., 0., 0.5));
+    }
+
+    #[test]
+    fn to_hsv_round_trip() {
+        assert_eq!(Color::rgb(0., 0., 0.).to_hsv(), (0., 0., 0.));
+        assert_eq!(Color::rgb(255., 255., 255.).to_hsv(), (0., 0., 1.));
+        assert_eq!(Color::rgb(255., 0., 255.).to_hsv(), (300., 1., 1.));
+
+        for &hex in &[0x4169e1, 0xff7f50, 0x2e8b57, 0x808000] {
+            let color = Color::from(hex);
+            let (h, s, v) = color.to_hsv();
+            assert_eq!(h, color.hue());
+
+            // Hues are rounded to the nearest degree, so this may be off by a small amount
+            let converted = Color::hsv(h, s, v);
+            for &(a, b) in &[(color.red, converted.red), (color.green, converted.green), (color.blue, converted.blue)] {
+                assert!((a - b).abs() <= 2., "{:?} != {:?}", color, converted);
+            }
+        }
+    }
+
+    #[test]
+    fn mix_many_averages_every_channel() {
+        let color = Color::rgba(10., 20., 30., 0.5);
+        assert_eq!(Color::mix_many(&[color]), Some(color));
+        assert_eq!(Color::mix_many(&[color; 7]), Some(color));
+
+        let mixed = Color::mix_many(&[Color::rgba(0., 0., 0., 0.), Color::rgba(255., 100., 50., 1.)]);
+        assert_eq!(mixed, Some(Color::rgba(127.5, 50., 25., 0.5)));
+
+        // Equal weights of any size give the same result as mixing evenly
+        let palette = [(Color::from(0x4169e1), 2.), (Color::from(0xff7f50), 2.), (Color::from(0x2e8b57), 2.)];
+        let colors: Vec<_> = palette.iter().map(|&(color, _)| color).collect();
+        assert_eq!(Color::mix_weighted(&palette), Color::mix_many(&colors));
+
+        // Colors with a weight of zero are ignored
+        let mixed = Color::mix_weighted(&[(color, 0.), (Color::rgb(1., 2., 3.), 0.5)]);
+        assert_eq!(mixed, Some(Color::rgb(1., 2., 3.)));
+        assert_eq!(Color::mix_weighted(&[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "-1 is not a valid value for weight, weights must be greater than or equal to 0.0")]
+    fn mix_weighted_negative_weight_panic() {
+        Color::mix_weighted(&[(Color::rgb(0., 0., 0.), 1.), (Color::rgb(1., 1., 1.), -1.)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid Color")]
+    fn mix_many_invalid_color_panic() {
+        Color::mix_many(&[Color {red: 300., green: 0., blue: 0., alpha: 1.}]);
+    }
+
     #[test]
     #[should_panic(expected = "-0.0000001 is not a valid value for red, values must be between 0.0 and 255.0")]
     fn ensure_rgb_invalid_red_negative_panic() {
@@ -1697,6 +2490,54 @@ mod tests {
         Color::hsla(20., 1., 1., 1.0000001);
     }
 
+    #[test]
+    #[should_panic(expected = "-0.0000001 is not a valid value for hue, values must be between 0.0 and 360.0")]
+    fn ensure_hsv_invalid_hue_negative_panic() {
+        Color::hsv(-0.0000001, 1., 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "360.0000001 is not a valid value for hue, values must be between 0.0 and 360.0")]
+    fn ensure_hsv_invalid_hue_positive_panic() {
+        Color::hsv(360.0000001, 1., 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "-0.0000001 is not a valid value for saturation, values must be between 0.0 and 1.0")]
+    fn ensure_hsv_invalid_saturation_negative_panic() {
+        Color::hsv(20., -0.0000001, 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.0000001 is not a valid value for saturation, values must be between 0.0 and 1.0")]
+    fn ensure_hsv_invalid_saturation_positive_panic() {
+        Color::hsv(20., 1.0000001, 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "-0.0000001 is not a valid value for value, values must be between 0.0 and 1.0")]
+    fn ensure_hsv_invalid_value_negative_panic() {
+        Color::hsv(20., 1., -0.0000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.0000001 is not a valid value for value, values must be between 0.0 and 1.0")]
+    fn ensure_hsv_invalid_value_positive_panic() {
+        Color::hsv(20., 1., 1.0000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "-0.0000001 is not a valid value for alpha, values must be between 0.0 and 1.0")]
+    fn ensure_hsva_invalid_alpha_negative_panic() {
+        Color::hsva(20., 1., 1., -0.0000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.0000001 is not a valid value for alpha, values must be between 0.0 and 1.0")]
+    fn ensure_hsva_invalid_alpha_positive_panic() {
+        Color::hsva(20., 1., 1., 1.0000001);
+    }
+
     #[test]
     #[should_panic(expected = "-0.0000001 is not a valid value for red, values must be between 0.0 and 255.0")]
     fn ensure_rgb_arr_invalid_red_negative_panic() {
@@ -2300,4 +3141,23 @@ mod tests {
         };
         let _ = c.rotate_hue(20.);
     }
+
+    #[test]
+    fn is_dark_picks_the_text_color_with_more_contrast() {
+        let contrast = |a: f64, b: f64| (a.max(b) + 0.05) / (a.min(b) + 0.05);
+
+        for &name in colors::COLOR_NAMES {
+            let color: Color = name.into();
+            let luminance = color.luminance();
+            let white_contrast = contrast(luminance, 1.0);
+            let black_contrast = contrast(luminance, 0.0);
+
+            assert_ne!(color.is_dark(), color.is_light());
+            if color.is_dark() {
+                assert!(white_contrast >= black_contrast, "{}", name);
+            } else {
+                assert!(black_contrast >= white_contrast, "{}", name);
+            }
+        }
+    }
 }
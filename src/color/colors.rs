@@ -1,8 +1,9 @@
-//! Supported color names and color constants
+//! Supported color names, color constants, and helpers for creating lists of colors
 //!
 //! Each color constant name is in uppercase in the list below. The color
 //! name you should use to refer to it is in lower case next to the
-//! constant. See the [`Color`] struct for more details.
+//! constant. Names are matched without regard to case or extra whitespace, so
+//! `"Cherry Red"` works too. See the [`Color`] struct for more details.
 //!
 //! ```rust
 //! use turtle::{Turtle, colors::CHERRY_RED};
@@ -15,16 +16,84 @@
 //!
 //! For your convenience, there is a static variable [`COLORS`] that contains
 //! the values of all the color constants listed in this module and another
-//! static variable [`COLOR_NAMES`] with the name of each color as a string.
+//! static variable [`COLOR_NAMES`] with the name of each color as a string. To go through
+//! each color along with its name, use [`all()`].
 //!
 //! This list of colors is based on: <https://xkcd.com/color/rgb>
 //!
+//! To create evenly spaced colors between two colors (e.g. for a chart), use [`gradient()`].
+//!
 //! [`Color`]: ../struct.Color.html
 //! [`COLORS`]: static.COLORS.html
 //! [`COLOR_NAMES`]: static.COLOR_NAMES.html
+//! [`gradient()`]: fn.gradient.html
+//! [`all()`]: fn.all.html
 
 use crate::Color;
 
+/// Returns `steps` evenly spaced colors from `start` to `end`, including both `start` and `end`
+///
+/// The colors are mixed the same way as [`Color::mix()`]. If `steps` is 1, only `start` is
+/// returned. If `steps` is 0, no colors are returned.
+///
+/// ```rust
+/// use turtle::{Color, colors::{self, BLUE}};
+///
+/// let colors = colors::gradient("red", BLUE, 5);
+/// assert_eq!(colors.len(), 5);
+/// assert_eq!(colors[0], "red".into());
+/// assert_eq!(colors[2], Color::from("red").mix(BLUE, 0.5));
+/// assert_eq!(colors[4], BLUE);
+///
+/// assert_eq!(colors::gradient("red", BLUE, 1), vec!["red".into()]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either color is invalid. See the [`Color`] documentation for more information.
+///
+/// [`Color::mix()`]: ../struct.Color.html#method.mix
+/// [`Color`]: ../struct.Color.html
+pub fn gradient<S: Into<Color>, E: Into<Color>>(start: S, end: E, steps: usize) -> Vec<Color> {
+    let start = start.into();
+    let end = end.into();
+    for color in &[start, end] {
+        assert!(color.is_valid(), "{:?} is not a valid Color. Please see color module documentation.", color);
+    }
+
+    if steps == 1 {
+        return vec![start];
+    }
+
+    (0..steps).map(|i| {
+        let t = i as f64 / (steps - 1) as f64;
+        // The weight given to mix() is the amount of `start` in the result
+        start.mix(end, 1.0 - t)
+    }).collect()
+}
+
+/// Returns an iterator over the name and value of every color in this module
+///
+/// The colors are in the same order as [`COLOR_NAMES`] and [`COLORS`]. Each name can be passed
+/// anywhere a color is expected and will result in the color it is paired with.
+///
+/// ```rust
+/// use turtle::{Color, colors};
+///
+/// for (name, color) in colors::all() {
+///     assert_eq!(Color::from(name), color);
+/// }
+///
+/// let (_, color) = colors::all().find(|&(name, _)| name == "cherry red").unwrap();
+/// assert_eq!(color, colors::CHERRY_RED);
+/// ```
+///
+/// [`COLOR_NAMES`]: static.COLOR_NAMES.html
+/// [`COLORS`]: static.COLORS.html
+pub fn all() -> impl Iterator<Item = (&'static str, Color)> {
+    COLOR_NAMES.iter().copied().zip(COLORS.iter().copied())
+}
+
 macro_rules! color_consts {
     ($($name:expr, $id:ident, ($r:expr, $g:expr, $b:expr, $a:expr);)*) => {
         /// A list of the values of all colors in the [`colors`](index.html)
@@ -83,8 +152,11 @@ macro_rules! color_consts {
             pub const $id: Color = Color {red: $r, green: $g, blue: $b, alpha: $a};
         )*
 
+        /// Returns the color with the given name, ignoring case, leading and trailing whitespace,
+        /// and repeated whitespace between words
         pub(crate) fn from_color_name(s: &str) -> Option<Color> {
-            match s {
+            let name = s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+            match name.as_str() {
                 $(
                     $name => Some($id),
                 )*
@@ -102,6 +174,43 @@ mod tests {
     fn colors_lists_size() {
         assert_eq!(COLORS.len(), COLOR_NAMES.len());
     }
+
+    #[test]
+    fn all_pairs_each_name_with_its_color() {
+        assert_eq!(all().count(), COLORS.len());
+        assert_eq!(all().find(|&(name, _)| name == "white"), Some(("white", WHITE)));
+        for (name, color) in all() {
+            assert_eq!(from_color_name(name), Some(color));
+        }
+    }
+
+    #[test]
+    fn color_names_ignore_case_and_whitespace() {
+        assert_eq!(from_color_name("RED"), Some(RED));
+        assert_eq!(from_color_name("Red"), Some(RED));
+        assert_eq!(from_color_name(" red "), Some(RED));
+        assert_eq!(from_color_name("Deep Lilac"), Some(DEEP_LILAC));
+        assert_eq!(from_color_name("\tdeep   LILAC\n"), Some(DEEP_LILAC));
+
+        // Words still need to be separated
+        assert_eq!(from_color_name("deeplilac"), None);
+        assert_eq!(from_color_name(""), None);
+    }
+
+    #[test]
+    fn gradient_includes_both_ends() {
+        let colors = gradient(BLACK, WHITE, 3);
+        assert_eq!(colors, vec![BLACK, Color::rgb(128.0, 128.0, 128.0), WHITE]);
+
+        assert!(gradient(BLACK, WHITE, 0).is_empty());
+        assert_eq!(gradient(RED, BLUE, 1), vec![RED]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid Color")]
+    fn gradient_rejects_invalid_colors() {
+        gradient(Color {red: f64::NAN, green: 0.0, blue: 0.0, alpha: 1.0}, WHITE, 2);
+    }
 }
 
 color_consts! {
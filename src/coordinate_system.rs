@@ -0,0 +1,123 @@
+use serde::{Serialize, Deserialize};
+
+use crate::{Point, Rect, Size};
+
+/// The coordinate system used for the positions of turtles and other points in a drawing
+///
+/// See [`Drawing::set_coordinate_system()`] for more information.
+///
+/// | Coordinate System | Origin `(0, 0)` | x increases | y increases |
+/// | ----------------- | --------------- | ----------- | ----------- |
+/// | `CenterYUp`       | Center          | Rightward   | Upward      |
+/// | `TopLeftYDown`    | Top-left corner | Rightward   | Downward    |
+///
+/// For `TopLeftYDown`, the origin is the top-left corner of the window at the time the coordinate
+/// system was set.
+///
+/// [`Drawing::set_coordinate_system()`]: struct.Drawing.html#method.set_coordinate_system
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoordinateSystem {
+    /// The origin is at the center of the drawing, x increases to the right, and y increases
+    /// upward, just like in math class. This is the default.
+    #[default]
+    CenterYUp,
+    /// The origin is at the top-left corner of the window, x increases to the right, and y
+    /// increases downward, like in many other graphics libraries.
+    TopLeftYDown,
+}
+
+/// A coordinate system along with the position of its origin in logical coordinates
+///
+/// The origin is captured when the coordinate system is set, so converting a point never needs to
+/// ask the server for the current size or center of the drawing.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct CoordinateTransform {
+    pub system: CoordinateSystem,
+    /// The origin `(0, 0)` of the coordinate system in logical coordinates
+    origin: Point,
+}
+
+impl CoordinateTransform {
+    /// Creates a transform for the given coordinate system based on the current center, size,
+    /// and zoom level of the drawing
+    pub fn new(system: CoordinateSystem, center: Point, size: Size, scale: f64) -> Self {
+        let origin = match system {
+            CoordinateSystem::CenterYUp => Point::origin(),
+            // The corner of the window, taking into account how much of the drawing fits in it
+            CoordinateSystem::TopLeftYDown => Point {
+                x: center.x - size.width as f64 / (2.0 * scale),
+                y: center.y + size.height as f64 / (2.0 * scale),
+            },
+        };
+
+        Self {system, origin}
+    }
+
+    /// Converts a point in this coordinate system to logical coordinates, which are always
+    /// `CenterYUp` with the origin at the center of the drawing
+    pub fn to_logical(self, point: Point) -> Point {
+        match self.system {
+            CoordinateSystem::CenterYUp => point,
+            CoordinateSystem::TopLeftYDown => Point {
+                x: self.origin.x + point.x,
+                y: self.origin.y - point.y,
+            },
+        }
+    }
+
+    /// Converts a point in logical coordinates to this coordinate system
+    pub fn to_user(self, point: Point) -> Point {
+        match self.system {
+            CoordinateSystem::CenterYUp => point,
+            CoordinateSystem::TopLeftYDown => Point {
+                x: point.x - self.origin.x,
+                y: self.origin.y - point.y,
+            },
+        }
+    }
+
+    /// Converts a rectangle in logical coordinates to this coordinate system
+    pub fn rect_to_user(self, rect: Rect) -> Rect {
+        let a = self.to_user(rect.min);
+        let b = self.to_user(rect.max);
+        // Flipping the y-axis swaps which corner has the smallest y coordinate
+        Rect {
+            min: Point {x: a.x.min(b.x), y: a.y.min(b.y)},
+            max: Point {x: a.x.max(b.x), y: a.y.max(b.y)},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_left_y_down_round_trip() {
+        let center = Point::origin();
+        let size = Size {width: 800, height: 600};
+        let transform = CoordinateTransform::new(CoordinateSystem::TopLeftYDown, center, size, 1.0);
+
+        assert_eq!(transform.to_logical(Point {x: 0.0, y: 0.0}), Point {x: -400.0, y: 300.0});
+        assert_eq!(transform.to_logical(Point {x: 400.0, y: 300.0}), Point::origin());
+        assert_eq!(transform.to_logical(Point {x: 800.0, y: 600.0}), Point {x: 400.0, y: -300.0});
+
+        let point = Point {x: 12.5, y: -40.0};
+        assert_eq!(transform.to_user(transform.to_logical(point)), point);
+
+        let rect = Rect {min: Point {x: -400.0, y: 200.0}, max: Point {x: -300.0, y: 300.0}};
+        let converted = Rect {min: Point {x: 0.0, y: 0.0}, max: Point {x: 100.0, y: 100.0}};
+        assert_eq!(transform.rect_to_user(rect), converted);
+    }
+
+    #[test]
+    fn top_left_y_down_uses_the_corner_of_the_view() {
+        let center = Point {x: 100.0, y: -50.0};
+        let size = Size {width: 800, height: 600};
+        let transform = CoordinateTransform::new(CoordinateSystem::TopLeftYDown, center, size, 2.0);
+
+        // Zoomed in 2x, only 400x300 logical pixels around the center are visible
+        assert_eq!(transform.to_logical(Point::origin()), Point {x: -100.0, y: 100.0});
+        assert_eq!(transform.to_user(center), Point {x: 200.0, y: 150.0});
+    }
+}
@@ -12,7 +12,7 @@ use std::fmt;
 
 use serde::{Serialize, Deserialize};
 
-use crate::{Color, Point, Speed};
+use crate::{Color, Point, Speed, LineCap, LineJoin, TurtleShape, FillRule, FillPattern};
 
 // None of the struct fields are public because we don't want to expose any
 // internal details. These types are for printing only!
@@ -26,6 +26,15 @@ pub struct Drawing {
     pub(crate) height: u32,
     pub(crate) is_maximized: bool,
     pub(crate) is_fullscreen: bool,
+    pub(crate) window_position: (i32, i32),
+    pub(crate) grid: Option<f64>,
+    pub(crate) show_axes: bool,
+    pub(crate) scale: f64,
+    pub(crate) exit_on_escape: bool,
+    pub(crate) exit_on_close: bool,
+    pub(crate) show_fps: bool,
+    pub(crate) tick_events: bool,
+    pub(crate) antialiasing: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,9 +42,21 @@ pub struct Turtle {
     pub(crate) position: Point,
     pub(crate) heading: DebugAngle,
     pub(crate) speed: Speed,
+    /// In pixels per second
+    pub(crate) movement_rate: Option<f64>,
+    /// Per second
+    pub(crate) rotation_rate: Option<DebugAngle>,
+    pub(crate) tracer: bool,
     pub(crate) pen: Pen,
     pub(crate) fill_color: Color,
+    pub(crate) fill_rule: FillRule,
+    pub(crate) fill_pattern: FillPattern,
+    pub(crate) group_opacity: f64,
     pub(crate) is_visible: bool,
+    pub(crate) shape: TurtleShape,
+    pub(crate) shape_scale: f64,
+    pub(crate) shape_outline_color: Color,
+    pub(crate) shape_fill_color: Color,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -61,4 +82,8 @@ pub struct Pen {
     pub(crate) is_enabled: bool,
     pub(crate) thickness: f64,
     pub(crate) color: Color,
+    pub(crate) end_color: Option<Color>,
+    pub(crate) thickness_change: Option<(f64, f64)>,
+    pub(crate) cap: LineCap,
+    pub(crate) join: LineJoin,
 }
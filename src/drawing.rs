@@ -1,8 +1,9 @@
 use std::fmt::{self, Debug};
 use std::path::Path;
 
-use crate::{Turtle, Color, Point, Size, ExportError};
+use crate::{Turtle, Color, Point, Rect, Size, ExportError, ImportJsonError, Recording, CoordinateSystem};
 use crate::async_drawing::AsyncDrawing;
+use crate::renderer_server::WindowConfig;
 use crate::sync_runtime::block_on;
 
 /// Provides access to properties of the drawing that the turtle is creating
@@ -55,8 +56,6 @@ use crate::sync_runtime::block_on;
 /// [`add_turtle()`]: struct.Drawing.html#method.add_turtle
 pub struct Drawing {
     drawing: AsyncDrawing,
-    //TODO: Remove this field when multiple turtles are supported
-    turtles: usize,
 }
 
 impl Debug for Drawing {
@@ -68,9 +67,7 @@ impl Debug for Drawing {
 
 impl From<AsyncDrawing> for Drawing {
     fn from(drawing: AsyncDrawing) -> Self {
-        //TODO: There is no way to set `turtles` properly here, but that's okay since it is going
-        // to be removed soon.
-        Self {drawing, turtles: 1}
+        Self {drawing}
     }
 }
 
@@ -91,18 +88,29 @@ impl Drawing {
 
         Drawing {
             drawing: block_on(AsyncDrawing::new()),
-            turtles: 0,
         }
     }
 
-    /// Adds a new turtle to this drawing and returns it
+    /// Returns a builder that can be used to configure a new drawing before its window opens
     ///
-    /// The newly created turtle will appear at center of the drawing.
+    /// ```rust
+    /// use turtle::Drawing;
     ///
-    /// # Unstable
+    /// let drawing = Drawing::builder()
+    ///     .title("My Drawing")
+    ///     .background_color("light blue")
+    ///     .build();
+    /// assert_eq!(drawing.background_color(), "light blue".into());
+    /// ```
+    ///
+    /// See [`DrawingBuilder`](struct.DrawingBuilder.html) for all of the available settings.
+    pub fn builder() -> DrawingBuilder {
+        DrawingBuilder::default()
+    }
+
+    /// Adds a new turtle to this drawing and returns it
     ///
-    /// Note that until the multiple turtles feature becomes stable, this method can only be called
-    /// once. Calling it more than once will result in a panic.
+    /// The newly created turtle will appear at center of the drawing.
     ///
     /// # Example
     ///
@@ -121,11 +129,36 @@ impl Drawing {
     ///     turtle.right(1.0);
     /// }
     /// ```
+    ///
+    /// # Multiple Turtles
+    ///
+    /// This method can be called as many times as you want. Each turtle has its own position,
+    /// heading, pen, and everything else. Turtles can be moved to other threads so that they draw
+    /// at the same time. Each turtle only waits for its own movements to finish, so one turtle
+    /// moving slowly will never hold up any of the others.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// use std::thread;
+    ///
+    /// let mut drawing = Drawing::new();
+    /// let mut left = drawing.add_turtle();
+    /// let mut right = drawing.add_turtle();
+    ///
+    /// let handle = thread::spawn(move || {
+    ///     left.set_pen_color("blue");
+    ///     left.left(90.0);
+    ///     left.forward(200.0);
+    /// });
+    ///
+    /// // The other turtle draws at the same time in the main thread
+    /// right.set_pen_color("red");
+    /// right.right(90.0);
+    /// right.forward(200.0);
+    ///
+    /// handle.join().unwrap();
+    /// ```
     pub fn add_turtle(&mut self) -> Turtle {
-        #[cfg(not(feature = "unstable"))]
-        assert!(self.turtles == 0, "Multiple turtles are unstable! Only call `add_turtle` once.");
-        self.turtles += 1;
-
         block_on(self.drawing.add_turtle()).into()
     }
 
@@ -206,6 +239,38 @@ impl Drawing {
         self.drawing.set_background_color(color)
     }
 
+    /// Gradually changes the color of the background to the given color over `secs` seconds.
+    ///
+    /// The background fades smoothly from its current color to the new one. Just like moving a
+    /// turtle, this method does not return until the animation has finished. Turtles on other
+    /// threads can keep drawing while the background changes.
+    ///
+    /// If `secs` is zero or negative, the background color is changed right away, just like with
+    /// [`set_background_color()`].
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     # #[allow(unused)] // Good to show turtle creation here even if unused
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     // Fade to black over two seconds, then back to white over half a second
+    ///     drawing.set_background_color_animated("black", 2.0);
+    ///     drawing.set_background_color_animated("white", 0.5);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the color is invalid or if `secs` is NaN or infinite.
+    ///
+    /// [`set_background_color()`]: struct.Drawing.html#method.set_background_color
+    pub fn set_background_color_animated<C: Into<Color> + Copy + Debug>(&mut self, color: C, secs: f64) {
+        block_on(self.drawing.set_background_color_animated(color, secs))
+    }
+
     /// Returns the center of the drawing
     ///
     /// ```rust
@@ -260,6 +325,49 @@ impl Drawing {
         self.drawing.set_center(center)
     }
 
+    /// Gradually moves the center of the drawing to the given point over `secs` seconds.
+    ///
+    /// The view pans smoothly from the current center to the new one, like a camera moving across
+    /// the drawing. Just like moving a turtle, this method does not return until the animation has
+    /// finished. See [`set_center()`] for more information about what the center is.
+    ///
+    /// If `secs` is zero or negative, the center is changed right away, just like with
+    /// [`set_center()`]. Calling this method with a point that is infinite or NaN does nothing.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     turtle.set_speed("instant");
+    ///     for x in 0..4 {
+    ///         turtle.pen_up();
+    ///         turtle.go_to([x as f64 * 500.0, 0.0]);
+    ///         turtle.pen_down();
+    ///         for _ in 0..4 {
+    ///             turtle.forward(100.0);
+    ///             turtle.right(90.0);
+    ///         }
+    ///     }
+    ///
+    ///     // Pan over to each part of the drawing, one second at a time
+    ///     for x in 0..4 {
+    ///         drawing.set_center_animated([x as f64 * 500.0, 0.0], 1.0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secs` is NaN or infinite.
+    ///
+    /// [`set_center()`]: struct.Drawing.html#method.set_center
+    pub fn set_center_animated<P: Into<Point>>(&mut self, center: P, secs: f64) {
+        block_on(self.drawing.set_center_animated(center, secs))
+    }
+
     /// Resets the center of the drawing back to its initial value
     ///
     /// ```rust
@@ -275,6 +383,72 @@ impl Drawing {
         self.drawing.reset_center()
     }
 
+    /// Returns the zoom level of the view of the drawing
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.scale(), 1.0);
+    /// drawing.set_scale(2.5);
+    /// assert_eq!(drawing.scale(), 2.5);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        block_on(self.drawing.scale())
+    }
+
+    /// Zooms the view of the drawing in or out by the given scale, about the [center] of the
+    /// drawing.
+    ///
+    /// A scale of `2.0` makes everything in the window look twice as large, and a scale of `0.5`
+    /// makes everything look half as large. The default scale is `1.0`. Only the view changes:
+    /// the positions of the turtles, the coordinates of mouse events, and the size of the window
+    /// all stay the same as they would be without zooming. Use [`set_center()`] to choose which
+    /// part of the drawing to zoom into.
+    ///
+    /// The scale is not used when the drawing is saved (e.g. with [`save_svg()`]).
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     for _ in 0..360 {
+    ///         turtle.forward(1.0);
+    ///         turtle.right(1.0);
+    ///     }
+    ///
+    ///     // Zoom in on the right side of the circle
+    ///     drawing.set_center([115.0, 0.0]);
+    ///     drawing.set_scale(4.0);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is not a finite number greater than zero.
+    ///
+    /// [center]: struct.Drawing.html#method.center
+    /// [`set_center()`]: struct.Drawing.html#method.set_center
+    /// [`save_svg()`]: struct.Drawing.html#method.save_svg
+    pub fn set_scale(&mut self, scale: f64) {
+        self.drawing.set_scale(scale)
+    }
+
+    /// Resets the zoom level of the view back to `1.0`
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// drawing.set_scale(3.0);
+    /// drawing.reset_scale();
+    /// assert_eq!(drawing.scale(), 1.0);
+    /// ```
+    pub fn reset_scale(&mut self) {
+        self.drawing.reset_scale()
+    }
+
     /// Returns the size of the drawing
     ///
     /// ```rust
@@ -363,6 +537,130 @@ impl Drawing {
         self.drawing.reset_size()
     }
 
+    /// Returns the smallest rectangle that contains everything that has been drawn so far, or
+    /// `None` if nothing has been drawn
+    ///
+    /// The rectangle includes the thickness of every line and every filled shape and piece of
+    /// text. The turtles themselves are not part of the drawing, so they are not included.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// assert_eq!(drawing.bounds(), None);
+    ///
+    /// turtle.set_pen_size(2.0);
+    /// turtle.go_to([0.0, 100.0]);
+    ///
+    /// let bounds = drawing.bounds().unwrap();
+    /// assert_eq!(bounds.min, Point {x: -1.0, y: -1.0});
+    /// assert_eq!(bounds.max, Point {x: 1.0, y: 101.0});
+    /// ```
+    pub fn bounds(&self) -> Option<Rect> {
+        block_on(self.drawing.bounds())
+    }
+
+    /// Centers and zooms the view so that everything that has been drawn so far fills the
+    /// window, leaving at least `padding` of space around it on every side
+    ///
+    /// This changes the [`center()`] and the [`scale()`] of the drawing, but not the size of the
+    /// window. The padding is in the same units as the drawing, so it is zoomed along with
+    /// everything else. Nothing happens if nothing has been drawn yet.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// drawing.set_size([800, 600]);
+    /// let mut turtle = drawing.add_turtle();
+    /// turtle.set_pen_size(2.0);
+    /// turtle.go_to([0.0, 100.0]);
+    ///
+    /// // The drawing is 102 units high, so with the padding it is 142 units high
+    /// drawing.fit_to_window(20.0);
+    /// assert_eq!(drawing.center(), Point {x: 0.0, y: 50.0});
+    /// assert_eq!(drawing.scale(), 600.0 / 142.0);
+    /// assert_eq!(drawing.size(), Size {width: 800, height: 600});
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `padding` is negative or not finite.
+    ///
+    /// [`center()`]: struct.Drawing.html#method.center
+    /// [`scale()`]: struct.Drawing.html#method.scale
+    pub fn fit_to_window(&mut self, padding: f64) {
+        block_on(self.drawing.fit_to_window(padding))
+    }
+
+    /// Returns the coordinate system used for the points passed to and returned from the methods
+    /// of this drawing and its turtles
+    ///
+    /// See [`set_coordinate_system()`] for more information.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let drawing = Drawing::new();
+    /// assert_eq!(drawing.coordinate_system(), CoordinateSystem::CenterYUp);
+    /// ```
+    ///
+    /// [`set_coordinate_system()`]: struct.Drawing.html#method.set_coordinate_system
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.drawing.coordinate_system()
+    }
+
+    /// Sets the coordinate system used for the points passed to and returned from the methods of
+    /// this drawing and its turtles
+    ///
+    /// By default, the drawing uses [`CoordinateSystem::CenterYUp`]: `(0, 0)` is at the center of
+    /// the drawing and y increases upward. With [`CoordinateSystem::TopLeftYDown`], `(0, 0)` is
+    /// the top-left corner of the drawing and y increases downward, which may be more familiar if
+    /// you have used other graphics libraries before.
+    ///
+    /// The new coordinate system applies to every point, including the ones used with
+    /// [`Turtle::position()`], [`Turtle::go_to()`], [`center()`], [`bounds()`], and the mouse
+    /// position in [`Event::MouseMove`]. Nothing that has already been drawn moves.
+    ///
+    /// The top-left corner is the one of the window at the time this method is called, based on
+    /// the current [`center()`], [`size()`], and zoom level of the drawing. The origin stays at
+    /// that point in the drawing afterwards, so resizing the window or moving its center never
+    /// changes the position of a turtle that hasn't moved. Call this method again to move the
+    /// origin to the new top-left corner of the window. Coordinates are always in the same units
+    /// as the rest of the drawing, no matter how far it is zoomed in.
+    ///
+    /// Only positions are affected. Angles keep their usual meaning, so a heading of 90 degrees
+    /// still points up and [`Turtle::left()`] still turns counterclockwise.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// drawing.set_size((800, 600));
+    /// drawing.set_coordinate_system(CoordinateSystem::TopLeftYDown);
+    ///
+    /// // The turtle starts in the middle of the drawing
+    /// assert_eq!(turtle.position(), Point {x: 400.0, y: 300.0});
+    ///
+    /// // Moving the turtle down the screen increases its y coordinate
+    /// turtle.go_to([400.0, 400.0]);
+    /// assert_eq!(turtle.position(), Point {x: 400.0, y: 400.0});
+    ///
+    /// drawing.set_coordinate_system(CoordinateSystem::CenterYUp);
+    /// assert_eq!(turtle.position(), Point {x: 0.0, y: -100.0});
+    /// ```
+    ///
+    /// [`CoordinateSystem::CenterYUp`]: enum.CoordinateSystem.html#variant.CenterYUp
+    /// [`CoordinateSystem::TopLeftYDown`]: enum.CoordinateSystem.html#variant.TopLeftYDown
+    /// [`Turtle::position()`]: struct.Turtle.html#method.position
+    /// [`Turtle::go_to()`]: struct.Turtle.html#method.go_to
+    /// [`Turtle::left()`]: struct.Turtle.html#method.left
+    /// [`center()`]: struct.Drawing.html#method.center
+    /// [`bounds()`]: struct.Drawing.html#method.bounds
+    /// [`size()`]: struct.Drawing.html#method.size
+    /// [`Event::MouseMove`]: event/enum.Event.html#variant.MouseMove
+    pub fn set_coordinate_system(&mut self, system: CoordinateSystem) {
+        block_on(self.drawing.set_coordinate_system(system))
+    }
+
     /// Returns true if the drawing is currently maximized.
     ///
     /// Note: Even if you set the drawing to the width and height of the current display, it won't
@@ -494,208 +792,2566 @@ impl Drawing {
         self.drawing.exit_fullscreen()
     }
 
-    //TODO(#16): This method is hidden because it hasn't been properly documented
-    #[doc(hidden)]
-    #[cfg(feature = "unstable")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
-    pub fn clear(&mut self) {
-        self.drawing.clear();
+    /// Returns the position of the top left corner of the window on the screen as `(x, y)`.
+    ///
+    /// Unlike the coordinates used for drawing, these are screen coordinates: `(0, 0)` is the top
+    /// left corner of the screen and y increases going *down* the screen. The position is updated
+    /// whenever the window is moved, including when the user drags it with the mouse.
+    ///
+    /// On platforms where the position of a window can't be found (e.g. Wayland), and when there
+    /// is no window, this returns the last position set with [`set_window_position()`], or
+    /// `(0, 0)` if it was never set.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// drawing.set_window_position(100, 50);
+    /// assert_eq!(drawing.window_position(), (100, 50));
+    /// ```
+    ///
+    /// [`set_window_position()`]: struct.Drawing.html#method.set_window_position
+    pub fn window_position(&self) -> (i32, i32) {
+        block_on(self.drawing.window_position())
     }
 
-    /// Returns the next event (if any). Returns `None` if there are no events to be processed at
-    /// the current moment. This **does not** mean that there will never be events later on as the
-    /// application continues to run.
+    /// Moves the window so that its top left corner is at the given position on the screen.
     ///
-    /// See the [`Event` enum](event/enum.Event.html) for the complete list of events that you can
-    /// handle in your applications.
+    /// See [`window_position()`] for more information about the coordinates used. Positions
+    /// outside of the screen, including negative positions, are allowed so that the window can be
+    /// placed on any monitor.
     ///
-    /// # Unstable
+    /// This is a best-effort request: some platforms (e.g. Wayland) don't let programs move their
+    /// windows, in which case this method does nothing to the window.
     ///
-    /// There are still many unanswered questions about the design of the events API in the turtle
-    /// crate. This method may change or be completely removed in the future. There will definitely
-    /// be *some* events API in the future, but it may end up looking different than it does today.
+    /// ```rust,no_run
+    /// use turtle::Drawing;
     ///
-    /// # Example
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     # #[allow(unused)] // Good to show turtle creation here even if unused
+    ///     let mut turtle = drawing.add_turtle();
+    ///     // Place the window near the top left corner of the screen
+    ///     drawing.set_window_position(20, 20);
+    /// }
+    /// ```
     ///
-    /// To use this advanced method, you need to create what is known as an "event loop". An "event
-    /// loop" is any loop that handles the events generated by the application. The reason that it
-    /// is important to create a loop like this is because events in Turtle are "polled". That
-    /// means that every time an event happens, it is placed in a queue (a list) until you ask to
-    /// look at it. If you do not check for events continuously, there is a chance that the events
-    /// you ask for from `poll_event()` will be outdated.
+    /// [`window_position()`]: struct.Drawing.html#method.window_position
+    pub fn set_window_position(&mut self, x: i32, y: i32) {
+        self.drawing.set_window_position(x, y)
+    }
+
+    /// Sets the icon of the window to the image at the location specified by `path`.
     ///
-    /// Even if you do not use every kind of event, you should aim to poll events using this method
-    /// until there are none left to poll. If you do not poll events for a significant amount of
-    /// time during your application, favor the events that come later as you poll since those will
-    /// be the most recent. This can happen if you run many animations between loop iterations.
+    /// The image must be a PNG file. Any PNG color type and bit depth is supported, including
+    /// images with transparency. Square images (e.g. 32x32 or 64x64 pixels) work best, since the
+    /// icon is scaled to whatever size the operating system uses for window icons.
     ///
-    /// See the [`examples/`](https://github.com/sunjay/turtle/raw/master/examples) directory in
-    /// the source code of this library for more examples of how to use events.
+    /// This can be called at any time, even after the window has opened. Returns an error if the
+    /// file could not be read or is not a valid PNG image, in which case the icon is not changed.
     ///
-    /// The following is an example of a basic event loop. Notice that it uses two loops. One to
-    /// move the turtle continuously, and another to handle all the events available at a given
-    /// moment. If it suits your purposes, you may also just use a single loop to handle events
-    /// and move the turtle from within that loop. This example is of a more complex case where
-    /// it really matters that the most recent information is taken into consideration before any
-    /// further movements take place.
+    /// Not every platform lets a program change the icon of its window. On macOS, for example,
+    /// the icon comes from the application bundle instead, so this method only checks that the
+    /// image can be loaded.
     ///
     /// ```rust,no_run
-    /// use turtle::{Drawing, Event, event::{Key, PressedState}};
+    /// use turtle::{Drawing, ExportError};
     ///
-    /// fn main() {
+    /// fn main() -> Result<(), ExportError> {
     ///     let mut drawing = Drawing::new();
+    ///     # #[allow(unused)] // Good to show turtle creation here even if unused
     ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_icon("icon.png")?;
     ///
-    ///     loop {
-    ///         turtle.forward(1.0);
-    ///
-    ///         while let Some(event) = drawing.poll_event() {
-    ///             use Key::{LeftArrow, RightArrow};
-    ///             match event {
-    ///                 Event::Key(key, PressedState::Pressed) => match key {
-    ///                     LeftArrow => {
-    ///                         turtle.set_speed(8);
-    ///                         for _ in 0..20 {
-    ///                             turtle.forward(1.0);
-    ///                             turtle.left(4.5);
-    ///                         }
-    ///                         turtle.set_speed(4);
-    ///                     },
-    ///                     RightArrow => {
-    ///                         turtle.set_speed(8);
-    ///                         for _ in 0..20 {
-    ///                             turtle.forward(1.0);
-    ///                             turtle.right(4.5);
-    ///                         }
-    ///                         turtle.set_speed(4);
-    ///                     },
-    ///                     _ => {},
-    ///                 },
-    ///                 _ => {},
-    ///             }
-    ///         }
-    ///     }
+    ///     Ok(())
     /// }
     /// ```
-    #[cfg(feature = "unstable")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
-    pub fn poll_event(&mut self) -> Option<crate::Event> {
-        block_on(self.drawing.poll_event())
+    pub fn set_icon<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ExportError> {
+        block_on(self.drawing.set_icon(path))
     }
 
-    /// Saves the current drawings in SVG format at the location specified by `path`.
+    /// Returns the spacing between the lines of the coordinate grid, or `None` if no grid is
+    /// being shown.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.grid(), None);
+    /// drawing.set_grid(Some(50.0));
+    /// assert_eq!(drawing.grid(), Some(50.0));
+    /// ```
+    ///
+    /// See [`set_grid()`](struct.Drawing.html#method.set_grid) for more information.
+    pub fn grid(&self) -> Option<f64> {
+        block_on(self.drawing.grid())
+    }
+
+    /// Shows a grid of faint lines behind the drawing, with the given spacing between each line.
+    /// Passing `None` hides the grid again.
+    ///
+    /// The grid lines are placed at every multiple of `spacing` along the x and y axes, so there
+    /// is always a line through the origin `(0, 0)`. Use [`show_axes()`] to also show the axes
+    /// themselves, labelled with the coordinates of the grid lines. The grid and axes are drawn
+    /// beneath everything that the turtles draw and always fill the entire window, even when the
+    /// window is resized or the [center] of the drawing changes.
+    ///
+    /// The grid and axes are only shown in the window. They are not included in the images
+    /// created by [`save_svg()`] or [`save_png()`].
     ///
     /// ```rust,no_run
-    /// use turtle::{Drawing, Turtle, Color, ExportError};
+    /// use turtle::Drawing;
     ///
-    /// fn main() -> Result<(), ExportError> {
+    /// fn main() {
     ///     let mut drawing = Drawing::new();
     ///     let mut turtle = drawing.add_turtle();
-    ///     drawing.set_background_color("pink");
+    ///     drawing.set_grid(Some(50.0));
+    ///     drawing.show_axes(true);
     ///
-    ///     for i in 0..36 {
-    ///         let base_color: Color = if i % 2 == 0 {
-    ///             "red".into()
-    ///         } else {
-    ///             "white".into()
-    ///         };
+    ///     // Move the turtle to (100, 50) to see where that is on the grid
+    ///     turtle.go_to([100.0, 50.0]);
+    /// }
+    /// ```
     ///
-    ///         turtle.set_fill_color(base_color.with_alpha(1.0 - i as f64 / 54.0));
-    ///         turtle.begin_fill();
-    ///         square(&mut turtle);
-    ///         turtle.end_fill();
-    ///         turtle.right(10.0);
-    ///    }
+    /// # Panics
     ///
-    ///    turtle.hide();
-    ///    drawing.save_svg("squares.svg")?;
+    /// Panics if the spacing is not a finite number greater than zero.
     ///
-    ///    Ok(())
+    /// [`show_axes()`]: struct.Drawing.html#method.show_axes
+    /// [center]: struct.Drawing.html#method.set_center
+    /// [`save_svg()`]: struct.Drawing.html#method.save_svg
+    /// [`save_png()`]: struct.Drawing.html#method.save_png
+    pub fn set_grid(&mut self, spacing: Option<f64>) {
+        self.drawing.set_grid(spacing)
+    }
+
+    /// Returns true if the x and y axes are being shown behind the drawing.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.is_showing_axes(), false);
+    /// drawing.show_axes(true);
+    /// assert_eq!(drawing.is_showing_axes(), true);
+    /// ```
+    pub fn is_showing_axes(&self) -> bool {
+        block_on(self.drawing.is_showing_axes())
+    }
+
+    /// Shows or hides the x and y axes behind the drawing.
+    ///
+    /// The axes are labelled at regular intervals so that you can tell the coordinates of any
+    /// point in the drawing. If a grid is being shown with [`set_grid()`], the labels line up with
+    /// the grid lines. Just like the grid, the axes are not included when the drawing is saved.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     # #[allow(unused)] // Good to show turtle creation here even if unused
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.show_axes(true);
     /// }
+    /// ```
     ///
-    /// fn square(turtle: &mut Turtle) {
-    ///     for _ in 0..4 {
-    ///         turtle.forward(200.0);
-    ///         turtle.right(90.0);
+    /// [`set_grid()`]: struct.Drawing.html#method.set_grid
+    pub fn show_axes(&mut self, show: bool) {
+        self.drawing.show_axes(show)
+    }
+
+    /// Returns true if the frame rate is being shown in the title of the window.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.is_showing_fps(), false);
+    /// drawing.show_fps(true);
+    /// assert_eq!(drawing.is_showing_fps(), true);
+    /// ```
+    pub fn is_showing_fps(&self) -> bool {
+        block_on(self.drawing.is_showing_fps())
+    }
+
+    /// Shows or hides the frame rate (in frames per second) after the title of the window.
+    ///
+    /// This is a debugging aid for finding out why a drawing feels slow. The frame rate is
+    /// measured about once per second while the window is being redrawn. The window is only
+    /// redrawn when something changes, so a drawing that isn't changing has a low frame rate. The
+    /// window will never be redrawn more than 60 times per second. The frame rate is not part of
+    /// the [`title()`].
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     drawing.show_fps(true);
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     for _ in 0..360 {
+    ///         turtle.forward(3.0);
+    ///         turtle.right(1.0);
     ///     }
     /// }
     /// ```
     ///
-    /// This will produce the following image in the current directory under the name `squares.svg`:
+    /// [`title()`]: struct.Drawing.html#method.title
+    pub fn show_fps(&mut self, show: bool) {
+        self.drawing.show_fps(show)
+    }
+
+    /// Returns true if the edges of shapes are smoothed (antialiased), both in the window and in
+    /// saved images.
     ///
-    /// ![squares](https://raw.githubusercontent.com/sunjay/turtle/master/docs/assets/images/docs/squares.svg?sanitize=true)
-    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
-        block_on(self.drawing.save_svg(path))
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.antialiasing(), true);
+    /// drawing.set_antialiasing(false);
+    /// assert_eq!(drawing.antialiasing(), false);
+    /// ```
+    pub fn antialiasing(&self) -> bool {
+        block_on(self.drawing.antialiasing())
     }
 
-    /// Destroys underlying window and drops self.
+    /// Sets whether the edges of shapes are smoothed (antialiased).
     ///
-    /// Subsequent commands to turtle, created using [`Drawing::add_turtle`], might panic.
+    /// This is enabled by default, which makes lines and curves look smooth by blending the
+    /// pixels along their edges with whatever is behind them. Disable it for pixel art or a retro
+    /// look, where every pixel should be exactly one color. This affects the window as well as
+    /// [`save_png()`], [`save_png_scaled()`], and [`screenshot()`]. Images saved with
+    /// [`save_svg()`] ask the program showing them to draw crisp edges instead. Images saved with
+    /// [`save_pdf()`] are not affected.
+    ///
+    /// Without antialiasing, the window is drawn on the CPU instead of the GPU, so very large
+    /// drawings may take longer to show up.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, ExportError};
+    ///
+    /// fn main() -> Result<(), ExportError> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_antialiasing(false);
+    ///
+    ///     turtle.set_pen_size(4.0);
+    ///     turtle.right(30.0);
+    ///     turtle.forward(100.0);
+    ///
+    ///     // The edges of the line are made of whole pixels, both in the window and in the image
+    ///     drawing.save_png("pixels.png")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`save_png()`]: struct.Drawing.html#method.save_png
+    /// [`save_png_scaled()`]: struct.Drawing.html#method.save_png_scaled
+    /// [`screenshot()`]: struct.Drawing.html#method.screenshot
+    /// [`save_svg()`]: struct.Drawing.html#method.save_svg
+    /// [`save_pdf()`]: struct.Drawing.html#method.save_pdf
+    pub fn set_antialiasing(&mut self, enabled: bool) {
+        self.drawing.set_antialiasing(enabled)
+    }
+
+    /// Returns true if the window will close when the Esc key is pressed.
     ///
     /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.exit_on_escape(), true);
+    /// drawing.set_exit_on_escape(false);
+    /// assert_eq!(drawing.exit_on_escape(), false);
+    /// ```
+    pub fn exit_on_escape(&self) -> bool {
+        block_on(self.drawing.exit_on_escape())
+    }
+
+    /// Sets whether pressing (and releasing) the Esc key closes the window.
+    ///
+    /// This is enabled by default. Disable it if your program uses the Esc key for something else
+    /// or if it shouldn't be easy to quit, e.g. in a kiosk. Once it is disabled, releasing the Esc
+    /// key is sent as an event just like releasing any other key.
+    ///
+    /// ```rust,no_run
     /// use turtle::Drawing;
     ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     drawing.set_exit_on_escape(false);
+    ///     # #[allow(unused)] // Good to show turtle creation here even if unused
+    ///     let mut turtle = drawing.add_turtle();
+    /// }
+    /// ```
+    pub fn set_exit_on_escape(&mut self, enabled: bool) {
+        self.drawing.set_exit_on_escape(enabled)
+    }
+
+    /// Returns true if the window will close when the user closes it, e.g. with its close button.
+    ///
+    /// ```rust
+    /// # use turtle::*;
     /// let mut drawing = Drawing::new();
-    /// let mut turtle = drawing.add_turtle();
+    /// assert_eq!(drawing.exit_on_close(), true);
+    /// drawing.set_exit_on_close(false);
+    /// assert_eq!(drawing.exit_on_close(), false);
+    /// ```
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn exit_on_close(&self) -> bool {
+        block_on(self.drawing.exit_on_close())
+    }
+
+    /// Sets whether the window closes when the user closes it, e.g. with its close button.
     ///
-    /// turtle.set_speed(2);
-    /// turtle.right(90.0);
-    /// turtle.forward(50.0);
+    /// This is enabled by default. When it is disabled, the window stays open and an
+    /// [`Event::CloseRequested`] is sent instead. That lets your program do something before
+    /// quitting (e.g. save the drawing) or ask whether the user really wants to quit. Use
+    /// [`destroy()`] to close the window once your program is ready.
     ///
-    /// // close window
-    /// drawing.destroy();
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Event};
     ///
-    /// // this will panic!
-    /// // turtle.forward(100.0)
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     drawing.set_exit_on_close(false);
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     loop {
+    ///         turtle.forward(1.0);
+    ///         turtle.right(1.0);
+    ///
+    ///         while let Some(event) = drawing.poll_event() {
+    ///             if event == Event::CloseRequested {
+    ///                 drawing.save_svg("drawing.svg").expect("unable to save the drawing");
+    ///                 drawing.destroy();
+    ///                 return;
+    ///             }
+    ///         }
+    ///     }
+    /// }
     /// ```
+    ///
+    /// [`Event::CloseRequested`]: event/enum.Event.html#variant.CloseRequested
+    /// [`destroy()`]: struct.Drawing.html#method.destroy
     #[cfg(feature = "unstable")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
-    pub fn destroy(self) {
-        self.drawing.destroy();
+    pub fn set_exit_on_close(&mut self, enabled: bool) {
+        self.drawing.set_exit_on_close(enabled)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns true if an [`Event::Tick`] is sent for every frame drawn in the window.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// assert_eq!(drawing.tick_events(), false);
+    /// drawing.set_tick_events(true);
+    /// assert_eq!(drawing.tick_events(), true);
+    /// ```
+    ///
+    /// [`Event::Tick`]: event/enum.Event.html#variant.Tick
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn tick_events(&self) -> bool {
+        block_on(self.drawing.tick_events())
+    }
 
-    #[test]
-    #[should_panic(expected = "Invalid color: Color { red: NaN, green: 0.0, blue: 0.0, alpha: 0.0 }. See the color module documentation for more information.")]
-    fn rejects_invalid_background_color() {
-        let mut drawing = Drawing::new();
-        drawing.set_background_color(Color {
-            red: ::std::f64::NAN,
-            green: 0.0,
-            blue: 0.0,
-            alpha: 0.0,
-        });
+    /// Sets whether an [`Event::Tick`] is sent for every frame drawn in the window.
+    ///
+    /// This is disabled by default. Normally, the window is only redrawn when something changes.
+    /// While tick events are enabled, the window keeps drawing frames (at most 60 per second) even
+    /// if nothing is changing and sends a tick event after each one. Each event contains the amount
+    /// of time that has passed since the previous tick. That makes it easy to write simulations
+    /// and games that move things at the same speed no matter how fast the computer is, without
+    /// having to keep checking the time yourself.
+    ///
+    /// Tick events are delivered on a best-effort basis. If your program hasn't received the
+    /// previous tick yet, no new tick is sent. Instead, the next tick that is sent covers all of the
+    /// time since the previous one. Ticks are never sent when there is no window (e.g. in headless
+    /// mode).
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Event};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     drawing.set_tick_events(true);
+    ///     let mut turtle = drawing.add_turtle();
+    ///     turtle.set_speed("instant");
+    ///
+    ///     loop {
+    ///         if let Some(Event::Tick {dt}) = drawing.poll_event() {
+    ///             // Move 100 steps and turn 90 degrees every second
+    ///             turtle.forward(100.0 * dt.as_secs_f64());
+    ///             turtle.right(90.0 * dt.as_secs_f64());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Event::Tick`]: event/enum.Event.html#variant.Tick
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn set_tick_events(&mut self, enabled: bool) {
+        self.drawing.set_tick_events(enabled)
     }
 
-    #[test]
-    #[should_panic(expected = "The size of the drawing must be non-zero")]
-    fn rejects_size_zero() {
-        let mut drawing = Drawing::new();
+    /// Starts recording the most recent positions of the given turtle, keeping up to `capacity`
+    /// positions at a time.
+    ///
+    /// A position is recorded every time the turtle finishes a movement (e.g. with
+    /// [`forward()`](struct.Turtle.html#method.forward) or
+    /// [`go_to()`](struct.Turtle.html#method.go_to)). Once `capacity` positions have been
+    /// recorded, the oldest position is forgotten each time a new one is added. Use
+    /// [`position_history()`](#method.position_history) to get the recorded positions. This is
+    /// useful for drawing trails that follow a turtle around without having to ask for its position
+    /// after every movement.
+    ///
+    /// Recording is disabled by default. Calling this method always forgets any positions that
+    /// were already recorded, and a `capacity` of zero turns recording off again.
+    ///
+    /// Each recorded position takes 16 bytes of memory, so even a capacity of many thousands of
+    /// positions is fairly cheap. Keep in mind that the memory for the positions is kept as long
+    /// as recording is enabled.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// drawing.set_track_history(&turtle, 2);
+    ///
+    /// turtle.forward(100.0);
+    /// turtle.right(90.0);
+    /// turtle.forward(100.0);
+    /// turtle.go_to([0.0, 0.0]);
+    ///
+    /// // Only the two most recent positions are kept
+    /// let history: Vec<Point> = drawing.position_history(&turtle)
+    ///     .into_iter()
+    ///     .map(|pos| pos.round())
+    ///     .collect();
+    /// assert_eq!(history, vec![Point {x: 100.0, y: 100.0}, Point {x: 0.0, y: 0.0}]);
+    /// ```
+    pub fn set_track_history(&mut self, turtle: &Turtle, capacity: usize) {
+        self.drawing.set_track_history(turtle.as_async(), capacity)
+    }
 
-        drawing.set_size([0, 0]);
+    /// Returns the most recent positions of the given turtle, from oldest to newest.
+    ///
+    /// The list will always be empty unless recording positions has been enabled with
+    /// [`set_track_history()`](#method.set_track_history). See that method for more information.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// turtle.forward(100.0);
+    /// assert!(drawing.position_history(&turtle).is_empty());
+    ///
+    /// drawing.set_track_history(&turtle, 10);
+    /// turtle.forward(100.0);
+    /// assert_eq!(drawing.position_history(&turtle).len(), 1);
+    /// ```
+    pub fn position_history(&self, turtle: &Turtle) -> Vec<Point> {
+        block_on(self.drawing.position_history(turtle.as_async()))
     }
 
-    #[test]
-    fn ignores_center_nan_inf() {
-        let center = Point {x: 5.0, y: 10.0};
+    /// Starts recording everything that is done to this drawing and to each of its turtles.
+    ///
+    /// Use [`stop_recording()`] to get a [`Recording`] of everything that happened after this
+    /// method was called. If a recording was already in progress, anything recorded so far is
+    /// discarded and recording starts over.
+    ///
+    /// See [`replay()`] for an example.
+    ///
+    /// [`stop_recording()`]: #method.stop_recording
+    /// [`Recording`]: struct.Recording.html
+    /// [`replay()`]: #method.replay
+    pub fn start_recording(&mut self) {
+        self.drawing.start_recording()
+    }
 
-        let mut drawing = Drawing::new();
-        drawing.set_center(center);
+    /// Stops recording and returns everything that was recorded since [`start_recording()`] was
+    /// called.
+    ///
+    /// If nothing was being recorded, an empty [`Recording`] is returned.
+    ///
+    /// [`start_recording()`]: #method.start_recording
+    /// [`Recording`]: struct.Recording.html
+    pub fn stop_recording(&mut self) -> Recording {
+        self.drawing.stop_recording()
+    }
 
-        drawing.set_center([::std::f64::NAN, 0.0]);
-        drawing.set_center([0.0, ::std::f64::NAN]);
-        drawing.set_center([::std::f64::NAN, ::std::f64::NAN]);
-        drawing.set_center([::std::f64::INFINITY, 0.0]);
-        drawing.set_center([0.0, ::std::f64::INFINITY]);
-        drawing.set_center([::std::f64::INFINITY, ::std::f64::INFINITY]);
-        drawing.set_center([-::std::f64::INFINITY, 0.0]);
-        drawing.set_center([0.0, -::std::f64::INFINITY]);
-        drawing.set_center([-::std::f64::INFINITY, -::std::f64::INFINITY]);
+    /// Runs every command in the given recording again, in the same order that they were
+    /// recorded.
+    ///
+    /// A new turtle is added to this drawing for each turtle that was used in the recording, so
+    /// the turtles that are already in the drawing are not affected. Each new turtle starts with
+    /// the default settings, so the replay looks the same as the original drawing if recording
+    /// started before any of its turtles were used.
+    ///
+    /// Movements and rotations are animated at the speed that each turtle had when they were
+    /// recorded. This method returns once the entire recording has been replayed.
+    ///
+    /// The stamps and fills made during the replay get new [`StampId`] and [`FillId`] values.
+    /// Recorded commands that clear a stamp or change the color of a fill use the new IDs, so they
+    /// change the same shapes that they did when they were recorded. Commands that refer to a
+    /// stamp or fill made before the recording started are skipped, since that stamp or fill is
+    /// not part of the replay.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Recording};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     drawing.start_recording();
+    ///     for _ in 0..4 {
+    ///         turtle.forward(100.0);
+    ///         turtle.right(90.0);
+    ///     }
+    ///     let recording = drawing.stop_recording();
+    ///
+    ///     // Recordings can be saved and loaded with serde
+    ///     let json = serde_json::to_string(&recording).unwrap();
+    ///     let recording: Recording = serde_json::from_str(&json).unwrap();
+    ///
+    ///     // Draw the same square again with a new turtle
+    ///     drawing.replay(&recording);
+    /// }
+    /// ```
+    ///
+    /// [`StampId`]: struct.StampId.html
+    /// [`FillId`]: struct.FillId.html
+    pub fn replay(&mut self, recording: &Recording) {
+        block_on(self.drawing.replay(recording))
+    }
 
-        assert_eq!(drawing.center(), center);
+    /// Delete everything that has been drawn by every turtle.
+    ///
+    /// This is like calling [`Turtle::clear()`] on every turtle at once. The turtles themselves
+    /// are not affected: their position, heading, speed, pen settings, etc. all stay the same. The
+    /// settings of the drawing (e.g. its background color, size, and title) stay the same too.
+    ///
+    /// Since the drawings no longer exist, they can't be brought back with [`Turtle::undo()`].
+    /// Any stamps are removed and any shape that was being filled stops being filled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle1 = drawing.add_turtle();
+    ///     let mut turtle2 = drawing.add_turtle();
+    ///
+    ///     turtle1.forward(100.0);
+    ///     turtle2.right(90.0);
+    ///     turtle2.forward(100.0);
+    ///
+    ///     turtle1.wait_for_click();
+    ///     // Both lines are removed, but both turtles stay where they are
+    ///     drawing.clear();
+    /// }
+    /// ```
+    ///
+    /// [`Turtle::clear()`]: struct.Turtle.html#method.clear
+    /// [`Turtle::undo()`]: struct.Turtle.html#method.undo
+    pub fn clear(&mut self) {
+        self.drawing.clear();
+    }
+
+    /// Redraw the window right away and wait until the new frame is on the screen.
+    ///
+    /// The window is normally redrawn automatically whenever something changes, so this is mostly
+    /// useful when a turtle's [tracer] is disabled. Turtles with a disabled tracer do not redraw
+    /// the window as they draw, so you can use this method to choose exactly when their progress
+    /// is shown.
+    ///
+    /// If there is no window (e.g. when the drawing is headless), this returns immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     turtle.set_tracer(false);
+    ///
+    ///     for i in 0..36 {
+    ///         // Draw a whole circle at a time without showing each line
+    ///         for _ in 0..360 {
+    ///             turtle.forward(2.0);
+    ///             turtle.right(1.0);
+    ///         }
+    ///         turtle.right(10.0);
+    ///
+    ///         // Show the progress after every fourth circle
+    ///         if i % 4 == 3 {
+    ///             drawing.update();
+    ///         }
+    ///     }
+    ///
+    ///     // Show everything, including any circles that haven't been shown yet
+    ///     turtle.set_tracer(true);
+    /// }
+    /// ```
+    ///
+    /// [tracer]: struct.Turtle.html#method.set_tracer
+    pub fn update(&mut self) {
+        block_on(self.drawing.update())
+    }
+
+    /// Returns the next event (if any). Returns `None` if there are no events to be processed at
+    /// the current moment. This **does not** mean that there will never be events later on as the
+    /// application continues to run.
+    ///
+    /// See the [`Event` enum](event/enum.Event.html) for the complete list of events that you can
+    /// handle in your applications.
+    ///
+    /// # Unstable
+    ///
+    /// There are still many unanswered questions about the design of the events API in the turtle
+    /// crate. This method may change or be completely removed in the future. There will definitely
+    /// be *some* events API in the future, but it may end up looking different than it does today.
+    ///
+    /// # Example
+    ///
+    /// To use this advanced method, you need to create what is known as an "event loop". An "event
+    /// loop" is any loop that handles the events generated by the application. The reason that it
+    /// is important to create a loop like this is because events in Turtle are "polled". That
+    /// means that every time an event happens, it is placed in a queue (a list) until you ask to
+    /// look at it. If you do not check for events continuously, there is a chance that the events
+    /// you ask for from `poll_event()` will be outdated.
+    ///
+    /// Even if you do not use every kind of event, you should aim to poll events using this method
+    /// until there are none left to poll. If you do not poll events for a significant amount of
+    /// time during your application, favor the events that come later as you poll since those will
+    /// be the most recent. This can happen if you run many animations between loop iterations.
+    ///
+    /// See the [`examples/`](https://github.com/sunjay/turtle/raw/master/examples) directory in
+    /// the source code of this library for more examples of how to use events.
+    ///
+    /// The following is an example of a basic event loop. Notice that it uses two loops. One to
+    /// move the turtle continuously, and another to handle all the events available at a given
+    /// moment. If it suits your purposes, you may also just use a single loop to handle events
+    /// and move the turtle from within that loop. This example is of a more complex case where
+    /// it really matters that the most recent information is taken into consideration before any
+    /// further movements take place.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Event, event::{Key, PressedState}};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     loop {
+    ///         turtle.forward(1.0);
+    ///
+    ///         while let Some(event) = drawing.poll_event() {
+    ///             use Key::{LeftArrow, RightArrow};
+    ///             match event {
+    ///                 // Keep turning for as long as the key is held down
+    ///                 Event::Key(key, PressedState::Pressed) | Event::KeyHeld {key, ..} => match key {
+    ///                     LeftArrow => {
+    ///                         turtle.set_speed(8);
+    ///                         for _ in 0..20 {
+    ///                             turtle.forward(1.0);
+    ///                             turtle.left(4.5);
+    ///                         }
+    ///                         turtle.set_speed(4);
+    ///                     },
+    ///                     RightArrow => {
+    ///                         turtle.set_speed(8);
+    ///                         for _ in 0..20 {
+    ///                             turtle.forward(1.0);
+    ///                             turtle.right(4.5);
+    ///                         }
+    ///                         turtle.set_speed(4);
+    ///                     },
+    ///                     _ => {},
+    ///                 },
+    ///                 _ => {},
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn poll_event(&mut self) -> Option<crate::Event> {
+        block_on(self.drawing.poll_event())
+    }
+
+    /// Returns every event that is currently available, in the order that they occurred. Returns
+    /// an empty `Vec` if there are no events.
+    ///
+    /// This is the same as calling [`poll_event()`] until it returns `None`, so any event that
+    /// occurs while you are handling the returned events is left for the next call. See
+    /// [`poll_event()`] for more information about events.
+    ///
+    /// # Unstable
+    ///
+    /// There are still many unanswered questions about the design of the events API in the turtle
+    /// crate. This method may change or be completely removed in the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Event, event::{Key, PressedState}};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     loop {
+    ///         turtle.forward(1.0);
+    ///
+    ///         for event in drawing.poll_events() {
+    ///             match event {
+    ///                 Event::Key(Key::LeftArrow, PressedState::Pressed) => turtle.left(90.0),
+    ///                 Event::Key(Key::RightArrow, PressedState::Pressed) => turtle.right(90.0),
+    ///                 _ => {},
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`poll_event()`]: #method.poll_event
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn poll_events(&mut self) -> Vec<crate::Event> {
+        block_on(self.drawing.poll_events())
+    }
+
+    /// Returns the next event (if any) for which `predicate` returns `true`. Any events that come
+    /// before that event and do not match are discarded. Returns `None` if no matching event is
+    /// available at the current moment, in which case all of the events that were available have
+    /// been discarded.
+    ///
+    /// This is useful when only a certain kind of event matters and all other events can be
+    /// ignored. See [`poll_event()`](#method.poll_event) for more information about events.
+    ///
+    /// # Unstable
+    ///
+    /// There are still many unanswered questions about the design of the events API in the turtle
+    /// crate. This method may change or be completely removed in the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Event, event::{MouseButton, PressedState}};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     loop {
+    ///         turtle.forward(1.0);
+    ///
+    ///         // Turn whenever the left mouse button is pressed, ignoring all other events
+    ///         let clicked = drawing.poll_event_matching(|event| matches!(
+    ///             event,
+    ///             Event::MouseButton(MouseButton::LeftButton, PressedState::Pressed)
+    ///         ));
+    ///         if clicked.is_some() {
+    ///             turtle.right(90.0);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn poll_event_matching<F: Fn(&crate::Event) -> bool>(&mut self, predicate: F) -> Option<crate::Event> {
+        block_on(self.drawing.poll_event_matching(predicate))
+    }
+
+    /// Returns the current position of the mouse, in the same coordinates that are used to
+    /// position turtles.
+    ///
+    /// This is the position of the mouse as of the most recent [`MouseMove`] event, whether or
+    /// not that event has been returned by [`poll_event()`] yet. That means you do not need to
+    /// keep track of the mouse position yourself. If the mouse has not moved over the window yet,
+    /// this returns the origin `(0, 0)`.
+    ///
+    /// # Unstable
+    ///
+    /// Just like the rest of the events API, this method may change or be completely removed in
+    /// the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     turtle.set_speed("instant");
+    ///
+    ///     // The turtle follows the mouse wherever it goes
+    ///     loop {
+    ///         let target = drawing.mouse_position();
+    ///         turtle.turn_towards(target);
+    ///         turtle.go_to(target);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`MouseMove`]: event/enum.Event.html#variant.MouseMove
+    /// [`poll_event()`]: #method.poll_event
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn mouse_position(&self) -> Point {
+        block_on(self.drawing.mouse_position())
+    }
+
+    /// Returns true if the given key is currently held down.
+    ///
+    /// Just like [`mouse_position()`], this is based on the most recent events from the window,
+    /// whether or not those events have been returned by [`poll_event()`] yet. Keys that are held
+    /// down while the window is not focused are not considered to be pressed.
+    ///
+    /// # Unstable
+    ///
+    /// Just like the rest of the events API, this method may change or be completely removed in
+    /// the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, event::Key};
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     // Steer the turtle for as long as the arrow keys are held down
+    ///     loop {
+    ///         if drawing.is_key_pressed(Key::LeftArrow) {
+    ///             turtle.left(5.0);
+    ///         }
+    ///         if drawing.is_key_pressed(Key::RightArrow) {
+    ///             turtle.right(5.0);
+    ///         }
+    ///         turtle.forward(2.0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`mouse_position()`]: #method.mouse_position
+    /// [`poll_event()`]: #method.poll_event
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn is_key_pressed(&self, key: crate::event::Key) -> bool {
+        block_on(self.drawing.is_key_pressed(key))
+    }
+
+    /// Returns all of the keys that are currently held down, in the order in which they were
+    /// pressed.
+    ///
+    /// See [`is_key_pressed()`] for more information.
+    ///
+    /// # Unstable
+    ///
+    /// Just like the rest of the events API, this method may change or be completely removed in
+    /// the future.
+    ///
+    /// [`is_key_pressed()`]: #method.is_key_pressed
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn pressed_keys(&self) -> Vec<crate::event::Key> {
+        block_on(self.drawing.pressed_keys())
+    }
+
+    /// Saves the current drawings in SVG format at the location specified by `path`.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, Turtle, Color, ExportError};
+    ///
+    /// fn main() -> Result<(), ExportError> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_background_color("pink");
+    ///
+    ///     for i in 0..36 {
+    ///         let base_color: Color = if i % 2 == 0 {
+    ///             "red".into()
+    ///         } else {
+    ///             "white".into()
+    ///         };
+    ///
+    ///         turtle.set_fill_color(base_color.with_alpha(1.0 - i as f64 / 54.0));
+    ///         turtle.begin_fill();
+    ///         square(&mut turtle);
+    ///         turtle.end_fill();
+    ///         turtle.right(10.0);
+    ///    }
+    ///
+    ///    turtle.hide();
+    ///    drawing.save_svg("squares.svg")?;
+    ///
+    ///    Ok(())
+    /// }
+    ///
+    /// fn square(turtle: &mut Turtle) {
+    ///     for _ in 0..4 {
+    ///         turtle.forward(200.0);
+    ///         turtle.right(90.0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// This will produce the following image in the current directory under the name `squares.svg`:
+    ///
+    /// ![squares](https://raw.githubusercontent.com/sunjay/turtle/master/docs/assets/images/docs/squares.svg?sanitize=true)
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
+        block_on(self.drawing.save_svg(path))
+    }
+
+    /// Saves the current drawings as a PNG image at the location specified by `path`.
+    ///
+    /// The image has the same size as the drawing (see [`size()`](#method.size)) and includes the
+    /// background color along with everything drawn by the turtles. The size of the image does not
+    /// depend on the DPI (scale factor) of the display that the window is on, so the same program
+    /// produces the same image on every machine. The turtles themselves are
+    /// not drawn. Use [`save_png_scaled()`](#method.save_png_scaled) to produce a higher (or
+    /// lower) resolution image.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, ExportError};
+    ///
+    /// fn main() -> Result<(), ExportError> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_size([400, 300]);
+    ///
+    ///     turtle.set_pen_size(5.0);
+    ///     turtle.forward(100.0);
+    ///
+    ///     // Produces a 400x300 image
+    ///     drawing.save_png("line.png")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
+        block_on(self.drawing.save_png(path))
+    }
+
+    /// Saves the current drawings as a PNG image at the location specified by `path`, with the
+    /// size of the image multiplied by `scale`.
+    ///
+    /// For example, a scale of `2.0` produces an image that is twice as wide and twice as tall as
+    /// the drawing. The width and height of the image are always the size of the drawing
+    /// multiplied by `scale` (rounded to the nearest pixel), regardless of the DPI of the display.
+    /// Everything in the image (including the thickness of lines) is scaled by the same amount, so
+    /// the image looks the same as the image from [`save_png()`], just with more detail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not greater than zero.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, ExportError};
+    ///
+    /// fn main() -> Result<(), ExportError> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_size([400, 300]);
+    ///
+    ///     turtle.forward(100.0);
+    ///
+    ///     // Produces an 800x600 image
+    ///     drawing.save_png_scaled("line@2x.png", 2.0)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`save_png()`]: #method.save_png
+    pub fn save_png_scaled<P: AsRef<Path>>(&self, path: P, scale: f64) -> Result<(), ExportError> {
+        block_on(self.drawing.save_png_scaled(path, scale))
+    }
+
+    /// Saves the current drawings as a PDF document at the location specified by `path`.
+    ///
+    /// The document has a single page with the same size as the drawing (see
+    /// [`size()`](#method.size)), where each pixel of the drawing is one point on the page. Like
+    /// [`save_svg()`], lines and filled shapes are saved as vector paths with their colors, pen
+    /// sizes, and fill rules, so the document can be printed at any size without losing quality.
+    /// The background color is included, but the turtles themselves are not drawn.
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Drawing, ExportError};
+    ///
+    /// fn main() -> Result<(), ExportError> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_size([400, 300]);
+    ///
+    ///     turtle.set_fill_color("orange");
+    ///     turtle.begin_fill();
+    ///     for _ in 0..3 {
+    ///         turtle.forward(100.0);
+    ///         turtle.right(120.0);
+    ///     }
+    ///     turtle.end_fill();
+    ///
+    ///     // Produces a PDF with a single 400x300 page
+    ///     drawing.save_pdf("triangle.pdf")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`save_svg()`]: #method.save_svg
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportError> {
+        block_on(self.drawing.save_pdf(path))
+    }
+
+    /// Returns the size of the drawing and an image of the current drawings, without saving
+    /// anything to a file.
+    ///
+    /// The image is exactly the same as the one saved by [`save_png()`], so it includes the
+    /// background color and everything drawn by the turtles, but not the turtles themselves. The
+    /// pixels are stored row by row from the top of the image, with 4 bytes (red, green, blue,
+    /// alpha) for each pixel. There is no padding, so the image always has
+    /// `width * height * 4` bytes.
+    ///
+    /// This is useful for working with the image in your own code, e.g. to combine several
+    /// drawings or to check the color of a pixel.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// drawing.set_size([200, 100]);
+    /// drawing.set_background_color("black");
+    ///
+    /// let (size, pixels) = drawing.screenshot().expect("unable to render the drawing");
+    /// assert_eq!(size, Size {width: 200, height: 100});
+    /// assert_eq!(pixels.len(), 200 * 100 * 4);
+    /// // The pixel in the top left corner is the background color
+    /// assert_eq!(&pixels[..4], &[0, 0, 0, 255]);
+    /// ```
+    ///
+    /// [`save_png()`]: #method.save_png
+    pub fn screenshot(&self) -> Result<(Size, Vec<u8>), ExportError> {
+        block_on(self.drawing.screenshot())
+    }
+
+    /// Renders the drawing into `buffer` and returns the size of the rendered image
+    ///
+    /// This is only available with the `embedded` feature, which runs the drawing inside your
+    /// own program instead of opening a window. Use it to show a drawing inside the window of
+    /// another GUI application: call this method whenever your application draws a frame and
+    /// display the pixels however your GUI library displays images.
+    ///
+    /// The image has the same format as the one returned by [`screenshot()`]: 4 bytes (red, green,
+    /// blue, alpha) for each pixel, row by row from the top of the image. Any previous contents of
+    /// `buffer` are replaced, so the same buffer can be reused for every frame without allocating
+    /// a new one each time. The image is rendered directly from the state of the drawing rather
+    /// than being sent from a renderer process. Just like in the window, it includes everything
+    /// that turtles have drawn so far (even halfway through an animation) and every turtle that is
+    /// visible.
+    ///
+    /// Since there is no window, methods like [`set_title()`] have no visible effect. Events like
+    /// mouse clicks only happen if your application passes them on with [`send_event()`].
+    ///
+    /// ```rust
+    /// use turtle::Drawing;
+    ///
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// turtle.forward(100.0);
+    ///
+    /// let mut pixels = Vec::new();
+    /// // Call this every time your application redraws
+    /// let size = drawing.render_to(&mut pixels).expect("unable to render the drawing");
+    /// assert_eq!(pixels.len(), size.width as usize * size.height as usize * 4);
+    /// ```
+    ///
+    /// [`screenshot()`]: #method.screenshot
+    /// [`set_title()`]: #method.set_title
+    /// [`send_event()`]: #method.send_event
+    #[cfg(feature = "embedded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "embedded")))]
+    pub fn render_to(&self, buffer: &mut Vec<u8>) -> Result<Size, ExportError> {
+        block_on(self.drawing.render_to(buffer))
+    }
+
+    /// Sends an event to the drawing as if it had happened in its window
+    ///
+    /// This is only available with the `embedded` feature. A drawing embedded in another
+    /// application has no window of its own, so it only receives the events that your application
+    /// passes on with this method. The event is returned by methods like [`poll_event()`] and
+    /// affects methods like [`Turtle::wait_for_click()`], [`mouse_position()`], and
+    /// [`is_key_pressed()`], just like an event from a window would. The position of an
+    /// [`Event::MouseMove`] should be in the same coordinates as the rest of the drawing.
+    ///
+    /// ```rust
+    /// use turtle::{Drawing, Event, event::{MouseButton, PressedState}};
+    ///
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    ///
+    /// // Pass on a click from your application's event loop
+    /// drawing.send_event(Event::MouseMove([10.0, 20.0].into()));
+    /// drawing.send_event(Event::MouseButton(MouseButton::LeftButton, PressedState::Pressed));
+    ///
+    /// // This returns right away since the click was already sent
+    /// turtle.wait_for_click();
+    /// assert_eq!(drawing.mouse_position(), [10.0, 20.0].into());
+    /// ```
+    ///
+    /// [`poll_event()`]: #method.poll_event
+    /// [`Turtle::wait_for_click()`]: struct.Turtle.html#method.wait_for_click
+    /// [`mouse_position()`]: #method.mouse_position
+    /// [`is_key_pressed()`]: #method.is_key_pressed
+    /// [`Event::MouseMove`]: event/enum.Event.html#variant.MouseMove
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "embedded", feature = "unstable"))))]
+    pub fn send_event(&mut self, event: crate::Event) {
+        self.drawing.send_event(event)
+    }
+
+    /// Starts recording everything shown in the window so that it can be saved as an animated
+    /// GIF at the given path.
+    ///
+    /// The GIF is only saved once [`stop_gif_recording()`] is called. Unlike the other methods for
+    /// saving the drawing, the GIF contains exactly what was shown in the window, including the
+    /// turtles. It has the size of the window in physical pixels, so it may be larger than
+    /// [`size()`] on high DPI displays.
+    ///
+    /// The window is drawn much more often than a GIF can show frames, so at most `fps` frames are
+    /// kept per second. Each frame is shown for as long as it was shown in the window, so the GIF
+    /// keeps the same pace as the drawing. Calling this method while a recording is already in
+    /// progress discards the frames of that recording and starts a new one.
+    ///
+    /// # Performance
+    ///
+    /// Recording is much slower than just drawing. While a recording is in progress, every frame
+    /// is copied from the graphics card and the frames are kept in memory until the recording is
+    /// stopped. Then they all have to be encoded, which can take several seconds for a long
+    /// recording. Keep recordings short and use a low frame rate (e.g. 10 or 15 FPS) to keep
+    /// things fast.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     drawing.start_gif_recording("square.gif", 15.0);
+    ///     for _ in 0..4 {
+    ///         turtle.forward(100.0);
+    ///         turtle.right(90.0);
+    ///     }
+    ///     drawing.stop_gif_recording()?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is not greater than zero or is greater than 50. Most GIF viewers cannot
+    /// show more than 50 frames per second.
+    ///
+    /// [`stop_gif_recording()`]: #method.stop_gif_recording
+    /// [`size()`]: #method.size
+    pub fn start_gif_recording<P: AsRef<Path>>(&mut self, path: P, fps: f64) {
+        self.drawing.start_gif_recording(path, fps)
+    }
+
+    /// Stops the recording started by [`start_gif_recording()`] and saves the GIF.
+    ///
+    /// Returns an error if no recording was started, if no frames were captured (e.g. when there
+    /// is no window to capture), or if the GIF could not be saved.
+    ///
+    /// [`start_gif_recording()`]: #method.start_gif_recording
+    pub fn stop_gif_recording(&mut self) -> Result<(), ExportError> {
+        block_on(self.drawing.stop_gif_recording())
+    }
+
+    /// Returns everything that has been drawn so far as a JSON string.
+    ///
+    /// Unlike [`save_svg()`], this keeps each line and filled shape exactly as it was drawn, so the
+    /// result is easy to process with other tools. The JSON is an object with two fields:
+    ///
+    /// * `"background"` - the background color of the drawing
+    /// * `"prims"` - every line (`"Line"`) and filled shape (`"Polygon"`) in the order that they
+    ///   are drawn, with their points in logical coordinates
+    ///
+    /// Colors are objects with `"red"`, `"green"`, `"blue"` and `"alpha"` fields, just like
+    /// [`Color`]. The turtles themselves and the rest of the drawing's settings are not included.
+    ///
+    /// Use [`import_json()`] to load the result back into a drawing.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///
+    ///     turtle.forward(100.0);
+    ///
+    ///     std::fs::write("line.json", drawing.export_json()).unwrap();
+    /// }
+    /// ```
+    ///
+    /// [`save_svg()`]: #method.save_svg
+    /// [`Color`]: struct.Color.html
+    /// [`import_json()`]: #method.import_json
+    pub fn export_json(&self) -> String {
+        block_on(self.drawing.export_json())
+    }
+
+    /// Replaces everything that has been drawn so far with the lines and filled shapes in the
+    /// given JSON string, and sets the background color to the one in that string.
+    ///
+    /// The JSON should be in the format returned by [`export_json()`]. If it is not,
+    /// [`ImportJsonError::Parse`] is returned. If it contains a value that can't be drawn (e.g. an
+    /// invalid color or a fill pattern with a spacing that is too small),
+    /// [`ImportJsonError::InvalidDrawing`] is returned. The drawing is left unchanged in both cases.
+    ///
+    /// Everything that each turtle has drawn is removed and can no longer be undone. The turtles
+    /// themselves (their position, pen, etc.) are not changed.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///
+    ///     let json = std::fs::read_to_string("line.json").unwrap();
+    ///     drawing.import_json(&json).expect("line.json is not a valid drawing");
+    /// }
+    /// ```
+    ///
+    /// [`export_json()`]: #method.export_json
+    /// [`ImportJsonError::Parse`]: enum.ImportJsonError.html#variant.Parse
+    /// [`ImportJsonError::InvalidDrawing`]: enum.ImportJsonError.html#variant.InvalidDrawing
+    pub fn import_json(&mut self, json: &str) -> Result<(), ImportJsonError> {
+        self.drawing.import_json(json)
+    }
+
+    /// Destroys underlying window and drops self.
+    ///
+    /// Subsequent commands to turtle, created using [`Drawing::add_turtle`], might panic.
+    ///
+    /// ```rust
+    /// use turtle::Drawing;
+    ///
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    ///
+    /// turtle.set_speed(2);
+    /// turtle.right(90.0);
+    /// turtle.forward(50.0);
+    ///
+    /// // close window
+    /// drawing.destroy();
+    ///
+    /// // this will panic!
+    /// // turtle.forward(100.0)
+    /// ```
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn destroy(self) {
+        self.drawing.destroy();
+    }
+}
+
+/// Configures a new drawing before its window is opened
+///
+/// Create a builder with [`Drawing::builder()`], change any of the settings you want, and then
+/// call [`build()`] to open the window. The window opens with these settings right away, so
+/// unlike calling [`set_title()`] or [`set_size()`] after [`Drawing::new()`], the window is never
+/// shown with the default settings first.
+///
+/// ```rust
+/// use turtle::Drawing;
+///
+/// let mut drawing = Drawing::builder()
+///     .title("Solar System")
+///     .size([400, 400])
+///     .background_color("black")
+///     .resizable(false)
+///     .build();
+/// # #[allow(unused)] // Good to show turtle creation here even if unused
+/// let mut turtle = drawing.add_turtle();
+///
+/// assert_eq!(drawing.title(), "Solar System");
+/// assert_eq!(drawing.size(), [400, 400].into());
+/// ```
+///
+/// Every setting can still be changed after the drawing has been created. The `reset_*` methods
+/// of [`Drawing`] reset each setting back to its default value, not the value it was built with.
+///
+/// [`Drawing::builder()`]: struct.Drawing.html#method.builder
+/// [`build()`]: struct.DrawingBuilder.html#method.build
+/// [`set_title()`]: struct.Drawing.html#method.set_title
+/// [`set_size()`]: struct.Drawing.html#method.set_size
+/// [`Drawing::new()`]: struct.Drawing.html#method.new
+/// [`Drawing`]: struct.Drawing.html
+#[derive(Debug, Clone, Default)]
+pub struct DrawingBuilder {
+    config: WindowConfig,
+}
+
+impl DrawingBuilder {
+    /// Sets the title of the window
+    ///
+    /// The default title is `"Turtle"`. See [`Drawing::set_title()`] for more information.
+    ///
+    /// [`Drawing::set_title()`]: struct.Drawing.html#method.set_title
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.config.title = title.into();
+        self
+    }
+
+    /// Sets the size of the drawing
+    ///
+    /// The default size is 800x600. See [`Drawing::set_size()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width or height is zero.
+    ///
+    /// [`Drawing::set_size()`]: struct.Drawing.html#method.set_size
+    pub fn size<S: Into<Size>>(mut self, size: S) -> Self {
+        let size = size.into();
+        assert!(size.width > 0 && size.height > 0, "The size of the drawing must be non-zero");
+
+        self.config.width = size.width;
+        self.config.height = size.height;
+        self
+    }
+
+    /// Sets the color of the background
+    ///
+    /// The default background is white. See [`Drawing::set_background_color()`] for more
+    /// information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the color is not valid. See the [`Color` struct](struct.Color.html) for more
+    /// information.
+    ///
+    /// [`Drawing::set_background_color()`]: struct.Drawing.html#method.set_background_color
+    pub fn background_color<C: Into<Color> + Copy + Debug>(mut self, color: C) -> Self {
+        let bg_color = color.into();
+        assert!(
+            bg_color.is_valid(),
+            "Invalid color: {:?}. See the color module documentation for more information.",
+            color
+        );
+
+        self.config.background = bg_color;
+        self
+    }
+
+    /// Sets whether the user can resize the window (default: `true`)
+    ///
+    /// This only applies to resizing the window with the mouse. Methods like
+    /// [`Drawing::set_size()`] and [`Drawing::maximize()`] can still change the size of the window.
+    ///
+    /// [`Drawing::set_size()`]: struct.Drawing.html#method.set_size
+    /// [`Drawing::maximize()`]: struct.Drawing.html#method.maximize
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.config.is_resizable = resizable;
+        self
+    }
+
+    /// Sets whether the window starts maximized (default: `false`)
+    ///
+    /// See [`Drawing::maximize()`] for more information.
+    ///
+    /// [`Drawing::maximize()`]: struct.Drawing.html#method.maximize
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.config.is_maximized = maximized;
+        self
+    }
+
+    /// Opens a new window with these settings and returns the new drawing
+    ///
+    /// This does the same thing as [`Drawing::new()`] other than the settings that are used.
+    ///
+    /// [`Drawing::new()`]: struct.Drawing.html#method.new
+    pub fn build(self) -> Drawing {
+        // We can't call start() within another tokio runtime, so we have to do it before block_on
+        crate::start();
+
+        Drawing {
+            drawing: block_on(AsyncDrawing::with_config(self.config)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Invalid color: Color { red: NaN, green: 0.0, blue: 0.0, alpha: 0.0 }. See the color module documentation for more information.")]
+    fn rejects_invalid_background_color() {
+        let mut drawing = Drawing::new();
+        drawing.set_background_color(Color {
+            red: ::std::f64::NAN,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 0.0,
+        });
+    }
+
+    #[test]
+    fn set_background_color_animated() {
+        let mut drawing = Drawing::new();
+        drawing.set_background_color_animated("red", 0.5);
+        assert_eq!(drawing.background_color(), "red".into());
+
+        // Durations that are zero or negative change the color right away
+        drawing.set_background_color_animated("blue", 0.0);
+        assert_eq!(drawing.background_color(), "blue".into());
+        drawing.set_background_color_animated("green", -1.0);
+        assert_eq!(drawing.background_color(), "green".into());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration: NaN. The duration of an animation must be a finite number of seconds")]
+    fn rejects_nan_background_animation_duration() {
+        let mut drawing = Drawing::new();
+        drawing.set_background_color_animated("red", ::std::f64::NAN);
+    }
+
+    #[test]
+    fn set_center_animated() {
+        let mut drawing = Drawing::new();
+        drawing.set_center_animated([100.0, -50.0], 0.5);
+        assert_eq!(drawing.center(), Point {x: 100.0, y: -50.0});
+
+        drawing.set_center_animated([1.0, 2.0], 0.0);
+        assert_eq!(drawing.center(), Point {x: 1.0, y: 2.0});
+
+        // Centers that are not finite are ignored, just like with set_center()
+        drawing.set_center_animated([f64::INFINITY, 0.0], 0.5);
+        assert_eq!(drawing.center(), Point {x: 1.0, y: 2.0});
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration: inf. The duration of an animation must be a finite number of seconds")]
+    fn rejects_infinite_center_animation_duration() {
+        let mut drawing = Drawing::new();
+        drawing.set_center_animated([0.0, 0.0], f64::INFINITY);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable")]
+    fn input_state_without_events() {
+        let drawing = Drawing::new();
+        // No events are produced during tests, so the input state stays at its defaults
+        assert_eq!(drawing.mouse_position(), Point::origin());
+        assert!(drawing.pressed_keys().is_empty());
+        assert!(!drawing.is_key_pressed(crate::event::Key::Space));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid grid spacing: 0. The grid spacing must be greater than zero")]
+    fn rejects_grid_spacing_zero() {
+        let mut drawing = Drawing::new();
+        drawing.set_grid(Some(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "The size of the drawing must be non-zero")]
+    fn rejects_size_zero() {
+        let mut drawing = Drawing::new();
+
+        drawing.set_size([0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable")]
+    fn builder_settings_are_initial_state() {
+        let drawing = Drawing::builder()
+            .title("Built")
+            .size([300, 200])
+            .background_color("red")
+            .maximized(true)
+            .resizable(false)
+            .build();
+        assert_eq!(drawing.title(), "Built");
+        assert_eq!(drawing.size(), Size {width: 300, height: 200});
+        assert_eq!(drawing.background_color(), "red".into());
+        assert!(drawing.is_maximized());
+
+        // Settings that are not changed keep their defaults
+        let drawing = Drawing::builder().title("Only title").build();
+        let default = Drawing::new();
+        assert_eq!(drawing.size(), default.size());
+        assert_eq!(drawing.background_color(), default.background_color());
+        assert!(!drawing.is_maximized());
+    }
+
+    #[test]
+    #[should_panic(expected = "The size of the drawing must be non-zero")]
+    fn builder_rejects_size_zero() {
+        Drawing::builder().size([100, 0]);
+    }
+
+    #[test]
+    fn bounds_include_lines_and_fills() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+
+        // Moving without the pen doesn't draw anything
+        turtle.pen_up();
+        turtle.go_to([-50.0, -50.0]);
+        assert_eq!(drawing.bounds(), None);
+
+        turtle.begin_fill();
+        turtle.go_to([-50.0, 20.0]);
+        turtle.go_to([30.0, 20.0]);
+        turtle.end_fill();
+        assert_eq!(drawing.bounds(), Some(Rect {
+            min: Point {x: -50.0, y: -50.0},
+            max: Point {x: 30.0, y: 20.0},
+        }));
+
+        turtle.pen_down();
+        turtle.set_pen_size(4.0);
+        turtle.go_to([100.0, 20.0]);
+        assert_eq!(drawing.bounds(), Some(Rect {
+            min: Point {x: -50.0, y: -50.0},
+            max: Point {x: 102.0, y: 22.0},
+        }));
+
+        // The drawing is 152 units wide, which is what limits how far it can be zoomed
+        drawing.set_size([304, 400]);
+        drawing.fit_to_window(0.0);
+        assert_eq!(drawing.center(), Point {x: 26.0, y: -14.0});
+        assert_eq!(drawing.scale(), 2.0);
+        assert_eq!(drawing.size(), Size {width: 304, height: 400});
+
+        drawing.clear();
+        assert_eq!(drawing.bounds(), None);
+        // Fitting an empty drawing leaves the view alone
+        drawing.reset_center();
+        drawing.reset_scale();
+        drawing.fit_to_window(10.0);
+        assert_eq!(drawing.scale(), 1.0);
+        assert_eq!(drawing.center(), Point::origin());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid padding: -1. The padding must be zero or greater")]
+    fn fit_to_window_rejects_negative_padding() {
+        let mut drawing = Drawing::new();
+        drawing.fit_to_window(-1.0);
+    }
+
+    #[test]
+    fn gif_recording_needs_frames() {
+        let mut drawing = Drawing::new();
+        assert!(drawing.stop_gif_recording().is_err());
+
+        // Nothing is rendered during tests, so there are no frames to save
+        let path = std::env::temp_dir().join(format!("turtle-drawing-{}.gif", std::process::id()));
+        drawing.start_gif_recording(&path, 10.0);
+        assert!(drawing.stop_gif_recording().is_err());
+        assert!(!path.exists());
+
+        // The recording stopped, so stopping it again fails too
+        assert!(drawing.stop_gif_recording().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid frame rate: 60. The frame rate of a GIF must be greater than zero and at most 50")]
+    fn gif_recording_rejects_high_frame_rate() {
+        let mut drawing = Drawing::new();
+        drawing.start_gif_recording("never.gif", 60.0);
+    }
+
+    #[test]
+    fn clear_removes_all_drawings() {
+        let mut drawing = Drawing::new();
+        let mut turtle1 = drawing.add_turtle();
+        let mut turtle2 = drawing.add_turtle();
+        turtle1.forward(100.0);
+        turtle2.begin_fill();
+        turtle2.right(90.0);
+        turtle2.forward(50.0);
+        turtle2.stamp();
+
+        drawing.clear();
+
+        assert_eq!(drawing.export_json(), r#"{"background":{"red":255.0,"green":255.0,"blue":255.0,"alpha":1.0},"prims":[]}"#);
+        // The turtles are left where they were
+        assert_eq!(turtle1.position().round(), Point {x: 0.0, y: 100.0});
+        assert_eq!(turtle2.position().round(), Point {x: 50.0, y: 0.0});
+        assert_eq!(turtle2.heading(), 0.0);
+        assert!(!turtle2.is_filling());
+    }
+
+    #[test]
+    fn update_returns_without_a_window() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_tracer(false);
+        turtle.forward(100.0);
+
+        // Responses are still received in order after waiting for the update
+        drawing.update();
+        drawing.update();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 100.0});
+    }
+
+    #[test]
+    fn multiple_turtles_draw_independently() {
+        let mut drawing = Drawing::new();
+        let mut turtle1 = drawing.add_turtle();
+        let mut turtle2 = drawing.add_turtle();
+
+        let handle = std::thread::spawn(move || {
+            turtle1.set_pen_color("red");
+            turtle1.forward(100.0);
+            turtle1
+        });
+
+        turtle2.right(90.0);
+        turtle2.forward(50.0);
+        let turtle1 = handle.join().unwrap();
+
+        assert_eq!(turtle1.position().round(), Point {x: 0.0, y: 100.0});
+        assert_eq!(turtle1.pen_color(), "red".into());
+        assert_eq!(turtle2.position().round(), Point {x: 50.0, y: 0.0});
+        assert_eq!(turtle2.pen_color(), "black".into());
+    }
+
+    #[test]
+    fn set_icon_loads_png_images() {
+        let mut drawing = Drawing::new();
+
+        // An RGB image without an alpha channel, unlike the images produced by save_png()
+        let path = std::env::temp_dir().join(format!("turtle-icon-{}.png", std::process::id()));
+        let file = std::io::BufWriter::new(std::fs::File::create(&path).unwrap());
+        let mut encoder = png::Encoder::new(file, 2, 2);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().unwrap().write_image_data(&[255; 2 * 2 * 3]).unwrap();
+        drawing.set_icon(&path).unwrap();
+
+        // Files that are not PNG images are rejected
+        let svg_path = path.with_extension("svg");
+        drawing.save_svg(&svg_path).unwrap();
+        assert!(matches!(drawing.set_icon(&svg_path), Err(ExportError::UnsupportedFormat)));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&svg_path).unwrap();
+        match drawing.set_icon(&path) {
+            Err(ExportError::Io(err)) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+            res => panic!("expected a missing file to be an IO error, got: {:?}", res),
+        }
+    }
+
+    /// Reads the size and RGBA pixels of the PNG image at the given path, then deletes the file
+    fn read_png(path: &Path) -> (Size, Vec<u8>) {
+        let decoder = png::Decoder::new(std::fs::File::open(path).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        (Size {width: info.width, height: info.height}, data)
+    }
+
+    /// Saves the drawing as an SVG file and returns its contents, then deletes the file
+    ///
+    /// The `name` is part of the file name, so it must be different in every test.
+    fn svg_string(drawing: &Drawing, name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("turtle-{}-{}.svg", name, std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        svg
+    }
+
+    /// Returns the opaque RGBA pixel of the given color, as it appears in an exported image
+    fn rgba(color: &str) -> [u8; 4] {
+        let Color {red, green, blue, ..} = color.into();
+        [red as u8, green as u8, blue as u8, 255]
+    }
+
+    /// Returns the RGBA pixel at the given position of an image that is `width` pixels wide
+    fn pixel(data: &[u8], width: usize, x: usize, y: usize) -> &[u8] {
+        &data[(y * width + x) * 4..][..4]
+    }
+
+    #[test]
+    fn save_png_includes_background_and_lines() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([40, 30]);
+        drawing.set_background_color("red");
+        let mut turtle = drawing.add_turtle();
+        turtle.set_pen_size(4.0);
+        turtle.set_pen_color("blue");
+        turtle.forward(10.0);
+
+        let path = std::env::temp_dir().join(format!("turtle-save-png-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+
+        let (size, data) = read_png(&path);
+
+        assert_eq!(size, Size {width: 40, height: 30});
+        // The line goes up from the center of the image
+        assert_eq!(pixel(&data, 40, 20, 10), rgba("blue"));
+        assert_eq!(pixel(&data, 40, 0, 0), rgba("red"));
+        assert_eq!(pixel(&data, 40, 39, 29), rgba("red"));
+    }
+
+    #[test]
+    fn write_text_and_undo() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_pen_color("blue");
+        turtle.write_with("o", 100.0, "center");
+
+        let path = std::env::temp_dir().join(format!("turtle-write-text-{}.png", std::process::id()));
+        let render = |drawing: &Drawing| {
+            drawing.save_png(&path).unwrap();
+            let (_, data) = read_png(&path);
+            data
+        };
+
+        // The "o" sits on the baseline in the center of the image and keeps its hole
+        let data = render(&drawing);
+        assert_eq!(pixel(&data, 100, 30, 25), rgba("blue"));
+        assert_eq!(pixel(&data, 100, 50, 25), rgba("white"));
+        assert_eq!(pixel(&data, 100, 50, 75), rgba("white"));
+
+        turtle.undo();
+        let data = render(&drawing);
+        assert_eq!(pixel(&data, 100, 30, 25), rgba("white"));
+    }
+
+    #[test]
+    fn stamps_are_exported_and_cleared() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.set_fill_color("red");
+        turtle.pen_up();
+
+        // The turtle faces up, so each stamp covers a few pixels just above its position
+        let first = turtle.stamp();
+        turtle.go_to([-30.0, 0.0]);
+        let second = turtle.stamp();
+        assert_ne!(first, second);
+        turtle.hide();
+        turtle.go_to([30.0, 0.0]);
+        turtle.stamp();
+
+        let path = std::env::temp_dir().join(format!("turtle-stamps-{}.png", std::process::id()));
+        let render = |drawing: &Drawing| {
+            drawing.save_png(&path).unwrap();
+            let (_, data) = read_png(&path);
+            data
+        };
+
+        let data = render(&drawing);
+        assert_eq!(pixel(&data, 100, 50, 46), rgba("red"));
+        assert_eq!(pixel(&data, 100, 20, 46), rgba("red"));
+        // Hidden turtles don't leave anything behind
+        assert_eq!(pixel(&data, 100, 80, 46), rgba("white"));
+
+        turtle.clear_stamp(first);
+        // Clearing the same stamp twice does nothing
+        turtle.clear_stamp(first);
+        let data = render(&drawing);
+        assert_eq!(pixel(&data, 100, 50, 46), rgba("white"));
+        assert_eq!(pixel(&data, 100, 20, 46), rgba("red"));
+
+        turtle.clear_stamps();
+        let data = render(&drawing);
+        assert_eq!(pixel(&data, 100, 20, 46), rgba("white"));
+    }
+
+    #[test]
+    fn stamps_use_turtle_shape() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_fill_color("red");
+
+        // The default triangle doesn't reach below the turtle's position, but a square does
+        let triangle = turtle.stamp();
+        turtle.set_shape("square");
+        assert_eq!(turtle.shape(), crate::TurtleShape::Square);
+        turtle.stamp();
+        turtle.clear_stamp(triangle);
+
+        let path = std::env::temp_dir().join(format!("turtle-shape-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        assert_eq!(pixel(&data, 100, 45, 55), rgba("red"));
+        assert_eq!(pixel(&data, 100, 55, 45), rgba("red"));
+        assert_eq!(pixel(&data, 100, 65, 50), rgba("white"));
+
+        turtle.reset();
+        assert_eq!(turtle.shape(), crate::TurtleShape::Triangle);
+    }
+
+    #[test]
+    fn stamps_use_shape_scale() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_fill_color("red");
+        turtle.set_shape("square");
+        // The square is usually 20x20, so this makes it 60x60
+        turtle.set_shape_scale(3.0);
+        turtle.stamp();
+
+        let path = std::env::temp_dir().join(format!("turtle-shape-scale-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        assert_eq!(pixel(&data, 100, 75, 50), rgba("red"));
+        assert_eq!(pixel(&data, 100, 85, 50), rgba("white"));
+
+        turtle.reset();
+        assert_eq!(turtle.shape_scale(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid font size: 0. The font size must be greater than zero")]
+    fn rejects_font_size_zero() {
+        let mut turtle = Turtle::new();
+        turtle.write_with("hello", 0.0, "left");
+    }
+
+    /// Draws a thick line to the right from (-20, 0) to (20, 0) and then, if `corner` is true, down
+    /// to (20, -40). Returns the color of the pixel at (28, 8), just past the top right of where
+    /// the line turns.
+    fn pen_style_pixel(cap: &str, join: &str, corner: bool) -> Vec<u8> {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.pen_up();
+        turtle.go_to([-20.0, 0.0]);
+        turtle.set_heading(0.0);
+        turtle.pen_down();
+        turtle.set_pen_size(20.0);
+        turtle.set_pen_cap(cap);
+        turtle.set_pen_join(join);
+        turtle.forward(40.0);
+        if corner {
+            turtle.right(90.0);
+            turtle.forward(40.0);
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "turtle-pen-style-{}-{}-{}-{}.png", cap, join, corner, std::process::id(),
+        ));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        data[(42 * 100 + 78) * 4..][..4].to_vec()
+    }
+
+    #[test]
+    fn save_png_line_caps_and_joins() {
+        let white = [255, 255, 255, 255];
+        let black = [0, 0, 0, 255];
+
+        assert_eq!(pen_style_pixel("butt", "round", false), white);
+        assert_eq!(pen_style_pixel("round", "round", false), white);
+        assert_eq!(pen_style_pixel("square", "round", false), black);
+
+        assert_eq!(pen_style_pixel("butt", "round", true), white);
+        assert_eq!(pen_style_pixel("butt", "bevel", true), white);
+        assert_eq!(pen_style_pixel("butt", "miter", true), black);
+    }
+
+    #[test]
+    fn save_svg_line_caps_and_joins() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_pen_cap("square");
+        turtle.set_pen_join("bevel");
+        turtle.forward(10.0);
+        turtle.right(90.0);
+        turtle.forward(10.0);
+
+        let path = std::env::temp_dir().join(format!("turtle-pen-style-{}.svg", std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Both lines are drawn as a single path so that the corner between them can be drawn
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert!(!svg.contains("<line"));
+        assert!(svg.contains("stroke-linecap=\"square\""));
+        assert!(svg.contains("stroke-linejoin=\"bevel\""));
+    }
+
+    #[test]
+    fn fill_rules() {
+        let star_center_pixel = |rule: &str| {
+            let mut drawing = Drawing::new();
+            drawing.set_size([100, 100]);
+            let mut turtle = drawing.add_turtle();
+            turtle.pen_up();
+            turtle.go_to([-40.0, 10.0]);
+            turtle.set_heading(0.0);
+            turtle.set_fill_color("blue");
+            turtle.set_fill_rule(rule);
+
+            // The center of a star is the average of its points
+            let mut center = Point::origin();
+            turtle.begin_fill();
+            for _ in 0..5 {
+                turtle.forward(80.0);
+                turtle.right(144.0);
+                center += turtle.position() / 5.0;
+            }
+            turtle.end_fill();
+
+            let path = std::env::temp_dir().join(format!("turtle-fill-rule-{}.png", std::process::id()));
+            drawing.save_png(&path).unwrap();
+            let (_, data) = read_png(&path);
+
+            let x = (50.0 + center.x).round() as usize;
+            let y = (50.0 - center.y).round() as usize;
+            data[(y * 100 + x) * 4..][..4].to_vec()
+        };
+
+        let Color {red, green, blue, ..} = "blue".into();
+        assert_eq!(star_center_pixel("nonzero"), [red as u8, green as u8, blue as u8, 255]);
+        assert_eq!(star_center_pixel("evenodd"), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn instant_fill_ends_at_each_target() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.pen_up();
+
+        // Each instant movement adds the point it moves to, so the fill reaches the last corner
+        // even though the turtle never goes back to where it started
+        turtle.begin_fill();
+        turtle.go_to([0.0, 40.0]);
+        turtle.go_to([40.0, 40.0]);
+        turtle.end_fill();
+        assert_eq!(drawing.bounds(), Some(Rect {
+            min: Point {x: 0.0, y: 0.0},
+            max: Point {x: 40.0, y: 40.0},
+        }));
+    }
+
+    #[test]
+    fn save_svg_fill_rule() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_fill_rule("evenodd");
+        turtle.begin_fill();
+        turtle.forward(10.0);
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.end_fill();
+
+        let path = std::env::temp_dir().join(format!("turtle-fill-rule-{}.svg", std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(svg.contains("fill-rule=\"evenodd\""));
+    }
+
+    #[test]
+    fn fill_pattern_only_paints_the_pattern() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.pen_up();
+        turtle.go_to([-40.0, -40.0]);
+        turtle.set_heading(0.0);
+        turtle.set_fill_color("black");
+        turtle.set_fill_pattern(crate::FillPattern::Hatch {angle: 0.0, spacing: 20.0});
+
+        turtle.begin_fill();
+        for _ in 0..4 {
+            turtle.forward(80.0);
+            turtle.left(90.0);
+        }
+        turtle.end_fill();
+
+        let path = std::env::temp_dir().join(format!("turtle-fill-pattern-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        // One of the lines goes through the origin, which is at the top edge of the pixel (50, 50)
+        assert_ne!(pixel(&data, 100, 50, 50), rgba("white"));
+        // The shape is not filled in between the lines or beyond its edges
+        assert_eq!(pixel(&data, 100, 50, 40), rgba("white"));
+        assert_eq!(pixel(&data, 100, 95, 50), rgba("white"));
+
+        let path = std::env::temp_dir().join(format!("turtle-fill-pattern-{}.svg", std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(svg.contains("<pattern"));
+        assert!(svg.contains("fill=\"url(#fill-pattern-0)\""));
+    }
+
+    #[test]
+    fn fill_pattern_of_a_huge_shape_has_a_bounded_number_of_lines() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.pen_up();
+        turtle.go_to([-1e9, -1e9]);
+        turtle.set_heading(0.0);
+        turtle.set_fill_pattern(crate::FillPattern::CrossHatch {spacing: 2.0});
+
+        // With the given spacing, this shape would need billions of lines
+        turtle.begin_fill();
+        for _ in 0..4 {
+            turtle.forward(2e9);
+            turtle.left(90.0);
+        }
+        turtle.end_fill();
+
+        let path = std::env::temp_dir().join(format!("turtle-huge-fill-pattern-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn pen_gradient_is_exported() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.pen_up();
+        turtle.go_to([-40.0, 0.0]);
+        turtle.set_heading(0.0);
+        turtle.pen_down();
+        turtle.set_pen_size(10.0);
+        turtle.set_pen_gradient("red", "blue");
+        turtle.forward(80.0);
+
+        let path = std::env::temp_dir().join(format!("turtle-pen-gradient-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        // The colors at the ends of the line are within a rounding error of the gradient's colors
+        let assert_close = |pixel: &[u8], color: &str| {
+            let Color {red, green, blue, ..} = color.into();
+            let expected = [red, green, blue, 255.0];
+            assert!(pixel.iter().zip(&expected).all(|(&a, &b)| (a as f64 - b).abs() <= 2.0),
+                "expected {:?} to be close to {:?}", pixel, expected);
+        };
+        assert_close(pixel(&data, 100, 10, 50), "red");
+        assert_close(pixel(&data, 100, 90, 50), "blue");
+        // The middle of the line is a mix of both colors
+        let middle = pixel(&data, 100, 50, 50);
+        assert!(middle[0] > 80 && middle[0] < 180 && middle[2] > 80 && middle[2] < 200, "{:?}", middle);
+
+        let path = std::env::temp_dir().join(format!("turtle-pen-gradient-{}.svg", std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(svg.contains("<linearGradient"));
+        assert!(svg.contains("stroke=\"url(#pen-gradient-0)\""));
+    }
+
+    #[test]
+    fn tapered_lines_are_exported() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([100, 100]);
+        let mut turtle = drawing.add_turtle();
+        turtle.hide();
+        turtle.teleport([-40.0, 0.0], 0.0);
+        turtle.set_pen_size(2.0);
+        turtle.set_pen_size_animated(30.0, 80.0);
+        turtle.forward(80.0);
+
+        let path = std::env::temp_dir().join(format!("turtle-tapered-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        // The number of pixels in the given column that are mostly covered by the line
+        let line_height = |x: usize| (0..100).filter(|&y| data[(y * 100 + x) * 4] < 128).count();
+        let start = line_height(15);
+        let end = line_height(85);
+        assert!((3..=5).contains(&start), "{}", start);
+        assert!((27..=30).contains(&end), "{}", end);
+
+        let path = std::env::temp_dir().join(format!("turtle-tapered-{}.svg", std::process::id()));
+        drawing.save_svg(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(svg.contains("<polygon"));
+        assert!(!svg.contains("<line"));
+    }
+
+    #[test]
+    fn replayed_recording_matches_original() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let mut other = drawing.add_turtle();
+        drawing.start_recording();
+        drawing.set_background_color("light grey");
+        turtle.set_pen_color("red");
+        turtle.begin_fill();
+        for _ in 0..3 {
+            turtle.forward(50.0);
+            turtle.right(120.0);
+        }
+        turtle.end_fill();
+        other.set_pen_size(4.0);
+        other.go_to([-30.0, 40.0]);
+        // Queries are not recorded
+        turtle.position();
+        let recording = drawing.stop_recording();
+        assert_eq!(recording.len(), 12);
+
+        let json = serde_json::to_string(&recording).unwrap();
+        let recording: Recording = serde_json::from_str(&json).unwrap();
+
+        let mut replayed = Drawing::new();
+        replayed.replay(&recording);
+        assert_eq!(svg_string(&replayed, "replay-replayed"), svg_string(&drawing, "replay-original"));
+
+        // Nothing is recorded after recording has stopped
+        turtle.forward(10.0);
+        assert!(drawing.stop_recording().is_empty());
+    }
+
+    #[test]
+    fn replay_uses_the_ids_of_replayed_stamps_and_fills() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        // Neither of these draw anything, but they still use up an ID
+        turtle.hide();
+        let hidden_stamp = turtle.stamp();
+        let empty_fill = turtle.end_fill();
+        turtle.show();
+
+        drawing.start_recording();
+        let first = turtle.stamp();
+        turtle.forward(30.0);
+        turtle.stamp();
+        turtle.clear_stamp(first);
+        // Refers to a stamp made before recording started
+        turtle.clear_stamp(hidden_stamp);
+
+        turtle.begin_fill();
+        turtle.right(90.0);
+        turtle.forward(30.0);
+        let fill = turtle.end_fill();
+        turtle.set_fill_color_of(fill, "red").unwrap();
+        assert!(turtle.set_fill_color_of(empty_fill, "blue").is_err());
+        let recording = drawing.stop_recording();
+
+        let json = serde_json::to_string(&recording).unwrap();
+        let recording: Recording = serde_json::from_str(&json).unwrap();
+
+        let mut replayed = Drawing::new();
+        replayed.replay(&recording);
+        assert_eq!(svg_string(&replayed, "replay-ids-replayed"), svg_string(&drawing, "replay-ids-original"));
+    }
+
+    #[test]
+    fn import_json_restores_export_json() {
+        let mut drawing = Drawing::new();
+        drawing.set_background_color("light blue");
+        let mut turtle = drawing.add_turtle();
+        turtle.set_fill_color("green");
+        turtle.begin_fill();
+        turtle.forward(40.0);
+        turtle.right(90.0);
+        turtle.set_pen_gradient("red", "blue");
+        turtle.forward(40.0);
+        turtle.end_fill();
+        let json = drawing.export_json();
+
+        let mut imported = Drawing::new();
+        let mut other = imported.add_turtle();
+        other.backward(100.0);
+        imported.import_json(&json).unwrap();
+        assert_eq!(imported.background_color(), drawing.background_color());
+        assert_eq!(svg_string(&imported, "json-imported"), svg_string(&drawing, "json-original"));
+        // The imported lines replaced everything that was drawn before
+        other.undo();
+        assert_eq!(other.position().round(), Point {x: 0.0, y: -100.0});
+
+        match imported.import_json("{\"background\": \"red\"}") {
+            Err(ImportJsonError::Parse(_)) => {},
+            res => panic!("expected a missing field to be a parse error, got: {:?}", res),
+        }
+        assert_eq!(imported.export_json(), json);
+    }
+
+    #[test]
+    fn import_json_rejects_invalid_values() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.set_fill_pattern(crate::FillPattern::Dots {spacing: 10.0});
+        turtle.begin_fill();
+        turtle.forward(40.0);
+        turtle.right(90.0);
+        turtle.forward(40.0);
+        turtle.end_fill();
+        let json = drawing.export_json();
+
+        let invalid = [
+            json.replace("\"spacing\":10.0", "\"spacing\":0.0"),
+            json.replace("\"thickness\":1.0", "\"thickness\":-1.0"),
+            json.replace("\"red\":255.0", "\"red\":300.0"),
+            json.replacen("\"alpha\":1.0", "\"alpha\":2.0", 1),
+        ];
+        let mut imported = Drawing::new();
+        for json in &invalid {
+            assert_eq!(imported.import_json(json), Err(ImportJsonError::InvalidDrawing), "{}", json);
+        }
+        // None of the invalid drawings replaced the drawing
+        assert_eq!(imported.export_json(), Drawing::new().export_json());
+
+        imported.import_json(&json).unwrap();
+        assert_eq!(imported.export_json(), json);
+    }
+
+    #[test]
+    fn save_png_dimensions_are_size_times_scale() {
+        let mut drawing = Drawing::new();
+        let path = std::env::temp_dir().join(format!("turtle-png-size-{}.png", std::process::id()));
+
+        for &(width, height) in &[(40, 30), (1, 1), (123, 77)] {
+            drawing.set_size([width, height]);
+
+            for &scale in &[1.0, 0.5, 1.5, 2.0, 3.0] {
+                drawing.save_png_scaled(&path, scale).unwrap();
+
+                let (size, _) = read_png(&path);
+                let expected = Size {
+                    width: (width as f64 * scale).round() as u32,
+                    height: (height as f64 * scale).round() as u32,
+                };
+                assert_eq!(size, expected, "size: {}x{}, scale: {}", width, height, scale);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid scale: 0. The scale must be greater than zero")]
+    fn rejects_png_scale_zero() {
+        let drawing = Drawing::new();
+
+        drawing.save_png_scaled("never-created.png", 0.0).unwrap();
+    }
+
+    #[test]
+    fn save_pdf_is_a_page_with_the_drawing_size() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([40, 30]);
+        let mut turtle = drawing.add_turtle();
+        turtle.set_fill_color(Color::from("green").with_alpha(0.5));
+        turtle.begin_fill();
+        turtle.set_pen_gradient("red", "blue");
+        turtle.forward(10.0);
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.end_fill();
+
+        let path = std::env::temp_dir().join(format!("turtle-export-{}.pdf", std::process::id()));
+        drawing.save_pdf(&path).unwrap();
+        let pdf = String::from_utf8_lossy(&std::fs::read(&path).unwrap()).into_owned();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(pdf.starts_with("%PDF-"));
+        assert!(pdf.contains("/MediaBox [0 0 40 30]"));
+        // The translucent fill uses a graphics state and the gradient lines use axial shadings
+        assert!(pdf.contains("/ca 0.5"));
+        assert_eq!(pdf.matches("/ShadingType 2").count(), 2);
+    }
+
+    #[test]
+    fn scale_only_changes_the_view() {
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        drawing.set_scale(2.0);
+        assert_eq!(drawing.scale(), 2.0);
+
+        turtle.forward(50.0);
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 50.0});
+        assert_eq!(drawing.size(), Size {width: 800, height: 600});
+        // The bounds include half the pen size at each end of the line
+        assert_eq!(drawing.bounds().map(|bounds| bounds.height()), Some(51.0));
+
+        drawing.reset_scale();
+        assert_eq!(drawing.scale(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid scale: 0. The scale must be greater than zero")]
+    fn rejects_scale_zero() {
+        let mut drawing = Drawing::new();
+        drawing.set_scale(0.0);
+    }
+
+    #[test]
+    fn ignores_center_nan_inf() {
+        let center = Point {x: 5.0, y: 10.0};
+
+        let mut drawing = Drawing::new();
+        drawing.set_center(center);
+
+        drawing.set_center([::std::f64::NAN, 0.0]);
+        drawing.set_center([0.0, ::std::f64::NAN]);
+        drawing.set_center([::std::f64::NAN, ::std::f64::NAN]);
+        drawing.set_center([::std::f64::INFINITY, 0.0]);
+        drawing.set_center([0.0, ::std::f64::INFINITY]);
+        drawing.set_center([::std::f64::INFINITY, ::std::f64::INFINITY]);
+        drawing.set_center([-::std::f64::INFINITY, 0.0]);
+        drawing.set_center([0.0, -::std::f64::INFINITY]);
+        drawing.set_center([-::std::f64::INFINITY, -::std::f64::INFINITY]);
+
+        assert_eq!(drawing.center(), center);
+    }
+
+    #[test]
+    fn screenshot_matches_saved_png() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([120, 80]);
+        drawing.set_background_color("light blue");
+        let mut turtle = drawing.add_turtle();
+        turtle.set_pen_color("red");
+        turtle.set_pen_size(10.0);
+        turtle.forward(30.0);
+
+        let (size, pixels) = drawing.screenshot().unwrap();
+        assert_eq!(size, Size {width: 120, height: 80});
+        assert_eq!(pixels.len(), 120 * 80 * 4);
+
+        let path = std::env::temp_dir().join(format!("turtle-screenshot-{}.png", std::process::id()));
+        drawing.save_png(&path).unwrap();
+        let (_, data) = read_png(&path);
+
+        assert_eq!(pixels, data);
+    }
+
+    #[test]
+    fn screenshot_without_antialiasing_has_no_blended_pixels() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([60, 60]);
+        drawing.set_background_color("white");
+        let mut turtle = drawing.add_turtle();
+        turtle.set_pen_color("black");
+        turtle.set_pen_size(5.0);
+        turtle.right(30.0);
+        turtle.forward(25.0);
+
+        let is_blended = |pixel: &[u8]| pixel[..3] != [0, 0, 0] && pixel[..3] != [255, 255, 255];
+
+        // Smoothing a slanted line blends some of the pixels along its edges
+        let (_, pixels) = drawing.screenshot().unwrap();
+        assert!(pixels.chunks_exact(4).any(is_blended));
+
+        drawing.set_antialiasing(false);
+        let (_, pixels) = drawing.screenshot().unwrap();
+        assert!(!pixels.chunks_exact(4).any(is_blended));
+        assert!(pixels.chunks_exact(4).any(|pixel| pixel[..3] == [0, 0, 0]));
+    }
+
+    #[test]
+    fn tracer_holds_back_drawings_until_update() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([60, 60]);
+        let mut turtle = drawing.add_turtle();
+        turtle.hide();
+        let (_, blank) = drawing.screenshot().unwrap();
+
+        turtle.set_tracer(false);
+        turtle.forward(20.0);
+        turtle.begin_fill();
+        turtle.right(90.0);
+        turtle.forward(20.0);
+        turtle.right(90.0);
+        turtle.forward(20.0);
+        turtle.end_fill();
+        turtle.stamp();
+        turtle.write("hi");
+        // Changes that would redraw the window don't show anything either
+        turtle.set_fill_color("red");
+        drawing.add_turtle().forward(0.0);
+        assert_eq!(drawing.screenshot().unwrap().1, blank);
+
+        drawing.update();
+        let (_, updated) = drawing.screenshot().unwrap();
+        assert_ne!(updated, blank);
+
+        // Enabling the tracer again shows the rest
+        turtle.forward(20.0);
+        assert_eq!(drawing.screenshot().unwrap().1, updated);
+        turtle.set_tracer(true);
+        assert_ne!(drawing.screenshot().unwrap().1, updated);
+    }
+
+    #[test]
+    #[cfg(feature = "embedded")]
+    fn render_to_matches_screenshot() {
+        let mut drawing = Drawing::new();
+        drawing.set_size([80, 40]);
+        let mut turtle = drawing.add_turtle();
+        turtle.forward(15.0);
+
+        // The previous contents of the buffer are replaced
+        let mut pixels = vec![1, 2, 3];
+        let size = drawing.render_to(&mut pixels).unwrap();
+        assert_eq!(size, Size {width: 80, height: 40});
+        // Just like the window, the image includes the turtle but screenshots don't
+        assert_ne!(drawing.screenshot().unwrap(), (size, pixels.clone()));
+        turtle.hide();
+        drawing.render_to(&mut pixels).unwrap();
+        assert_eq!(drawing.screenshot().unwrap(), (size, pixels.clone()));
+
+        // Requests sent right before rendering are always part of the image
+        drawing.set_background_color("black");
+        drawing.render_to(&mut pixels).unwrap();
+        assert_eq!(&pixels[..4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    #[cfg(feature = "embedded")]
+    fn send_event_reaches_the_drawing() {
+        use crate::event::{MouseButton::LeftButton, PressedState::Pressed, Key};
+
+        let mut drawing = Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        drawing.set_size((400, 300));
+        drawing.set_coordinate_system(crate::CoordinateSystem::TopLeftYDown);
+
+        let click = crate::Event::MouseButton(LeftButton, Pressed);
+        drawing.send_event(crate::Event::MouseMove(Point {x: 10.0, y: 20.0}));
+        drawing.send_event(click.clone());
+        assert_eq!(drawing.mouse_position(), Point {x: 10.0, y: 20.0});
+        assert_eq!(drawing.poll_events(), vec![crate::Event::MouseMove(Point {x: 10.0, y: 20.0}), click.clone()]);
+
+        // Waiting for an event returns once the application sends one
+        drawing.send_event(click);
+        turtle.wait_for_click();
+        drawing.send_event(crate::Event::Key(Key::A, Pressed));
+        assert!(drawing.is_key_pressed(Key::A));
+        assert_eq!(turtle.wait_for_key(), Key::A);
+    }
+
+    #[test]
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    fn poll_events_drains_events_in_order() {
+        use crate::event::{Key, MouseButton::LeftButton, PressedState::{Pressed, Released}};
+
+        let mut drawing = Drawing::new();
+        let events = vec![
+            crate::Event::Key(Key::A, Pressed),
+            crate::Event::MouseMove(Point {x: 5.0, y: -5.0}),
+            crate::Event::MouseButton(LeftButton, Pressed),
+            crate::Event::Key(Key::A, Released),
+        ];
+        for event in &events {
+            drawing.send_event(event.clone());
+        }
+
+        assert_eq!(drawing.poll_events(), events);
+        // Every event was returned, so there is nothing left
+        assert_eq!(drawing.poll_events(), Vec::new());
+        assert_eq!(drawing.poll_event(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    fn poll_event_matching_discards_other_events() {
+        use crate::event::{Key, MouseButton::LeftButton, PressedState::{Pressed, Released}};
+
+        let mut drawing = Drawing::new();
+        let is_click = |event: &crate::Event| matches!(event, crate::Event::MouseButton(LeftButton, Pressed));
+        let events = [
+            crate::Event::Key(Key::A, Pressed),
+            crate::Event::MouseButton(LeftButton, Pressed),
+            crate::Event::Key(Key::A, Released),
+            crate::Event::MouseMove(Point {x: 5.0, y: -5.0}),
+        ];
+        for event in &events {
+            drawing.send_event(event.clone());
+        }
+
+        // The key press before the click is discarded, but the events after it are kept
+        assert_eq!(drawing.poll_event_matching(is_click), Some(crate::Event::MouseButton(LeftButton, Pressed)));
+        assert_eq!(drawing.poll_event(), Some(crate::Event::Key(Key::A, Released)));
+
+        // Without a matching event, every remaining event is discarded
+        assert_eq!(drawing.poll_event_matching(is_click), None);
+        assert_eq!(drawing.poll_event(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    fn dropped_event_stream_does_not_take_the_next_event() {
+        use futures_util::{FutureExt, StreamExt};
+
+        let mut drawing = Drawing::new();
+        let mut events = Box::pin(drawing.drawing.events());
+        // Polling the stream once leaves it waiting for an event
+        assert_eq!(events.next().now_or_never(), None);
+        drop(events);
+
+        // Makes sure the server has seen that the stream is gone before the event is sent
+        drawing.title();
+        let event = crate::Event::Key(crate::event::Key::A, crate::event::PressedState::Pressed);
+        drawing.send_event(event.clone());
+        // Gives the server a chance to hand the event to anyone still waiting for one
+        drawing.title();
+        assert_eq!(drawing.poll_event(), Some(event));
+    }
+
+    #[test]
+    fn poll_events_is_empty_when_idle() {
+        let mut drawing = Drawing::new();
+        assert_eq!(drawing.poll_events(), Vec::new());
+        assert_eq!(drawing.poll_event(), None);
+    }
+
+    #[test]
+    fn show_fps_keeps_title() {
+        let mut drawing = Drawing::new();
+        drawing.set_title("My Drawing");
+        drawing.show_fps(true);
+        assert!(drawing.is_showing_fps());
+        assert_eq!(drawing.title(), "My Drawing");
+
+        drawing.show_fps(false);
+        assert!(!drawing.is_showing_fps());
     }
 }
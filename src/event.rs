@@ -8,14 +8,20 @@
 //! crate. This module may change or be completely removed in the future. There will definitely
 //! be *some* events API in the future, but it may end up looking different than it does today.
 
+use std::time::Duration;
+
 use serde::{Serialize, Deserialize};
 use glutin::{
-    dpi::{LogicalSize, PhysicalPosition},
-    event::{self as glutin_event, WindowEvent, KeyboardInput},
+    dpi::{LogicalSize, LogicalPosition, PhysicalPosition},
+    event::{self as glutin_event, WindowEvent, KeyboardInput, MouseScrollDelta},
 };
 
 use crate::Point;
 
+/// The number of logical pixels that are treated as one line of scrolling when a device (e.g. a
+/// touchpad) reports scrolling in pixels
+const PIXELS_PER_SCROLL_LINE: f64 = 20.0;
+
 /// Possible events returned from [`Drawing::poll_event()`](../struct.Drawing.html#method.poll_event).
 ///
 /// Events are used to make programs more interactive. See that method's documentation for more
@@ -24,8 +30,19 @@ use crate::Point;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Event {
     /// Sent when a keyboard key is pressed or released
+    ///
+    /// Each press of a key is only sent once, even if the key is held down. The presses that the
+    /// operating system repeats while a key is held down are sent as `KeyHeld` events instead.
     Key(Key, PressedState),
 
+    /// Sent repeatedly while a keyboard key is held down, as often as the operating system repeats
+    /// the key
+    ///
+    /// `duration` is how long the key has been held down for. Use this for actions that should
+    /// keep happening for as long as a key is held (e.g. moving a character), and use `Key` events
+    /// for actions that should only happen once per press.
+    KeyHeld { key: Key, duration: Duration },
+
     /// Sent when a mouse button is pressed or released
     MouseButton(MouseButton, PressedState),
 
@@ -35,12 +52,24 @@ pub enum Event {
     /// Coordinates are relative to the center of the window.
     MouseMove(Point),
 
-    /// Sent when the mouse is scrolled. Only sent when the mouse is over the window.
-    /// `x` and `y` are in scroll ticks.
-    MouseScroll { x: f64, y: f64 },
+    /// Sent when the mouse wheel or touchpad is scrolled. Only sent when the mouse is over the
+    /// window.
+    ///
+    /// `delta_x` and `delta_y` are measured in lines, the same unit as one "click" of a typical
+    /// mouse wheel. Scrolling from devices that report a number of pixels instead (e.g. most
+    /// touchpads) is converted to lines, so the amounts may be fractional. Positive values of
+    /// `delta_y` mean scrolling up (away from the user) and positive values of `delta_x` mean
+    /// scrolling to the right.
+    MouseScroll { delta_x: f64, delta_y: f64 },
 
     /// Sent when the window gets resized
-    WindowResized { width: u32, height: u32 },
+    ///
+    /// `width` and `height` are the new size of the window in logical pixels, the same units used
+    /// by [`Drawing::size()`](../struct.Drawing.html#method.size) and by the coordinates of the
+    /// mouse. They may be fractional if the window is on a display with a fractional scale factor.
+    /// The size of the drawing has already been updated by the time this event is received, so it
+    /// is safe to use it to lay out the drawing again.
+    WindowResized { width: f64, height: f64 },
 
     /// Sent when the window focus changes
     ///
@@ -51,13 +80,29 @@ pub enum Event {
     /// The boolean value is true if the cursor entered the window, and false if it left.
     WindowCursor(bool),
 
+    /// Sent when the user tries to close the window (e.g. with its close button), but only if
+    /// closing the window was disabled with
+    /// [`Drawing::set_exit_on_close()`](../struct.Drawing.html#method.set_exit_on_close)
+    ///
+    /// The window stays open, so the program can decide what to do, e.g. save the drawing and
+    /// then call [`Drawing::destroy()`](../struct.Drawing.html#method.destroy).
+    CloseRequested,
+
     /// Sent when the window is closed
     WindowClosed,
+
+    /// Sent after a frame is drawn in the window, but only if tick events were enabled with
+    /// [`Drawing::set_tick_events()`](../struct.Drawing.html#method.set_tick_events)
+    ///
+    /// `dt` is the amount of time that has passed since the previous `Tick` event (or zero for
+    /// the first one). Ticks are coalesced: a new tick is only sent once the previous one has been
+    /// received, so `dt` may span several frames if events are not polled often enough.
+    Tick { dt: Duration },
 }
 
 impl Event {
     /// Returns `None` if the input event is not a supported variant of `Event`
-    #[cfg_attr(any(feature = "test", test), allow(dead_code))]
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
     pub(crate) fn from_window_event(
         event: WindowEvent,
         scale_factor: f64,
@@ -84,17 +129,27 @@ impl Event {
                 MouseButton::from_button(button)?,
                 PressedState::from_state(state),
             )),
+            WindowEvent::MouseWheel {delta, ..} => {
+                let (delta_x, delta_y) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => (x as f64, y as f64),
+                    MouseScrollDelta::PixelDelta(position) => {
+                        let LogicalPosition {x, y} = position.to_logical::<f64>(scale_factor);
+                        (x / PIXELS_PER_SCROLL_LINE, y / PIXELS_PER_SCROLL_LINE)
+                    },
+                };
+                Some(Event::MouseScroll {delta_x, delta_y})
+            },
             WindowEvent::Focused(focused) => Some(Event::WindowFocused(focused)),
             WindowEvent::Destroyed => Some(Event::WindowClosed),
 
-            WindowEvent::Moved(_) |
+            // Only sent if closing the window is disabled, so that is checked elsewhere
             WindowEvent::CloseRequested |
+            WindowEvent::Moved(_) |
             WindowEvent::DroppedFile(_) |
             WindowEvent::HoveredFile(_) |
             WindowEvent::HoveredFileCancelled |
             WindowEvent::ReceivedCharacter(_) |
             WindowEvent::ModifiersChanged(_) |
-            WindowEvent::MouseWheel {..} |
             WindowEvent::TouchpadPressure {..} |
             WindowEvent::AxisMotion {..} |
             WindowEvent::Touch(_) |
@@ -112,7 +167,7 @@ pub enum PressedState {
 }
 
 impl PressedState {
-    #[cfg_attr(any(feature = "test", test), allow(dead_code))]
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
     fn from_state(state: glutin_event::ElementState) -> PressedState {
         match state {
             glutin_event::ElementState::Pressed => PressedState::Pressed,
@@ -261,7 +316,7 @@ pub enum Key {
 }
 
 impl Key {
-    #[cfg_attr(any(feature = "test", test), allow(dead_code))]
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
     fn from_keycode(key: glutin_event::VirtualKeyCode) -> Option<Self> {
         use glutin_event::VirtualKeyCode::*;
         #[deny(unreachable_patterns, unused_variables)]
@@ -452,7 +507,7 @@ pub enum MouseButton {
 }
 
 impl MouseButton {
-    #[cfg_attr(any(feature = "test", test), allow(dead_code))]
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
     fn from_button(button: glutin_event::MouseButton) -> Option<Self> {
         use glutin_event::MouseButton::*;
         #[deny(unreachable_patterns, unused_variables)]
@@ -464,3 +519,44 @@ impl MouseButton {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glutin::dpi::PhysicalSize;
+
+    #[test]
+    fn window_resized_uses_logical_size() {
+        let event = WindowEvent::Resized(PhysicalSize::new(1600, 1200));
+        let event = Event::from_window_event(event, 2.0, |_| unreachable!("no position to convert"));
+        assert_eq!(event, Some(Event::WindowResized {width: 800.0, height: 600.0}));
+
+        let event = WindowEvent::Resized(PhysicalSize::new(1001, 750));
+        let event = Event::from_window_event(event, 1.25, |_| unreachable!("no position to convert"));
+        assert_eq!(event, Some(Event::WindowResized {width: 800.8, height: 600.0}));
+    }
+
+    #[test]
+    #[allow(deprecated)] // The `modifiers` field must still be provided
+    fn mouse_scroll_is_measured_in_lines() {
+        use glutin::event::{DeviceId, ModifiersState, TouchPhase};
+
+        let scroll = |delta, scale_factor| Event::from_window_event(WindowEvent::MouseWheel {
+            // Safe because the device ID is never used to identify a real device
+            device_id: unsafe { DeviceId::dummy() },
+            delta,
+            phase: TouchPhase::Moved,
+            modifiers: ModifiersState::empty(),
+        }, scale_factor, |_| unreachable!("no position to convert"));
+
+        // Mouse wheels report lines, which are passed through as is
+        assert_eq!(scroll(MouseScrollDelta::LineDelta(-1.0, 2.0), 2.0),
+            Some(Event::MouseScroll {delta_x: -1.0, delta_y: 2.0}));
+
+        // Touchpads report physical pixels, which are converted to lines
+        let delta = MouseScrollDelta::PixelDelta(PhysicalPosition::new(80.0, -20.0));
+        assert_eq!(scroll(delta, 2.0), Some(Event::MouseScroll {delta_x: 2.0, delta_y: -0.5}));
+        assert_eq!(scroll(delta, 1.0), Some(Event::MouseScroll {delta_x: 4.0, delta_y: -1.0}));
+    }
+}
@@ -0,0 +1,146 @@
+use serde::{Serialize, Deserialize};
+use thiserror::Error;
+
+use crate::{Angle, Distance};
+
+/// Identifies a filled shape completed by the [`end_fill()` method]
+///
+/// Pass this to the [`set_fill_color_of()` method] to change the color of that shape after it has
+/// been drawn. A `FillId` can only be used with the turtle that created it.
+///
+/// [`end_fill()` method]: struct.Turtle.html#method.end_fill
+/// [`set_fill_color_of()` method]: struct.Turtle.html#method.set_fill_color_of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FillId(pub(crate) usize);
+
+/// The error returned by the [`set_fill_color_of()` method] when the filled shape is not on the
+/// drawing
+///
+/// This happens if the shape has been removed (e.g. by [`clear()`] or [`undo()`]), or if the
+/// `FillId` was returned by a call to [`end_fill()`] that didn't fill anything.
+///
+/// [`set_fill_color_of()` method]: struct.Turtle.html#method.set_fill_color_of
+/// [`clear()`]: struct.Turtle.html#method.clear
+/// [`undo()`]: struct.Turtle.html#method.undo
+/// [`end_fill()`]: struct.Turtle.html#method.end_fill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Serialize, Deserialize)]
+#[error("the filled shape {0:?} is not on the drawing")]
+pub struct UnknownFillError(pub FillId);
+
+/// Decides which parts of a filled shape are inside the shape when its outline crosses over
+/// itself
+///
+/// See the [`set_fill_rule()` method] for more information.
+///
+/// You can create a `FillRule` value by converting one of the strings `"nonzero"` or
+/// `"evenodd"`. Any other string will cause a panic.
+///
+/// ```rust
+/// # use turtle::FillRule;
+/// let rule: FillRule = "evenodd".into();
+/// assert_eq!(rule, FillRule::EvenOdd);
+/// ```
+///
+/// [`set_fill_rule()` method]: struct.Turtle.html#method.set_fill_rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FillRule {
+    /// Every area enclosed by the outline is filled, unless the outline goes around it the same
+    /// number of times in each direction
+    ///
+    /// A star drawn with a single outline is completely filled in, including its center.
+    NonZero,
+    /// Areas that are enclosed by the outline an odd number of times are filled and areas that
+    /// are enclosed an even number of times are left empty
+    ///
+    /// A star drawn with a single outline has a hole in its center.
+    EvenOdd,
+}
+
+/// The default fill rule is "nonzero"
+impl Default for FillRule {
+    fn default() -> Self {
+        FillRule::NonZero
+    }
+}
+
+impl<'a> From<&'a str> for FillRule {
+    fn from(rule: &'a str) -> Self {
+        match rule {
+            "nonzero" => FillRule::NonZero,
+            "evenodd" => FillRule::EvenOdd,
+            _ => panic!("Invalid fill rule specified, use one of the words: \"nonzero\", \"evenodd\""),
+        }
+    }
+}
+
+/// The smallest distance allowed between the lines or dots of a fill pattern
+///
+/// Lines any closer together than this would overlap and look like a solid fill anyway.
+pub(crate) const MIN_PATTERN_SPACING: Distance = 2.0;
+
+/// The way that a filled shape is painted with its fill color
+///
+/// See the [`set_fill_pattern()` method] for more information.
+///
+/// Every pattern is drawn in the fill color. The lines and dots of a pattern are lined up with the
+/// origin, so shapes next to each other that are filled with the same pattern line up as well.
+/// The spacing of a pattern must be at least `2.0`.
+///
+/// [`set_fill_pattern()` method]: struct.Turtle.html#method.set_fill_pattern
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FillPattern {
+    /// The entire shape is filled in
+    Solid,
+    /// The shape is filled with evenly spaced parallel lines
+    Hatch {
+        /// The direction of the lines, measured counterclockwise from the positive x-axis in the
+        /// angle unit of the turtle (e.g. `0.0` for horizontal lines)
+        angle: Angle,
+        /// The distance between each line
+        spacing: Distance,
+    },
+    /// The shape is filled with two sets of evenly spaced diagonal lines that cross each other
+    CrossHatch {
+        /// The distance between each line
+        spacing: Distance,
+    },
+    /// The shape is filled with a grid of dots
+    Dots {
+        /// The distance between each dot and the dots next to it
+        spacing: Distance,
+    },
+}
+
+/// The default fill pattern is `Solid`
+impl Default for FillPattern {
+    fn default() -> Self {
+        FillPattern::Solid
+    }
+}
+
+impl FillPattern {
+    /// Returns this pattern with the given function applied to its angle, if it has one
+    ///
+    /// Used to convert the angle between the unit used by the turtle and radians.
+    pub(crate) fn map_angle<F: FnOnce(Angle) -> Angle>(self, f: F) -> Self {
+        match self {
+            FillPattern::Hatch {angle, spacing} => FillPattern::Hatch {angle: f(angle), spacing},
+            pattern => pattern,
+        }
+    }
+
+    /// Returns true if this pattern has a finite angle and a spacing of at least
+    /// `MIN_PATTERN_SPACING`
+    pub(crate) fn is_valid(&self) -> bool {
+        match *self {
+            FillPattern::Solid => true,
+            FillPattern::Hatch {angle, spacing} => angle.is_finite() && is_valid_pattern_spacing(spacing),
+            FillPattern::CrossHatch {spacing} | FillPattern::Dots {spacing} => is_valid_pattern_spacing(spacing),
+        }
+    }
+}
+
+/// Returns true if the given spacing can be used for the lines or dots of a fill pattern
+pub(crate) fn is_valid_pattern_spacing(spacing: Distance) -> bool {
+    spacing >= MIN_PATTERN_SPACING && spacing.is_finite()
+}
@@ -1,9 +1,12 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use serde::{Serialize, Deserialize};
 
-use crate::{Color, Point, Speed, Event, Distance, Size};
-use crate::renderer_server::{TurtleId, ExportError};
+use crate::event::Key;
+use crate::{Color, Point, Rect, Speed, Event, Distance, Size, TextAlign, LineCap, LineJoin, PenState, StampId, TurtleShape, FillRule, FillPattern, FillId, UnknownFillError};
+use crate::path_recorder::PathId;
+use crate::renderer_server::{TurtleId, ExportError, DisplayListData};
 use crate::{async_turtle::AngleUnit, radians::Radians, debug};
 
 /// The different kinds of requests that can be sent from a client
@@ -23,16 +26,69 @@ pub enum ClientRequest {
     ///
     /// Response: `ServerResponse::NewTurtle`
     CreateTurtle,
+    /// Creates a new turtle with the same state (position, heading, pen, etc.) as the given turtle
+    /// and provides the `TurtleId` that can be used to control it
+    ///
+    /// Nothing drawn by the given turtle is copied to the new turtle.
+    ///
+    /// Response: `ServerResponse::NewTurtle`
+    CloneTurtle(TurtleId),
 
     /// Export the drawing in its current state to the given path using the given format
     ///
     /// Response: `ServerResponse::ExportComplete`
     Export(PathBuf, ExportFormat),
+    /// Render the drawing in its current state to an image in memory
+    ///
+    /// Response: `ServerResponse::Screenshot`
+    Screenshot,
+    /// Get everything that has been drawn so far, along with the background color of the drawing
+    ///
+    /// Response: `ServerResponse::DisplayList`
+    ExportDisplayList,
+    /// Replace everything that has been drawn so far with the given primitives and set the
+    /// background color of the drawing
+    ///
+    /// Like `ClearAll`, this also clears the drawings of every turtle so that they cannot be
+    /// undone.
+    ///
+    /// Response: N/A
+    ImportDisplayList(DisplayListData),
+    /// Start capturing the frames rendered in the window, at (at most) the given frame rate, so
+    /// they can be saved as a GIF at the given path
+    ///
+    /// Any frames captured by a recording that was already in progress are discarded.
+    ///
+    /// Response: N/A
+    StartGifRecording(PathBuf, f64),
+    /// Stop capturing frames and save the GIF
+    ///
+    /// Response: `ServerResponse::GifRecordingComplete`
+    StopGifRecording,
+    /// Get the smallest rectangle that contains everything that has been drawn so far
+    ///
+    /// Response: `ServerResponse::Bounds`
+    DrawingBounds,
 
     /// Get the next event (if any are currently available)
     ///
     /// Response: `ServerResponse::Event`
     PollEvent,
+    /// Wait for the next event
+    ///
+    /// The server does not respond until an event is available, so a client that sends this
+    /// request should not be used to send any other requests in the meantime.
+    ///
+    /// Response: `ServerResponse::Event`
+    NextEvent,
+    /// Get the current position of the mouse, as of the most recent event
+    ///
+    /// Response: `ServerResponse::MousePosition`
+    MousePosition,
+    /// Get the keys that are currently held down, as of the most recent event
+    ///
+    /// Response: `ServerResponse::PressedKeys`
+    PressedKeys,
 
     /// Get the given property of the drawing
     ///
@@ -46,6 +102,17 @@ pub enum ClientRequest {
     ///
     /// Response: N/A
     ResetDrawingProp(DrawingProp),
+    /// Load the PNG image at the given path and use it as the icon of the window
+    ///
+    /// Response: `ServerResponse::SetIconComplete`
+    SetIcon(PathBuf),
+    /// Redraw the window, even if nothing has changed
+    ///
+    /// The server does not respond until the frame has been drawn, so every change made before
+    /// this request is on the screen by the time the client receives the response.
+    ///
+    /// Response: `ServerResponse::DrawingUpdated`
+    UpdateDrawing,
 
     /// Get the given property of a turtle
     ///
@@ -76,6 +143,10 @@ pub enum ClientRequest {
     ///
     /// Response: `ServerResponse::AnimationComplete`
     MoveForward(TurtleId, Distance),
+    /// Move a turtle forward by the given amount over the given duration, ignoring its speed
+    ///
+    /// Response: `ServerResponse::AnimationComplete`
+    MoveForwardIn(TurtleId, Distance, Duration),
     /// Move a turtle to the given position
     ///
     /// The turtle movement is animated at its current speed. This may draw a line if the turtle's
@@ -88,6 +159,12 @@ pub enum ClientRequest {
     ///
     /// Response: `ServerResponse::AnimationComplete`
     MoveTo(TurtleId, Point),
+    /// Instantly move a turtle to the given point and set its heading to the given angle
+    ///
+    /// There is no animation and no line is drawn, regardless of the turtle's speed and pen.
+    ///
+    /// Response: N/A
+    Teleport(TurtleId, Point, Radians),
     /// Rotate a turtle in place by the given angle in the given direction
     ///
     /// The turtle rotates with an animation at its current speed. It will not draw any line while
@@ -99,6 +176,44 @@ pub enum ClientRequest {
     ///
     /// Response: `ServerResponse::AnimationComplete`
     RotateInPlace(TurtleId, Radians, RotationDirection),
+    /// Wait for the animation currently playing for a turtle to complete
+    ///
+    /// If no animation is playing for the turtle, the response is sent right away. Since requests
+    /// are handled in order, the response is never sent before any earlier requests are handled.
+    ///
+    /// Response: `ServerResponse::AnimationComplete`
+    WaitForAnimation(TurtleId),
+    /// Run the given commands for a turtle in order, all at once
+    ///
+    /// None of the commands are animated, regardless of the turtle's speed. The turtle jumps
+    /// straight to wherever the last command leaves it, drawing every line along the way. This
+    /// takes a single request no matter how many commands there are.
+    ///
+    /// Response: `ServerResponse::AnimationComplete`
+    Batch(TurtleId, Vec<BatchCommand>),
+    /// Change the background color of the drawing, gradually fading from the current background
+    /// color to the given color over the given number of seconds
+    ///
+    /// A duration that is zero or negative changes the background color right away.
+    ///
+    /// Response: `ServerResponse::BackgroundAnimationComplete`
+    AnimateBackground(Color, f64),
+    /// Move the center of the drawing, gradually panning from the current center to the given
+    /// point over the given number of seconds
+    ///
+    /// A duration that is zero or negative changes the center right away.
+    ///
+    /// Response: `ServerResponse::CenterAnimationComplete`
+    AnimateCenter(Point, f64),
+    /// Change the color of the fill that a turtle most recently completed, gradually fading from
+    /// the first color to the second color over the given number of seconds
+    ///
+    /// A duration that is zero or negative (or a turtle with its tracer disabled) changes the fill
+    /// color to the second color right away. If the turtle has not completed any fill that is
+    /// still drawn, this request does nothing. The turtle's fill color property is not changed.
+    ///
+    /// Response: `ServerResponse::AnimationComplete`
+    AnimateFillColor(TurtleId, Color, Color, f64),
 
     /// Creates a fill polygon from a turtle's current position
     ///
@@ -115,10 +230,18 @@ pub enum ClientRequest {
     /// No further points will be added to the polygon and it will remain the color that it was at
     /// the time that this request was sent.
     ///
-    /// If the turtle was not filling when this request was sent, this request is ignored.
+    /// If the turtle was not filling when this request was sent, no polygon is completed and the
+    /// returned ID does not refer to any fill.
     ///
-    /// Response: N/A
+    /// Response: `ServerResponse::FillEnded`
     EndFill(TurtleId),
+    /// Changes the fill color of a fill polygon completed by a turtle
+    ///
+    /// The turtle's group opacity is applied to the color, just like for any other fill it draws.
+    /// If the polygon is no longer drawn (e.g. it was cleared), an error is sent back instead.
+    ///
+    /// Response: `ServerResponse::FillColorSet`
+    SetFillColorOf(TurtleId, FillId, Color),
 
     /// Clears all drawings for all turtles that exist at the time when the request is sent
     ///
@@ -142,6 +265,94 @@ pub enum ClientRequest {
     /// Response: N/A
     ClearTurtle(TurtleId),
 
+    /// Draws text for a turtle with the given font size and alignment
+    ///
+    /// The text is drawn with the turtle's pen color and its first line has its baseline at the
+    /// turtle's current position. Text is drawn even if the pen is up. The turtle does not move.
+    ///
+    /// Response: N/A
+    WriteText(TurtleId, String, f64, TextAlign),
+
+    /// Leaves a copy of a turtle's shell on the drawing at its current position and heading
+    ///
+    /// The stamp is filled with the turtle's fill color and outlined with its pen color. If the
+    /// turtle is hidden, the stamp is still created, but nothing is drawn.
+    ///
+    /// Response: `ServerResponse::Stamp`
+    Stamp(TurtleId),
+    /// Removes a single stamp created by a turtle
+    ///
+    /// If the stamp was already removed, this request is ignored.
+    ///
+    /// Response: N/A
+    ClearStamp(TurtleId, StampId),
+    /// Removes all stamps created by a turtle
+    ///
+    /// Response: N/A
+    ClearStamps(TurtleId),
+
+    /// Undoes the most recent command run by a single turtle
+    ///
+    /// The turtle's position, heading, pen, and fill color are restored to what they were before
+    /// the command and any drawings created by the command are removed. If the command started or
+    /// ended a fill, the turtle goes back to filling the way it was before. Nothing happens if
+    /// there are no commands left to undo. Clearing or resetting the turtle also removes any
+    /// history of commands that could have been undone.
+    ///
+    /// Response: N/A
+    Undo(TurtleId),
+    /// Starts grouping the commands run by the given turtle so that they are all undone at once
+    ///
+    /// The group continues until `EndUndoGroup` is sent. Starting a group while another group is
+    /// running has no effect.
+    ///
+    /// Response: N/A
+    BeginUndoGroup(TurtleId),
+    /// Stops grouping the commands run by the given turtle, so that each command after this is
+    /// undone on its own again
+    ///
+    /// Response: N/A
+    EndUndoGroup(TurtleId),
+
+    /// Sets the maximum number of recent positions to record for the given turtle
+    ///
+    /// A position is recorded every time the turtle finishes moving. Once the maximum is reached,
+    /// the oldest position is discarded whenever a new one is recorded. A capacity of zero stops
+    /// recording positions. Any positions already recorded are cleared.
+    ///
+    /// Response: N/A
+    SetTrackHistory(TurtleId, usize),
+    /// Returns the recent positions recorded for the given turtle, from oldest to most recent
+    ///
+    /// Response: `ServerResponse::PositionHistory`
+    PositionHistory(TurtleId),
+    /// Starts recording every position that the given turtle moves to, beginning with its
+    /// current position
+    ///
+    /// If a polygon was already being recorded, the points recorded so far are discarded.
+    ///
+    /// Response: N/A
+    BeginPoly(TurtleId),
+    /// Stops recording positions for the given turtle and returns the recorded points
+    ///
+    /// Response: `ServerResponse::Poly`
+    EndPoly(TurtleId),
+    /// Starts a new recording of every position that the given turtle moves to, beginning with
+    /// its current position
+    ///
+    /// Unlike `BeginPoly`, any number of paths can be recorded for the same turtle at once.
+    ///
+    /// Response: `ServerResponse::PathStarted`
+    StartPath(TurtleId),
+    /// Returns the points recorded so far for the given path of the given turtle
+    ///
+    /// Response: `ServerResponse::Path`
+    RecordedPath(TurtleId, PathId),
+    /// Stops recording the given path of the given turtle and discards its points
+    ///
+    /// Response: N/A
+    StopPath(TurtleId, PathId),
+
     /// Returns the entire current state of the given turtle in a format useful
     /// for printing only.
     ///
@@ -157,6 +368,78 @@ pub enum ClientRequest {
     ///
     /// Response: N/A
     DestroyDrawing,
+    /// Forget the client that sent this request, which will not send any more requests
+    ///
+    /// If the client was waiting for an event or for the next frame, it stops waiting so that the
+    /// event or frame is not sent to a client that no longer exists.
+    ///
+    /// Response: N/A
+    ReleaseClient,
+}
+
+impl ClientRequest {
+    /// Returns the ID of the turtle that this request applies to, if any
+    pub fn turtle_id_mut(&mut self) -> Option<&mut TurtleId> {
+        use ClientRequest::*;
+        match self {
+            CloneTurtle(id) |
+            TurtleProp(id, _) |
+            SetTurtleProp(id, _) |
+            ResetTurtleProp(id, _) |
+            ResetTurtle(id) |
+            MoveForward(id, _) |
+            MoveForwardIn(id, _, _) |
+            MoveTo(id, _) |
+            Teleport(id, _, _) |
+            RotateInPlace(id, _, _) |
+            WaitForAnimation(id) |
+            Batch(id, _) |
+            AnimateFillColor(id, ..) |
+            BeginFill(id) |
+            EndFill(id) |
+            SetFillColorOf(id, ..) |
+            ClearTurtle(id) |
+            WriteText(id, _, _, _) |
+            Stamp(id) |
+            ClearStamp(id, _) |
+            ClearStamps(id) |
+            Undo(id) |
+            BeginUndoGroup(id) |
+            EndUndoGroup(id) |
+            SetTrackHistory(id, _) |
+            PositionHistory(id) |
+            BeginPoly(id) |
+            EndPoly(id) |
+            StartPath(id) |
+            RecordedPath(id, _) |
+            StopPath(id, _) |
+            DebugTurtle(id, _) => Some(id),
+
+            CreateTurtle |
+            Export(..) |
+            Screenshot |
+            ExportDisplayList |
+            ImportDisplayList(_) |
+            StartGifRecording(..) |
+            StopGifRecording |
+            DrawingBounds |
+            PollEvent |
+            NextEvent |
+            MousePosition |
+            PressedKeys |
+            DrawingProp(_) |
+            SetDrawingProp(_) |
+            ResetDrawingProp(_) |
+            SetIcon(_) |
+            UpdateDrawing |
+            AnimateBackground(..) |
+            AnimateCenter(..) |
+            ClearAll |
+            DebugDrawing |
+            DestroyDrawing |
+            ReleaseClient => None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -166,25 +449,70 @@ pub enum ServerResponse {
 
     /// The result of the export, possibly an error if something went wrong
     ExportComplete(Result<(), ExportError>),
+    /// The size of the rendered image and its pixels (4 bytes of RGBA each, row by row), or an
+    /// error if the image could not be rendered
+    Screenshot(Result<(Size, Vec<u8>), ExportError>),
+    /// The result of loading the window icon, possibly an error if the image could not be loaded
+    SetIconComplete(Result<(), ExportError>),
+    /// Everything that has been drawn so far, along with the background color of the drawing
+    DisplayList(DisplayListData),
+    /// The result of saving the GIF recording, possibly an error if no recording was started or if
+    /// the GIF could not be saved
+    GifRecordingComplete(Result<(), ExportError>),
+    /// The smallest rectangle that contains everything that has been drawn so far, or `None` if
+    /// nothing has been drawn
+    Bounds(Option<Rect>),
 
     /// The next event, if any
     ///
-    /// A response of `None` only indicates that no events are *currently* available, NOT that no
-    /// events will *ever* be available.
+    /// In response to `PollEvent`, a response of `None` only indicates that no events are
+    /// *currently* available, NOT that no events will *ever* be available. In response to
+    /// `NextEvent`, `None` means that no more events will ever be sent.
     Event(Option<Event>),
+    /// The current position of the mouse in logical coordinates
+    MousePosition(Point),
+    /// The keys that are currently held down, in the order in which they were pressed
+    PressedKeys(Vec<Key>),
 
     /// The value of the given property of the drawing
     DrawingProp(DrawingPropValue),
+    /// The window was redrawn in response to `UpdateDrawing`
+    DrawingUpdated,
 
     /// The value of the given property of a turtle
     TurtleProp(TurtleId, TurtlePropValue),
 
-    /// An animation was completed for a given turtle
-    AnimationComplete(TurtleId),
+    /// An animation was completed for a given turtle, along with the position and heading of the
+    /// turtle once it completed
+    AnimationComplete(TurtleId, Point, Radians),
+    /// An animation of the background color was completed
+    BackgroundAnimationComplete,
+    /// An animation of the center of the drawing was completed
+    CenterAnimationComplete,
+
+    /// The recent positions of a given turtle, from oldest to most recent
+    PositionHistory(TurtleId, Vec<Point>),
+    /// The points recorded for a given turtle since it began recording a polygon, or no points if
+    /// it was not recording one
+    Poly(TurtleId, Vec<Point>),
+    /// The ID of a path that just started being recorded for a given turtle
+    PathStarted(TurtleId, PathId),
+    /// The points recorded so far for a path of a given turtle, or no points if that path is not
+    /// being recorded
+    Path(TurtleId, Vec<Point>),
+
+    /// The ID of a newly created stamp for a given turtle, guaranteed to be unique for that turtle
+    Stamp(TurtleId, StampId),
+    /// The ID of a fill that was just completed by a given turtle, guaranteed to be unique for
+    /// that turtle
+    FillEnded(TurtleId, FillId),
+    /// The result of changing the fill color of a fill completed by a given turtle, which is an
+    /// error if that fill is no longer drawn
+    FillColorSet(TurtleId, Result<(), UnknownFillError>),
 
     /// A representation of the entire state of a turtle, suitable for printing
-    /// only
-    DebugTurtle(TurtleId, debug::Turtle),
+    /// only (boxed since it is much larger than every other response)
+    DebugTurtle(TurtleId, Box<debug::Turtle>),
     /// A representation of the entire state of the drawing, suitable for
     /// printing only
     DebugDrawing(debug::Drawing),
@@ -196,9 +524,26 @@ pub enum RotationDirection {
     Counterclockwise,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// A command that can be run as part of a `Batch` request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BatchCommand {
+    /// Move forward by the given amount, just like `MoveForward`
+    MoveForward(Distance),
+    /// Move to the given position, just like `MoveTo`
+    MoveTo(Point),
+    /// Rotate in place, just like `RotateInPlace`
+    RotateInPlace(Radians, RotationDirection),
+    /// Lift the pen up (false) or put it down (true)
+    SetPenEnabled(bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ExportFormat {
     Svg,
+    /// A rasterized PNG image, with the size of the drawing multiplied by the given scale factor
+    Png(f64),
+    /// A single page PDF document with the same size as the drawing
+    Pdf,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -211,6 +556,15 @@ pub enum DrawingProp {
     Height,
     IsMaximized,
     IsFullscreen,
+    WindowPosition,
+    Grid,
+    ShowAxes,
+    Scale,
+    ExitOnEscape,
+    ExitOnClose,
+    ShowFps,
+    TickEvents,
+    Antialiasing,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -223,25 +577,55 @@ pub enum DrawingPropValue {
     Height(u32),
     IsMaximized(bool),
     IsFullscreen(bool),
+    /// The x and y position of the top left corner of the window on the screen
+    WindowPosition(i32, i32),
+    Grid(Option<f64>),
+    ShowAxes(bool),
+    /// The zoom level of the view, which does not change any coordinates of the drawing
+    Scale(f64),
+    ExitOnEscape(bool),
+    /// If false, closing the window sends `Event::CloseRequested` instead of closing it
+    ExitOnClose(bool),
+    /// If true, the measured frame rate is shown after the title of the window (but is never
+    /// part of `Title`)
+    ShowFps(bool),
+    /// If true, the window keeps drawing frames and sends an `Event::Tick` for each one
+    TickEvents(bool),
+    /// If true, the edges of shapes are smoothed in the window and in exported images
+    Antialiasing(bool),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TurtleProp {
     Pen(PenProp),
     FillColor,
+    FillRule,
+    FillPattern,
+    GroupOpacity,
     IsFilling,
     Position,
     PositionX,
     PositionY,
     Heading,
     Speed,
+    MovementRate,
+    RotationRate,
+    Tracer,
     IsVisible,
+    Shape,
+    ShapeScale,
+    ShapeColors,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TurtlePropValue {
     Pen(PenPropValue),
     FillColor(Color),
+    FillRule(FillRule),
+    /// The angle of a hatch pattern is always in radians
+    FillPattern(FillPattern),
+    /// Multiplied with the alpha of the pen and fill colors of anything drawn after it is set
+    GroupOpacity(f64),
     /// NOTE: Instead of using this with `SetTurtleProp`, use `BeginFill` and `EndFill` instead.
     IsFilling(bool),
     /// NOTE: Instead of using this with `SetTurtleProp`, use `MoveTo` instead.
@@ -252,20 +636,53 @@ pub enum TurtlePropValue {
     PositionY(f64),
     /// NOTE: Instead of using this with `SetTurtleProp`, use `RotateInPlace` instead.
     Heading(Radians),
+    /// Setting the `Speed` also clears the `MovementRate` and `RotationRate`, so that the turtle
+    /// moves and rotates at the rates given by the speed
     Speed(Speed),
+    /// The movement speed in pixels per second, or `None` if the rate given by `Speed` is used
+    MovementRate(Option<Distance>),
+    /// The rotation speed per second, or `None` if the rate given by `Speed` is used
+    RotationRate(Option<Radians>),
+    Tracer(bool),
     IsVisible(bool),
+    Shape(TurtleShape),
+    /// Multiplied with the size of the shape when the turtle is drawn
+    ShapeScale(f64),
+    /// The outline and fill colors of the turtle shape, in that order
+    ShapeColors(Color, Color),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PenProp {
     IsEnabled,
     Thickness,
+    ThicknessChange,
     Color,
+    Gradient,
+    Cap,
+    Join,
+    /// Every setting of the pen at once
+    State,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PenPropValue {
     IsEnabled(bool),
     Thickness(f64),
+    /// The thickness that the pen is gradually changing to and the distance that the turtle has
+    /// left to travel before the pen reaches that thickness, or `None` if the thickness of the pen
+    /// stays the same as the turtle moves
+    ///
+    /// Setting the `Thickness` of the pen also stops it from changing.
+    ThicknessChange(Option<(f64, f64)>),
     Color(Color),
+    /// The start and end colors of the gradient drawn by the pen, or `None` if the pen draws in a
+    /// solid color
+    ///
+    /// Setting the `Color` of the pen also switches it back to drawing in a solid color.
+    Gradient(Option<(Color, Color)>),
+    Cap(LineCap),
+    Join(LineJoin),
+    /// Setting the `State` of the pen also stops its thickness from changing
+    State(PenState),
 }
@@ -1,9 +1,17 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
 
 use crate::renderer_client::RendererClient;
-use crate::renderer_server::{TurtleId, ExportError};
-use crate::radians::Radians;
-use crate::{Distance, Point, Color, Speed, Event, Size, async_turtle::AngleUnit, debug};
+use crate::renderer_server::{TurtleId, ExportError, DisplayListData, WindowConfig};
+use crate::radians::{self, Radians};
+use crate::event::Key;
+use crate::{Distance, Point, Rect, Color, Speed, Event, Size, TextAlign, LineCap, LineJoin, PenState, StampId, TurtleShape, FillRule, FillPattern, FillId, UnknownFillError, Recording, CoordinateSystem, async_turtle::AngleUnit, debug};
+use crate::path_recorder::PathId;
+use crate::coordinate_system::CoordinateTransform;
 
 use super::{
     ConnectionError,
@@ -17,29 +25,177 @@ use super::{
     PenProp,
     PenPropValue,
     RotationDirection,
+    BatchCommand,
 };
 
 /// A wrapper for `RendererClient` that encodes the the IPC protocol in a type-safe manner
 pub struct ProtocolClient {
     client: RendererClient,
+    /// The coordinate system used for the points passed to and returned from the public API
+    ///
+    /// Only the client needs to know about this. Everything sent to the server is in logical
+    /// coordinates. Shared between every client created with `split()`.
+    coordinates: Arc<Mutex<CoordinateTransform>>,
 }
 
 impl From<RendererClient> for ProtocolClient {
     fn from(client: RendererClient) -> Self {
-        Self {client}
+        Self {client, coordinates: Default::default()}
     }
 }
 
 impl ProtocolClient {
-    /// Spawns a new server process and creates a connection to it
-    pub async fn new() -> Result<Self, ConnectionError> {
-        let client = RendererClient::new().await?;
+    /// Spawns a new server process that opens a window with the given settings and creates a
+    /// connection to it
+    pub async fn new(config: WindowConfig) -> Result<Self, ConnectionError> {
+        let client = RendererClient::new(config).await?;
         Ok(client.into())
     }
 
     /// Creates a new renderer client that can also communicate to the same server
     pub async fn split(&self) -> Self {
-        self.client.split().await.into()
+        Self {
+            client: self.client.split().await,
+            coordinates: self.coordinates.clone(),
+        }
+    }
+
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.coordinates.lock().system
+    }
+
+    /// Sets the coordinate system, placing its origin based on the current view of the drawing
+    pub async fn set_coordinate_system(&self, system: CoordinateSystem) {
+        let transform = match system {
+            // The origin is always the center of the drawing, so nothing needs to be queried
+            CoordinateSystem::CenterYUp => CoordinateTransform::default(),
+            _ => {
+                let center = self.drawing_center().await;
+                let size = self.drawing_size().await;
+                let scale = self.drawing_scale().await;
+                CoordinateTransform::new(system, center, size, scale)
+            },
+        };
+
+        *self.coordinates.lock() = transform;
+    }
+
+    /// Converts a point in the current coordinate system to logical coordinates
+    pub fn to_logical(&self, point: Point) -> Point {
+        self.coordinates.lock().to_logical(point)
+    }
+
+    /// Converts a point in logical coordinates to the current coordinate system
+    pub fn to_user(&self, point: Point) -> Point {
+        self.coordinates.lock().to_user(point)
+    }
+
+    /// Converts several points in logical coordinates to the current coordinate system
+    pub fn points_to_user(&self, mut points: Vec<Point>) -> Vec<Point> {
+        let transform = *self.coordinates.lock();
+        for point in &mut points {
+            *point = transform.to_user(*point);
+        }
+        points
+    }
+
+    /// Converts a rectangle in logical coordinates to the current coordinate system
+    pub fn rect_to_user(&self, rect: Rect) -> Rect {
+        self.coordinates.lock().rect_to_user(rect)
+    }
+
+    /// Converts the position carried by an event (if any) to the current coordinate system
+    pub fn event_to_user(&self, event: Event) -> Event {
+        match event {
+            Event::MouseMove(position) => Event::MouseMove(self.to_user(position)),
+            event => event,
+        }
+    }
+
+    pub fn start_recording(&self) {
+        self.client.start_recording()
+    }
+
+    pub fn stop_recording(&self) -> Recording {
+        self.client.stop_recording()
+    }
+
+    pub async fn replay(&self, recording: &Recording) {
+        // Each turtle in the recording is replayed by a new turtle
+        let mut turtles = HashMap::new();
+        // The stamps and fills made during the replay have new IDs, so the requests that refer to
+        // a stamp or fill made while recording must use the new ID instead
+        let mut recorded_stamps = recording.stamp_ids();
+        let mut recorded_fills = recording.fill_ids();
+        let mut stamps = HashMap::new();
+        let mut fills = HashMap::new();
+
+        for request in recording.requests() {
+            let mut request = request.clone();
+            let recorded_id = request.turtle_id_mut().copied();
+            if let Some(id) = request.turtle_id_mut() {
+                *id = match turtles.get(id) {
+                    Some(&replay_id) => replay_id,
+                    None => {
+                        let replay_id = self.create_turtle().await;
+                        turtles.insert(*id, replay_id);
+                        replay_id
+                    },
+                };
+            }
+
+            use ClientRequest::*;
+            match &mut request {
+                ClearStamp(_, stamp_id) => match recorded_id.and_then(|id| stamps.get(&(id, *stamp_id))) {
+                    Some(&replay_id) => *stamp_id = replay_id,
+                    // The stamp was made before recording started, so it isn't in the replay
+                    None => continue,
+                },
+                SetFillColorOf(_, fill_id, _) => match recorded_id.and_then(|id| fills.get(&(id, *fill_id))) {
+                    Some(&replay_id) => *fill_id = replay_id,
+                    // The fill was completed before recording started, so it isn't in the replay
+                    None => continue,
+                },
+                _ => {},
+            }
+
+            // Only some of the recorded requests get a response. Other than the IDs of new stamps
+            // and fills, the responses are not needed, but waiting for them makes sure that each
+            // animation completes before the next request.
+            let has_response = matches!(request,
+                MoveForward(..) |
+                MoveForwardIn(..) |
+                MoveTo(..) |
+                RotateInPlace(..) |
+                Batch(..) |
+                AnimateBackground(..) |
+                AnimateCenter(..) |
+                AnimateFillColor(..) |
+                EndFill(_) |
+                SetFillColorOf(..) |
+                Stamp(_));
+
+            self.client.send(request);
+            if !has_response {
+                continue;
+            }
+
+            match self.client.recv().await {
+                ServerResponse::Stamp(_, replay_id) => {
+                    let recorded = recorded_id.and_then(|id| Some((id, recorded_stamps.get_mut(&id)?.pop_front()?)));
+                    if let Some(recorded) = recorded {
+                        stamps.insert(recorded, replay_id);
+                    }
+                },
+                ServerResponse::FillEnded(_, replay_id) => {
+                    let recorded = recorded_id.and_then(|id| Some((id, recorded_fills.get_mut(&id)?.pop_front()?)));
+                    if let Some(recorded) = recorded {
+                        fills.insert(recorded, replay_id);
+                    }
+                },
+                _ => {},
+            }
+        }
     }
 
     pub async fn create_turtle(&self) -> TurtleId {
@@ -52,6 +208,16 @@ impl ProtocolClient {
         }
     }
 
+    pub async fn clone_turtle(&self, id: TurtleId) -> TurtleId {
+        self.client.send(ClientRequest::CloneTurtle(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::NewTurtle(id) => id,
+            _ => unreachable!("bug: expected to receive `NewTurtle` in response to `CloneTurtle` request"),
+        }
+    }
+
     pub async fn export_svg(&self, path: PathBuf) -> Result<(), ExportError> {
         self.client.send(ClientRequest::Export(path, ExportFormat::Svg));
 
@@ -62,6 +228,93 @@ impl ProtocolClient {
         }
     }
 
+    pub async fn export_png(&self, path: PathBuf, scale: f64) -> Result<(), ExportError> {
+        self.client.send(ClientRequest::Export(path, ExportFormat::Png(scale)));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::ExportComplete(res) => res,
+            _ => unreachable!("bug: expected to receive `ExportComplete` in response to `Export` request"),
+        }
+    }
+
+    pub async fn export_pdf(&self, path: PathBuf) -> Result<(), ExportError> {
+        self.client.send(ClientRequest::Export(path, ExportFormat::Pdf));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::ExportComplete(res) => res,
+            _ => unreachable!("bug: expected to receive `ExportComplete` in response to `Export` request"),
+        }
+    }
+
+    pub async fn screenshot(&self) -> Result<(Size, Vec<u8>), ExportError> {
+        self.client.send(ClientRequest::Screenshot);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Screenshot(res) => res,
+            _ => unreachable!("bug: expected to receive `Screenshot` in response to `Screenshot` request"),
+        }
+    }
+
+    pub async fn export_display_list(&self) -> DisplayListData {
+        self.client.send(ClientRequest::ExportDisplayList);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DisplayList(data) => data,
+            _ => unreachable!("bug: expected to receive `DisplayList` in response to `ExportDisplayList` request"),
+        }
+    }
+
+    pub fn import_display_list(&self, data: DisplayListData) {
+        self.client.send(ClientRequest::ImportDisplayList(data));
+    }
+
+    pub fn start_gif_recording(&self, path: PathBuf, fps: f64) {
+        debug_assert!(fps > 0.0 && fps <= 50.0,
+            "bug: frame rate should be validated before sending to renderer server");
+        self.client.send(ClientRequest::StartGifRecording(path, fps));
+    }
+
+    pub async fn stop_gif_recording(&self) -> Result<(), ExportError> {
+        self.client.send(ClientRequest::StopGifRecording);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::GifRecordingComplete(res) => res,
+            _ => unreachable!("bug: expected to receive `GifRecordingComplete` in response to `StopGifRecording` request"),
+        }
+    }
+
+    #[cfg(feature = "embedded")]
+    pub async fn render_to(&self, buffer: &mut Vec<u8>) -> Result<Size, ExportError> {
+        // The server handles requests in order, so once any response arrives, every request sent
+        // before it has been handled and will be part of the rendered image
+        self.drawing_size().await;
+        self.client.render_to(buffer)
+    }
+
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    pub fn send_event(&self, event: Event) {
+        let event = match event {
+            Event::MouseMove(position) => Event::MouseMove(self.to_logical(position)),
+            event => event,
+        };
+        self.client.send_event(event)
+    }
+
+    pub async fn drawing_bounds(&self) -> Option<Rect> {
+        self.client.send(ClientRequest::DrawingBounds);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Bounds(bounds) => bounds,
+            _ => unreachable!("bug: expected to receive `Bounds` in response to `DrawingBounds` request"),
+        }
+    }
+
     pub async fn poll_event(&self) -> Option<Event> {
         self.client.send(ClientRequest::PollEvent);
 
@@ -72,6 +325,36 @@ impl ProtocolClient {
         }
     }
 
+    pub async fn next_event(&self) -> Option<Event> {
+        self.client.send(ClientRequest::NextEvent);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Event(event) => event,
+            _ => unreachable!("bug: expected to receive `Event` in response to `NextEvent` request"),
+        }
+    }
+
+    pub async fn mouse_position(&self) -> Point {
+        self.client.send(ClientRequest::MousePosition);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::MousePosition(position) => position,
+            _ => unreachable!("bug: expected to receive `MousePosition` in response to `MousePosition` request"),
+        }
+    }
+
+    pub async fn pressed_keys(&self) -> Vec<Key> {
+        self.client.send(ClientRequest::PressedKeys);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::PressedKeys(keys) => keys,
+            _ => unreachable!("bug: expected to receive `PressedKeys` in response to `PressedKeys` request"),
+        }
+    }
+
     pub async fn drawing_title(&self) -> String {
         self.client.send(ClientRequest::DrawingProp(DrawingProp::Title));
 
@@ -132,10 +415,122 @@ impl ProtocolClient {
         }
     }
 
+    pub async fn drawing_window_position(&self) -> (i32, i32) {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::WindowPosition));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::WindowPosition(x, y)) => (x, y),
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_grid(&self) -> Option<f64> {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::Grid));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::Grid(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_show_axes(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::ShowAxes));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::ShowAxes(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_show_fps(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::ShowFps));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::ShowFps(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_tick_events(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::TickEvents));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::TickEvents(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_antialiasing(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::Antialiasing));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::Antialiasing(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_exit_on_escape(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::ExitOnEscape));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::ExitOnEscape(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_exit_on_close(&self) -> bool {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::ExitOnClose));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::ExitOnClose(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
+    pub async fn drawing_scale(&self) -> f64 {
+        self.client.send(ClientRequest::DrawingProp(DrawingProp::Scale));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingProp(DrawingPropValue::Scale(value)) => value,
+            _ => unreachable!("bug: expected to receive `DrawingProp` in response to `DrawingProp` request"),
+        }
+    }
+
     pub fn drawing_set_title(&self, value: String) {
         self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::Title(value)))
     }
 
+    pub async fn drawing_animate_background(&self, value: Color, secs: f64) {
+        debug_assert!(value.is_valid(), "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::AnimateBackground(value, secs));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::BackgroundAnimationComplete => {},
+            _ => unreachable!("bug: expected to receive `BackgroundAnimationComplete` in response to `AnimateBackground` request"),
+        }
+    }
+
+    pub async fn drawing_animate_center(&self, value: Point, secs: f64) {
+        debug_assert!(value.is_finite(), "bug: center should be validated before sending to renderer server");
+        self.client.send(ClientRequest::AnimateCenter(value, secs));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::CenterAnimationComplete => {},
+            _ => unreachable!("bug: expected to receive `CenterAnimationComplete` in response to `AnimateCenter` request"),
+        }
+    }
+
     pub fn drawing_set_background(&self, value: Color) {
         debug_assert!(value.is_valid(), "bug: colors should be validated before sending to renderer server");
         self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::Background(value)))
@@ -159,6 +554,69 @@ impl ProtocolClient {
         self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::IsFullscreen(value)))
     }
 
+    pub fn drawing_set_window_position(&self, x: i32, y: i32) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::WindowPosition(x, y)))
+    }
+
+    pub async fn drawing_set_icon(&self, path: PathBuf) -> Result<(), ExportError> {
+        self.client.send(ClientRequest::SetIcon(path));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::SetIconComplete(res) => res,
+            _ => unreachable!("bug: expected to receive `SetIconComplete` in response to `SetIcon` request"),
+        }
+    }
+
+    pub async fn drawing_update(&self) {
+        self.client.send(ClientRequest::UpdateDrawing);
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::DrawingUpdated => {},
+            _ => unreachable!("bug: expected to receive `DrawingUpdated` in response to `UpdateDrawing` request"),
+        }
+    }
+
+    pub fn drawing_set_grid(&self, value: Option<f64>) {
+        debug_assert!(value.into_iter().all(|spacing| spacing > 0.0 && spacing.is_finite()),
+            "bug: grid spacing should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::Grid(value)))
+    }
+
+    pub fn drawing_set_show_axes(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::ShowAxes(value)))
+    }
+
+    pub fn drawing_set_show_fps(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::ShowFps(value)))
+    }
+
+    pub fn drawing_set_tick_events(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::TickEvents(value)))
+    }
+
+    pub fn drawing_set_antialiasing(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::Antialiasing(value)))
+    }
+
+    pub fn drawing_set_exit_on_escape(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::ExitOnEscape(value)))
+    }
+
+    pub fn drawing_set_exit_on_close(&self, value: bool) {
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::ExitOnClose(value)))
+    }
+
+    pub fn drawing_set_scale(&self, value: f64) {
+        debug_assert!(value > 0.0 && value.is_finite(), "bug: scale should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetDrawingProp(DrawingPropValue::Scale(value)))
+    }
+
+    pub fn drawing_reset_scale(&self) {
+        self.client.send(ClientRequest::ResetDrawingProp(DrawingProp::Scale))
+    }
+
     pub fn drawing_reset_center(&self) {
         self.client.send(ClientRequest::ResetDrawingProp(DrawingProp::Center))
     }
@@ -206,12 +664,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_fill_color(&self, id: TurtleId) -> Color {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::FillColor));
+    pub async fn turtle_pen_gradient(&self, id: TurtleId) -> Option<(Color, Color)> {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Pen(PenProp::Gradient)));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::FillColor(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Pen(PenPropValue::Gradient(value))) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -219,12 +677,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_is_filling(&self, id: TurtleId) -> bool {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::IsFilling));
+    pub async fn turtle_pen_cap(&self, id: TurtleId) -> LineCap {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Pen(PenProp::Cap)));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::IsFilling(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Pen(PenPropValue::Cap(value))) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -232,12 +690,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_position(&self, id: TurtleId) -> Point {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Position));
+    pub async fn turtle_pen_join(&self, id: TurtleId) -> LineJoin {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Pen(PenProp::Join)));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Position(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Pen(PenPropValue::Join(value))) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -245,12 +703,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_heading(&self, id: TurtleId) -> Radians {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Heading));
+    pub async fn turtle_pen_state(&self, id: TurtleId) -> PenState {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Pen(PenProp::State)));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Heading(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Pen(PenPropValue::State(value))) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -258,12 +716,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_speed(&self, id: TurtleId) -> Speed {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Speed));
+    pub async fn turtle_fill_color(&self, id: TurtleId) -> Color {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::FillColor));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Speed(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::FillColor(value)) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -271,12 +729,12 @@ impl ProtocolClient {
         }
     }
 
-    pub async fn turtle_is_visible(&self, id: TurtleId) -> bool {
-        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::IsVisible));
+    pub async fn turtle_fill_rule(&self, id: TurtleId) -> FillRule {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::FillRule));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::TurtleProp(recv_id, TurtlePropValue::IsVisible(value)) => {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::FillRule(value)) => {
                 debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
                 value
             },
@@ -284,18 +742,212 @@ impl ProtocolClient {
         }
     }
 
-    pub fn turtle_pen_set_is_enabled(&self, id: TurtleId, value: bool) {
-        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::IsEnabled(value))))
-    }
+    pub async fn turtle_fill_pattern(&self, id: TurtleId) -> FillPattern {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::FillPattern));
 
-    pub fn turtle_pen_set_thickness(&self, id: TurtleId, value: f64) {
-        debug_assert!(value >= 0.0 && value.is_finite(), "bug: pen size should be validated before sending to renderer server");
-        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Thickness(value))))
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::FillPattern(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
     }
 
-    pub fn turtle_pen_set_color(&self, id: TurtleId, value: Color) {
-        debug_assert!(value.is_valid(), "bug: colors should be validated before sending to renderer server");
-        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Color(value))))
+    pub async fn turtle_group_opacity(&self, id: TurtleId) -> f64 {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::GroupOpacity));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::GroupOpacity(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_is_filling(&self, id: TurtleId) -> bool {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::IsFilling));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::IsFilling(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_position(&self, id: TurtleId) -> Point {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Position));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Position(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_heading(&self, id: TurtleId) -> Radians {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Heading));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Heading(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_speed(&self, id: TurtleId) -> Speed {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Speed));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Speed(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_movement_rate(&self, id: TurtleId) -> Option<Distance> {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::MovementRate));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::MovementRate(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_rotation_rate(&self, id: TurtleId) -> Option<Radians> {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::RotationRate));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::RotationRate(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_tracer(&self, id: TurtleId) -> bool {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Tracer));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Tracer(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_is_visible(&self, id: TurtleId) -> bool {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::IsVisible));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::IsVisible(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_shape(&self, id: TurtleId) -> TurtleShape {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::Shape));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::Shape(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_shape_scale(&self, id: TurtleId) -> f64 {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::ShapeScale));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::ShapeScale(value)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                value
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub async fn turtle_shape_colors(&self, id: TurtleId) -> (Color, Color) {
+        self.client.send(ClientRequest::TurtleProp(id, TurtleProp::ShapeColors));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::TurtleProp(recv_id, TurtlePropValue::ShapeColors(outline_color, fill_color)) => {
+                debug_assert_eq!(id, recv_id, "bug: received data for incorrect turtle");
+                (outline_color, fill_color)
+            },
+            _ => unreachable!("bug: expected to receive `TurtleProp` in response to `TurtleProp` request"),
+        }
+    }
+
+    pub fn turtle_pen_set_is_enabled(&self, id: TurtleId, value: bool) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::IsEnabled(value))))
+    }
+
+    pub fn turtle_pen_set_thickness(&self, id: TurtleId, value: f64) {
+        debug_assert!(value >= 0.0 && value.is_finite(), "bug: pen size should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Thickness(value))))
+    }
+
+    pub fn turtle_pen_set_thickness_change(&self, id: TurtleId, thickness: f64, distance: f64) {
+        debug_assert!(thickness >= 0.0 && thickness.is_finite(), "bug: pen size should be validated before sending to renderer server");
+        debug_assert!(distance > 0.0 && distance.is_finite(), "bug: distance should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::ThicknessChange(Some((thickness, distance))))))
+    }
+
+    pub fn turtle_pen_set_color(&self, id: TurtleId, value: Color) {
+        debug_assert!(value.is_valid(), "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Color(value))))
+    }
+
+    pub fn turtle_pen_set_gradient(&self, id: TurtleId, start: Color, end: Color) {
+        debug_assert!(start.is_valid() && end.is_valid(), "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Gradient(Some((start, end))))))
+    }
+
+    pub fn turtle_pen_set_cap(&self, id: TurtleId, value: LineCap) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Cap(value))))
+    }
+
+    pub fn turtle_pen_set_join(&self, id: TurtleId, value: LineJoin) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::Join(value))))
+    }
+
+    pub fn turtle_pen_set_state(&self, id: TurtleId, value: PenState) {
+        debug_assert!(value.color.is_valid() && value.end_color.is_none_or(|color| color.is_valid()),
+            "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Pen(PenPropValue::State(value))))
     }
 
     pub fn turtle_set_fill_color(&self, id: TurtleId, value: Color) {
@@ -303,14 +955,60 @@ impl ProtocolClient {
         self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::FillColor(value)))
     }
 
+    pub fn turtle_set_fill_rule(&self, id: TurtleId, value: FillRule) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::FillRule(value)))
+    }
+
+    pub fn turtle_set_fill_pattern(&self, id: TurtleId, value: FillPattern) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::FillPattern(value)))
+    }
+
+    pub fn turtle_set_group_opacity(&self, id: TurtleId, value: f64) {
+        debug_assert!((0.0..=1.0).contains(&value),
+            "bug: group opacity should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::GroupOpacity(value)))
+    }
+
     pub fn turtle_set_speed(&self, id: TurtleId, value: Speed) {
         self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Speed(value)))
     }
 
+    pub fn turtle_set_movement_rate(&self, id: TurtleId, value: Option<Distance>) {
+        debug_assert!(value.into_iter().all(|rate| rate > 0.0 && rate.is_finite()),
+            "bug: movement rate should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::MovementRate(value)))
+    }
+
+    pub fn turtle_set_rotation_rate(&self, id: TurtleId, value: Option<Radians>) {
+        debug_assert!(value.into_iter().all(|rate| rate.to_radians() > 0.0 && rate.is_finite()),
+            "bug: rotation rate should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::RotationRate(value)))
+    }
+
+    pub fn turtle_set_tracer(&self, id: TurtleId, value: bool) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Tracer(value)))
+    }
+
     pub fn turtle_set_is_visible(&self, id: TurtleId, value: bool) {
         self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::IsVisible(value)))
     }
 
+    pub fn turtle_set_shape(&self, id: TurtleId, value: TurtleShape) {
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::Shape(value)))
+    }
+
+    pub fn turtle_set_shape_scale(&self, id: TurtleId, value: f64) {
+        debug_assert!(value > 0.0 && value.is_finite(),
+            "bug: shape scale should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::ShapeScale(value)))
+    }
+
+    pub fn turtle_set_shape_colors(&self, id: TurtleId, outline_color: Color, fill_color: Color) {
+        debug_assert!(outline_color.is_valid() && fill_color.is_valid(),
+            "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::SetTurtleProp(id, TurtlePropValue::ShapeColors(outline_color, fill_color)))
+    }
+
     pub fn turtle_reset_heading(&self, id: TurtleId) {
         self.client.send(ClientRequest::ResetTurtleProp(id, TurtleProp::Heading))
     }
@@ -319,22 +1017,41 @@ impl ProtocolClient {
         self.client.send(ClientRequest::ResetTurtle(id))
     }
 
-    pub async fn move_forward(&self, id: TurtleId, distance: Distance) {
+    /// Returns the position of the turtle once it is done moving, or `None` if the distance was
+    /// ignored and the turtle did not move
+    pub async fn move_forward(&self, id: TurtleId, distance: Distance) -> Option<Point> {
         if !distance.is_normal() {
-            return;
+            return None;
         }
 
         self.client.send(ClientRequest::MoveForward(id, distance));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::AnimationComplete(recv_id) => {
+            ServerResponse::AnimationComplete(recv_id, position, _) => {
                 debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
+                Some(position)
             },
             _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `MoveForward` request"),
         }
     }
 
+    pub async fn move_forward_in(&self, id: TurtleId, distance: Distance, duration: Duration) {
+        if !distance.is_normal() {
+            return;
+        }
+
+        self.client.send(ClientRequest::MoveForwardIn(id, distance, duration));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::AnimationComplete(recv_id, ..) => {
+                debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
+            },
+            _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `MoveForwardIn` request"),
+        }
+    }
+
     pub async fn move_to(&self, id: TurtleId, target: Point) {
         if !target.is_finite() {
             return;
@@ -344,29 +1061,82 @@ impl ProtocolClient {
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::AnimationComplete(recv_id) => {
+            ServerResponse::AnimationComplete(recv_id, ..) => {
                 debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
             },
             _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `MoveTo` request"),
         }
     }
 
-    pub async fn rotate_in_place(&self, id: TurtleId, angle: Radians, direction: RotationDirection) {
-        if !angle.is_normal() {
+    pub fn teleport(&self, id: TurtleId, position: Point, heading: Radians) {
+        if !position.is_finite() || !heading.is_finite() {
             return;
         }
 
+        self.client.send(ClientRequest::Teleport(id, position, heading));
+    }
+
+    /// Returns the heading of the turtle once it is done rotating, or `None` if the angle was
+    /// ignored and the turtle did not rotate
+    pub async fn rotate_in_place(&self, id: TurtleId, angle: Radians, direction: RotationDirection) -> Option<Radians> {
+        if !angle.is_normal() {
+            return None;
+        }
+
         self.client.send(ClientRequest::RotateInPlace(id, angle, direction));
 
         let response = self.client.recv().await;
         match response {
-            ServerResponse::AnimationComplete(recv_id) => {
+            ServerResponse::AnimationComplete(recv_id, _, heading) => {
                 debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
+                Some(heading)
             },
             _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `RotateInPlace` request"),
         }
     }
 
+    pub async fn wait_for_animation(&self, id: TurtleId) {
+        self.client.send(ClientRequest::WaitForAnimation(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::AnimationComplete(recv_id, ..) => {
+                debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
+            },
+            _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `WaitForAnimation` request"),
+        }
+    }
+
+    pub async fn turtle_animate_fill_color(&self, id: TurtleId, from: Color, to: Color, secs: f64) {
+        debug_assert!(from.is_valid() && to.is_valid(),
+            "bug: colors should be validated before sending to renderer server");
+        self.client.send(ClientRequest::AnimateFillColor(id, from, to, secs));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::AnimationComplete(recv_id, ..) => {
+                debug_assert_eq!(id, recv_id, "bug: notified of complete animation for incorrect turtle");
+            },
+            _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `AnimateFillColor` request"),
+        }
+    }
+
+    pub async fn batch(&self, id: TurtleId, commands: Vec<BatchCommand>) {
+        if commands.is_empty() {
+            return;
+        }
+
+        self.client.send(ClientRequest::Batch(id, commands));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::AnimationComplete(recv_id, ..) => {
+                debug_assert_eq!(id, recv_id, "bug: notified of complete batch for incorrect turtle");
+            },
+            _ => unreachable!("bug: expected to receive `AnimationComplete` in response to `Batch` request"),
+        }
+    }
+
     pub async fn circular_arc(&self, id: TurtleId, radius: Distance, extent: Radians, direction: RotationDirection) {
         if !radius.is_normal() || !extent.is_normal() {
             return;
@@ -382,12 +1152,220 @@ impl ProtocolClient {
         }
     }
 
+    /// Moves the turtle forward by `distance` (backward if `distance` is negative), stopping early
+    /// if it would leave the visible area of the drawing
+    ///
+    /// Returns the distance that the turtle actually moved, negative if it moved backward.
+    pub async fn move_forward_bounded(&self, id: TurtleId, distance: Distance) -> Distance {
+        if !distance.is_normal() {
+            return 0.0;
+        }
+
+        let position = self.turtle_position(id).await;
+        let heading = self.turtle_heading(id).await;
+        let center = self.drawing_center().await;
+        let size = self.drawing_size().await;
+        let scale = self.drawing_scale().await;
+        // Zooming in shows less of the drawing, so the edges move closer to the center
+        let half_size = Point {x: size.width as f64 / (2.0 * scale), y: size.height as f64 / (2.0 * scale)};
+        let (min, max) = (center - half_size, center + half_size);
+
+        let direction = Point {x: heading.cos(), y: heading.sin()} * distance.signum();
+        // The distance to the edge that the turtle is moving towards along each axis. A turtle
+        // that is already past that edge can't move at all.
+        let limit = |position: f64, direction: f64, min: f64, max: f64| {
+            if direction > f64::EPSILON {
+                ((max - position) / direction).max(0.0)
+            } else if direction < -f64::EPSILON {
+                ((min - position) / direction).max(0.0)
+            } else {
+                f64::INFINITY
+            }
+        };
+        let traveled = distance.abs()
+            .min(limit(position.x, direction.x, min.x, max.x))
+            .min(limit(position.y, direction.y, min.y, max.y))
+            .copysign(distance);
+
+        self.move_forward(id, traveled).await;
+        traveled
+    }
+
+    /// Moves the turtle counterclockwise around `center` by `angle` (clockwise if `angle` is
+    /// negative), keeping the turtle's heading tangent to the circle it moves along
+    ///
+    /// The entire arc is undone at once.
+    pub async fn circular_arc_around(&self, id: TurtleId, center: Point, angle: Radians) {
+        if !center.is_finite() || !angle.is_normal() {
+            return;
+        }
+
+        let position = self.turtle_position(id).await;
+        let offset = position - center;
+        // The turtle is on the center, so there is no circle to move along
+        if offset.is_not_normal() {
+            return;
+        }
+
+        // Turn to face along the circle in the direction of the movement, rotating as little as
+        // possible by keeping the turn between -pi and pi
+        let heading = self.turtle_heading(id).await;
+        let tangent = Radians::from_radians_value(offset.atan2()) + radians::PI / 2.0 * angle.signum();
+        let turn = (tangent - heading + radians::PI).normalized() - radians::PI;
+        self.begin_undo_group(id);
+        self.rotate_in_place(id, turn, RotationDirection::Counterclockwise).await;
+
+        // About one step per degree so that small arcs (e.g. in a simulation loop) stay quick
+        let steps = angle.abs().to_degrees().ceil().max(1.0) as usize;
+        let step = angle / steps as f64;
+
+        // Each step moves along the chord between two points on the circle, turning half of the
+        // step before and after so that the heading ends up tangent to the circle again. The
+        // points are calculated from the center so that no error builds up over many steps.
+        for i in 1..=steps {
+            self.rotate_in_place(id, step / 2.0, RotationDirection::Counterclockwise).await;
+            let target = center + offset.rotate((step * i as f64).to_degrees());
+            self.move_to(id, target).await;
+            self.rotate_in_place(id, step / 2.0, RotationDirection::Counterclockwise).await;
+        }
+        self.end_undo_group(id);
+    }
+
+    /// Moves the turtle along an Archimedean spiral whose radius starts at `start_radius` and
+    /// changes by `growth` with each full turn
+    ///
+    /// The spiral goes counterclockwise if `turns` is positive and clockwise if it is negative. It
+    /// starts at the turtle's position, going in the direction that the turtle is facing. The
+    /// entire spiral is undone at once.
+    pub async fn spiral(&self, id: TurtleId, start_radius: Distance, growth: Distance, turns: f64) {
+        // A spiral that never leaves its center has nothing to draw
+        if !turns.is_normal() || (start_radius == 0.0 && growth == 0.0) {
+            return;
+        }
+
+        let position = self.turtle_position(id).await;
+        let heading = self.turtle_heading(id).await;
+        let direction = turns.signum();
+
+        // At the start, the spiral moves away from the center by `growth / 2pi` for every unit
+        // that it moves around it. The offset from the center is picked so that this direction
+        // is the turtle's heading.
+        let outward_angle = start_radius.atan2(growth / radians::TWO_PI.to_radians());
+        let start_angle = heading - Radians::from_radians_value(outward_angle) * direction;
+        let start = Point {x: start_angle.cos(), y: start_angle.sin()};
+        let center = position - start * start_radius;
+
+        // About one step per degree, like with arcs around a point
+        let total = radians::TWO_PI * turns;
+        let steps = total.abs().to_degrees().ceil().max(1.0) as usize;
+
+        // Each point is calculated from the center so that no error builds up over many steps
+        let points = (1..=steps).map(|i| {
+            let t = i as f64 / steps as f64;
+            let radius = start_radius + growth * turns.abs() * t;
+            center + start.rotate((total * t).to_degrees()) * radius
+        });
+        self.begin_undo_group(id);
+        self.move_along(id, position, heading, points).await;
+        self.end_undo_group(id);
+    }
+
+    /// Moves the turtle along the cubic Bézier curve that starts at its current position
+    ///
+    /// The entire curve is undone at once.
+    pub async fn cubic_bezier(&self, id: TurtleId, control1: Point, control2: Point, end: Point) {
+        if !control1.is_finite() || !control2.is_finite() || !end.is_finite() {
+            return;
+        }
+
+        let start = self.turtle_position(id).await;
+        let heading = self.turtle_heading(id).await;
+
+        let steps = bezier_steps(&[start, control1, control2, end]);
+        let points = (1..=steps).map(|i| {
+            let t = i as f64 / steps as f64;
+            let u = 1.0 - t;
+            u * u * u * start + 3.0 * u * u * t * control1 + 3.0 * u * t * t * control2 + t * t * t * end
+        });
+        self.begin_undo_group(id);
+        self.move_along(id, start, heading, points).await;
+        self.end_undo_group(id);
+    }
+
+    /// Moves the turtle along the quadratic Bézier curve that starts at its current position
+    ///
+    /// The entire curve is undone at once.
+    pub async fn quadratic_bezier(&self, id: TurtleId, control: Point, end: Point) {
+        if !control.is_finite() || !end.is_finite() {
+            return;
+        }
+
+        let start = self.turtle_position(id).await;
+        let heading = self.turtle_heading(id).await;
+
+        let steps = bezier_steps(&[start, control, end]);
+        let points = (1..=steps).map(|i| {
+            let t = i as f64 / steps as f64;
+            let u = 1.0 - t;
+            u * u * start + 2.0 * u * t * control + t * t * end
+        });
+        self.begin_undo_group(id);
+        self.move_along(id, start, heading, points).await;
+        self.end_undo_group(id);
+    }
+
+    /// Moves the turtle through each of the given points in order, turning to face each point
+    /// before moving to it
+    ///
+    /// The turtle must currently be at `position`, facing `heading`.
+    async fn move_along<I>(&self, id: TurtleId, mut position: Point, mut heading: Radians, points: I)
+        where I: IntoIterator<Item=Point>,
+    {
+        for target in points {
+            // Nothing to face if the turtle is already there
+            if (target - position).is_not_normal() {
+                continue;
+            }
+
+            // Turn as little as possible by keeping the turn between -pi and pi
+            let facing = Radians::from_radians_value((target - position).atan2());
+            let turn = (facing - heading + radians::PI).normalized() - radians::PI;
+            self.rotate_in_place(id, turn, RotationDirection::Counterclockwise).await;
+            heading = facing;
+
+            self.move_to(id, target).await;
+            position = target;
+        }
+    }
+
     pub fn begin_fill(&self, id: TurtleId) {
         self.client.send(ClientRequest::BeginFill(id))
     }
 
-    pub fn end_fill(&self, id: TurtleId) {
-        self.client.send(ClientRequest::EndFill(id))
+    pub async fn end_fill(&self, id: TurtleId) -> FillId {
+        self.client.send(ClientRequest::EndFill(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::FillEnded(recv_id, fill_id) => {
+                debug_assert_eq!(id, recv_id, "bug: received fill for incorrect turtle");
+                fill_id
+            },
+            _ => unreachable!("bug: expected to receive `FillEnded` in response to `EndFill` request"),
+        }
+    }
+
+    pub async fn set_fill_color_of(&self, id: TurtleId, fill_id: FillId, color: Color) -> Result<(), UnknownFillError> {
+        self.client.send(ClientRequest::SetFillColorOf(id, fill_id, color));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::FillColorSet(recv_id, res) => {
+                debug_assert_eq!(id, recv_id, "bug: received fill color for incorrect turtle");
+                res
+            },
+            _ => unreachable!("bug: expected to receive `FillColorSet` in response to `SetFillColorOf` request"),
+        }
     }
 
     pub fn clear_all(&self) {
@@ -398,6 +1376,107 @@ impl ProtocolClient {
         self.client.send(ClientRequest::ClearTurtle(id))
     }
 
+    pub fn write_text(&self, id: TurtleId, text: String, font_size: f64, align: TextAlign) {
+        self.client.send(ClientRequest::WriteText(id, text, font_size, align))
+    }
+
+    pub async fn stamp(&self, id: TurtleId) -> StampId {
+        self.client.send(ClientRequest::Stamp(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Stamp(recv_id, stamp_id) => {
+                debug_assert_eq!(id, recv_id, "bug: received stamp for incorrect turtle");
+                stamp_id
+            },
+            _ => unreachable!("bug: expected to receive `Stamp` in response to `Stamp` request"),
+        }
+    }
+
+    pub fn clear_stamp(&self, id: TurtleId, stamp_id: StampId) {
+        self.client.send(ClientRequest::ClearStamp(id, stamp_id))
+    }
+
+    pub fn clear_stamps(&self, id: TurtleId) {
+        self.client.send(ClientRequest::ClearStamps(id))
+    }
+
+    pub fn undo(&self, id: TurtleId) {
+        self.client.send(ClientRequest::Undo(id))
+    }
+
+    pub fn begin_undo_group(&self, id: TurtleId) {
+        self.client.send(ClientRequest::BeginUndoGroup(id))
+    }
+
+    pub fn end_undo_group(&self, id: TurtleId) {
+        self.client.send(ClientRequest::EndUndoGroup(id))
+    }
+
+    pub fn turtle_set_track_history(&self, id: TurtleId, capacity: usize) {
+        self.client.send(ClientRequest::SetTrackHistory(id, capacity))
+    }
+
+    pub fn turtle_begin_poly(&self, id: TurtleId) {
+        self.client.send(ClientRequest::BeginPoly(id))
+    }
+
+    pub async fn turtle_end_poly(&self, id: TurtleId) -> Vec<Point> {
+        self.client.send(ClientRequest::EndPoly(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Poly(recv_id, points) => {
+                debug_assert_eq!(id, recv_id, "bug: received polygon for incorrect turtle");
+                points
+            },
+            _ => unreachable!("bug: expected to receive `Poly` in response to `EndPoly` request"),
+        }
+    }
+
+    pub async fn turtle_start_path(&self, id: TurtleId) -> PathId {
+        self.client.send(ClientRequest::StartPath(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::PathStarted(recv_id, path_id) => {
+                debug_assert_eq!(id, recv_id, "bug: received path for incorrect turtle");
+                path_id
+            },
+            _ => unreachable!("bug: expected to receive `PathStarted` in response to `StartPath` request"),
+        }
+    }
+
+    pub async fn turtle_recorded_path(&self, id: TurtleId, path_id: PathId) -> Vec<Point> {
+        self.client.send(ClientRequest::RecordedPath(id, path_id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::Path(recv_id, points) => {
+                debug_assert_eq!(id, recv_id, "bug: received path for incorrect turtle");
+                points
+            },
+            _ => unreachable!("bug: expected to receive `Path` in response to `RecordedPath` request"),
+        }
+    }
+
+    pub fn turtle_stop_path(&self, id: TurtleId, path_id: PathId) {
+        self.client.send(ClientRequest::StopPath(id, path_id))
+    }
+
+    pub async fn turtle_position_history(&self, id: TurtleId) -> Vec<Point> {
+        self.client.send(ClientRequest::PositionHistory(id));
+
+        let response = self.client.recv().await;
+        match response {
+            ServerResponse::PositionHistory(recv_id, positions) => {
+                debug_assert_eq!(id, recv_id, "bug: received position history for incorrect turtle");
+                positions
+            },
+            _ => unreachable!("bug: expected to receive `PositionHistory` in response to `PositionHistory` request"),
+        }
+    }
+
     pub async fn debug_turtle(&self, id: TurtleId, angle_unit: AngleUnit) -> debug::Turtle {
         self.client.send(ClientRequest::DebugTurtle(id, angle_unit));
 
@@ -405,7 +1484,7 @@ impl ProtocolClient {
         match response {
             ServerResponse::DebugTurtle(recv_id, state) => {
                 debug_assert_eq!(id, recv_id, "bug: received debug turtle for incorrect turtle");
-                state
+                *state
             },
             _ => unreachable!("bug: expected to receive `DebugTurtle` in response to `DebugTurtle` request"),
         }
@@ -427,3 +1506,13 @@ impl ProtocolClient {
         self.client.send(ClientRequest::DestroyDrawing);
     }
 }
+
+/// Returns the number of line segments to use when drawing a Bézier curve with the given points
+///
+/// The curve is never longer than the lines between its points, so the length of those lines is
+/// used to pick a number of segments that are each only a few pixels long. The speed of the turtle
+/// is deliberately not used so that the curve looks the same at every speed.
+fn bezier_steps(points: &[Point]) -> usize {
+    let length: f64 = points.windows(2).map(|pair| (pair[1] - pair[0]).len()).sum();
+    (length / 4.0).ceil().clamp(8.0, 1000.0) as usize
+}
@@ -21,6 +21,11 @@
 //! See the [`rand` module](rand/index.html) for information about generating random colors, speeds,
 //! angles, and more which can be used in your programs to produce some interesting results!
 //!
+//! # Logo Programs
+//!
+//! See the [`logo` module](logo/index.html) for running programs written in the Logo programming
+//! language, where turtle graphics comes from.
+//!
 //! # Event Handling
 //!
 #![cfg_attr(not(feature = "unstable"), doc = "
@@ -72,6 +77,25 @@ broken unless you do so. See [Unstable features](#unstable-features) below for m
 //! If you are developing this crate with a local version of the turtle repository, see the
 //! [CONTRIBUTING.md] file for the specific commands you should run to generate the documentation.
 //!
+//! # Embedding a drawing in another application
+//!
+//! Normally, every drawing is shown in its own window. To show a drawing inside the window of an
+//! existing GUI application instead, enable the "embedded" feature:
+//!
+//! ```toml
+//! [dependencies]
+//! turtle = { version = "...", features = ["embedded"] }
+//! ```
+//!
+//! With this feature, no window is ever opened and the drawing runs inside your program. Call
+//! [`Drawing::render_to()`](struct.Drawing.html#method.render_to) whenever your application draws
+//! a frame to get an image of the drawing that you can display however you like. If the
+//! "unstable" feature is also enabled, pass on mouse and keyboard events from your application with
+//! [`Drawing::send_event()`](struct.Drawing.html#method.send_event). Without the "unstable"
+//! feature, the drawing never receives any events, so
+//! [`Turtle::wait_for_click()`](struct.Turtle.html#method.wait_for_click) panics instead of
+//! waiting forever.
+//!
 //! [CONTRIBUTING.md]: https://github.com/sunjay/turtle/blob/master/CONTRIBUTING.md
 
 // This warning usually signals an error and so it should be treated as such.
@@ -82,14 +106,29 @@ broken unless you do so. See [Unstable features](#unstable-features) below for m
 #![doc(html_logo_url = "https://raw.githubusercontent.com/sunjay/turtle/master/docs/assets/images/turtle-logo-512.png")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[cfg(all(test, not(feature = "test")))]
+// The "embedded" feature never opens a window either, so it can be tested without "test"
+#[cfg(all(test, not(any(feature = "test", feature = "embedded"))))]
 compile_error!("Make sure you run tests with `cargo test --features \"test unstable\"`");
 
+#[cfg(all(feature = "test", feature = "embedded"))]
+compile_error!("The \"test\" and \"embedded\" features cannot be enabled at the same time. Test the \"embedded\" feature with `cargo test --features \"embedded unstable\" --lib` instead.");
+
 mod radians;
 mod point;
+mod rect;
+mod coordinate_system;
 mod speed;
 mod color;
+mod pen;
+mod fill;
+mod shape;
+mod stamp;
+mod recording;
+mod path_recorder;
+mod batch;
+mod text;
 pub mod rand;
+pub mod logo;
 
 mod ipc_protocol;
 mod renderer_server;
@@ -103,10 +142,20 @@ mod turtle;
 
 pub use crate::color::Color;
 pub use crate::color::colors;
-pub use crate::async_drawing::Size;
-pub use crate::drawing::Drawing;
+pub use crate::async_drawing::{Size, ImportJsonError};
+pub use crate::drawing::{Drawing, DrawingBuilder};
 pub use crate::point::Point;
+pub use crate::rect::Rect;
+pub use crate::coordinate_system::CoordinateSystem;
 pub use crate::speed::Speed;
+pub use crate::pen::{LineCap, LineJoin, PenState, Pen};
+pub use crate::fill::{FillRule, FillPattern, FillId, UnknownFillError};
+pub use crate::shape::TurtleShape;
+pub use crate::stamp::StampId;
+pub use crate::recording::Recording;
+pub use crate::path_recorder::PathRecorder;
+pub use crate::batch::TurtleBatch;
+pub use crate::text::TextAlign;
 pub use crate::async_turtle::{Angle, Distance};
 pub use crate::turtle::Turtle;
 pub use crate::renderer_server::{ExportError, start};
@@ -117,6 +166,10 @@ cfg_if::cfg_if! {
         pub mod event;
         #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
         pub use crate::event::Event;
+        #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+        pub use crate::async_turtle::AsyncTurtle;
+        #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+        pub use crate::async_drawing::AsyncDrawing;
 
     } else {
         mod event;
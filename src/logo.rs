@@ -0,0 +1,446 @@
+//! Running programs written in the [Logo] programming language
+//!
+//! Logo is the language that turtle graphics comes from. Many books and classes about turtle
+//! graphics use it, so being able to run Logo programs makes it easy to follow along with them.
+//! Use [`run_logo()`] to have a turtle run a Logo program:
+//!
+//! ```rust
+//! use turtle::{Turtle, logo::run_logo};
+//!
+//! let mut turtle = Turtle::new();
+//! // Draws a square
+//! run_logo(&mut turtle, "REPEAT 4 [FD 100 RT 90]").expect("the program has an error");
+//! ```
+//!
+//! Only a small part of Logo is supported: the commands that move the turtle and control its
+//! pen. Commands are not case sensitive and may be separated by any whitespace. A `;` starts a
+//! comment that continues until the end of the line. There are no variables, procedures, or
+//! arithmetic, so each input must be a number (e.g. `100`, `-2.5`).
+//!
+//! | Command | Short form | Inputs | Description |
+//! | ------- | ---------- | ------ | ----------- |
+//! | `FORWARD` | `FD` | distance | Moves forward |
+//! | `BACK` | `BK` | distance | Moves backward |
+//! | `LEFT` | `LT` | angle | Turns left |
+//! | `RIGHT` | `RT` | angle | Turns right |
+//! | `PENUP` | `PU` | | Stops drawing while moving |
+//! | `PENDOWN` | `PD` | | Starts drawing while moving again |
+//! | `REPEAT` | | count, `[commands]` | Runs the commands in the brackets `count` times |
+//! | `SETPENCOLOR` | `SETPC` | color | Sets the pen color |
+//! | `SETPENSIZE` | `SETPS`, `SETWIDTH`, `SETPW` | size | Sets the thickness of the pen |
+//! | `HOME` | | | Moves back to the center, facing north |
+//! | `SETX` | | x | Moves horizontally to the given x coordinate |
+//! | `SETY` | | y | Moves vertically to the given y coordinate |
+//! | `SETXY` | | x, y | Moves to the given position |
+//! | `SETHEADING` | `SETH` | heading | Turns to face the given heading |
+//! | `SHOWTURTLE` | `ST` | | Shows the turtle |
+//! | `HIDETURTLE` | `HT` | | Hides the turtle |
+//! | `CLEAN` | | | Erases everything the turtle has drawn |
+//! | `CLEARSCREEN` | `CS` | | Erases everything and moves back to the center |
+//!
+//! Angles are always in degrees, even if the turtle is [using radians]. Like in Logo, a heading of
+//! `0` is north and headings increase clockwise, so `SETH 90` faces east. That is different from
+//! [`Turtle::set_heading()`], where `0.0` is east and headings increase counterclockwise.
+//!
+//! A color is either a number from `0` to `15` for one of the standard Logo colors (e.g. `4` is
+//! red), a list of red, green, and blue values from `0` to `255` (e.g. `[255 165 0]`), or a color
+//! name starting with a `"` (e.g. `"orange`). See the [`colors` module] for the names that can be
+//! used.
+//!
+//! [Logo]: https://en.wikipedia.org/wiki/Logo_(programming_language)
+//! [`run_logo()`]: fn.run_logo.html
+//! [using radians]: ../struct.Turtle.html#method.use_radians
+//! [`Turtle::set_heading()`]: ../struct.Turtle.html#method.set_heading
+//! [`colors` module]: ../colors/index.html
+
+use thiserror::Error;
+
+use crate::{Turtle, Color, Point, colors};
+
+/// The colors that Logo uses for the color numbers `0` to `15`
+const PALETTE: [Color; 16] = [
+    colors::BLACK,
+    colors::BLUE,
+    colors::GREEN,
+    colors::CYAN,
+    colors::RED,
+    colors::MAGENTA,
+    colors::YELLOW,
+    colors::WHITE,
+    colors::BROWN,
+    colors::TAN,
+    colors::FOREST_GREEN,
+    colors::AQUA,
+    colors::SALMON,
+    colors::PURPLE,
+    colors::ORANGE,
+    colors::GREY,
+];
+
+/// An error in a Logo program passed to [`run_logo()`]
+///
+/// Every error includes the line number (starting at 1) where the error was found.
+///
+/// [`run_logo()`]: fn.run_logo.html
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LogoError {
+    /// A word that is not one of the supported commands
+    #[error("line {line}: I don't know how to {word}")]
+    UnknownCommand {word: String, line: usize},
+    /// A command that was not given all of the inputs it needs (e.g. `FD` without a distance)
+    #[error("line {line}: not enough inputs to {command}")]
+    MissingInput {command: String, line: usize},
+    /// A command that was given an input it cannot use (e.g. `FD abc` or `SETPS -1`)
+    #[error("line {line}: {command} doesn't like {input} as input")]
+    InvalidInput {command: String, input: String, line: usize},
+    /// A `[` without a matching `]`
+    #[error("line {line}: [ without a matching ]")]
+    UnclosedBracket {line: usize},
+    /// A `]` without a matching `[`
+    #[error("line {line}: ] without a matching [")]
+    UnexpectedBracket {line: usize},
+}
+
+/// Runs the given Logo program with the given turtle
+///
+/// The whole program is checked before the turtle does anything, so if there is an error, nothing
+/// is drawn. See the [module documentation](index.html) for the commands that can be used.
+///
+/// # Example
+///
+/// ```rust
+/// use turtle::{Turtle, logo::{run_logo, LogoError}};
+///
+/// let mut turtle = Turtle::new();
+/// run_logo(&mut turtle, "
+///     ; A red triangle
+///     SETPC 4
+///     REPEAT 3 [FD 100 RT 120]
+/// ").expect("the program has an error");
+/// assert_eq!(turtle.pen_color(), "red".into());
+///
+/// let error = run_logo(&mut turtle, "FD 100\nJUMP 50").unwrap_err();
+/// assert_eq!(error, LogoError::UnknownCommand {word: "JUMP".to_owned(), line: 2});
+/// assert_eq!(error.to_string(), "line 2: I don't know how to JUMP");
+/// ```
+pub fn run_logo(turtle: &mut Turtle, source: &str) -> Result<(), LogoError> {
+    let tokens = tokenize(source);
+    let mut parser = Parser {tokens: &tokens, next: 0};
+    let commands = parser.parse_commands()?;
+    if let Some(token) = parser.peek() {
+        // Only a `]` can stop the commands from being parsed before the end of the program
+        return Err(LogoError::UnexpectedBracket {line: token.line});
+    }
+
+    // Logo always uses degrees, no matter what the turtle was using before
+    let was_using_radians = turtle.is_using_radians();
+    turtle.use_degrees();
+    run_commands(turtle, &commands);
+    if was_using_radians {
+        turtle.use_radians();
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Forward(f64),
+    Backward(f64),
+    Left(f64),
+    Right(f64),
+    PenUp,
+    PenDown,
+    Repeat(u64, Vec<Command>),
+    SetPenColor(Color),
+    SetPenSize(f64),
+    Home,
+    SetX(f64),
+    SetY(f64),
+    SetXY(f64, f64),
+    SetHeading(f64),
+    ShowTurtle,
+    HideTurtle,
+    Clean,
+    ClearScreen,
+}
+
+fn run_commands(turtle: &mut Turtle, commands: &[Command]) {
+    for command in commands {
+        use Command::*;
+        match *command {
+            Forward(distance) => turtle.forward(distance),
+            Backward(distance) => turtle.backward(distance),
+            Left(angle) => turtle.left(angle),
+            Right(angle) => turtle.right(angle),
+            PenUp => turtle.pen_up(),
+            PenDown => turtle.pen_down(),
+            Repeat(count, ref commands) => for _ in 0..count {
+                run_commands(turtle, commands);
+            },
+            SetPenColor(color) => turtle.set_pen_color(color),
+            SetPenSize(size) => turtle.set_pen_size(size),
+            Home => turtle.home(),
+            SetX(x) => turtle.set_x(x),
+            SetY(y) => turtle.set_y(y),
+            SetXY(x, y) => turtle.go_to(Point {x, y}),
+            // Logo headings start at north and go clockwise
+            SetHeading(heading) => turtle.set_heading(90.0 - heading),
+            ShowTurtle => turtle.show(),
+            HideTurtle => turtle.hide(),
+            Clean => turtle.clear(),
+            ClearScreen => {
+                turtle.clear();
+                turtle.home();
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+/// Splits the source into words and brackets, skipping comments
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let code = match line.find(';') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+
+        for word in code.split_whitespace() {
+            // Brackets do not need to be separated from the words around them, e.g. `[FD 10]`
+            let mut rest = word;
+            while let Some(bracket) = rest.find(['[', ']']) {
+                if bracket > 0 {
+                    tokens.push(Token {text: &rest[..bracket], line: line_number});
+                }
+                tokens.push(Token {text: &rest[bracket..bracket+1], line: line_number});
+                rest = &rest[bracket+1..];
+            }
+            if !rest.is_empty() {
+                tokens.push(Token {text: rest, line: line_number});
+            }
+        }
+    }
+
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token<'a>],
+    next: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).copied()
+    }
+
+    fn advance(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        if token.is_some() {
+            self.next += 1;
+        }
+        token
+    }
+
+    /// Parses commands until the end of the program or a `]`, which is not consumed
+    fn parse_commands(&mut self) -> Result<Vec<Command>, LogoError> {
+        let mut commands = Vec::new();
+        while let Some(token) = self.peek() {
+            if token.text == "]" {
+                break;
+            }
+            self.next += 1;
+            commands.push(self.parse_command(token)?);
+        }
+
+        Ok(commands)
+    }
+
+    fn parse_command(&mut self, token: Token<'a>) -> Result<Command, LogoError> {
+        use Command::*;
+        let command = match token.text.to_uppercase().as_str() {
+            "FORWARD" | "FD" => Forward(self.number(token)?),
+            "BACK" | "BK" => Backward(self.number(token)?),
+            "LEFT" | "LT" => Left(self.number(token)?),
+            "RIGHT" | "RT" => Right(self.number(token)?),
+            "PENUP" | "PU" => PenUp,
+            "PENDOWN" | "PD" => PenDown,
+            "REPEAT" => {
+                let count = self.number(token)?;
+                if count < 0.0 || count.fract() != 0.0 {
+                    return Err(invalid_input(token, &count.to_string()));
+                }
+                Repeat(count as u64, self.list(token)?)
+            },
+            "SETPENCOLOR" | "SETPC" => SetPenColor(self.color(token)?),
+            "SETPENSIZE" | "SETPS" | "SETWIDTH" | "SETPW" => {
+                let size = self.number(token)?;
+                if size < 0.0 {
+                    return Err(invalid_input(token, &size.to_string()));
+                }
+                SetPenSize(size)
+            },
+            "HOME" => Home,
+            "SETX" => SetX(self.number(token)?),
+            "SETY" => SetY(self.number(token)?),
+            "SETXY" => SetXY(self.number(token)?, self.number(token)?),
+            "SETHEADING" | "SETH" => SetHeading(self.number(token)?),
+            "SHOWTURTLE" | "ST" => ShowTurtle,
+            "HIDETURTLE" | "HT" => HideTurtle,
+            "CLEAN" => Clean,
+            "CLEARSCREEN" | "CS" => ClearScreen,
+            _ => return Err(LogoError::UnknownCommand {word: token.text.to_owned(), line: token.line}),
+        };
+
+        Ok(command)
+    }
+
+    /// Parses the next input of `command` as a finite number
+    fn number(&mut self, command: Token<'a>) -> Result<f64, LogoError> {
+        let input = self.advance().ok_or_else(|| missing_input(command))?;
+        match input.text.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            _ => Err(invalid_input(command, input.text)),
+        }
+    }
+
+    /// Parses the next input of `command` as a bracketed list of commands
+    fn list(&mut self, command: Token<'a>) -> Result<Vec<Command>, LogoError> {
+        let open = self.advance().ok_or_else(|| missing_input(command))?;
+        if open.text != "[" {
+            return Err(invalid_input(command, open.text));
+        }
+
+        let commands = self.parse_commands()?;
+        match self.advance() {
+            Some(_) => Ok(commands),
+            None => Err(LogoError::UnclosedBracket {line: open.line}),
+        }
+    }
+
+    /// Parses the next input of `command` as a color number, `[red green blue]` list, or quoted
+    /// color name
+    fn color(&mut self, command: Token<'a>) -> Result<Color, LogoError> {
+        let input = self.peek().ok_or_else(|| missing_input(command))?;
+
+        if input.text == "[" {
+            self.next += 1;
+            let mut values = [0.0; 3];
+            for value in &mut values {
+                *value = self.number(command)?;
+                if !(0.0..=255.0).contains(value) {
+                    return Err(invalid_input(command, &value.to_string()));
+                }
+            }
+            return match self.advance() {
+                Some(Token {text: "]", ..}) => Ok(Color::rgb(values[0], values[1], values[2])),
+                Some(token) => Err(invalid_input(command, token.text)),
+                None => Err(LogoError::UnclosedBracket {line: input.line}),
+            };
+        }
+
+        self.next += 1;
+        if let Some(name) = input.text.strip_prefix('"') {
+            return colors::from_color_name(name).ok_or_else(|| invalid_input(command, input.text));
+        }
+
+        match input.text.parse::<usize>() {
+            Ok(index) if index < PALETTE.len() => Ok(PALETTE[index]),
+            _ => Err(invalid_input(command, input.text)),
+        }
+    }
+}
+
+fn missing_input(command: Token<'_>) -> LogoError {
+    LogoError::MissingInput {command: command.text.to_owned(), line: command.line}
+}
+
+fn invalid_input(command: Token<'_>, input: &str) -> LogoError {
+    LogoError::InvalidInput {command: command.text.to_owned(), input: input.to_owned(), line: command.line}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Result<Vec<Command>, LogoError> {
+        let tokens = tokenize(source);
+        let mut parser = Parser {tokens: &tokens, next: 0};
+        parser.parse_commands()
+    }
+
+    #[test]
+    fn brackets_do_not_need_spaces() {
+        use Command::*;
+        assert_eq!(
+            parse("repeat 2[fd 10 Rt 90]pu ; comment [").unwrap(),
+            vec![Repeat(2, vec![Forward(10.0), Right(90.0)]), PenUp],
+        );
+    }
+
+    #[test]
+    fn pen_size_aliases() {
+        assert_eq!(
+            parse("setpensize 1 SETPS 2 setwidth 3 SetPW 4").unwrap(),
+            vec![Command::SetPenSize(1.0), Command::SetPenSize(2.0), Command::SetPenSize(3.0), Command::SetPenSize(4.0)],
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(parse("SETPC 4").unwrap(), vec![Command::SetPenColor(colors::RED)]);
+        assert_eq!(parse("SETPC [0 128 255]").unwrap(), vec![Command::SetPenColor(Color::rgb(0.0, 128.0, 255.0))]);
+        assert_eq!(parse("SETPC \"Light Blue").unwrap_err(), LogoError::InvalidInput {
+            command: "SETPC".to_owned(),
+            input: "\"Light".to_owned(),
+            line: 1,
+        });
+        assert_eq!(parse("SETPC \"orange").unwrap(), vec![Command::SetPenColor(colors::ORANGE)]);
+        assert!(parse("SETPC 16").is_err());
+        assert!(parse("SETPC [0 300 0]").is_err());
+        assert_eq!(parse("SETPC [0 0 0").unwrap_err(), LogoError::UnclosedBracket {line: 1});
+    }
+
+    #[test]
+    fn errors_have_line_numbers() {
+        assert_eq!(parse("FD 10\n\nFD").unwrap_err(), LogoError::MissingInput {command: "FD".to_owned(), line: 3});
+        assert_eq!(parse("fd inf").unwrap_err(), LogoError::InvalidInput {
+            command: "fd".to_owned(),
+            input: "inf".to_owned(),
+            line: 1,
+        });
+        assert_eq!(parse("REPEAT 2.5 [FD 1]").unwrap_err(), LogoError::InvalidInput {
+            command: "REPEAT".to_owned(),
+            input: "2.5".to_owned(),
+            line: 1,
+        });
+        assert_eq!(parse("REPEAT 4\n[FD 1").unwrap_err(), LogoError::UnclosedBracket {line: 2});
+    }
+
+    #[test]
+    fn nothing_runs_if_there_is_an_error() {
+        let mut turtle = Turtle::new();
+        assert_eq!(run_logo(&mut turtle, "FD 100 ]").unwrap_err(), LogoError::UnexpectedBracket {line: 1});
+        assert_eq!(turtle.position(), Point::origin());
+    }
+
+    #[test]
+    fn logo_headings_and_degrees() {
+        let mut turtle = Turtle::new();
+        turtle.use_radians();
+        run_logo(&mut turtle, "SETH 90 FD 50 LT 90 FD 20").unwrap();
+        assert_eq!(turtle.position().round(), Point {x: 50.0, y: 20.0});
+        assert!(turtle.is_using_radians());
+
+        run_logo(&mut turtle, "CS").unwrap();
+        assert_eq!(turtle.position(), Point::origin());
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Debug};
+
+use serde::{Serialize, Deserialize};
+
+use crate::Point;
+use crate::ipc_protocol::ProtocolClient;
+use crate::renderer_server::TurtleId;
+use crate::sync_runtime::block_on;
+
+/// Identifies one of the paths being recorded for a turtle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub(crate) struct PathId(pub(crate) usize);
+
+/// Records the positions that a turtle moves to, created by [`Turtle::record_path()`]
+///
+/// The recording continues for as long as the recorder exists, even while the turtle is moved
+/// by other code. Use [`points()`] to look at the path so far or [`stop()`] to stop recording and
+/// get the entire path. Dropping the recorder also stops the recording.
+///
+/// A turtle can have any number of recorders at the same time. Each one records its own path
+/// starting from where the turtle was when that recorder was created.
+///
+/// [`Turtle::record_path()`]: struct.Turtle.html#method.record_path
+/// [`points()`]: #method.points
+/// [`stop()`]: #method.stop
+pub struct PathRecorder {
+    client: ProtocolClient,
+    turtle: TurtleId,
+    id: PathId,
+}
+
+impl Debug for PathRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The points are not included since getting them requires waiting for the renderer
+        f.debug_struct("PathRecorder")
+            .field("turtle", &self.turtle)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for PathRecorder {
+    fn drop(&mut self) {
+        self.client.turtle_stop_path(self.turtle, self.id)
+    }
+}
+
+impl PathRecorder {
+    /// Starts recording the path of the given turtle using a client of its own
+    pub(crate) async fn start(client: ProtocolClient, turtle: TurtleId) -> Self {
+        let id = client.turtle_start_path(turtle).await;
+        Self {client, turtle, id}
+    }
+
+    /// Returns the points recorded so far, without stopping the recording
+    ///
+    /// The first point is the position of the turtle when the recording started. After that,
+    /// there is a point for every position the turtle moved to, in order.
+    pub fn points(&self) -> Vec<Point> {
+        let points = block_on(self.client.turtle_recorded_path(self.turtle, self.id));
+        self.client.points_to_user(points)
+    }
+
+    /// Stops recording and returns every point that was recorded
+    ///
+    /// See [`points()`](#method.points) for more information.
+    pub fn stop(self) -> Vec<Point> {
+        // The recording is stopped when `self` is dropped
+        self.points()
+    }
+}
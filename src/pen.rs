@@ -0,0 +1,237 @@
+use serde::{Serialize, Deserialize};
+
+use crate::{Color, Turtle};
+
+/// The shape drawn at the ends of the lines drawn by the turtle's pen
+///
+/// See the [`set_pen_cap()` method] for more information.
+///
+/// You can create a `LineCap` value by converting one of the strings `"butt"`, `"round"`, or
+/// `"square"`. Any other string will cause a panic.
+///
+/// ```rust
+/// # use turtle::LineCap;
+/// let cap: LineCap = "square".into();
+/// assert_eq!(cap, LineCap::Square);
+/// ```
+///
+/// [`set_pen_cap()` method]: struct.Turtle.html#method.set_pen_cap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LineCap {
+    /// The line ends exactly at its end point with a flat edge
+    Butt,
+    /// The end of the line is rounded off with a half circle
+    Round,
+    /// The line ends with a flat edge that extends past its end point by half the pen size
+    Square,
+}
+
+/// The default line cap is "round"
+impl Default for LineCap {
+    fn default() -> Self {
+        LineCap::Round
+    }
+}
+
+impl<'a> From<&'a str> for LineCap {
+    fn from(cap: &'a str) -> Self {
+        match cap {
+            "butt" => LineCap::Butt,
+            "round" => LineCap::Round,
+            "square" => LineCap::Square,
+            _ => panic!("Invalid line cap specified, use one of the words: \"butt\", \"round\", \"square\""),
+        }
+    }
+}
+
+/// The shape drawn at the corners where the lines drawn by the turtle's pen meet
+///
+/// See the [`set_pen_join()` method] for more information.
+///
+/// You can create a `LineJoin` value by converting one of the strings `"miter"`, `"round"`, or
+/// `"bevel"`. Any other string will cause a panic.
+///
+/// ```rust
+/// # use turtle::LineJoin;
+/// let join: LineJoin = "bevel".into();
+/// assert_eq!(join, LineJoin::Bevel);
+/// ```
+///
+/// [`set_pen_join()` method]: struct.Turtle.html#method.set_pen_join
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LineJoin {
+    /// The outer edges of the lines are extended until they meet in a sharp corner
+    ///
+    /// Very sharp corners are drawn as if they were beveled instead so that they don't stick out
+    /// too far.
+    Miter,
+    /// The corner is rounded off
+    Round,
+    /// The corner is cut off with a straight edge
+    Bevel,
+}
+
+/// The default line join is "round"
+impl Default for LineJoin {
+    fn default() -> Self {
+        LineJoin::Round
+    }
+}
+
+impl<'a> From<&'a str> for LineJoin {
+    fn from(join: &'a str) -> Self {
+        match join {
+            "miter" => LineJoin::Miter,
+            "round" => LineJoin::Round,
+            "bevel" => LineJoin::Bevel,
+            _ => panic!("Invalid line join specified, use one of the words: \"miter\", \"round\", \"bevel\""),
+        }
+    }
+}
+
+/// A snapshot of every setting of the turtle's pen
+///
+/// Use [`pen_state()`] to take a snapshot and [`set_pen_state()`] to restore it later. This is
+/// useful in recursive drawings (e.g. fractals) where each branch changes the pen and then needs
+/// to go back to the pen that was used before it.
+///
+/// ```rust
+/// # use turtle::*;
+/// let mut turtle = Turtle::new();
+/// turtle.set_pen_color("green");
+/// let state = turtle.pen_state();
+/// assert_eq!(state.color, "green".into());
+/// assert_eq!(state.size, 1.0);
+///
+/// turtle.set_pen_color("brown");
+/// turtle.set_pen_size(5.0);
+/// turtle.pen_up();
+///
+/// turtle.set_pen_state(state);
+/// assert_eq!(turtle.pen_color(), "green".into());
+/// assert_eq!(turtle.pen_size(), 1.0);
+/// assert!(turtle.is_pen_down());
+/// ```
+///
+/// [`pen_state()`]: struct.Turtle.html#method.pen_state
+/// [`set_pen_state()`]: struct.Turtle.html#method.set_pen_state
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PenState {
+    /// True if the pen is down and the turtle draws lines as it moves
+    pub is_down: bool,
+    /// The thickness of the pen
+    pub size: f64,
+    /// The color of the pen, or the start color of each line if the pen draws a gradient
+    pub color: Color,
+    /// The color at the end of each line if the pen draws a gradient, or `None` if every line is
+    /// drawn using `color` alone
+    pub end_color: Option<Color>,
+    /// The shape drawn at the ends of each line
+    pub cap: LineCap,
+    /// The shape drawn at the corners where lines meet
+    pub join: LineJoin,
+}
+
+/// Changes several settings of a turtle's pen at once
+///
+/// Created with [`Turtle::pen()`], which starts from the current settings of the pen. Each method
+/// changes one setting, and nothing happens to the turtle until [`apply()`] is called. All of the
+/// settings are then changed together in a single request, which counts as a single change when
+/// it is [undone].
+///
+/// ```rust
+/// # use turtle::*;
+/// let mut turtle = Turtle::new();
+/// turtle.pen().color("blue").size(3.0).cap("round").apply();
+///
+/// assert_eq!(turtle.pen_color(), "blue".into());
+/// assert_eq!(turtle.pen_size(), 3.0);
+/// assert_eq!(turtle.pen_cap(), LineCap::Round);
+/// ```
+///
+/// [`Turtle::pen()`]: struct.Turtle.html#method.pen
+/// [`apply()`]: struct.Pen.html#method.apply
+/// [undone]: struct.Turtle.html#method.undo
+#[must_use = "the pen does not change until `apply()` is called"]
+#[derive(Debug)]
+pub struct Pen<'a> {
+    turtle: &'a mut Turtle,
+    state: PenState,
+}
+
+impl<'a> Pen<'a> {
+    pub(crate) fn new(turtle: &'a mut Turtle, state: PenState) -> Self {
+        Self {turtle, state}
+    }
+
+    /// Puts the pen down so that the turtle draws as it moves
+    ///
+    /// See [`Turtle::pen_down()`](struct.Turtle.html#method.pen_down) for more information.
+    pub fn down(mut self) -> Self {
+        self.state.is_down = true;
+        self
+    }
+
+    /// Picks the pen up so that the turtle stops drawing as it moves
+    ///
+    /// See [`Turtle::pen_up()`](struct.Turtle.html#method.pen_up) for more information.
+    pub fn up(mut self) -> Self {
+        self.state.is_down = false;
+        self
+    }
+
+    /// Sets the thickness of the pen
+    ///
+    /// See [`Turtle::set_pen_size()`](struct.Turtle.html#method.set_pen_size) for more information.
+    pub fn size(mut self, thickness: f64) -> Self {
+        self.state.size = thickness;
+        self
+    }
+
+    /// Sets the color of the pen, so that it draws in a solid color
+    ///
+    /// See [`Turtle::set_pen_color()`](struct.Turtle.html#method.set_pen_color) for more
+    /// information.
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.state.color = color.into();
+        self.state.end_color = None;
+        self
+    }
+
+    /// Sets the pen to draw each line as a gradient from the `start` color to the `end` color
+    ///
+    /// See [`Turtle::set_pen_gradient()`](struct.Turtle.html#method.set_pen_gradient) for more
+    /// information.
+    pub fn gradient<S: Into<Color>, E: Into<Color>>(mut self, start: S, end: E) -> Self {
+        self.state.color = start.into();
+        self.state.end_color = Some(end.into());
+        self
+    }
+
+    /// Sets the shape drawn at the ends of each line
+    ///
+    /// See [`Turtle::set_pen_cap()`](struct.Turtle.html#method.set_pen_cap) for more information.
+    pub fn cap<C: Into<LineCap>>(mut self, cap: C) -> Self {
+        self.state.cap = cap.into();
+        self
+    }
+
+    /// Sets the shape drawn at the corners where lines meet
+    ///
+    /// See [`Turtle::set_pen_join()`](struct.Turtle.html#method.set_pen_join) for more
+    /// information.
+    pub fn join<J: Into<LineJoin>>(mut self, join: J) -> Self {
+        self.state.join = join.into();
+        self
+    }
+
+    /// Changes every setting of the pen of the turtle at once
+    ///
+    /// This works just like [`Turtle::set_pen_state()`], so it panics if the size of the pen is
+    /// negative or if any of the colors are invalid.
+    ///
+    /// [`Turtle::set_pen_state()`]: struct.Turtle.html#method.set_pen_state
+    pub fn apply(self) {
+        self.turtle.set_pen_state(self.state)
+    }
+}
@@ -1,8 +1,9 @@
-use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign};
 
 use serde::{Serialize, Deserialize};
 use interpolation::Lerp;
 
+use crate::Angle;
 use crate::rand::{Random, RandomRange};
 
 /// A point in 2D space
@@ -33,6 +34,18 @@ use crate::rand::{Random, RandomRange};
 /// assert_eq!(Point {x: 100.0, y: 40.0}, (100.0, 40.0).into());
 /// ```
 ///
+/// Points can be converted back into arrays and tuples too, which is useful when passing
+/// coordinates to code that doesn't use this crate.
+///
+/// ```rust
+/// # use turtle::Point;
+/// let pt = Point {x: 100.0, y: 40.0};
+/// let arr: [f64; 2] = pt.into();
+/// let (x, y): (f64, f64) = pt.into();
+/// assert_eq!(arr, [100.0, 40.0]);
+/// assert_eq!((x, y), (100.0, 40.0));
+/// ```
+///
 /// Notice that we need to convert the right side using `into()` before it can be compared
 /// in `assert_eq!()`.
 ///
@@ -45,8 +58,8 @@ use crate::rand::{Random, RandomRange};
 ///
 /// # Manipulating Points
 ///
-/// You can add or subtract points and multiply or divide points by scalar (f64) values. There
-/// are a variety of [method functions](struct.Point.html#methods) described in the
+/// You can add or subtract points, negate points, and multiply or divide points by scalar (f64)
+/// values. There are a variety of [method functions](struct.Point.html#methods) described in the
 /// documentation below that provide even more operations.
 ///
 /// ```rust
@@ -60,6 +73,18 @@ use crate::rand::{Random, RandomRange};
 /// assert_eq!(pt * 2.0, Point {x: a * 2.0, y: b * 2.0});
 /// assert_eq!(pt2 / 5.0, Point {x: c / 5.0, y: d / 5.0});
 /// assert_eq!(pt2 * 2.0 - pt, Point {x: c * 2.0 - a, y: d * 2.0 - b});
+/// assert_eq!(-pt, Point {x: -a, y: -b});
+/// ```
+///
+/// Points can also be added or subtracted in place:
+///
+/// ```rust
+/// # use turtle::Point;
+/// let mut pt = Point {x: 10.0, y: 20.0};
+/// pt += Point {x: 1.0, y: 2.0};
+/// assert_eq!(pt, Point {x: 11.0, y: 22.0});
+/// pt -= [10.0, 10.0].into();
+/// assert_eq!(pt, Point {x: 1.0, y: 12.0});
 /// ```
 ///
 /// # Accessing Point Components
@@ -118,7 +143,9 @@ use crate::rand::{Random, RandomRange};
 /// ```
 ///
 /// When [`random_range()`] is used to generate a `Point`, it creates a random point within the
-/// rectangle formed by the two points given as arguments to [`random_range()`].
+/// rectangle formed by the two points given as arguments to [`random_range()`]. The two points can
+/// be any two opposite corners of the rectangle, so the smaller coordinate on each axis does not
+/// need to come first.
 ///
 /// ```rust
 /// use turtle::{Point, rand::random_range};
@@ -186,6 +213,46 @@ impl Point {
         }
     }
 
+    /// Returns a new `Point` with x and y rounded to the given number of decimal places.
+    /// Rounds half-way cases away from 0.0.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// let p = Point {x: 12.3456, y: -0.015};
+    /// assert_eq!(p.round_to(2), Point {x: 12.35, y: -0.02});
+    /// assert_eq!(p.round_to(0), p.round());
+    /// ```
+    ///
+    /// A value that is too large to be rounded to that many decimal places is returned as is.
+    pub fn round_to(self, decimals: u32) -> Self {
+        let factor = 10f64.powi(decimals.min(i32::MAX as u32) as i32);
+        let round = |value: f64| {
+            let rounded = (value * factor).round() / factor;
+            if rounded.is_finite() { rounded } else { value }
+        };
+
+        Self {
+            x: round(self.x),
+            y: round(self.y),
+        }
+    }
+
+    /// Returns true if x and y are each within `epsilon` of the x and y of the other point
+    ///
+    /// This is useful for comparing computed positions, which often differ by a tiny amount
+    /// from the exact value because of floating point error.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// let p = Point {x: 0.1 + 0.2, y: 10.0};
+    /// assert_ne!(p, Point {x: 0.3, y: 10.0});
+    /// assert!(p.approx_eq(Point {x: 0.3, y: 10.0}, 1e-9));
+    /// assert!(!p.approx_eq(Point {x: 0.3, y: 10.5}, 0.1));
+    /// ```
+    pub fn approx_eq(self, other: Self, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
     /// Returns the minimum x and y coordinates of the two points
     ///
     /// ```rust
@@ -234,6 +301,92 @@ impl Point {
     pub fn atan2(self) -> f64 {
         self.y.atan2(self.x)
     }
+
+    /// Returns the magnitude of this point, treating it as a vector from the origin.
+    ///
+    /// This is the same as [`len()`](#method.len).
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// assert_eq!(Point {x: 3.0, y: 4.0}.magnitude(), 5.0);
+    /// ```
+    pub fn magnitude(self) -> f64 {
+        self.len()
+    }
+
+    /// Returns the distance between this point and another point.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// let p1 = Point {x: 1.0, y: 2.0};
+    /// let p2 = Point {x: 4.0, y: -2.0};
+    /// assert_eq!(p1.distance(p2), 5.0);
+    /// assert_eq!(p2.distance(p1), 5.0);
+    /// ```
+    pub fn distance(self, other: Self) -> f64 {
+        (other - self).len()
+    }
+
+    /// Linearly interpolates between this point and another point.
+    ///
+    /// A value of `t = 0.0` returns this point and `t = 1.0` returns `other`. Values in between
+    /// return points along the straight line between the two points. Values outside of that range
+    /// return points on the same line, but beyond either end.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// let p1 = Point {x: 0.0, y: 10.0};
+    /// let p2 = Point {x: 100.0, y: 20.0};
+    /// assert_eq!(p1.lerp(p2, 0.0), p1);
+    /// assert_eq!(p1.lerp(p2, 0.5), Point {x: 50.0, y: 15.0});
+    /// assert_eq!(p1.lerp(p2, 1.0), p2);
+    /// ```
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Rotates this point counterclockwise around the origin by the given angle in degrees.
+    ///
+    /// Use a negative angle to rotate clockwise. To rotate by an angle in radians, convert it
+    /// with [`f64::to_degrees()`] first.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// let pt = Point {x: 10.0, y: 0.0};
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(pt.rotate(90.0).round(), Point {x: 0.0, y: 10.0});
+    /// assert_eq!(pt.rotate(-90.0).round(), Point {x: 0.0, y: -10.0});
+    /// assert_eq!(pt.rotate(180.0).round(), Point {x: -10.0, y: 0.0});
+    /// ```
+    ///
+    /// [`f64::to_degrees()`]: https://doc.rust-lang.org/std/primitive.f64.html#method.to_degrees
+    pub fn rotate(self, angle: Angle) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Returns a point in the same direction as this point (treated as a vector from the origin)
+    /// but with a magnitude of `1.0`.
+    ///
+    /// The origin has no direction, so normalizing it returns the origin.
+    ///
+    /// ```rust
+    /// # use turtle::Point;
+    /// assert_eq!(Point {x: 3.0, y: 4.0}.normalized(), Point {x: 0.6, y: 0.8});
+    /// assert_eq!(Point {x: 0.0, y: -7.0}.normalized(), Point {x: 0.0, y: -1.0});
+    /// assert_eq!(Point::origin().normalized(), Point::origin());
+    /// ```
+    pub fn normalized(self) -> Self {
+        let len = self.len();
+        if len == 0.0 {
+            return self;
+        }
+
+        self / len
+    }
 }
 
 impl From<(f64, f64)> for Point {
@@ -242,6 +395,12 @@ impl From<(f64, f64)> for Point {
     }
 }
 
+impl From<Point> for (f64, f64) {
+    fn from(pt: Point) -> Self {
+        (pt.x, pt.y)
+    }
+}
+
 impl From<[f64; 2]> for Point {
     fn from(pt: [f64; 2]) -> Self {
         Self { x: pt[0], y: pt[1] }
@@ -265,6 +424,13 @@ impl Add for Point {
     }
 }
 
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
 impl Sub for Point {
     type Output = Self;
 
@@ -276,6 +442,24 @@ impl Sub for Point {
     }
 }
 
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl Mul<f64> for Point {
     type Output = Self;
 
@@ -349,6 +533,8 @@ impl Random for Point {
     }
 }
 
+/// Any two opposite corners of the rectangle can be given, in any order, so this never panics
+/// because of the order of its arguments
 impl<B: Into<Point>> RandomRange<B> for Point {
     fn random_range(p1: B, p2: B) -> Self {
         let p1 = p1.into();
@@ -363,3 +549,28 @@ impl<B: Into<Point>> RandomRange<B> for Point {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_range_accepts_corners_in_any_order() {
+        use crate::rand::random_range;
+
+        // Every combination of swapped corners describes the same rectangle
+        let corners = [
+            ([-10.0, 20.0], [30.0, 50.0]),
+            ([30.0, 50.0], [-10.0, 20.0]),
+            ([30.0, 20.0], [-10.0, 50.0]),
+            ([-10.0, 50.0], [30.0, 20.0]),
+        ];
+        for &(low, high) in &corners {
+            for _ in 0..100 {
+                let point: Point = random_range(low, high);
+                assert!(point.x >= -10.0 && point.x <= 30.0, "{:?} is not between x = -10 and 30", point);
+                assert!(point.y >= 20.0 && point.y <= 50.0, "{:?} is not between y = 20 and 50", point);
+            }
+        }
+    }
+}
@@ -55,6 +55,11 @@ impl Radians {
         self.0.is_normal()
     }
 
+    /// Returns true if this number is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
     /// Returns true if this value is `NaN` and false otherwise.
     pub fn is_nan(self) -> bool {
         self.0.is_nan()
@@ -74,6 +79,15 @@ impl Radians {
     pub fn floor(self) -> Self {
         Radians(self.0.floor())
     }
+
+    /// Returns the equivalent angle between 0 (inclusive) and 2*pi (exclusive)
+    pub fn normalized(self) -> Self {
+        // Formula adapted from: https://stackoverflow.com/a/24234924/551904
+        // More info: https://stackoverflow.com/a/28316446/551904
+        let angle = self - TWO_PI * (self / TWO_PI).floor();
+        // Tiny negative angles can round up to exactly 2*pi
+        if angle >= TWO_PI { ZERO } else { angle }
+    }
 }
 
 impl Lerp for Radians {
@@ -17,11 +17,23 @@
 //!
 //! * [`random()`] - for generating a single random value of a given type
 //! * [`random_range()`] - for generating a single random value of a given type in a certain range
+//! * [`random_bool()`] - for generating `true` or `false` with a certain probability
+//! * [`random_in_circle()`] and [`random_on_circle()`] - for generating points spread evenly
+//!   inside or around the edge of a circle
 //! * [`shuffle()`] - for mixing up a slice of values (`Vec`, slices, etc.)
 //! * [`choose()`] - for choosing a single value from a slice of values (`Vec`, slices, etc.)
+//! * [`weighted_choose()`] - for choosing a single value from a slice, where some values are more
+//!   likely to be chosen than others
 //!
 //! See the documentation for each of those functions for more on what you can use them for.
 //!
+//! # Reproducible Random Values
+//!
+//! By default, you will get different random values every time your program runs. Call [`seed()`]
+//! at the start of your program to get the exact same sequence of random values every time
+//! instead. This is useful if you want to recreate a drawing that you liked or if you want to
+//! write tests that use random values.
+//!
 //! # Generating Random Values
 //!
 //! The [`random()`] function supports all of the common primitive types you would expect:
@@ -75,8 +87,13 @@
 //! [pseudo-random number generation]: https://en.wikipedia.org/wiki/Pseudorandom_number_generator
 //! [`random()`]: fn.random.html
 //! [`random_range()`]: fn.random_range.html
+//! [`random_bool()`]: fn.random_bool.html
+//! [`random_in_circle()`]: fn.random_in_circle.html
+//! [`random_on_circle()`]: fn.random_on_circle.html
 //! [`shuffle()`]: fn.shuffle.html
 //! [`choose()`]: fn.choose.html
+//! [`weighted_choose()`]: fn.weighted_choose.html
+//! [`seed()`]: fn.seed.html
 //! [`Random`]: trait.Random.html
 //! [`RandomRange`]: trait.RandomRange.html
 //! [`RandomSlice`]: trait.RandomSlice.html
@@ -87,7 +104,68 @@
 //! [`Point`]: ../struct.Point.html
 //! [`opaque()`]: ../color/struct.Color.html#method.opaque
 
+use std::cell::RefCell;
 use std::num::Wrapping;
+use std::f64::consts::PI;
+
+use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+use crate::Point;
+
+thread_local! {
+    /// The generator used for all random values on the current thread, or `None` if `thread_rng()`
+    /// should be used because no seed has been provided
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Calls the given function with the random number generator for the current thread
+///
+/// The function must not generate any random values using the functions in this module, since
+/// the generator is already borrowed while it runs.
+fn with_rng<T, F: FnOnce(&mut dyn RngCore) -> T>(f: F) -> T {
+    SEEDED_RNG.with(|seeded_rng| match &mut *seeded_rng.borrow_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::thread_rng()),
+    })
+}
+
+/// Makes all random values generated on the current thread reproducible, based on the given
+/// seed value.
+///
+/// After this is called, [`random()`], [`random_range()`], [`shuffle()`], [`choose()`], and
+/// [`weighted_choose()`] will produce the exact same sequence of values every time the program
+/// runs. Using a different seed produces a different sequence of values. Calling this function
+/// again with the same seed starts the same sequence over from the beginning.
+///
+/// This only affects the thread that calls it. Each thread that needs reproducible values must
+/// call this function itself. The sequence of values for a given seed may change between versions
+/// of the turtle crate.
+///
+/// # Example
+///
+/// ```rust
+/// use turtle::rand::{seed, random, random_range};
+///
+/// seed(42);
+/// let a: f64 = random();
+/// let b: u32 = random_range(1, 100);
+///
+/// // Using the same seed again produces the same values
+/// seed(42);
+/// assert_eq!(random::<f64>(), a);
+/// assert_eq!(random_range::<u32, _>(1, 100), b);
+/// ```
+///
+/// [`random()`]: fn.random.html
+/// [`random_range()`]: fn.random_range.html
+/// [`shuffle()`]: fn.shuffle.html
+/// [`choose()`]: fn.choose.html
+/// [`weighted_choose()`]: fn.weighted_choose.html
+pub fn seed(seed: u64) {
+    SEEDED_RNG.with(|seeded_rng| {
+        *seeded_rng.borrow_mut() = Some(StdRng::seed_from_u64(seed));
+    });
+}
 
 /// This trait represents any type that can have random values generated for it.
 ///
@@ -201,7 +279,8 @@ pub trait Random {
 /// A notable exception to this is the `Point` type. You can interpret a call to
 /// `random_range(Point {x: 1.0, y: 2.0}, Point {x: 5.0, y: 8.0})` as wanting to
 /// generate a random `Point` in the rectangle formed by the two points provided
-/// as arguments.
+/// as arguments. Since those points are just two opposite corners of the rectangle,
+/// they can be given in any order.
 ///
 /// [`Speed`]: ../speed/struct.Speed.html
 /// [`Color`]: ../color/struct.Color.html
@@ -276,7 +355,7 @@ macro_rules! impl_random {
             impl Random for $typ {
                 fn random() -> Self {
                     use rand::Rng;
-                    rand::thread_rng().gen()
+                    with_rng(|rng| rng.gen())
                 }
             }
 
@@ -284,7 +363,7 @@ macro_rules! impl_random {
                 fn random_range(low: Self, high: Self) -> Self {
                     use rand::{Rng, distributions::Uniform};
                     let uniform = Uniform::new_inclusive(low, high);
-                    rand::thread_rng().sample(&uniform)
+                    with_rng(|rng| rng.sample(&uniform))
                 }
             }
         )*
@@ -297,7 +376,7 @@ impl_random!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128,
 impl Random for char {
     fn random() -> Self {
         use rand::Rng;
-        rand::thread_rng().gen()
+        with_rng(|rng| rng.gen())
     }
 }
 
@@ -305,7 +384,7 @@ impl Random for char {
 impl Random for bool {
     fn random() -> Self {
         use rand::Rng;
-        rand::thread_rng().gen()
+        with_rng(|rng| rng.gen())
     }
 }
 
@@ -464,6 +543,130 @@ pub fn random_range<T: RandomRange<B>, B>(low: B, high: B) -> T {
     RandomRange::random_range(low, high)
 }
 
+/// Returns `true` with the given probability and `false` otherwise.
+///
+/// A `probability` of `0.0` always returns `false`, `1.0` always returns `true`, and `0.25`
+/// returns `true` about one out of every four times.
+///
+/// ```rust
+/// use turtle::rand::random_bool;
+///
+/// assert_eq!(random_bool(0.0), false);
+/// assert_eq!(random_bool(1.0), true);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `probability` is not between `0.0` and `1.0` (inclusive).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use turtle::{Turtle, rand::random_bool};
+///
+/// let mut turtle = Turtle::new();
+/// for _ in 0..100 {
+///     turtle.forward(10.0);
+///     // Turn about a quarter of the time
+///     if random_bool(0.25) {
+///         turtle.right(90.0);
+///     }
+/// }
+/// ```
+pub fn random_bool(probability: f64) -> bool {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "Invalid probability: {}. The probability must be between 0.0 and 1.0",
+        probability
+    );
+
+    use rand::Rng;
+    with_rng(|rng| rng.gen_bool(probability))
+}
+
+/// Returns a random point inside the circle with the given `radius` centered at the origin.
+///
+/// Every part of the circle is equally likely to contain the point. (Picking a random angle and
+/// a random distance from the center would put too many points near the middle.) Add the
+/// returned point to the center of your circle to move it somewhere else.
+///
+/// ```rust
+/// use turtle::rand::random_in_circle;
+///
+/// let point = random_in_circle(50.0);
+/// assert!(point.len() <= 50.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative, NaN, or infinite.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use turtle::{Turtle, Point, rand::random_in_circle};
+///
+/// let mut turtle = Turtle::new();
+/// turtle.set_speed("instant");
+/// turtle.pen_up();
+///
+/// // A round cluster of short dashes centered above the origin
+/// let center = Point {x: 0.0, y: 100.0};
+/// for _ in 0..200 {
+///     turtle.go_to(center + random_in_circle(150.0));
+///     turtle.pen_down();
+///     turtle.forward(3.0);
+///     turtle.pen_up();
+/// }
+/// ```
+pub fn random_in_circle(radius: f64) -> Point {
+    assert_valid_radius(radius);
+
+    use rand::Rng;
+    let (angle, t): (f64, f64) = with_rng(|rng| (rng.gen_range(0.0..2.0 * PI), rng.gen()));
+    // The area of the circle within a distance grows with the square of that distance, so the
+    // square root spreads the points evenly over the whole area
+    point_at(radius * t.sqrt(), angle)
+}
+
+/// Returns a random point on the edge of the circle with the given `radius` centered at the
+/// origin.
+///
+/// Every part of the edge of the circle is equally likely to contain the point. Add the returned
+/// point to the center of your circle to move it somewhere else.
+///
+/// ```rust
+/// use turtle::rand::random_on_circle;
+///
+/// let point = random_on_circle(50.0);
+/// assert!((point.len() - 50.0).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative, NaN, or infinite.
+pub fn random_on_circle(radius: f64) -> Point {
+    assert_valid_radius(radius);
+
+    use rand::Rng;
+    let angle = with_rng(|rng| rng.gen_range(0.0..2.0 * PI));
+    point_at(radius, angle)
+}
+
+fn assert_valid_radius(radius: f64) {
+    assert!(
+        radius >= 0.0 && radius.is_finite(),
+        "Invalid radius: {}. The radius must be greater than or equal to zero",
+        radius
+    );
+}
+
+/// Returns the point at the given distance from the origin in the direction of the given angle
+/// (in radians)
+fn point_at(distance: f64, angle: f64) -> Point {
+    Point {x: distance * angle.cos(), y: distance * angle.sin()}
+}
+
 /// This trait represents useful random operations for slices.
 ///
 /// You will not typically use this trait directly or even import it.
@@ -491,14 +694,12 @@ impl<T> RandomSlice for [T] {
 
     fn shuffle(&mut self) {
         use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        <Self as SliceRandom>::shuffle(self, &mut rng);
+        with_rng(|rng| <Self as SliceRandom>::shuffle(self, rng));
     }
 
     fn choose(&self) -> Option<&Self::Item> {
         use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        <Self as SliceRandom>::choose(self, &mut rng)
+        with_rng(|rng| <Self as SliceRandom>::choose(self, rng))
     }
 }
 
@@ -594,3 +795,98 @@ pub fn shuffle<S: RandomSlice + ?Sized>(slice: &mut S) {
 pub fn choose<S: RandomSlice + ?Sized>(slice: &S) -> Option<&<S as RandomSlice>::Item> {
     slice.choose()
 }
+
+/// Chooses a random element from `items` and returns a reference to it, picking each element with
+/// a probability proportional to its weight in `weights`.
+///
+/// An element with a weight of `2.0` is twice as likely to be chosen as an element with a weight
+/// of `1.0`. Elements with a weight of `0.0` are never chosen. If `items` is empty or every weight
+/// is `0.0`, returns None.
+///
+/// ```rust
+/// use turtle::rand::weighted_choose;
+///
+/// let angles = [15.0, 30.0, 45.0];
+/// let angle = weighted_choose(&angles, &[0.0, 1.0, 0.0]);
+/// assert_eq!(angle, Some(&30.0));
+///
+/// let empty: [f64; 0] = [];
+/// assert_eq!(weighted_choose(&empty, &[]), None);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `items` and `weights` have different lengths, or if any weight is negative or not
+/// finite.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use turtle::{Turtle, rand::weighted_choose};
+///
+/// let mut turtle = Turtle::new();
+///
+/// // Mostly turn by a small angle, but occasionally make a sharp turn
+/// let angles = [10.0, 45.0, 90.0];
+/// let weights = [0.7, 0.2, 0.1];
+/// for _ in 0..100 {
+///     turtle.forward(10.0);
+///     let angle = weighted_choose(&angles, &weights).copied().unwrap();
+///     turtle.right(angle);
+/// }
+/// ```
+pub fn weighted_choose<'a, T>(items: &'a [T], weights: &[f64]) -> Option<&'a T> {
+    assert_eq!(
+        items.len(),
+        weights.len(),
+        "Invalid weights: expected one weight for each of the {} items, but got {} weights",
+        items.len(),
+        weights.len()
+    );
+    for &weight in weights {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "Invalid weight: {}. Every weight must be a finite number greater than or equal to 0.0",
+            weight
+        );
+    }
+
+    let max_weight = weights.iter().copied().fold(0.0, f64::max);
+    if max_weight <= 0.0 {
+        return None;
+    }
+
+    // Dividing by the largest weight keeps the total finite even if the sum of the weights is too
+    // large to be represented by an f64
+    let total: f64 = weights.iter().map(|weight| weight / max_weight).sum();
+
+    use rand::Rng;
+    let mut target = with_rng(|rng| rng.gen_range(0.0..total));
+    for (item, &weight) in items.iter().zip(weights) {
+        let weight = weight / max_weight;
+        if target < weight {
+            return Some(item);
+        }
+        target -= weight;
+    }
+
+    // Rounding error in the subtraction above may leave a tiny bit of the target over, in which
+    // case the last item that can be chosen is the right one
+    items.iter().zip(weights).rev().find(|&(_, &weight)| weight > 0.0).map(|(item, _)| item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_choose_huge_weights() {
+        let items = [1, 2, 3];
+        // The sum of these weights is infinite
+        let weights = [f64::MAX, 0.0, f64::MAX];
+        for _ in 0..100 {
+            let item = weighted_choose(&items, &weights);
+            assert!(item == Some(&1) || item == Some(&3), "{:?}", item);
+        }
+    }
+}
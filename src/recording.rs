@@ -0,0 +1,170 @@
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Serialize, Deserialize};
+
+use crate::{StampId, FillId};
+use crate::ipc_protocol::{ClientRequest, ServerResponse};
+use crate::renderer_server::TurtleId;
+
+/// A sequence of turtle and drawing commands captured by [`Drawing::start_recording()`]
+///
+/// Pass a recording to [`Drawing::replay()`] to run the same commands again. Recordings can be
+/// serialized with [serde](https://serde.rs), so they can be saved to a file (e.g. as JSON) and
+/// replayed later or by someone else.
+///
+/// Only the commands that change the drawing or the state of a turtle are recorded. Methods that
+/// only return information (e.g. [`position()`]) or that save the drawing to a file are not.
+///
+/// ```rust
+/// use turtle::Drawing;
+///
+/// let mut drawing = Drawing::new();
+/// let mut turtle = drawing.add_turtle();
+///
+/// drawing.start_recording();
+/// turtle.forward(100.0);
+/// // Getting the position of the turtle does not change anything, so it isn't recorded
+/// let position = turtle.position();
+/// turtle.right(90.0);
+/// let recording = drawing.stop_recording();
+///
+/// assert_eq!(recording.len(), 2);
+/// ```
+///
+/// [`Drawing::start_recording()`]: struct.Drawing.html#method.start_recording
+/// [`Drawing::replay()`]: struct.Drawing.html#method.replay
+/// [`position()`]: struct.Turtle.html#method.position
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Recording {
+    requests: Vec<ClientRequest>,
+    /// The ID of each stamp made while recording, in the order that the stamps were made
+    ///
+    /// Replaying makes new stamps with different IDs, so these are used to find out which stamp a
+    /// recorded request refers to.
+    #[serde(default)]
+    stamps: Vec<(TurtleId, StampId)>,
+    /// The ID of each fill completed while recording, in the order that the fills were completed
+    #[serde(default)]
+    fills: Vec<(TurtleId, FillId)>,
+}
+
+impl Recording {
+    /// Returns the number of commands in this recording
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns true if no commands were recorded
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Returns the requests in the order in which they were sent
+    pub(crate) fn requests(&self) -> &[ClientRequest] {
+        &self.requests
+    }
+
+    /// Adds the given request to the recording if it is one that should be replayed
+    pub(crate) fn record(&mut self, request: &ClientRequest) {
+        if is_recorded(request) {
+            self.requests.push(request.clone());
+        }
+    }
+
+    /// Records the ID in the given response if it is the ID of a new stamp or fill
+    pub(crate) fn record_response(&mut self, response: &ServerResponse) {
+        match *response {
+            ServerResponse::Stamp(id, stamp_id) => self.stamps.push((id, stamp_id)),
+            ServerResponse::FillEnded(id, fill_id) => self.fills.push((id, fill_id)),
+            _ => {},
+        }
+    }
+
+    /// Returns the IDs of the stamps made by each turtle while recording, in the order that each
+    /// turtle made them
+    pub(crate) fn stamp_ids(&self) -> HashMap<TurtleId, VecDeque<StampId>> {
+        let mut stamps: HashMap<_, VecDeque<_>> = HashMap::new();
+        for &(id, stamp_id) in &self.stamps {
+            stamps.entry(id).or_default().push_back(stamp_id);
+        }
+        stamps
+    }
+
+    /// Returns the IDs of the fills completed by each turtle while recording, in the order that
+    /// each turtle completed them
+    pub(crate) fn fill_ids(&self) -> HashMap<TurtleId, VecDeque<FillId>> {
+        let mut fills: HashMap<_, VecDeque<_>> = HashMap::new();
+        for &(id, fill_id) in &self.fills {
+            fills.entry(id).or_default().push_back(fill_id);
+        }
+        fills
+    }
+}
+
+/// Returns true if the request changes the drawing or the state of a turtle
+///
+/// Creating a turtle is not recorded because replaying a recording creates a new turtle for each
+/// turtle that was used in the recording. The same applies to cloning a turtle, so a clone starts
+/// with the default state when it is replayed. The window icon is not part of the drawing, and
+/// the image file it was loaded from may not exist when the recording is replayed.
+fn is_recorded(request: &ClientRequest) -> bool {
+    use ClientRequest::*;
+    match request {
+        CreateTurtle |
+        CloneTurtle(_) |
+        Export(..) |
+        Screenshot |
+        ExportDisplayList |
+        StartGifRecording(..) |
+        StopGifRecording |
+        DrawingBounds |
+        PollEvent |
+        NextEvent |
+        MousePosition |
+        PressedKeys |
+        DrawingProp(_) |
+        SetIcon(_) |
+        UpdateDrawing |
+        TurtleProp(..) |
+        PositionHistory(_) |
+        EndPoly(_) |
+        StartPath(_) |
+        RecordedPath(..) |
+        StopPath(..) |
+        WaitForAnimation(_) |
+        DebugTurtle(..) |
+        DebugDrawing |
+        DestroyDrawing |
+        ReleaseClient => false,
+
+        ImportDisplayList(_) |
+        SetDrawingProp(_) |
+        ResetDrawingProp(_) |
+        SetTurtleProp(..) |
+        ResetTurtleProp(..) |
+        ResetTurtle(_) |
+        MoveForward(..) |
+        MoveForwardIn(..) |
+        MoveTo(..) |
+        Teleport(..) |
+        RotateInPlace(..) |
+        Batch(..) |
+        AnimateBackground(..) |
+        AnimateCenter(..) |
+        AnimateFillColor(..) |
+        BeginFill(_) |
+        EndFill(_) |
+        SetFillColorOf(..) |
+        ClearAll |
+        ClearTurtle(_) |
+        WriteText(..) |
+        Stamp(_) |
+        ClearStamp(..) |
+        ClearStamps(_) |
+        Undo(_) |
+        BeginUndoGroup(_) |
+        EndUndoGroup(_) |
+        SetTrackHistory(..) |
+        BeginPoly(_) => true,
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Serialize, Deserialize};
+
+use crate::Point;
+
+/// An axis-aligned rectangle in logical coordinates
+///
+/// Since the y-axis points up, `min` is the bottom-left corner of the rectangle and `max` is its
+/// top-right corner.
+///
+/// ```rust
+/// # use turtle::{Rect, Point};
+/// let rect = Rect {min: Point {x: -50.0, y: 0.0}, max: Point {x: 150.0, y: 100.0}};
+/// assert_eq!(rect.width(), 200.0);
+/// assert_eq!(rect.height(), 100.0);
+/// assert_eq!(rect.center(), Point {x: 50.0, y: 50.0});
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rect {
+    /// The corner of the rectangle with the smallest x and y coordinates
+    pub min: Point,
+    /// The corner of the rectangle with the largest x and y coordinates
+    pub max: Point,
+}
+
+impl Rect {
+    /// Returns the distance between the left and right sides of the rectangle
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    /// Returns the distance between the bottom and top sides of the rectangle
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the point in the middle of the rectangle
+    pub fn center(&self) -> Point {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Returns true if the given point is inside the rectangle or on one of its edges
+    ///
+    /// ```rust
+    /// # use turtle::{Rect, Point};
+    /// let rect = Rect {min: Point {x: 0.0, y: 0.0}, max: Point {x: 100.0, y: 50.0}};
+    /// assert!(rect.contains(Point {x: 20.0, y: 10.0}));
+    /// assert!(rect.contains(Point {x: 100.0, y: 50.0}));
+    /// assert!(!rect.contains(Point {x: 20.0, y: 60.0}));
+    /// ```
+    pub fn contains(&self, point: Point) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x
+            && self.min.y <= point.y && point.y <= self.max.y
+    }
+
+    /// Returns the smallest rectangle that contains both this rectangle and the given point
+    pub(crate) fn including(self, point: Point) -> Self {
+        Self {
+            min: Point {x: self.min.x.min(point.x), y: self.min.y.min(point.y)},
+            max: Point {x: self.max.x.max(point.x), y: self.max.y.max(point.y)},
+        }
+    }
+}
@@ -6,7 +6,8 @@ use tokio::sync::{mpsc, RwLock, Mutex};
 use thiserror::Error;
 
 use crate::ipc_protocol::{ClientSender, ConnectionError, ClientRequest, ServerResponse};
-use crate::renderer_server::RendererServer;
+use crate::renderer_server::{RendererServer, WindowConfig};
+use crate::Recording;
 
 /// Signals that the IPC connection has been disconnected and therefore the window was probably
 /// closed
@@ -37,11 +38,17 @@ struct ClientDispatcher {
     /// Using `RwLock` allows sending multiple times concurrently using `RwLock::read()` and also
     /// allows more clients to be added using `RwLock::write()`.
     clients: Arc<RwLock<Vec<mpsc::UnboundedSender<Result<ServerResponse, Disconnected>>>>>,
+
+    /// The requests sent by every client since recording started, or `None` if nothing is being
+    /// recorded
+    ///
+    /// This is shared by all clients so that every turtle in the drawing is recorded.
+    recording: parking_lot::Mutex<Option<Recording>>,
 }
 
 impl ClientDispatcher {
-    async fn new() -> Result<(Self, ClientSender), ConnectionError> {
-        let (server, sender, server_responses) = RendererServer::spawn().await?;
+    async fn new(config: WindowConfig) -> Result<(Self, ClientSender), ConnectionError> {
+        let (server, sender, server_responses) = RendererServer::spawn(config).await?;
         let clients = Arc::new(RwLock::new(Vec::<mpsc::UnboundedSender<_>>::new()));
 
         let task_clients = clients.clone();
@@ -73,7 +80,9 @@ impl ClientDispatcher {
             }
         });
 
-        Ok((Self {server, clients}, sender))
+        let recording = parking_lot::Mutex::new(None);
+
+        Ok((Self {server, clients, recording}, sender))
     }
 
     async fn add_client(&self) -> (ClientId, mpsc::UnboundedReceiver<Result<ServerResponse, Disconnected>>) {
@@ -97,9 +106,10 @@ pub struct RendererClient {
 }
 
 impl RendererClient {
-    /// Spawns a new server process and creates a connection to it
-    pub async fn new() -> Result<Self, ConnectionError> {
-        let (dispatcher, sender) = ClientDispatcher::new().await?;
+    /// Spawns a new server process that opens a window with the given settings and creates a
+    /// connection to it
+    pub async fn new(config: WindowConfig) -> Result<Self, ConnectionError> {
+        let (dispatcher, sender) = ClientDispatcher::new(config).await?;
         let dispatcher = Arc::new(dispatcher);
         let (id, receiver) = dispatcher.add_client().await;
         let receiver = Mutex::new(receiver);
@@ -121,12 +131,46 @@ impl RendererClient {
     ///
     /// When possible, prefer using methods from `ProtocolClient` instead of using this directly
     pub fn send(&self, req: ClientRequest) {
+        // The lock is held until the request is sent so that requests are recorded in the same
+        // order that the server receives them
+        let mut recording = self.dispatcher.recording.lock();
+        if let Some(recording) = &mut *recording {
+            recording.record(&req);
+        }
+
         // The error produced by send is a serialization error, so it signals a bug in this code,
         // not something that should be propagated to be handled elsewhere.
         self.sender.send(self.id, req)
             .expect("bug: error while sending message through IPC")
     }
 
+    /// Starts recording the requests sent by every client connected to the same server,
+    /// discarding anything that was recorded so far
+    pub fn start_recording(&self) {
+        *self.dispatcher.recording.lock() = Some(Recording::default());
+    }
+
+    /// Stops recording and returns everything that was recorded
+    ///
+    /// Returns an empty recording if nothing was being recorded.
+    pub fn stop_recording(&self) -> Recording {
+        self.dispatcher.recording.lock().take().unwrap_or_default()
+    }
+
+    /// Renders the drawing into the given buffer without sending a request to the server
+    ///
+    /// Requests that were sent but not handled by the server yet are not reflected in the image.
+    #[cfg(feature = "embedded")]
+    pub fn render_to(&self, buffer: &mut Vec<u8>) -> Result<crate::Size, crate::ExportError> {
+        self.dispatcher.server.render_to(buffer)
+    }
+
+    /// Sends an event to the drawing without sending a request to the server
+    #[cfg(all(feature = "embedded", feature = "unstable"))]
+    pub fn send_event(&self, event: crate::Event) {
+        self.dispatcher.server.send_event(event)
+    }
+
     /// Receives a response from the server process
     ///
     /// Note that if the same client sends multiple requests, there is no guarantee that the
@@ -136,12 +180,29 @@ impl RendererClient {
     /// When possible, prefer using methods from `ProtocolClient` instead of using this directly
     pub async fn recv(&self) -> ServerResponse {
         let mut receiver = self.receiver.lock().await;
-        receiver.recv().await
+        let response = receiver.recv().await
             // Since this struct keeps a ref-counted copy of the senders, they can't have possibly
             // been dropped at this point.
             .expect("bug: client senders should not be dropped yet")
             // This panic causes the program to exit if turtle commands continue after the window
             // closes
-            .unwrap_or_else(|err| panic!("IPC response not received: {}", err))
+            .unwrap_or_else(|err| panic!("IPC response not received: {}", err));
+
+        // The IDs of new stamps and fills are needed to replay the requests that refer to them
+        if let Some(recording) = &mut *self.dispatcher.recording.lock() {
+            recording.record_response(&response);
+        }
+
+        response
+    }
+}
+
+impl Drop for RendererClient {
+    fn drop(&mut self) {
+        // Makes sure that an event or frame is not held for this client after it is gone (e.g. if
+        // an event stream is dropped while it waits for an event). Not recorded since no other
+        // request can refer to this client. The error is ignored because the server may have
+        // already shut down.
+        self.sender.send(self.id, ClientRequest::ReleaseClient).unwrap_or(());
     }
 }
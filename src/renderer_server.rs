@@ -8,7 +8,7 @@ mod handlers;
 mod start;
 
 cfg_if::cfg_if! {
-    if #[cfg(any(feature = "test", test))] {
+    if #[cfg(any(feature = "test", feature = "embedded", test))] {
         mod test_event_loop_notifier;
         use test_event_loop_notifier as event_loop_notifier;
 
@@ -19,15 +19,20 @@ cfg_if::cfg_if! {
 }
 
 pub(crate) use app::TurtleId;
+pub(crate) use state::WindowConfig;
 pub(crate) use backend::RendererServer;
 pub use renderer::export::ExportError;
+pub(crate) use renderer::display_list::DisplayListData;
 pub use start::start;
 
+use std::collections::VecDeque;
+
 use ipc_channel::ipc::IpcError;
 use tokio::sync::mpsc;
 use parking_lot::{RwLock, Mutex};
 
 use crate::ipc_protocol::{ServerSender, ServerOneshotSender, ServerReceiver, ClientRequest};
+use crate::renderer_client::ClientId;
 use crate::Event;
 
 use app::{SharedApp, App};
@@ -36,6 +41,7 @@ use event_loop_notifier::EventLoopNotifier;
 use animation::AnimationRunner;
 
 /// Serves requests from the client forever
+#[allow(clippy::too_many_arguments)]
 async fn serve(
     conn: ServerSender,
     client_requests: ServerReceiver,
@@ -43,6 +49,7 @@ async fn serve(
     display_list: SharedDisplayList,
     event_loop: EventLoopNotifier,
     mut events_receiver: mpsc::UnboundedReceiver<Event>,
+    mut frames_receiver: mpsc::Receiver<()>,
     mut server_shutdown_receiver: mpsc::Receiver<()>,
 ) {
     let anim_runner = AnimationRunner::new(
@@ -52,13 +59,44 @@ async fn serve(
         event_loop.clone(),
     );
 
+    // The clients that sent a `NextEvent` request and are still waiting for an event, in the
+    // order that they sent their requests
+    let mut event_waiters = VecDeque::new();
+    // The clients that sent an `UpdateDrawing` request and are still waiting for the next frame
+    let mut frame_waiters = Vec::new();
+
     loop {
-        // This will either receive the next request or end this task
+        // This will either receive the next request, respond to a client waiting for an event, or
+        // end this task
         let (client_id, request) = tokio::select! {
             // If the main thread shuts down successfully, this will receive Some(()). If the main
             // thread panics, this will return None. In either case, this loop needs to stop.
             _ = server_shutdown_receiver.recv() => break,
 
+            // Events are only taken from the channel here if someone is waiting for them. Otherwise
+            // they stay in the channel until they are polled.
+            event = events_receiver.recv(), if !event_waiters.is_empty() => {
+                let client_id = event_waiters.pop_front()
+                    .expect("bug: should only receive events while a client is waiting for one");
+                handle_handler_result(handlers::send_next_event(
+                    ServerOneshotSender::new(client_id, &conn),
+                    &mut app.write(),
+                    event,
+                ));
+                continue;
+            },
+
+            // The channel is closed if there is no window, in which case no frame will ever be
+            // drawn and the clients are not kept waiting
+            _ = frames_receiver.recv(), if !frame_waiters.is_empty() => {
+                for client_id in frame_waiters.drain(..) {
+                    handle_handler_result(handlers::send_drawing_updated(
+                        ServerOneshotSender::new(client_id, &conn),
+                    ));
+                }
+                continue;
+            },
+
             req = client_requests.recv() => match req {
                 Ok(req) => req,
                 // Client has disconnected completely, no purpose in continuing this loop
@@ -74,6 +112,9 @@ async fn serve(
             &display_list,
             &event_loop,
             &mut events_receiver,
+            &mut event_waiters,
+            &mut frames_receiver,
+            &mut frame_waiters,
             &anim_runner,
             request,
         ));
@@ -81,12 +122,16 @@ async fn serve(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn dispatch_request(
     conn: ServerOneshotSender,
     app: &RwLock<App>,
     display_list: &Mutex<DisplayList>,
     event_loop: &EventLoopNotifier,
     events_receiver: &mut mpsc::UnboundedReceiver<Event>,
+    event_waiters: &mut VecDeque<ClientId>,
+    frames_receiver: &mut mpsc::Receiver<()>,
+    frame_waiters: &mut Vec<ClientId>,
     anim_runner: &AnimationRunner,
     request: ClientRequest,
 ) -> Result<(), handlers::HandlerError> {
@@ -95,13 +140,46 @@ fn dispatch_request(
         CreateTurtle => {
             handlers::create_turtle(conn, &mut app.write(), event_loop)
         },
+        CloneTurtle(id) => {
+            handlers::clone_turtle(conn, &mut app.write(), event_loop, id)
+        },
 
         Export(path, format) => {
             handlers::export_drawings(conn, &app.read(), &display_list.lock(), &path, format)
         },
+        Screenshot => {
+            handlers::screenshot(conn, &app.read(), &display_list.lock())
+        },
+        ExportDisplayList => {
+            handlers::export_display_list(conn, &app.read(), &display_list.lock())
+        },
+        ImportDisplayList(data) => {
+            handlers::import_display_list(&mut app.write(), &mut display_list.lock(), event_loop, anim_runner, data)
+        },
+        StartGifRecording(path, fps) => {
+            handlers::start_gif_recording(&mut app.write(), event_loop, path, fps)
+        },
+        StopGifRecording => {
+            // Taken out of the app first so that the window can keep rendering while the GIF is
+            // encoded, which may take a while
+            let recording = app.write().take_gif_recording();
+            handlers::stop_gif_recording(conn, recording)
+        },
+        DrawingBounds => {
+            handlers::drawing_bounds(conn, &display_list.lock())
+        },
 
         PollEvent => {
-            handlers::poll_event(conn, events_receiver)
+            handlers::poll_event(conn, &mut app.write(), events_receiver)
+        },
+        NextEvent => {
+            handlers::next_event(conn, &mut app.write(), events_receiver, event_waiters)
+        },
+        MousePosition => {
+            handlers::mouse_position(conn, &app.read())
+        },
+        PressedKeys => {
+            handlers::pressed_keys(conn, &app.read())
         },
 
         DrawingProp(prop) => {
@@ -113,6 +191,12 @@ fn dispatch_request(
         ResetDrawingProp(prop) => {
             handlers::reset_drawing_prop(&mut app.write(), event_loop, prop)
         },
+        SetIcon(path) => {
+            handlers::set_icon(conn, event_loop, &path)
+        },
+        UpdateDrawing => {
+            handlers::update_drawing(conn, &mut display_list.lock(), event_loop, frames_receiver, frame_waiters)
+        },
 
         TurtleProp(id, prop) => {
             handlers::turtle_prop(conn, &app.read(), id, prop)
@@ -128,20 +212,44 @@ fn dispatch_request(
         },
 
         MoveForward(id, distance) => {
-            handlers::move_forward(conn, &mut app.write(), &mut display_list.lock(), event_loop, anim_runner, id, distance)
+            handlers::move_forward(conn, &mut app.write(), &mut display_list.lock(), event_loop, anim_runner, id, distance, None)
+        },
+        MoveForwardIn(id, distance, duration) => {
+            handlers::move_forward(conn, &mut app.write(), &mut display_list.lock(), event_loop, anim_runner, id, distance, Some(duration))
         },
         MoveTo(id, target_pos) => {
             handlers::move_to(conn, &mut app.write(), &mut display_list.lock(), event_loop, anim_runner, id, target_pos)
         },
+        Teleport(id, position, heading) => {
+            handlers::teleport(&mut app.write(), &mut display_list.lock(), event_loop, id, position, heading)
+        },
         RotateInPlace(id, angle, direction) => {
-            handlers::rotate_in_place(conn, &mut app.write(), event_loop, anim_runner, id, angle, direction)
+            handlers::rotate_in_place(conn, &mut app.write(), &display_list.lock(), event_loop, anim_runner, id, angle, direction)
+        },
+        WaitForAnimation(id) => {
+            handlers::wait_for_animation(conn, anim_runner, id)
+        },
+        Batch(id, commands) => {
+            handlers::batch(conn, &mut app.write(), &mut display_list.lock(), event_loop, id, commands)
+        },
+        AnimateBackground(color, secs) => {
+            handlers::animate_background(conn, &mut app.write(), event_loop, anim_runner, color, secs)
+        },
+        AnimateCenter(center, secs) => {
+            handlers::animate_center(conn, &mut app.write(), event_loop, anim_runner, center, secs)
+        },
+        AnimateFillColor(id, from, to, secs) => {
+            handlers::animate_fill_color(conn, &mut app.write(), &mut display_list.lock(), event_loop, anim_runner, id, from, to, secs)
         },
 
         BeginFill(id) => {
             handlers::begin_fill(&mut app.write(), &mut display_list.lock(), event_loop, id)
         },
         EndFill(id) => {
-            handlers::end_fill(&mut app.write(), id)
+            handlers::end_fill(conn, &mut app.write(), &display_list.lock(), id)
+        },
+        SetFillColorOf(id, fill_id, color) => {
+            handlers::set_fill_color_of(conn, &mut app.write(), &mut display_list.lock(), event_loop, id, fill_id, color)
         },
 
         ClearAll => {
@@ -151,6 +259,52 @@ fn dispatch_request(
             handlers::clear_turtle(&mut app.write(), &mut display_list.lock(), event_loop, id)
         },
 
+        WriteText(id, text, font_size, align) => {
+            handlers::write_text(&mut app.write(), &mut display_list.lock(), event_loop, id, &text, font_size, align)
+        },
+
+        Stamp(id) => {
+            handlers::stamp(conn, &mut app.write(), &mut display_list.lock(), event_loop, id)
+        },
+        ClearStamp(id, stamp_id) => {
+            handlers::clear_stamp(&mut app.write(), &mut display_list.lock(), event_loop, id, stamp_id)
+        },
+        ClearStamps(id) => {
+            handlers::clear_stamps(&mut app.write(), &mut display_list.lock(), event_loop, id)
+        },
+
+        Undo(id) => {
+            handlers::undo(&mut app.write(), &mut display_list.lock(), event_loop, id)
+        },
+        BeginUndoGroup(id) => {
+            handlers::begin_undo_group(&mut app.write(), id)
+        },
+        EndUndoGroup(id) => {
+            handlers::end_undo_group(&mut app.write(), id)
+        },
+
+        SetTrackHistory(id, capacity) => {
+            handlers::set_track_history(&mut app.write(), id, capacity)
+        },
+        PositionHistory(id) => {
+            handlers::position_history(conn, &app.read(), id)
+        },
+        BeginPoly(id) => {
+            handlers::begin_poly(&mut app.write(), id)
+        },
+        EndPoly(id) => {
+            handlers::end_poly(conn, &mut app.write(), id)
+        },
+        StartPath(id) => {
+            handlers::start_path(conn, &mut app.write(), id)
+        },
+        RecordedPath(id, path_id) => {
+            handlers::recorded_path(conn, &app.read(), id, path_id)
+        },
+        StopPath(id, path_id) => {
+            handlers::stop_path(&mut app.write(), id, path_id)
+        },
+
         DebugTurtle(id, angle_unit) => {
             handlers::debug_turtle(conn, &app.read(), id, angle_unit)
         },
@@ -161,6 +315,9 @@ fn dispatch_request(
         DestroyDrawing => {
             handlers::destroy_drawing(event_loop)
         },
+        ReleaseClient => {
+            handlers::release_client(conn, event_waiters, frame_waiters)
+        },
     }
 }
 
@@ -8,12 +8,12 @@ use parking_lot::{RwLock, Mutex};
 use crate::renderer_client::ClientId;
 use crate::ipc_protocol::{ServerSender, RotationDirection, ServerResponse};
 use crate::radians::{self, Radians};
-use crate::Point;
+use crate::{Point, Color, FillId};
 
 use super::{
     handle_handler_result,
     app::{SharedApp, App, TurtleDrawings, TurtleId},
-    state::TurtleState,
+    state::{TurtleState, DrawingState, Pen},
     renderer::display_list::{DisplayList, SharedDisplayList, PrimHandle},
     event_loop_notifier::EventLoopNotifier,
     handlers::HandlerError,
@@ -28,10 +28,26 @@ const MICROS_PER_SEC: u64 = 1_000_000;
 /// The maximum length of an animation frame
 const FRAME_DURATION: time::Duration = time::Duration::from_micros(MICROS_PER_SEC / FPS);
 
+/// The part of the drawing that is changed by an animation
+///
+/// Only one animation can play for each target at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AnimationTarget {
+    /// The turtle with the given ID
+    Turtle(TurtleId),
+    /// The background of the drawing
+    Background,
+    /// The center of the drawing
+    Center,
+}
+
 #[derive(Debug)]
 pub enum AnimationKind {
     Move(MoveAnimation),
     Rotate(RotateAnimation),
+    Background(BackgroundAnimation),
+    Center(CenterAnimation),
+    FillColor(FillColorAnimation),
 }
 
 impl From<MoveAnimation> for AnimationKind {
@@ -46,20 +62,49 @@ impl From<RotateAnimation> for AnimationKind {
     }
 }
 
+impl From<BackgroundAnimation> for AnimationKind {
+    fn from(anim: BackgroundAnimation) -> Self {
+        AnimationKind::Background(anim)
+    }
+}
+
+impl From<CenterAnimation> for AnimationKind {
+    fn from(anim: CenterAnimation) -> Self {
+        AnimationKind::Center(anim)
+    }
+}
+
+impl From<FillColorAnimation> for AnimationKind {
+    fn from(anim: FillColorAnimation) -> Self {
+        AnimationKind::FillColor(anim)
+    }
+}
+
 #[derive(Debug)]
 struct Animation {
-    /// The ID of the turtle associated with this animation
-    turtle_id: TurtleId,
+    /// The part of the drawing associated with this animation
+    target: AnimationTarget,
     /// The animation that will be played
     kind: AnimationKind,
     /// The client that will be notified when that animation is completed
     client_id: ClientId,
+    /// Any other clients waiting for this animation to complete
+    waiters: Vec<ClientId>,
 }
 
 impl Animation {
-    pub fn new(turtle_id: TurtleId, kind: impl Into<AnimationKind>, client_id: ClientId) -> Self {
+    pub fn new(target: AnimationTarget, kind: impl Into<AnimationKind>, client_id: ClientId) -> Self {
         let kind = kind.into();
-        Self {turtle_id, kind, client_id}
+        Self {target, kind, client_id, waiters: Vec::new()}
+    }
+
+    /// Notifies every client waiting for this animation that it has completed
+    pub fn send_complete(&self, conn: &ServerSender, app: &App) -> Result<(), HandlerError> {
+        for &client_id in std::iter::once(&self.client_id).chain(&self.waiters) {
+            conn.send(client_id, self.complete_response(app))?;
+        }
+
+        Ok(())
     }
 
     pub fn is_running(&self) -> bool {
@@ -67,6 +112,9 @@ impl Animation {
         match &self.kind {
             Move(anim) => anim.is_running(),
             Rotate(anim) => anim.is_running(),
+            Background(anim) => anim.is_running(),
+            Center(anim) => anim.is_running(),
+            FillColor(anim) => anim.is_running(),
         }
     }
 
@@ -75,6 +123,74 @@ impl Animation {
         match &self.kind {
             Move(anim) => anim.next_update(),
             Rotate(anim) => anim.next_update(),
+            Background(anim) => anim.next_update(),
+            Center(anim) => anim.next_update(),
+            FillColor(anim) => anim.next_update(),
+        }
+    }
+
+    /// Returns the response that notifies the client that this animation has completed
+    pub fn complete_response(&self, app: &App) -> ServerResponse {
+        match self.target {
+            AnimationTarget::Turtle(id) => turtle_animation_complete(app, id),
+            AnimationTarget::Background => ServerResponse::BackgroundAnimationComplete,
+            AnimationTarget::Center => ServerResponse::CenterAnimationComplete,
+        }
+    }
+}
+
+/// Returns the response that notifies a client that the animation of the given turtle has
+/// completed, which includes the state of the turtle that the animation leaves it in
+fn turtle_animation_complete(app: &App, id: TurtleId) -> ServerResponse {
+    let TurtleState {position, heading, ..} = app.turtle(id).state;
+    ServerResponse::AnimationComplete(id, position, heading)
+}
+
+/// Starts the line that the turtle draws as it moves from `start` to `end`, returning a handle to
+/// a line that currently ends at `start`, or `None` if no line should be drawn
+///
+/// If the new line would continue the last line drawn by the turtle in a straight line with the
+/// same stroke, that line is returned so it can be extended. This keeps the display list (and
+/// any exported drawings) small when a line is drawn with many short movements.
+///
+/// A line drawn while the tracer of the turtle is disabled is hidden until it is shown again, so
+/// a line is only extended if it is hidden in the same way as the new line would be.
+fn draw_line(
+    turtle: &mut TurtleDrawings,
+    display_list: &mut DisplayList,
+    start: Point,
+    end: Point,
+    pen: &Pen,
+) -> Option<PrimHandle> {
+    let hidden = !turtle.state.tracer;
+    if let Some(line_handle) = turtle.last_line() {
+        if display_list.is_hidden(line_handle) == hidden && display_list.can_extend_line(line_handle, start, end, pen) {
+            return Some(line_handle);
+        }
+    }
+
+    let prim = display_list.push_line(start, start, pen);
+    if let (Some(handle), true) = (prim, hidden) {
+        display_list.hide(handle);
+    }
+    turtle.drawings.extend(prim);
+    turtle.last_line = prim;
+    prim
+}
+
+/// Updates the thickness of the pen once the turtle has travelled the given distance
+///
+/// The `thickness_change` is the thickness of the pen before the turtle started moving, the
+/// thickness it is changing to, and the distance over which it changes. If it is `None`, the pen
+/// is left as is.
+fn advance_thickness_change(pen: &mut Pen, thickness_change: Option<(f64, f64, f64)>, travelled: f64) {
+    if let Some((start_thickness, end_thickness, distance)) = thickness_change {
+        if travelled >= distance {
+            pen.thickness = end_thickness;
+            pen.thickness_change = None;
+        } else {
+            pen.thickness = lerp(&start_thickness, &end_thickness, &(travelled / distance));
+            pen.thickness_change = Some((end_thickness, distance - travelled));
         }
     }
 }
@@ -101,29 +217,52 @@ pub struct MoveAnimation {
     total_duration: time::Duration,
     /// A handle to the line that is manipulated by this animation (if any)
     prim: Option<PrimHandle>,
+    /// The colors at the start and end of the line if the pen is drawing a gradient
+    gradient: Option<(Color, Color)>,
+    /// The thickness of the pen at the start of the animation, the thickness it is changing to,
+    /// and the distance over which it changes, if the thickness of the pen is changing
+    thickness_change: Option<(f64, f64, f64)>,
     /// The index of this point in the fill polygon (if any)
     fill_poly_index: Option<usize>,
 }
 
 impl MoveAnimation {
+    /// Creates an animation that moves the turtle to the target position
+    ///
+    /// If `duration` is `None`, the duration of the animation is determined by the turtle's speed.
+    /// The animation is always instant while the turtle's tracer is disabled.
     pub fn new(
         turtle: &mut TurtleDrawings,
         display_list: &mut DisplayList,
         target_pos: Point,
+        duration: Option<time::Duration>,
     ) -> Self {
-        let TurtleState {position, speed, ref pen, ..} = turtle.state;
+        let TurtleState {position, tracer, ..} = turtle.state;
+        let px_per_sec = turtle.state.px_per_sec();
+        let pen = &turtle.state.drawing_pen();
+        let gradient = pen.end_color.map(|end_color| (pen.color, end_color));
+        let thickness_change = pen.thickness_change
+            .map(|(end_thickness, distance)| (pen.thickness, end_thickness, distance));
 
         let start = time::Instant::now();
 
-        if cfg!(any(feature = "test", test)) || speed.is_instant() {
+        let is_instant = !tracer || match duration {
+            Some(duration) => duration.as_micros() == 0,
+            None => px_per_sec.is_infinite(),
+        };
+
+        if cfg!(any(feature = "test", test)) || is_instant {
             // Set to the final position and draw a line with no animation
             turtle.state.position = target_pos;
-            let prim = display_list.push_line(position, target_pos, pen);
-            turtle.drawings.extend(prim);
+            let prim = draw_line(turtle, display_list, position, target_pos, pen);
+            if let Some(prim) = prim {
+                display_list.line_update_end(prim, target_pos);
+            }
+            advance_thickness_change(&mut turtle.state.pen, thickness_change, (target_pos - position).len());
 
             // Append to the current fill polygon, if any
             let fill_poly_index = turtle.current_fill_polygon.map(|poly_handle| {
-                display_list.polygon_push(poly_handle, position)
+                display_list.polygon_push(poly_handle, target_pos)
             });
 
             Self {
@@ -136,15 +275,18 @@ impl MoveAnimation {
                 current_pos: position,
                 total_duration: time::Duration::from_micros(0),
                 prim,
+                gradient,
+                thickness_change,
                 fill_poly_index,
             }
 
         } else {
-            let px_per_sec = speed.to_px_per_sec();
-            let abs_distance = (target_pos - position).len();
-            // Use microseconds instead of ms for greater precision
-            let total_micros = abs_distance * MICROS_PER_SEC as f64 / px_per_sec;
-            let total_duration = time::Duration::from_micros(total_micros as u64);
+            let total_duration = duration.unwrap_or_else(|| {
+                let abs_distance = (target_pos - position).len();
+                // Use microseconds instead of ms for greater precision
+                let total_micros = abs_distance * MICROS_PER_SEC as f64 / px_per_sec;
+                time::Duration::from_micros(total_micros as u64)
+            });
 
             // If the duration of the animation is less than a frame, don't wait the entire frame
             // to complete it
@@ -153,9 +295,9 @@ impl MoveAnimation {
 
             // No need to update position since the turtle hasn't move anywhere yet
 
-            // Start with a zero-length line since the animation hasn't started yet
-            let prim = display_list.push_line(position, position, pen);
-            turtle.drawings.extend(prim);
+            // Start with a zero-length line (or leave the extended line as it is) since the
+            // animation hasn't started yet
+            let prim = draw_line(turtle, display_list, position, target_pos, pen);
 
             // Append to the current fill polygon, if any
             let fill_poly_index = turtle.current_fill_polygon.map(|poly_handle| {
@@ -171,6 +313,8 @@ impl MoveAnimation {
                 current_pos: position,
                 total_duration,
                 prim,
+                gradient,
+                thickness_change,
                 fill_poly_index,
             }
         }
@@ -195,6 +339,8 @@ impl MoveAnimation {
             ref mut current_pos,
             total_duration,
             prim: _,
+            gradient: _,
+            thickness_change: _,
             fill_poly_index: _,
         } = self;
 
@@ -228,10 +374,22 @@ impl MoveAnimation {
 
         // Update state with the current position
         state.position = pos;
+        advance_thickness_change(&mut state.pen, self.thickness_change, (pos - self.start_pos).len());
 
         // Update the end of the line we have been drawing, if any
         if let Some(prim) = self.prim {
             display_list.line_update_end(prim, pos);
+
+            // The end of a gradient line has the color of the gradient at the current position
+            if let Some((start_color, end_color)) = self.gradient {
+                let total_distance = (self.target_pos - self.start_pos).len();
+                let t = if total_distance > 0.0 {
+                    (pos - self.start_pos).len() / total_distance
+                } else {
+                    1.0
+                };
+                display_list.line_update_end_color(prim, lerp_color(start_color, end_color, t));
+            }
         }
 
         // Replace the point in the current fill polygon, if any
@@ -272,11 +430,12 @@ impl RotateAnimation {
         delta_angle: Radians,
         direction: RotationDirection,
     ) -> Self {
-        let TurtleState {heading, speed, ..} = turtle.state;
+        let TurtleState {heading, tracer, ..} = turtle.state;
+        let rad_per_sec = turtle.state.rad_per_sec();
 
         let start = time::Instant::now();
 
-        if cfg!(any(feature = "test", test)) || speed.is_instant() {
+        if cfg!(any(feature = "test", test)) || !tracer || !rad_per_sec.is_finite() {
             // Set to the final heading with no animation
             turtle.state.heading = rotate(heading, delta_angle, direction);
 
@@ -293,7 +452,6 @@ impl RotateAnimation {
             }
 
         } else {
-            let rad_per_sec = speed.to_rad_per_sec();
             // Use microseconds instead of ms for greater precision
             let total_micros = (delta_angle * MICROS_PER_SEC as f64 / rad_per_sec).to_radians();
             // abs() because time is always positive, even if angle is negative
@@ -367,28 +525,286 @@ impl RotateAnimation {
     }
 }
 
+/// A value that changes linearly from a start value to a target value over a fixed duration
+///
+/// This drives every animation that only needs to gradually change a single value, e.g. a color.
+#[derive(Debug)]
+struct Interpolation<T> {
+    /// true if the animation should continue, false if it should stop
+    running: bool,
+    /// The next instant at which the animation loop should step this animation, up to
+    /// `FRAME_DURATION` from now
+    ///
+    /// Updated with every call to `step`
+    next_update: time::Instant,
+    /// The instant that the animation started, used to precisely determine how long the animation
+    /// has been running
+    start: time::Instant,
+    /// The value at the start of the animation
+    start_value: T,
+    /// The value at the end of the animation
+    target_value: T,
+    /// The current value (updated by step)
+    current_value: T,
+    /// The total duration of the animation
+    total_duration: time::Duration,
+}
+
+impl<T: Interpolate> Interpolation<T> {
+    /// Starts changing the value from `start_value` to `target_value` over `secs` seconds
+    ///
+    /// The change is instant (and the animation is not running) if `secs` is zero or negative.
+    /// The caller must write the current value right away since it may already be the target.
+    fn new(start_value: T, target_value: T, secs: f64) -> Self {
+        let start = time::Instant::now();
+
+        if cfg!(any(feature = "test", test)) || secs <= 0.0 {
+            Self {
+                // stop the animation right away since it has already completed
+                running: false,
+                next_update: start,
+                start,
+                start_value,
+                target_value,
+                current_value: target_value,
+                total_duration: time::Duration::from_micros(0),
+            }
+
+        } else {
+            // Use microseconds instead of ms for greater precision
+            let total_micros = secs * MICROS_PER_SEC as f64;
+            let total_duration = time::Duration::from_micros(total_micros as u64);
+
+            // If the duration of the animation is less than a frame, don't wait the entire frame
+            // to complete it
+            let next_delay = min(total_duration, FRAME_DURATION);
+            let next_update = start + next_delay;
+
+            Self {
+                running: true,
+                next_update,
+                start,
+                start_value,
+                target_value,
+                current_value: start_value,
+                total_duration,
+            }
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.running
+    }
+
+    fn next_update(&self) -> time::Instant {
+        self.next_update
+    }
+
+    fn current_value(&self) -> T {
+        self.current_value
+    }
+
+    /// Advances the animation based on the amount of time that has elapsed since it started
+    fn step(&mut self, now: time::Instant) {
+        let &mut Self {
+            ref mut running,
+            ref mut next_update,
+            ref start,
+            start_value,
+            target_value,
+            ref mut current_value,
+            total_duration,
+        } = self;
+
+        let elapsed = start.elapsed();
+        *current_value = if elapsed >= total_duration {
+            *running = false;
+            *next_update = now;
+
+            target_value
+
+        } else {
+            // t is the total progress made in the animation so far
+            let t = elapsed.as_micros() as f64 / total_duration.as_micros() as f64;
+
+            // If the time remaining is less than a frame, don't wait the entire frame
+            let remaining = total_duration - elapsed;
+            *next_update = now + min(remaining, FRAME_DURATION);
+
+            T::interpolate(start_value, target_value, t)
+        };
+    }
+}
+
+/// A value that can be changed gradually by an `Interpolation`
+trait Interpolate: Copy {
+    /// Returns the value that is `t` of the way from `start` to `end`, where `t` is between 0.0
+    /// and 1.0
+    fn interpolate(start: Self, end: Self, t: f64) -> Self;
+}
+
+impl Interpolate for Point {
+    fn interpolate(start: Self, end: Self, t: f64) -> Self {
+        lerp(&start, &end, &t)
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(start: Self, end: Self, t: f64) -> Self {
+        lerp_color(start, end, t)
+    }
+}
+
+/// Linearly interpolates each component of the two colors
+fn lerp_color(start: Color, end: Color, t: f64) -> Color {
+    Color {
+        red: lerp(&start.red, &end.red, &t),
+        green: lerp(&start.green, &end.green, &t),
+        blue: lerp(&start.blue, &end.blue, &t),
+        alpha: lerp(&start.alpha, &end.alpha, &t),
+    }
+}
+
+#[derive(Debug)]
+pub struct BackgroundAnimation {
+    /// The background color
+    color: Interpolation<Color>,
+}
+
+impl BackgroundAnimation {
+    pub fn new(drawing: &mut DrawingState, target_color: Color, secs: f64) -> Self {
+        let anim = Self {color: Interpolation::new(drawing.background, target_color, secs)};
+        anim.write_current_state(drawing);
+        anim
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.color.is_running()
+    }
+
+    pub fn next_update(&self) -> time::Instant {
+        self.color.next_update()
+    }
+
+    /// Advances the animation based on the amount of time that has elapsed since it started
+    pub fn step(&mut self, now: time::Instant) {
+        self.color.step(now);
+    }
+
+    pub fn write_current_state(&self, drawing: &mut DrawingState) {
+        drawing.background = self.color.current_value();
+    }
+}
+
+#[derive(Debug)]
+pub struct CenterAnimation {
+    /// The center of the drawing
+    center: Interpolation<Point>,
+}
+
+impl CenterAnimation {
+    pub fn new(drawing: &mut DrawingState, target_center: Point, secs: f64) -> Self {
+        let anim = Self {center: Interpolation::new(drawing.center, target_center, secs)};
+        anim.write_current_state(drawing);
+        anim
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.center.is_running()
+    }
+
+    pub fn next_update(&self) -> time::Instant {
+        self.center.next_update()
+    }
+
+    /// Advances the animation based on the amount of time that has elapsed since it started
+    pub fn step(&mut self, now: time::Instant) {
+        self.center.step(now);
+    }
+
+    pub fn write_current_state(&self, drawing: &mut DrawingState) {
+        drawing.center = self.center.current_value();
+    }
+}
+
+#[derive(Debug)]
+pub struct FillColorAnimation {
+    /// The fill whose color is changed by this animation
+    fill_id: FillId,
+    /// The fill color
+    color: Interpolation<Color>,
+}
+
+impl FillColorAnimation {
+    /// Starts changing the color of the given fill of the turtle, which jumps to the start color
+    /// right away (even if it was a different color)
+    pub fn new(
+        turtle: &mut TurtleDrawings,
+        display_list: &mut DisplayList,
+        fill_id: FillId,
+        start_color: Color,
+        target_color: Color,
+        secs: f64,
+    ) -> Self {
+        let anim = Self {fill_id, color: Interpolation::new(start_color, target_color, secs)};
+
+        turtle.animated_fill = Some(fill_id);
+        anim.write_current_state(turtle, display_list);
+        if !anim.is_running() {
+            turtle.animated_fill = None;
+        }
+
+        anim
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.color.is_running()
+    }
+
+    pub fn next_update(&self) -> time::Instant {
+        self.color.next_update()
+    }
+
+    /// Advances the animation based on the amount of time that has elapsed since it started
+    pub fn step(&mut self, now: time::Instant) {
+        self.color.step(now);
+    }
+
+    /// Updates the color of the fill, unless the fill has been removed or its color has been set
+    /// some other way since the animation started
+    pub fn write_current_state(&self, turtle: &TurtleDrawings, display_list: &mut DisplayList) {
+        if turtle.animated_fill != Some(self.fill_id) {
+            return;
+        }
+
+        if let Some(poly_handle) = turtle.fill_polygon(self.fill_id) {
+            display_list.polygon_set_fill_color(poly_handle, self.color.current_value());
+        }
+    }
+}
+
 /// Rotates the given `angle` by the given `rotation` in the given `direction`
 ///
 /// Let's say you have a starting angle X. Standard angles go counterclockwise, so
 /// if clockwise is true, we need to subtract the `rotation` from X resulting in
 /// `X - rotation`. If clockwise is false, we can just add normally.
-fn rotate(angle: Radians, rotation: Radians, direction: RotationDirection) -> Radians {
+pub fn rotate(angle: Radians, rotation: Radians, direction: RotationDirection) -> Radians {
     use RotationDirection::*;
     let angle = match direction {
         Clockwise => angle - rotation,
         Counterclockwise => angle + rotation,
     };
 
-    // Normalize the angle to be between 0 and 2*pi
-    // Formula adapted from: https://stackoverflow.com/a/24234924/551904
-    // More info: https://stackoverflow.com/a/28316446/551904
-    angle - radians::TWO_PI * (angle / radians::TWO_PI).floor()
+    angle.normalized()
 }
 
 #[derive(Debug)]
 enum Message {
     /// Run the given animation
-    Play(Animation),
+    Play(Box<Animation>),
+    /// Notify the given client once the animation currently playing for the given turtle has
+    /// completed, or right away if no animation is playing for that turtle
+    WaitFor(TurtleId, ClientId),
     /// Stop all animations that are currently playing
     ///
     /// Animations stop at wherever they were last updated.
@@ -422,7 +838,20 @@ impl AnimationRunner {
     }
 
     pub fn play(&self, turtle_id: TurtleId, kind: impl Into<AnimationKind>, client_id: ClientId) {
-        self.send(Message::Play(Animation::new(turtle_id, kind, client_id)));
+        let target = AnimationTarget::Turtle(turtle_id);
+        self.send(Message::Play(Box::new(Animation::new(target, kind, client_id))));
+    }
+
+    pub fn play_background(&self, anim: BackgroundAnimation, client_id: ClientId) {
+        self.send(Message::Play(Box::new(Animation::new(AnimationTarget::Background, anim, client_id))));
+    }
+
+    pub fn play_center(&self, anim: CenterAnimation, client_id: ClientId) {
+        self.send(Message::Play(Box::new(Animation::new(AnimationTarget::Center, anim, client_id))));
+    }
+
+    pub fn wait_for(&self, turtle_id: TurtleId, client_id: ClientId) {
+        self.send(Message::WaitFor(turtle_id, client_id));
     }
 
     pub fn stop_all(&self) {
@@ -443,8 +872,8 @@ async fn animation_loop(
     event_loop: EventLoopNotifier,
     mut receiver: mpsc::UnboundedReceiver<Message>,
 ) {
-    // Map of turtle ID to the current animation playing for it (if any)
-    let mut animations: HashMap<TurtleId, Animation> = HashMap::new();
+    // Map of each animation target to the current animation playing for it (if any)
+    let mut animations: HashMap<AnimationTarget, Animation> = HashMap::new();
 
     let mut next_frame = time::Instant::now() + FRAME_DURATION;
     // It's important to update as soon as an animation is ready to be updated because otherwise we
@@ -461,18 +890,29 @@ async fn animation_loop(
                     // Insert the new animation so we can account for it when selecting the next
                     // update time. Keeping the previous next frame value since we don't want to
                     // bump to another future frame just because we got another animation.
-                    debug_assert!(!animations.contains_key(&anim.turtle_id),
-                        "bug: cannot animate turtle while another animation is playing");
-                    animations.insert(anim.turtle_id, anim);
+                    debug_assert!(!animations.contains_key(&anim.target),
+                        "bug: cannot animate turtle, background, or center while another animation is playing");
+                    animations.insert(anim.target, *anim);
+                },
+
+                Some(Message::WaitFor(id, client_id)) => {
+                    let target = AnimationTarget::Turtle(id);
+                    match animations.get_mut(&target) {
+                        // The client that started the animation is already going to be notified
+                        Some(anim) if anim.client_id == client_id => {},
+                        Some(anim) => anim.waiters.push(client_id),
+                        None => handle_handler_result(conn.send(
+                            client_id,
+                            turtle_animation_complete(&app.read(), id),
+                        ).map_err(HandlerError::IpcChannelError)),
+                    }
                 },
 
                 Some(Message::StopAll) => {
                     // Complete all pending animations at their last update
+                    let app = app.read();
                     for anim in animations.values() {
-                        handle_handler_result(conn.send(
-                            anim.client_id,
-                            ServerResponse::AnimationComplete(anim.turtle_id),
-                        ).map_err(HandlerError::IpcChannelError));
+                        handle_handler_result(anim.send_complete(&conn, &app));
                     }
 
                     animations.clear();
@@ -515,7 +955,7 @@ async fn animation_loop(
 /// Compute the time of the next update, returning a value up to the time of the next frame
 fn compute_next_update(
     next_frame: time::Instant,
-    animations: &HashMap<TurtleId, Animation>,
+    animations: &HashMap<AnimationTarget, Animation>,
 ) -> time::Instant {
     let next_update = animations.values()
         .map(|anim| anim.next_update())
@@ -533,7 +973,7 @@ fn update_animations(
     app: &RwLock<App>,
     display_list: &Mutex<DisplayList>,
     event_loop: &EventLoopNotifier,
-    animations: &mut HashMap<TurtleId, Animation>,
+    animations: &mut HashMap<AnimationTarget, Animation>,
 ) -> Result<(), HandlerError> {
     // true if even one animation was updated
     let mut animation_updated = false;
@@ -549,13 +989,14 @@ fn update_animations(
         match &mut anim.kind {
             Move(anim) => anim.step(now),
             Rotate(anim) => anim.step(now),
+            Background(anim) => anim.step(now),
+            Center(anim) => anim.step(now),
+            FillColor(anim) => anim.step(now),
         }
 
         // Check if the animation has completed
         if !anim.is_running() {
-            conn.send(anim.client_id, ServerResponse::AnimationComplete(anim.turtle_id))?;
-
-            completed_animations.push(anim.turtle_id);
+            completed_animations.push(anim.target);
         }
 
         animation_updated = true;
@@ -566,18 +1007,42 @@ fn update_animations(
         // as possible
         let mut app = app.write();
         let mut display_list = display_list.lock();
-        for anim in animations.values_mut() {
-            let TurtleDrawings {state, current_fill_polygon, ..} = app.turtle_mut(anim.turtle_id);
-
+        for anim in animations.values() {
             use AnimationKind::*;
-            match &anim.kind {
-                Move(anim) => {
+            match (&anim.kind, anim.target) {
+                (Move(anim), AnimationTarget::Turtle(id)) => {
+                    let turtle = app.turtle_mut(id);
+                    let TurtleDrawings {state, current_fill_polygon, ..} = turtle;
+
                     anim.write_current_state(state, *current_fill_polygon, &mut display_list);
+
+                    if !anim.is_running() {
+                        turtle.record_position();
+                    }
+                },
+
+                (Rotate(anim), AnimationTarget::Turtle(id)) => {
+                    anim.write_current_state(&mut app.turtle_mut(id).state);
+                },
+
+                (Background(anim), AnimationTarget::Background) => {
+                    anim.write_current_state(app.drawing_mut());
                 },
 
-                Rotate(anim) => {
-                    anim.write_current_state(state);
+                (Center(anim), AnimationTarget::Center) => {
+                    anim.write_current_state(app.drawing_mut());
                 },
+
+                (FillColor(anim), AnimationTarget::Turtle(id)) => {
+                    let turtle = app.turtle_mut(id);
+                    anim.write_current_state(turtle, &mut display_list);
+
+                    if !anim.is_running() && turtle.animated_fill == Some(anim.fill_id) {
+                        turtle.animated_fill = None;
+                    }
+                },
+
+                _ => unreachable!("bug: animation was played for the wrong kind of target"),
             }
         }
 
@@ -586,9 +1051,15 @@ fn update_animations(
     }
 
     // Wait to remove the completed animations so we have a chance to update the turtles with the
-    // final state of each animation
-    for id in completed_animations {
-        animations.remove(&id);
+    // final state of each animation. The clients are only notified after that so that the next
+    // request from a client always sees its turtle in its final position. Animations for other
+    // turtles keep running regardless of how long any client takes to respond.
+    if !completed_animations.is_empty() {
+        let app = app.read();
+        for target in completed_animations {
+            let anim = animations.remove(&target).expect("bug: completed animation should still exist");
+            anim.send_complete(conn, &app)?;
+        }
     }
 
     Ok(())
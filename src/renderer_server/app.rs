@@ -1,10 +1,21 @@
 use std::sync::Arc;
+use std::collections::{VecDeque, BTreeMap, HashSet};
 
 use serde::{Serialize, Deserialize};
 use parking_lot::RwLock;
 
-use super::state::{TurtleState, DrawingState};
-use super::renderer::display_list::PrimHandle;
+use crate::{Point, StampId, FillId};
+use crate::path_recorder::PathId;
+
+use super::state::{TurtleState, DrawingState, InputState};
+use super::renderer::display_list::{DisplayList, PrimHandle};
+use super::renderer::gif_recording::GifRecording;
+
+/// The maximum number of commands that can be undone for each turtle
+///
+/// Bounds the memory used by the undo history. Once this many records are stored, the oldest
+/// record is discarded whenever a new one is added.
+pub const MAX_UNDO_HISTORY: usize = 1000;
 
 /// The unique ID of a particular turtle
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -24,6 +35,235 @@ pub struct TurtleDrawings {
     /// This handle will already be present in `drawings`, so it does not need to be added after
     /// the fill has begun.
     pub current_fill_polygon: Option<PrimHandle>,
+
+    /// The handle to the line most recently drawn by this turtle as it moved, if any
+    ///
+    /// The next movement may extend this line instead of drawing a new one. The line may have
+    /// been removed since or followed by other drawings, so this handle is only valid if it is
+    /// still the last item in `drawings`.
+    pub last_line: Option<PrimHandle>,
+
+    /// The records used to undo the most recent commands run by this turtle, with the most recent
+    /// record at the back
+    ///
+    /// Never contains more than `MAX_UNDO_HISTORY` records.
+    pub undo_history: VecDeque<UndoRecord>,
+
+    /// Whether the commands run by this turtle are currently being grouped so that they are all
+    /// undone at once
+    pub undo_group: UndoGroup,
+
+    /// The most recent positions that this turtle has moved to, with the most recent position at
+    /// the back
+    ///
+    /// Never contains more than `position_history_capacity` positions.
+    pub position_history: VecDeque<Point>,
+
+    /// The maximum number of positions to keep in `position_history`
+    ///
+    /// A capacity of zero (the default) means that no positions are recorded.
+    pub position_history_capacity: usize,
+
+    /// Every position this turtle has moved to since it started recording a polygon, or `None` if
+    /// no polygon is being recorded
+    pub poly: Option<Vec<Point>>,
+
+    /// Every position this turtle has moved to since each of its path recordings started
+    pub paths: BTreeMap<PathId, Vec<Point>>,
+
+    /// The ID to use for the next path recorded for this turtle, must be monotonic so that IDs
+    /// are never reused
+    pub next_path_id: usize,
+
+    /// The drawings that make up each stamp created by this turtle that has not been cleared yet
+    ///
+    /// These handles are also present in `drawings`.
+    pub stamps: BTreeMap<StampId, Vec<PrimHandle>>,
+
+    /// The ID to use for the next stamp created by this turtle, must be monotonic so that IDs are
+    /// never reused
+    pub next_stamp_id: usize,
+
+    /// The fill polygon of each fill completed by this turtle that is still drawn
+    ///
+    /// These handles are also present in `drawings`. Each entry must be removed as soon as its
+    /// polygon is removed from the display list (e.g. by undoing or clearing).
+    pub fills: BTreeMap<FillId, PrimHandle>,
+
+    /// The ID to use for the next fill completed by this turtle, must be monotonic so that IDs
+    /// are never reused
+    pub next_fill_id: usize,
+
+    /// The fill whose color is currently being animated, if any
+    ///
+    /// Setting the color of that fill in any other way resets this to `None` so that the
+    /// animation stops changing it.
+    pub animated_fill: Option<FillId>,
+}
+
+impl TurtleDrawings {
+    /// Records the current state of this turtle so that the next command can be undone
+    ///
+    /// This must be called *before* the command modifies the turtle or its drawings.
+    pub fn push_undo_record(&mut self, display_list: &DisplayList) {
+        // Only the first command of a group is recorded, so undoing it undoes the entire group.
+        // The later commands can only extend the lines drawn by the group, so restoring the line
+        // that the first command may extend is enough.
+        match self.undo_group {
+            UndoGroup::Recorded => return,
+            UndoGroup::Started => self.undo_group = UndoGroup::Recorded,
+            UndoGroup::None => {},
+        }
+
+        let fill = self.current_fill_polygon
+            .map(|poly_handle| (poly_handle, display_list.polygon_len(poly_handle)));
+
+        let line_end = self.last_line()
+            .map(|line_handle| (line_handle, display_list.line_end(line_handle)));
+
+        let record = UndoRecord {
+            state: self.state.clone(),
+            drawings_len: self.drawings.len(),
+            fill,
+            line_end,
+            next_fill_id: self.next_fill_id,
+            next_stamp_id: self.next_stamp_id,
+        };
+
+        if self.undo_history.len() >= MAX_UNDO_HISTORY {
+            self.undo_history.pop_front();
+        }
+        self.undo_history.push_back(record);
+    }
+
+    /// Records a new stamp made up of the given drawings, returning its ID
+    pub fn add_stamp(&mut self, prims: Vec<PrimHandle>) -> StampId {
+        let id = StampId(self.next_stamp_id);
+        self.next_stamp_id += 1;
+        self.stamps.insert(id, prims);
+        id
+    }
+
+    /// Records a completed fill, returning its ID
+    ///
+    /// A fill that drew nothing still gets an ID so that every completed fill has one.
+    pub fn add_fill(&mut self, poly_handle: Option<PrimHandle>) -> FillId {
+        let id = FillId(self.next_fill_id);
+        self.next_fill_id += 1;
+        if let Some(poly_handle) = poly_handle {
+            self.fills.insert(id, poly_handle);
+        }
+        id
+    }
+
+    /// Returns the handle to the fill polygon of the given fill, if it is still drawn
+    pub fn fill_polygon(&self, fill_id: FillId) -> Option<PrimHandle> {
+        self.fills.get(&fill_id).copied()
+    }
+
+    /// Forgets the given drawings, along with every fill whose polygon is one of them
+    ///
+    /// This must be called whenever drawings are removed from the display list. The undo records
+    /// are updated so that undoing still only removes the drawings created by each command.
+    pub fn remove_drawings(&mut self, removed: &[PrimHandle]) {
+        let removed: HashSet<_> = removed.iter().copied().collect();
+
+        // The number of removed drawings before each index of `drawings`, including the index
+        // just past the end
+        let mut removed_before = Vec::with_capacity(self.drawings.len() + 1);
+        removed_before.push(0);
+        self.drawings.retain(|handle| {
+            let is_removed = removed.contains(handle);
+            let count = removed_before.last().copied().unwrap_or(0);
+            removed_before.push(count + is_removed as usize);
+            !is_removed
+        });
+
+        for record in &mut self.undo_history {
+            record.drawings_len -= removed_before[record.drawings_len];
+        }
+
+        self.fills.retain(|_, poly_handle| !removed.contains(poly_handle));
+    }
+
+    /// Returns the ID of the most recently completed fill of this turtle that is still drawn
+    pub fn last_fill(&self) -> Option<FillId> {
+        self.fills.keys().next_back().copied()
+    }
+
+    /// Returns the handle to the line most recently drawn by this turtle as it moved, if nothing
+    /// else has been drawn by this turtle since
+    pub fn last_line(&self) -> Option<PrimHandle> {
+        self.last_line.filter(|&handle| self.drawings.last() == Some(&handle))
+    }
+
+    /// Starts recording a new path from the current position of this turtle, returning its ID
+    pub fn start_path(&mut self) -> PathId {
+        let id = PathId(self.next_path_id);
+        self.next_path_id += 1;
+        self.paths.insert(id, vec![self.state.position]);
+        id
+    }
+
+    /// Records the current position of this turtle in its position history, if tracking the
+    /// position history is enabled, and in the polygon and paths being recorded, if any
+    ///
+    /// This should be called every time the turtle finishes moving.
+    pub fn record_position(&mut self) {
+        let position = self.state.position;
+
+        // Moving to the same position (e.g. moving a distance of zero) doesn't add a vertex
+        for points in self.poly.iter_mut().chain(self.paths.values_mut()) {
+            if points.last() != Some(&position) {
+                points.push(position);
+            }
+        }
+
+        if self.position_history_capacity == 0 {
+            return;
+        }
+
+        if self.position_history.len() >= self.position_history_capacity {
+            self.position_history.pop_front();
+        }
+        self.position_history.push_back(position);
+    }
+}
+
+/// Whether the commands of a turtle are being grouped so that they are all undone at once
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UndoGroup {
+    /// Each command is undone on its own
+    #[default]
+    None,
+    /// A group has started, but none of its commands have been recorded yet
+    Started,
+    /// A group has started and the record that undoes all of its commands has been pushed
+    Recorded,
+}
+
+/// The information needed to restore a turtle to the way it was before a command was run
+#[derive(Debug)]
+pub struct UndoRecord {
+    /// The state of the turtle before the command
+    pub state: TurtleState,
+    /// The number of drawings created by the turtle before the command
+    ///
+    /// Any drawings after this index were created by the command.
+    pub drawings_len: usize,
+    /// The fill polygon that the turtle was filling before the command (if any), along with the
+    /// number of points it had at that time
+    pub fill: Option<(PrimHandle, usize)>,
+    /// The line that the command may extend (if any), along with its end point at that time
+    pub line_end: Option<(PrimHandle, Point)>,
+    /// The ID that the next completed fill would have had before the command
+    ///
+    /// Any fills with this ID or higher were completed by the command.
+    pub next_fill_id: usize,
+    /// The ID that the next stamp would have had before the command
+    ///
+    /// Any stamps with this ID or higher were created by the command.
+    pub next_stamp_id: usize,
 }
 
 /// The entire state of the application, shared between threads in the server
@@ -36,9 +276,18 @@ pub struct App {
     /// Need to be very careful deleting from this field because the `TurtleId` returned from
     /// `add_turtle()` must remain unique and thus can never be repeated.
     turtles: Vec<TurtleDrawings>,
+    /// The current state of the mouse and keyboard
+    input: InputState,
+    /// The frames captured so far if a GIF is being recorded
+    gif_recording: Option<GifRecording>,
 }
 
 impl App {
+    /// Creates the application state with no turtles and the given drawing state
+    pub fn with_drawing(drawing: DrawingState) -> Self {
+        Self {drawing, ..Self::default()}
+    }
+
     /// Adds a new turtle to the application state, returning its `TurtleId`
     pub fn add_turtle(&mut self) -> TurtleId {
         let id = TurtleId(self.turtles.len());
@@ -56,6 +305,38 @@ impl App {
         &mut self.drawing
     }
 
+    /// Returns a read-only handle to the mouse and keyboard state
+    pub fn input(&self) -> &InputState {
+        &self.input
+    }
+
+    /// Returns a mutable handle to the mouse and keyboard state
+    pub fn input_mut(&mut self) -> &mut InputState {
+        &mut self.input
+    }
+
+    /// Returns the GIF recording that is in progress, if any
+    #[cfg_attr(any(feature = "test", feature = "embedded"), allow(dead_code))] // Used in main thread, but not for tests or embedded drawings
+    pub fn gif_recording_mut(&mut self) -> Option<&mut GifRecording> {
+        self.gif_recording.as_mut()
+    }
+
+    /// Returns true if a GIF is being recorded
+    #[cfg_attr(any(feature = "test", feature = "embedded"), allow(dead_code))] // Used in main thread, but not for tests or embedded drawings
+    pub fn is_recording_gif(&self) -> bool {
+        self.gif_recording.is_some()
+    }
+
+    /// Starts the given GIF recording, discarding any recording that was already in progress
+    pub fn start_gif_recording(&mut self, recording: GifRecording) {
+        self.gif_recording = Some(recording);
+    }
+
+    /// Stops the GIF recording that is in progress (if any) and returns it
+    pub fn take_gif_recording(&mut self) -> Option<GifRecording> {
+        self.gif_recording.take()
+    }
+
     /// Returns a read-only handle to the given turtle
     pub fn turtle(&self, id: TurtleId) -> &TurtleDrawings {
         let TurtleId(index) = id;
@@ -69,7 +350,7 @@ impl App {
     }
 
     /// Returns an iterator over all of the turtles
-    #[cfg_attr(feature = "test", allow(dead_code))] // Used in renderer, but not for tests
+    #[cfg_attr(any(feature = "test", feature = "embedded"), allow(dead_code))] // Used in renderer, but not for tests or embedded drawings
     pub fn turtles(&self) -> impl Iterator<Item=(TurtleId, &TurtleDrawings)> {
         (0..).zip(self.turtles.iter()).map(|(id, turtle)| (TurtleId(id), turtle))
     }
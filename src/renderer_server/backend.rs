@@ -1,5 +1,11 @@
 cfg_if::cfg_if! {
-    if #[cfg(any(feature = "test", test))] {
+    // The "test" and "embedded" features are never enabled together (see lib.rs), but the
+    // embedded backend is used when its own tests are run
+    if #[cfg(feature = "embedded")] {
+        mod embedded;
+        pub use embedded::*;
+
+    } else if #[cfg(any(feature = "test", test))] {
         mod test;
         pub use test::*;
 
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+#[cfg(feature = "unstable")]
+use std::time::Instant;
+
+use tokio::sync::{mpsc, oneshot};
+use futures_util::future::{FutureExt, RemoteHandle};
+use parking_lot::RwLock;
+
+use crate::ipc_protocol::{
+    ClientSender,
+    ClientReceiver,
+    ConnectionError,
+    connect_server,
+    connect_client,
+};
+use crate::{Size, Event};
+
+use super::super::{
+    serve,
+    state::WindowConfig,
+    app::{SharedApp, App},
+    renderer::{display_list::SharedDisplayList, export::{self, ExportError}},
+    event_loop_notifier::EventLoopNotifier,
+};
+
+/// Spawns the task responsible for handling and responding to client requests in the current
+/// process, without opening a window
+///
+/// The state of the drawing is shared with this struct so that the application embedding the
+/// drawing can render it and send events to it whenever it wants to.
+#[derive(Debug)]
+pub struct RendererServer {
+    /// The task is dropped along with this handle, so the drawing only lives as long as the
+    /// `Drawing` or `Turtle` that created it
+    _task_handle: RemoteHandle<()>,
+    app: SharedApp,
+    display_list: SharedDisplayList,
+    /// There is no window, so the application embedding the drawing is the only source of events
+    #[cfg(feature = "unstable")]
+    events_sender: mpsc::UnboundedSender<Event>,
+}
+
+impl RendererServer {
+    /// Runs any initialization logic required at the beginning of the program
+    pub fn start() {}
+
+    /// Spawns the backend in a new task and returns the struct that will be used to
+    /// interface with it.
+    pub async fn spawn(config: WindowConfig) -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
+        // The state of the drawing and the state/drawings associated with each turtle
+        let app: SharedApp = Arc::new(RwLock::new(App::with_drawing(config.initial_drawing())));
+        // All of the drawing primitives in the order in which they wil be drawn
+        let display_list = SharedDisplayList::default();
+        let (events_sender, events_receiver) = mpsc::unbounded_channel();
+        // Events can only be sent with `send_event()`. Without it, the channel is closed right away
+        // so that waiting for an event ends instead of waiting forever.
+        #[cfg(not(feature = "unstable"))]
+        drop(events_sender);
+
+        let (server_name_sender, server_name_receiver) = oneshot::channel();
+        let task_app = app.clone();
+        let task_display_list = display_list.clone();
+        // Spawn a separate task for the server so this task can continue to make progress
+        // while that runs. The remote handle will drop that future when it is dropped.
+        let (child, task_handle) = async move {
+            let server_name = server_name_receiver.await
+                .expect("bug: unable to receive server name");
+            run_embedded(task_app, task_display_list, events_receiver, server_name).await;
+        }.remote_handle();
+
+        tokio::spawn(child);
+
+        let (conn_sender, conn_receiver) = connect_client(move |name| async {
+            server_name_sender.send(name)
+                .expect("bug: unable to send server name to embedded renderer server");
+            Ok(())
+        }).await?;
+
+        let server = Self {
+            _task_handle: task_handle,
+            app,
+            display_list,
+            #[cfg(feature = "unstable")]
+            events_sender,
+        };
+
+        Ok((server, conn_sender, conn_receiver))
+    }
+
+    /// Renders the drawing and its turtles into the given buffer, replacing its contents, and
+    /// returns the size of the rendered image
+    pub fn render_to(&self, buffer: &mut Vec<u8>) -> Result<Size, ExportError> {
+        let image = {
+            let app = self.app.read();
+            let display_list = self.display_list.lock();
+            let turtles = app.turtles().map(|(_, turtle)| &turtle.state);
+            export::render_image(&display_list, app.drawing(), turtles, 1.0)?
+        };
+
+        buffer.clear();
+        buffer.extend_from_slice(&image.rgba);
+
+        Ok(Size {width: image.width, height: image.height})
+    }
+
+    /// Sends an event to the drawing as if it had come from a window
+    #[cfg(feature = "unstable")]
+    pub fn send_event(&self, event: Event) {
+        let event = self.app.write().input_mut().update(event, Instant::now());
+
+        // The server task lives as long as this struct, so it is always there to receive this
+        self.events_sender.send(event).unwrap_or(());
+    }
+}
+
+async fn run_embedded(
+    app: SharedApp,
+    display_list: SharedDisplayList,
+    events_receiver: mpsc::UnboundedReceiver<Event>,
+    server_name: String,
+) {
+    // The application embedding the drawing decides when to render it, so there is no window or
+    // main loop to notify
+    let event_loop_notifier = EventLoopNotifier::new();
+    // No frames are ever drawn by the server, so the sender is dropped right away
+    let (_, frames_receiver) = mpsc::channel(1);
+    // The server stops when the client disconnects or when this task is dropped
+    let (_server_shutdown, server_shutdown_receiver) = mpsc::channel(1);
+
+    let (conn_sender, conn_receiver) = connect_server(server_name)
+        .expect("unable to establish turtle server connection");
+
+    serve(
+        conn_sender,
+        conn_receiver,
+        app,
+        display_list,
+        event_loop_notifier,
+        events_receiver,
+        frames_receiver,
+        server_shutdown_receiver,
+    ).await;
+}
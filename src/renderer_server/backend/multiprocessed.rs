@@ -19,10 +19,17 @@ use crate::ipc_protocol::{
     connect_client,
 };
 
-use super::super::main::run_main;
+use super::super::{
+    state::WindowConfig,
+    main::{run_main, is_headless},
+};
 
 /// The environment variable that is set to indicate that the current process is a server process
 const RENDERER_PROCESS_ENV_VAR: &str = "RUN_TURTLE_CANVAS";
+/// The environment variable used to pass the window settings (as JSON) to the server process
+///
+/// This needs to be available before the window opens, so it can't be sent over IPC.
+const WINDOW_CONFIG_ENV_VAR: &str = "TURTLE_WINDOW_CONFIG";
 
 /// Spawns the task/process responsible for handling and responding to client requests
 #[derive(Debug)]
@@ -57,8 +64,13 @@ impl RendererServer {
             let runtime = Runtime::new()
                 .expect("unable to spawn tokio runtime to run turtle server process");
 
+            let config = env::var(WINDOW_CONFIG_ENV_VAR).ok()
+                .map(|config| serde_json::from_str(&config)
+                    .expect("bug: unable to parse window config passed to turtle server process"))
+                .unwrap_or_default();
+
             // Run the renderer process
-            run_main(runtime.handle().clone(), connect_server_stdin());
+            run_main(runtime.handle().clone(), config, connect_server_stdin());
             // Must exit after finishing or the program may execute twice
             process::exit(0);
         }
@@ -66,13 +78,16 @@ impl RendererServer {
 
     /// Spawns the backend in a new task and returns the struct that will be used to
     /// interface with it.
-    pub async fn spawn() -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
+    pub async fn spawn(config: WindowConfig) -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
         let current_exe = env::current_exe()?;
+        let config = serde_json::to_string(&config)
+            .expect("bug: unable to serialize window config");
 
         // The new process is the same executable as this process but with a special environment
         // variable passed in
         let mut child = Command::new(current_exe)
             .env(RENDERER_PROCESS_ENV_VAR, "true")
+            .env(WINDOW_CONFIG_ENV_VAR, config)
             // Pipe input so we can communicate with the spawned process
             //
             // stdout/stderr will be inherited from the current process
@@ -136,6 +151,12 @@ impl Drop for RendererServer {
             return;
         }
 
+        // Without a window, there is nothing for the user to look at after the main thread ends.
+        // The process is configured with `kill_on_drop`, so it will end as well.
+        if is_headless() {
+            return;
+        }
+
         // If this is just a normal ending of the main thread, we want to leave the renderer
         // running so that the user can see their drawing as long as they keep the window open
 
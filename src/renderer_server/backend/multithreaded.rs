@@ -15,7 +15,10 @@ use crate::ipc_protocol::{
     connect_client,
 };
 
-use super::super::main::run_main;
+use super::super::{
+    state::WindowConfig,
+    main::{run_main, is_headless},
+};
 
 /// Spawns the task responsible for handling and responding to client requests
 #[derive(Debug)]
@@ -40,7 +43,7 @@ impl RendererServer {
 
     /// Spawns the backend in a new task and returns the struct that will be used to
     /// interface with it.
-    pub async fn spawn() -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
+    pub async fn spawn(config: WindowConfig) -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
         let (server_name_sender, server_name_receiver) = oneshot::channel();
         // Spawn a separate task for the server so this task can continue to make progress
         // while that runs. The remote handle will drop that future when it is dropped.
@@ -51,7 +54,7 @@ impl RendererServer {
             // spawn_blocking() takes care of catching any panics that might occur, so we don't
             // need to do that explicitly here even though Drop will need that information.
             task::spawn_blocking(|| {
-                run_main(handle, async { connect_server(server_name) })
+                run_main(handle, config, async { connect_server(server_name) })
             }).await
         }.remote_handle();
 
@@ -81,6 +84,12 @@ impl Drop for RendererServer {
             return;
         }
 
+        // Without a window, there is nothing for the user to look at after the main thread ends.
+        // The task will end on its own once the client disconnects.
+        if is_headless() {
+            return;
+        }
+
         // If this is just a normal ending of the main thread, we want to leave the renderer
         // running so that the user can see their drawing as long as they keep the window open
 
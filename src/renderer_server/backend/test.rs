@@ -9,9 +9,14 @@ use crate::ipc_protocol::{
     connect_client,
 };
 
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
 use super::super::{
     serve,
-    app::SharedApp,
+    state::WindowConfig,
+    app::{SharedApp, App},
     renderer::display_list::SharedDisplayList,
     test_event_loop_notifier::EventLoopNotifier
 };
@@ -30,14 +35,14 @@ impl RendererServer {
 
     /// Spawns the backend in a new task and returns the struct that will be used to
     /// interface with it.
-    pub async fn spawn() -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
+    pub async fn spawn(config: WindowConfig) -> Result<(Self, ClientSender, ClientReceiver), ConnectionError> {
         let (server_name_sender, server_name_receiver) = oneshot::channel();
         // Spawn a separate task for the server so this task can continue to make progress
         // while that runs. The remote handle will drop that future when it is dropped.
         let (child, task_handle) = async move {
             let server_name = server_name_receiver.await
                 .expect("bug: unable to receive server name");
-            run_main(server_name).await;
+            run_main(config, server_name).await;
         }.remote_handle();
 
         tokio::spawn(child);
@@ -52,9 +57,9 @@ impl RendererServer {
     }
 }
 
-pub async fn run_main(server_name: String) {
+pub async fn run_main(config: WindowConfig, server_name: String) {
     // The state of the drawing and the state/drawings associated with each turtle
-    let app = SharedApp::default();
+    let app: SharedApp = Arc::new(RwLock::new(App::with_drawing(config.initial_drawing())));
     // All of the drawing primitives in the order in which they wil be drawn
     //
     // This is managed separately from the rest of the app state because the display list is shared
@@ -66,6 +71,8 @@ pub async fn run_main(server_name: String) {
     let event_loop_notifier = EventLoopNotifier::new();
     // A channel for transferring events
     let (_events_sender, events_receiver) = mpsc::unbounded_channel();
+    // No frames are ever drawn, so the sender is dropped right away
+    let (_, frames_receiver) = mpsc::channel(1);
     // A channel for notifying on shutdown
     let (_server_shutdown, server_shutdown_receiver) = mpsc::channel(1);
 
@@ -79,6 +86,7 @@ pub async fn run_main(server_name: String) {
         display_list,
         event_loop_notifier,
         events_receiver,
+        frames_receiver,
         server_shutdown_receiver,
     ).await;
 }
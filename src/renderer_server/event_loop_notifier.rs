@@ -1,9 +1,11 @@
 use glutin::{
-    dpi::LogicalSize,
+    dpi::{LogicalSize, LogicalPosition},
     event_loop::{self, EventLoopProxy},
 };
 use thiserror::Error;
 
+use super::renderer::export::RgbaImage;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 #[error("event loop closed while messages were still being sent to it")]
 pub struct EventLoopClosed;
@@ -27,6 +29,10 @@ pub enum MainThreadAction {
     SetIsMaximized(bool),
     /// Change the fullscreen state of the window
     SetIsFullscreen(bool),
+    /// Move the window on the screen (in logical coordinates)
+    SetPosition(LogicalPosition<i32>),
+    /// Change the icon of the window
+    SetIcon(RgbaImage),
     /// Exit event loop (close window)
     Exit,
 }
@@ -34,12 +40,18 @@ pub enum MainThreadAction {
 /// Notifies the main loop when actions need to take place
 #[derive(Debug, Clone)]
 pub struct EventLoopNotifier {
-    event_loop: EventLoopProxy<MainThreadAction>,
+    /// `None` when running headless, since there is no window or main loop to notify
+    event_loop: Option<EventLoopProxy<MainThreadAction>>,
 }
 
 impl EventLoopNotifier {
     pub fn new(event_loop: EventLoopProxy<MainThreadAction>) -> Self {
-        Self {event_loop}
+        Self {event_loop: Some(event_loop)}
+    }
+
+    /// Creates a notifier that ignores all actions, for use when there is no window
+    pub fn headless() -> Self {
+        Self {event_loop: None}
     }
 
     pub fn request_redraw(&self) -> Result<(), EventLoopClosed> {
@@ -62,11 +74,22 @@ impl EventLoopNotifier {
         self.send_action(MainThreadAction::SetIsFullscreen(is_fullscreen))
     }
 
+    pub fn set_position<P: Into<LogicalPosition<i32>>>(&self, position: P) -> Result<(), EventLoopClosed> {
+        self.send_action(MainThreadAction::SetPosition(position.into()))
+    }
+
+    pub fn set_icon(&self, icon: RgbaImage) -> Result<(), EventLoopClosed> {
+        self.send_action(MainThreadAction::SetIcon(icon))
+    }
+
     pub fn exit(&self) -> Result<(), EventLoopClosed> {
         self.send_action(MainThreadAction::Exit)
     }
 
     fn send_action(&self, action: MainThreadAction) -> Result<(), EventLoopClosed> {
-        Ok(self.event_loop.send_event(action)?)
+        match &self.event_loop {
+            Some(event_loop) => Ok(event_loop.send_event(action)?),
+            None => Ok(()),
+        }
     }
 }
@@ -4,10 +4,17 @@ mod poll_event;
 mod drawing_prop;
 mod turtle_prop;
 mod animation;
+mod batch;
 mod fill;
 mod clear;
 mod debug;
 mod destroy_drawing;
+mod write_text;
+mod stamp;
+mod undo;
+mod position_history;
+mod update_drawing;
+mod release_client;
 
 pub(crate) use create_turtle::*;
 pub(crate) use export_drawings::*;
@@ -15,10 +22,17 @@ pub(crate) use poll_event::*;
 pub(crate) use drawing_prop::*;
 pub(crate) use turtle_prop::*;
 pub(crate) use animation::*;
+pub(crate) use batch::*;
 pub(crate) use fill::*;
 pub(crate) use clear::*;
 pub(crate) use debug::*;
 pub(crate) use destroy_drawing::*;
+pub(crate) use write_text::*;
+pub(crate) use stamp::*;
+pub(crate) use undo::*;
+pub(crate) use position_history::*;
+pub(crate) use update_drawing::*;
+pub(crate) use release_client::*;
 
 use thiserror::Error;
 
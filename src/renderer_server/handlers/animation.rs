@@ -1,16 +1,19 @@
+use std::time::Duration;
+
 use crate::ipc_protocol::{ServerOneshotSender, ServerResponse, RotationDirection};
 use crate::radians::Radians;
-use crate::{Distance, Point};
+use crate::{Distance, Point, Color};
 
 use super::HandlerError;
 use super::super::{
     event_loop_notifier::EventLoopNotifier,
     state::TurtleState,
     app::{TurtleId, App},
-    animation::{MoveAnimation, RotateAnimation, AnimationRunner},
+    animation::{MoveAnimation, RotateAnimation, BackgroundAnimation, CenterAnimation, FillColorAnimation, AnimationRunner},
     renderer::display_list::DisplayList,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn move_forward(
     conn: ServerOneshotSender,
     app: &mut App,
@@ -19,8 +22,10 @@ pub(crate) fn move_forward(
     anim_runner: &AnimationRunner,
     id: TurtleId,
     distance: Distance,
+    duration: Option<Duration>,
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
+    turtle.push_undo_record(display_list);
 
     let TurtleState {position, heading, ..} = turtle.state;
 
@@ -31,17 +36,22 @@ pub(crate) fn move_forward(
     };
     let target_pos = position + movement;
 
-    let anim = MoveAnimation::new(turtle, display_list, target_pos);
+    let anim = MoveAnimation::new(turtle, display_list, target_pos, duration);
 
     if anim.is_running() {
         anim_runner.play(id, anim, conn.client_id());
 
     } else {
+        turtle.record_position();
+
         // Instant animations complete right away and don't need to be queued
-        // Signal the main thread that the image has changed
-        event_loop.request_redraw()?;
+        // Signal the main thread that the image has changed, unless the turtle's tracer is
+        // disabled and the change should only be shown later
+        if turtle.state.tracer {
+            event_loop.request_redraw()?;
+        }
 
-        conn.send(ServerResponse::AnimationComplete(id))?;
+        conn.send(ServerResponse::AnimationComplete(id, turtle.state.position, turtle.state.heading))?;
     }
 
     Ok(())
@@ -57,26 +67,63 @@ pub(crate) fn move_to(
     target_pos: Point,
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
+    turtle.push_undo_record(display_list);
 
-    let anim = MoveAnimation::new(turtle, display_list, target_pos);
+    let anim = MoveAnimation::new(turtle, display_list, target_pos, None);
 
     if anim.is_running() {
         anim_runner.play(id, anim, conn.client_id());
 
     } else {
+        turtle.record_position();
+
         // Instant animations complete right away and don't need to be queued
-        // Signal the main thread that the image has changed
-        event_loop.request_redraw()?;
+        // Signal the main thread that the image has changed, unless the turtle's tracer is
+        // disabled and the change should only be shown later
+        if turtle.state.tracer {
+            event_loop.request_redraw()?;
+        }
+
+        conn.send(ServerResponse::AnimationComplete(id, turtle.state.position, turtle.state.heading))?;
+    }
+
+    Ok(())
+}
 
-        conn.send(ServerResponse::AnimationComplete(id))?;
+pub(crate) fn teleport(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+    position: Point,
+    heading: Radians,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+    turtle.push_undo_record(display_list);
+
+    turtle.state.position = position;
+    // Normalize the angle to be between 0 and 2*pi, just like after a rotation
+    turtle.state.heading = heading.normalized();
+    turtle.record_position();
+
+    // The fill follows the turtle even though no line is drawn, just like when the pen is up
+    if let Some(poly_handle) = turtle.current_fill_polygon {
+        display_list.polygon_push(poly_handle, position);
+    }
+
+    // Signal the main thread that the image has changed
+    if turtle.state.tracer {
+        event_loop.request_redraw()?;
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn rotate_in_place(
     conn: ServerOneshotSender,
     app: &mut App,
+    display_list: &DisplayList,
     event_loop: &EventLoopNotifier,
     anim_runner: &AnimationRunner,
     id: TurtleId,
@@ -84,18 +131,126 @@ pub(crate) fn rotate_in_place(
     direction: RotationDirection,
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
+    turtle.push_undo_record(display_list);
 
     let anim = RotateAnimation::new(turtle, angle, direction);
 
     if anim.is_running() {
         anim_runner.play(id, anim, conn.client_id());
 
+    } else {
+        // Instant animations complete right away and don't need to be queued
+        // Signal the main thread that the image has changed
+        if turtle.state.tracer {
+            event_loop.request_redraw()?;
+        }
+
+        conn.send(ServerResponse::AnimationComplete(id, turtle.state.position, turtle.state.heading))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn wait_for_animation(
+    conn: ServerOneshotSender,
+    anim_runner: &AnimationRunner,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    // The animation runner responds once the animation has completed, if there is one
+    anim_runner.wait_for(id, conn.client_id());
+
+    Ok(())
+}
+
+pub(crate) fn animate_background(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    event_loop: &EventLoopNotifier,
+    anim_runner: &AnimationRunner,
+    color: Color,
+    secs: f64,
+) -> Result<(), HandlerError> {
+    let anim = BackgroundAnimation::new(app.drawing_mut(), color, secs);
+
+    if anim.is_running() {
+        anim_runner.play_background(anim, conn.client_id());
+
+    } else {
+        // Instant animations complete right away and don't need to be queued
+        // Signal the main thread that the image has changed
+        event_loop.request_redraw()?;
+
+        conn.send(ServerResponse::BackgroundAnimationComplete)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn animate_center(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    event_loop: &EventLoopNotifier,
+    anim_runner: &AnimationRunner,
+    center: Point,
+    secs: f64,
+) -> Result<(), HandlerError> {
+    let anim = CenterAnimation::new(app.drawing_mut(), center, secs);
+
+    if anim.is_running() {
+        anim_runner.play_center(anim, conn.client_id());
+
     } else {
         // Instant animations complete right away and don't need to be queued
         // Signal the main thread that the image has changed
         event_loop.request_redraw()?;
 
-        conn.send(ServerResponse::AnimationComplete(id))?;
+        conn.send(ServerResponse::CenterAnimationComplete)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn animate_fill_color(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    anim_runner: &AnimationRunner,
+    id: TurtleId,
+    from: Color,
+    to: Color,
+    secs: f64,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+    let TurtleState {position, heading, tracer, ..} = turtle.state;
+
+    let fill_id = match turtle.last_fill() {
+        Some(fill_id) => fill_id,
+        // Nothing to animate
+        None => {
+            conn.send(ServerResponse::AnimationComplete(id, position, heading))?;
+            return Ok(());
+        },
+    };
+
+    // The colors are drawn the same way as any other fill drawn by the turtle
+    let from = turtle.state.apply_group_opacity(from);
+    let to = turtle.state.apply_group_opacity(to);
+    // The change is always instant while the turtle's tracer is disabled
+    let secs = if tracer { secs } else { 0.0 };
+    let anim = FillColorAnimation::new(turtle, display_list, fill_id, from, to, secs);
+
+    if anim.is_running() {
+        anim_runner.play(id, anim, conn.client_id());
+
+    } else {
+        // Instant animations complete right away and don't need to be queued
+        if tracer {
+            event_loop.request_redraw()?;
+        }
+
+        conn.send(ServerResponse::AnimationComplete(id, position, heading))?;
     }
 
     Ok(())
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use crate::ipc_protocol::{ServerOneshotSender, ServerResponse, BatchCommand};
+use crate::Point;
+
+use super::HandlerError;
+use super::super::{
+    event_loop_notifier::EventLoopNotifier,
+    state::TurtleState,
+    app::{TurtleId, TurtleDrawings, App},
+    animation::{self, MoveAnimation},
+    renderer::display_list::DisplayList,
+};
+
+pub(crate) fn batch(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+    commands: Vec<BatchCommand>,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    for command in commands {
+        // Each command can be undone separately, just as if it had been sent on its own
+        turtle.push_undo_record(display_list);
+
+        use BatchCommand::*;
+        match command {
+            MoveForward(distance) => {
+                let TurtleState {position, heading, ..} = turtle.state;
+                let movement = Point {
+                    x: distance * heading.cos(),
+                    y: distance * heading.sin(),
+                };
+                move_instantly(turtle, display_list, position + movement);
+            },
+
+            MoveTo(target_pos) => move_instantly(turtle, display_list, target_pos),
+
+            RotateInPlace(angle, direction) => {
+                turtle.state.heading = animation::rotate(turtle.state.heading, angle, direction);
+            },
+
+            SetPenEnabled(is_enabled) => turtle.state.pen.is_enabled = is_enabled,
+        }
+    }
+
+    // Signal the main thread that the image has changed, unless the turtle's tracer is disabled
+    // and the change should only be shown later
+    if turtle.state.tracer {
+        event_loop.request_redraw()?;
+    }
+
+    let TurtleState {position, heading, ..} = turtle.state;
+    conn.send(ServerResponse::AnimationComplete(id, position, heading))?;
+
+    Ok(())
+}
+
+fn move_instantly(turtle: &mut TurtleDrawings, display_list: &mut DisplayList, target_pos: Point) {
+    // A duration of zero always moves the turtle right away, without an animation
+    let anim = MoveAnimation::new(turtle, display_list, target_pos, Some(Duration::from_secs(0)));
+    debug_assert!(!anim.is_running(), "bug: batched movement should never be animated");
+
+    turtle.record_position();
+}
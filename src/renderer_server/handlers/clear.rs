@@ -15,10 +15,14 @@ pub(crate) fn clear_all(
     display_list.clear();
 
     for (_, turtle) in app.turtles_mut() {
-        let TurtleDrawings {state: _, drawings, current_fill_polygon} = turtle;
+        let TurtleDrawings {drawings, current_fill_polygon, undo_history, stamps, fills, ..} = turtle;
 
         drawings.clear();
         *current_fill_polygon = None;
+        stamps.clear();
+        fills.clear();
+        // The drawings referred to by the history no longer exist
+        undo_history.clear();
     }
 
     // Stop all animations that may have been running
@@ -38,11 +42,15 @@ pub(crate) fn clear_turtle(
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {state: _, drawings, current_fill_polygon} = turtle;
+    let TurtleDrawings {drawings, current_fill_polygon, undo_history, stamps, fills, ..} = turtle;
 
     display_list.remove(drawings.iter().copied());
     drawings.clear();
     *current_fill_polygon = None;
+    stamps.clear();
+    fills.clear();
+    // The drawings referred to by the history no longer exist
+    undo_history.clear();
 
     // Signal the main thread that the image has changed
     event_loop.request_redraw()?;
@@ -3,7 +3,7 @@ use crate::ipc_protocol::{ServerOneshotSender, ServerResponse};
 use super::HandlerError;
 use super::super::{
     event_loop_notifier::EventLoopNotifier,
-    app::App,
+    app::{App, TurtleId},
 };
 
 pub(crate) fn create_turtle(
@@ -20,3 +20,22 @@ pub(crate) fn create_turtle(
 
     Ok(())
 }
+
+pub(crate) fn clone_turtle(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    event_loop: &EventLoopNotifier,
+    source: TurtleId,
+) -> Result<(), HandlerError> {
+    // Only the state is copied, the new turtle starts without any drawings or undo history
+    let state = app.turtle(source).state.clone();
+    let id = app.add_turtle();
+    app.turtle_mut(id).state = state;
+
+    // Signal the main thread that the image has changed (otherwise the new turtle won't be drawn)
+    event_loop.request_redraw()?;
+
+    conn.send(ServerResponse::NewTurtle(id))?;
+
+    Ok(())
+}
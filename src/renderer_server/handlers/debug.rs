@@ -23,7 +23,7 @@ pub(crate) fn debug_turtle(
 
     let debug_state = turtle.to_debug(angle_unit);
 
-    conn.send(ServerResponse::DebugTurtle(id, debug_state))?;
+    conn.send(ServerResponse::DebugTurtle(id, Box::new(debug_state)))?;
 
     Ok(())
 }
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use crate::ipc_protocol::{ServerOneshotSender, ServerResponse, DrawingProp, DrawingPropValue};
 
 use super::HandlerError;
@@ -5,6 +7,7 @@ use super::super::{
     event_loop_notifier::EventLoopNotifier,
     state::DrawingState,
     app::App,
+    renderer::export,
 };
 
 pub(crate) fn drawing_prop(
@@ -24,6 +27,18 @@ pub(crate) fn drawing_prop(
         Height => DrawingPropValue::Height(drawing.height),
         IsMaximized => DrawingPropValue::IsMaximized(drawing.is_maximized),
         IsFullscreen => DrawingPropValue::IsFullscreen(drawing.is_fullscreen),
+        WindowPosition => {
+            let (x, y) = drawing.window_position;
+            DrawingPropValue::WindowPosition(x, y)
+        },
+        Grid => DrawingPropValue::Grid(drawing.grid),
+        ShowAxes => DrawingPropValue::ShowAxes(drawing.show_axes),
+        Scale => DrawingPropValue::Scale(drawing.scale),
+        ExitOnEscape => DrawingPropValue::ExitOnEscape(drawing.exit_on_escape),
+        ExitOnClose => DrawingPropValue::ExitOnClose(drawing.exit_on_close),
+        ShowFps => DrawingPropValue::ShowFps(drawing.show_fps),
+        TickEvents => DrawingPropValue::TickEvents(drawing.tick_events),
+        Antialiasing => DrawingPropValue::Antialiasing(drawing.antialiasing),
     };
 
     conn.send(ServerResponse::DrawingProp(value))?;
@@ -61,9 +76,40 @@ pub(crate) fn reset_drawing_prop(
         Height => DrawingPropValue::Height(DrawingState::DEFAULT_HEIGHT),
         IsMaximized => DrawingPropValue::IsMaximized(DrawingState::DEFAULT_IS_MAXIMIZED),
         IsFullscreen => DrawingPropValue::IsFullscreen(DrawingState::DEFAULT_IS_FULLSCREEN),
+        WindowPosition => {
+            let (x, y) = DrawingState::DEFAULT_WINDOW_POSITION;
+            DrawingPropValue::WindowPosition(x, y)
+        },
+        Grid => DrawingPropValue::Grid(DrawingState::DEFAULT_GRID),
+        ShowAxes => DrawingPropValue::ShowAxes(DrawingState::DEFAULT_SHOW_AXES),
+        Scale => DrawingPropValue::Scale(DrawingState::DEFAULT_SCALE),
+        ExitOnEscape => DrawingPropValue::ExitOnEscape(DrawingState::DEFAULT_EXIT_ON_ESCAPE),
+        ExitOnClose => DrawingPropValue::ExitOnClose(DrawingState::DEFAULT_EXIT_ON_CLOSE),
+        ShowFps => DrawingPropValue::ShowFps(DrawingState::DEFAULT_SHOW_FPS),
+        TickEvents => DrawingPropValue::TickEvents(DrawingState::DEFAULT_TICK_EVENTS),
+        Antialiasing => DrawingPropValue::Antialiasing(DrawingState::DEFAULT_ANTIALIASING),
     })
 }
 
+pub(crate) fn set_icon(
+    conn: ServerOneshotSender,
+    event_loop: &EventLoopNotifier,
+    path: &Path,
+) -> Result<(), HandlerError> {
+    let res = match export::load_png(path) {
+        Ok(icon) => {
+            // Signal the main thread to change the icon of the window
+            event_loop.set_icon(icon)?;
+            Ok(())
+        },
+        Err(err) => Err(err),
+    };
+
+    conn.send(ServerResponse::SetIconComplete(res))?;
+
+    Ok(())
+}
+
 fn modify_drawing(
     drawing: &mut DrawingState,
     event_loop: &EventLoopNotifier,
@@ -127,6 +173,60 @@ fn modify_drawing(
             // Signal the main thread to change this property on the window
             event_loop.set_is_fullscreen(is_fullscreen)?;
         },
+
+        WindowPosition(x, y) => {
+            drawing.window_position = (x, y);
+
+            // Signal the main thread to change this property on the window
+            event_loop.set_position((x, y))?;
+        },
+
+        Grid(grid) => {
+            drawing.grid = grid;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
+
+        ShowAxes(show_axes) => {
+            drawing.show_axes = show_axes;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
+
+        Scale(scale) => {
+            drawing.scale = scale;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
+
+        // The main thread checks these whenever the window might be closed
+        ExitOnEscape(exit_on_escape) => drawing.exit_on_escape = exit_on_escape,
+        ExitOnClose(exit_on_close) => drawing.exit_on_close = exit_on_close,
+
+        ShowFps(show_fps) => {
+            drawing.show_fps = show_fps;
+
+            // The main thread adds the frame rate (if it is shown) whenever it sets the title
+            event_loop.set_title(drawing.title.clone())?;
+            // Frames need to be drawn for the frame rate to be measured
+            event_loop.request_redraw()?;
+        },
+
+        TickEvents(tick_events) => {
+            drawing.tick_events = tick_events;
+
+            // The main thread checks this after every frame, so drawing a frame either starts
+            // or stops the ticks
+            event_loop.request_redraw()?;
+        },
+
+        Antialiasing(antialiasing) => {
+            drawing.antialiasing = antialiasing;
+            event_loop.request_redraw()?;
+        },
     }
 
     Ok(())
@@ -1,11 +1,18 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::Size;
 use crate::ipc_protocol::{ServerOneshotSender, ServerResponse, ExportFormat};
 
-use super::HandlerError;
+use super::{HandlerError, clear_all};
 use super::super::{
     app::App,
-    renderer::{export, display_list::DisplayList},
+    event_loop_notifier::EventLoopNotifier,
+    animation::AnimationRunner,
+    renderer::{
+        export::{self, ExportError},
+        gif_recording::GifRecording,
+        display_list::{DisplayList, DisplayListData},
+    },
 };
 
 pub(crate) fn export_drawings(
@@ -20,9 +27,103 @@ pub(crate) fn export_drawings(
     use ExportFormat::*;
     let res = match format {
         Svg => export::save_svg(display_list, drawing, path),
+        Png(scale) => export::save_png(display_list, drawing, path, scale),
+        Pdf => export::save_pdf(display_list, drawing, path),
     };
 
     conn.send(ServerResponse::ExportComplete(res))?;
 
     Ok(())
 }
+
+pub(crate) fn screenshot(
+    conn: ServerOneshotSender,
+    app: &App,
+    display_list: &DisplayList,
+) -> Result<(), HandlerError> {
+    let res = export::render_image(display_list, app.drawing(), std::iter::empty(), 1.0)
+        .map(|image| (Size {width: image.width, height: image.height}, image.rgba));
+
+    conn.send(ServerResponse::Screenshot(res))?;
+
+    Ok(())
+}
+
+pub(crate) fn export_display_list(
+    conn: ServerOneshotSender,
+    app: &App,
+    display_list: &DisplayList,
+) -> Result<(), HandlerError> {
+    let data = DisplayListData {
+        background: app.drawing().background,
+        prims: display_list.iter().cloned().collect(),
+    };
+
+    conn.send(ServerResponse::DisplayList(data))?;
+
+    Ok(())
+}
+
+pub(crate) fn start_gif_recording(
+    app: &mut App,
+    event_loop: &EventLoopNotifier,
+    path: PathBuf,
+    fps: f64,
+) -> Result<(), HandlerError> {
+    app.start_gif_recording(GifRecording::new(path, fps));
+
+    // Render right away so that the recording starts with the drawing as it is now
+    event_loop.request_redraw()?;
+
+    Ok(())
+}
+
+pub(crate) fn stop_gif_recording(
+    conn: ServerOneshotSender,
+    recording: Option<GifRecording>,
+) -> Result<(), HandlerError> {
+    let res = match recording {
+        Some(recording) => recording.save(),
+        None => Err(ExportError::Render("no GIF recording was started".to_string())),
+    };
+
+    conn.send(ServerResponse::GifRecordingComplete(res))?;
+
+    Ok(())
+}
+
+pub(crate) fn drawing_bounds(
+    conn: ServerOneshotSender,
+    display_list: &DisplayList,
+) -> Result<(), HandlerError> {
+    conn.send(ServerResponse::Bounds(display_list.bounds()))?;
+
+    Ok(())
+}
+
+pub(crate) fn import_display_list(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    anim_runner: &AnimationRunner,
+    data: DisplayListData,
+) -> Result<(), HandlerError> {
+    // The client checks the data too, but drawing invalid values (e.g. a fill pattern with no
+    // spacing) could make the renderer hang, so they are never imported
+    if !data.is_valid() {
+        return Ok(());
+    }
+
+    let DisplayListData {background, prims} = data;
+
+    // The imported primitives replace everything that was drawn before. This also requests a
+    // redraw, which can't happen until the display list is unlocked after the loop below.
+    clear_all(app, display_list, event_loop, anim_runner)?;
+
+    app.drawing_mut().background = background;
+    for prim in prims {
+        display_list.push(prim);
+    }
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use crate::{Color, FillId, UnknownFillError};
+use crate::ipc_protocol::{ServerOneshotSender, ServerResponse};
+
 use super::HandlerError;
 use super::super::{
     event_loop_notifier::EventLoopNotifier,
@@ -13,34 +16,88 @@ pub(crate) fn begin_fill(
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {state: turtle, drawings, current_fill_polygon} = turtle;
-
     // Ignore the request if we are already filling
-    if current_fill_polygon.is_some() {
+    if turtle.current_fill_polygon.is_some() {
         return Ok(());
     }
 
-    let poly_handle = display_list.push_polygon_start(turtle.position, turtle.fill_color);
+    turtle.push_undo_record(display_list);
+
+    let TurtleDrawings {state: turtle, drawings, current_fill_polygon, ..} = turtle;
+
+    let poly_handle = display_list.push_polygon_start(
+        turtle.position,
+        turtle.drawing_fill_color(),
+        turtle.fill_rule,
+        turtle.fill_pattern,
+    );
     drawings.push(poly_handle);
     *current_fill_polygon = Some(poly_handle);
 
-    event_loop.request_redraw()?;
+    if turtle.tracer {
+        event_loop.request_redraw()?;
+    } else {
+        display_list.hide(poly_handle);
+    }
 
     Ok(())
 }
 
 pub(crate) fn end_fill(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    display_list: &DisplayList,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    // If we weren't filling already, the response still contains an ID but it doesn't refer to
+    // any fill polygon
+    let poly_handle = turtle.current_fill_polygon;
+    if poly_handle.is_some() {
+        turtle.push_undo_record(display_list);
+
+        // No need to add the turtle's current position to the polygon since it should already be
+        // there
+        turtle.current_fill_polygon = None;
+    }
+
+    let fill_id = turtle.add_fill(poly_handle);
+    conn.send(ServerResponse::FillEnded(id, fill_id))?;
+
+    Ok(())
+}
+
+pub(crate) fn set_fill_color_of(
+    conn: ServerOneshotSender,
     app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
     id: TurtleId,
+    fill_id: FillId,
+    color: Color,
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {current_fill_polygon, ..} = turtle;
+    // Fills that are no longer drawn (e.g. after being undone or cleared) can't be changed
+    let res = match turtle.fill_polygon(fill_id) {
+        Some(poly_handle) => {
+            // The color is drawn the same way as any other fill drawn by the turtle
+            let color = turtle.state.apply_group_opacity(color);
+            display_list.polygon_set_fill_color(poly_handle, color);
+
+            // Stop any animation of this fill from overwriting the new color
+            if turtle.animated_fill == Some(fill_id) {
+                turtle.animated_fill = None;
+            }
 
-    // No need to add the turtle's current position to the polygon since it should already be there
+            event_loop.request_redraw()?;
+            Ok(())
+        },
+        None => Err(UnknownFillError(fill_id)),
+    };
 
-    // Changes nothing if we weren't filling already
-    *current_fill_polygon = None;
+    conn.send(ServerResponse::FillColorSet(id, res))?;
 
     Ok(())
 }
@@ -1,25 +1,93 @@
+use std::collections::VecDeque;
+
 use tokio::sync::mpsc::{self, error::TryRecvError};
 
 use crate::ipc_protocol::{
     ServerOneshotSender,
     ServerResponse,
 };
+use crate::renderer_client::ClientId;
 use crate::Event;
 
 use super::HandlerError;
+use super::super::app::App;
 
 pub(crate) fn poll_event(
     conn: ServerOneshotSender,
+    app: &mut App,
     events_receiver: &mut mpsc::UnboundedReceiver<Event>,
 ) -> Result<(), HandlerError> {
     let event = match events_receiver.try_recv() {
-        Ok(event) => Some(event),
-        Err(TryRecvError::Empty) => None,
-        // The main thread must have ended so no more events will be sent ever
-        Err(TryRecvError::Closed) => return Ok(()),
+        Ok(event) => {
+            app.input_mut().event_received(&event);
+            Some(event)
+        },
+        // No more events will be sent ever if the channel is closed (e.g. the main thread ended)
+        Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => None,
     };
 
     conn.send(ServerResponse::Event(event))?;
 
     Ok(())
 }
+
+pub(crate) fn next_event(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    events_receiver: &mut mpsc::UnboundedReceiver<Event>,
+    event_waiters: &mut VecDeque<ClientId>,
+) -> Result<(), HandlerError> {
+    // Clients that are already waiting get the next events first
+    if !event_waiters.is_empty() {
+        event_waiters.push_back(conn.client_id());
+        return Ok(());
+    }
+
+    match events_receiver.try_recv() {
+        Ok(event) => {
+            app.input_mut().event_received(&event);
+            conn.send(ServerResponse::Event(Some(event)))?;
+        },
+        // The response will be sent by `send_next_event` once an event arrives
+        Err(TryRecvError::Empty) => event_waiters.push_back(conn.client_id()),
+        // The main thread must have ended so no more events will be sent ever
+        Err(TryRecvError::Closed) => conn.send(ServerResponse::Event(None))?,
+    }
+
+    Ok(())
+}
+
+/// Responds to a client that was waiting in `next_event`
+///
+/// The event is `None` if no more events will be sent ever.
+pub(crate) fn send_next_event(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    event: Option<Event>,
+) -> Result<(), HandlerError> {
+    if let Some(event) = &event {
+        app.input_mut().event_received(event);
+    }
+
+    conn.send(ServerResponse::Event(event))?;
+
+    Ok(())
+}
+
+pub(crate) fn mouse_position(
+    conn: ServerOneshotSender,
+    app: &App,
+) -> Result<(), HandlerError> {
+    conn.send(ServerResponse::MousePosition(app.input().mouse_position))?;
+
+    Ok(())
+}
+
+pub(crate) fn pressed_keys(
+    conn: ServerOneshotSender,
+    app: &App,
+) -> Result<(), HandlerError> {
+    conn.send(ServerResponse::PressedKeys(app.input().pressed_keys()))?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::ipc_protocol::{ServerOneshotSender, ServerResponse};
+use crate::path_recorder::PathId;
+
+use super::HandlerError;
+use super::super::app::{TurtleId, App};
+
+pub(crate) fn set_track_history(
+    app: &mut App,
+    id: TurtleId,
+    capacity: usize,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    turtle.position_history_capacity = capacity;
+    turtle.position_history.clear();
+    // Avoid reallocating as positions are recorded, but don't allocate more than we might need
+    // right away in case the capacity is very large
+    turtle.position_history.shrink_to_fit();
+    turtle.position_history.reserve(capacity.min(1024));
+
+    Ok(())
+}
+
+pub(crate) fn begin_poly(
+    app: &mut App,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    // The polygon starts where the turtle is right now, even if it was already recording one
+    turtle.poly = Some(vec![turtle.state.position]);
+
+    Ok(())
+}
+
+pub(crate) fn end_poly(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    let points = turtle.poly.take().unwrap_or_default();
+
+    conn.send(ServerResponse::Poly(id, points))?;
+
+    Ok(())
+}
+
+pub(crate) fn start_path(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    let path_id = turtle.start_path();
+
+    conn.send(ServerResponse::PathStarted(id, path_id))?;
+
+    Ok(())
+}
+
+pub(crate) fn recorded_path(
+    conn: ServerOneshotSender,
+    app: &App,
+    id: TurtleId,
+    path_id: PathId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle(id);
+
+    let points = turtle.paths.get(&path_id).cloned().unwrap_or_default();
+
+    conn.send(ServerResponse::Path(id, points))?;
+
+    Ok(())
+}
+
+pub(crate) fn stop_path(
+    app: &mut App,
+    id: TurtleId,
+    path_id: PathId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    turtle.paths.remove(&path_id);
+
+    Ok(())
+}
+
+pub(crate) fn position_history(
+    conn: ServerOneshotSender,
+    app: &App,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle(id);
+
+    let positions = turtle.position_history.iter().copied().collect();
+
+    conn.send(ServerResponse::PositionHistory(id, positions))?;
+
+    Ok(())
+}
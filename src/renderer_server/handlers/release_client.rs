@@ -0,0 +1,19 @@
+use std::collections::VecDeque;
+
+use crate::ipc_protocol::ServerOneshotSender;
+use crate::renderer_client::ClientId;
+
+use super::HandlerError;
+
+pub(crate) fn release_client(
+    conn: ServerOneshotSender,
+    event_waiters: &mut VecDeque<ClientId>,
+    frame_waiters: &mut Vec<ClientId>,
+) -> Result<(), HandlerError> {
+    // A client that is gone must not be given an event, since the event would never be received
+    let client_id = conn.client_id();
+    event_waiters.retain(|&id| id != client_id);
+    frame_waiters.retain(|&id| id != client_id);
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::StampId;
+use crate::ipc_protocol::{ServerOneshotSender, ServerResponse};
+
+use super::HandlerError;
+use super::super::{
+    event_loop_notifier::EventLoopNotifier,
+    app::{TurtleId, TurtleDrawings, App},
+    state::{Pen, TurtleState},
+    renderer::{turtle_shell, display_list::DisplayList},
+};
+
+/// The thickness of the outline drawn around each stamp
+const STAMP_OUTLINE_THICKNESS: f64 = 1.0;
+
+pub(crate) fn stamp(
+    conn: ServerOneshotSender,
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    turtle.push_undo_record(display_list);
+
+    let TurtleDrawings {state, drawings, ..} = turtle;
+    let &mut TurtleState {ref shape, shape_scale, position, heading, is_visible, tracer, ..} = state;
+    let pen = state.drawing_pen();
+    let fill_color = state.drawing_fill_color();
+
+    // A hidden turtle leaves a stamp that doesn't draw anything
+    let mut prims = Vec::new();
+    if is_visible {
+        let shell: Vec<_> = turtle_shell(shape, shape_scale, position, heading).collect();
+
+        let outline_pen = Pen {
+            is_enabled: true,
+            thickness: STAMP_OUTLINE_THICKNESS,
+            // The outline is drawn in a solid color and a constant thickness even if the pen is
+            // drawing a gradient or changing its thickness
+            end_color: None,
+            thickness_change: None,
+            ..pen
+        };
+        prims.push(display_list.push_polygon(shell.clone(), fill_color));
+        // Draw each side of the shell, including the one that closes it
+        for (&start, &end) in shell.iter().zip(shell.iter().cycle().skip(1)) {
+            prims.extend(display_list.push_line(start, end, &outline_pen));
+        }
+
+        drawings.extend(prims.iter().copied());
+
+        if tracer {
+            event_loop.request_redraw()?;
+        } else {
+            for &prim in &prims {
+                display_list.hide(prim);
+            }
+        }
+    }
+
+    let stamp_id = turtle.add_stamp(prims);
+    conn.send(ServerResponse::Stamp(id, stamp_id))?;
+
+    Ok(())
+}
+
+pub(crate) fn clear_stamp(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+    stamp_id: StampId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    // Stamps that were already cleared are ignored
+    if let Some(prims) = turtle.stamps.remove(&stamp_id) {
+        turtle.remove_drawings(&prims);
+        display_list.remove(prims.into_iter());
+
+        event_loop.request_redraw()?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn clear_stamps(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    let stamps = std::mem::take(&mut turtle.stamps);
+    let prims: Vec<_> = stamps.into_values().flatten().collect();
+    turtle.remove_drawings(&prims);
+    display_list.remove(prims.into_iter());
+
+    event_loop.request_redraw()?;
+
+    Ok(())
+}
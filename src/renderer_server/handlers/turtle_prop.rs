@@ -30,15 +30,31 @@ pub(crate) fn turtle_prop(
     let value = match prop {
         Pen(IsEnabled) => TurtlePropValue::Pen(PenPropValue::IsEnabled(turtle.pen.is_enabled)),
         Pen(Thickness) => TurtlePropValue::Pen(PenPropValue::Thickness(turtle.pen.thickness)),
+        Pen(ThicknessChange) => TurtlePropValue::Pen(PenPropValue::ThicknessChange(turtle.pen.thickness_change)),
         Pen(Color) => TurtlePropValue::Pen(PenPropValue::Color(turtle.pen.color)),
+        Pen(Gradient) => TurtlePropValue::Pen(PenPropValue::Gradient(
+            turtle.pen.end_color.map(|end_color| (turtle.pen.color, end_color)),
+        )),
+        Pen(Cap) => TurtlePropValue::Pen(PenPropValue::Cap(turtle.pen.cap)),
+        Pen(Join) => TurtlePropValue::Pen(PenPropValue::Join(turtle.pen.join)),
+        Pen(State) => TurtlePropValue::Pen(PenPropValue::State(turtle.pen.to_pen_state())),
         FillColor => TurtlePropValue::FillColor(turtle.fill_color),
+        FillRule => TurtlePropValue::FillRule(turtle.fill_rule),
+        FillPattern => TurtlePropValue::FillPattern(turtle.fill_pattern),
+        GroupOpacity => TurtlePropValue::GroupOpacity(turtle.group_opacity),
         IsFilling => TurtlePropValue::IsFilling(current_fill_polygon.is_some()),
         Position => TurtlePropValue::Position(turtle.position),
         PositionX => TurtlePropValue::PositionX(turtle.position.x),
         PositionY => TurtlePropValue::PositionY(turtle.position.y),
         Heading => TurtlePropValue::Heading(turtle.heading),
         Speed => TurtlePropValue::Speed(turtle.speed),
+        MovementRate => TurtlePropValue::MovementRate(turtle.movement_rate),
+        RotationRate => TurtlePropValue::RotationRate(turtle.rotation_rate),
+        Tracer => TurtlePropValue::Tracer(turtle.tracer),
         IsVisible => TurtlePropValue::IsVisible(turtle.is_visible),
+        Shape => TurtlePropValue::Shape(turtle.shape.clone()),
+        ShapeScale => TurtlePropValue::ShapeScale(turtle.shape_scale),
+        ShapeColors => TurtlePropValue::ShapeColors(turtle.shape_outline_color, turtle.shape_fill_color),
     };
 
     conn.send(ServerResponse::TurtleProp(id, value))?;
@@ -55,21 +71,64 @@ pub(crate) fn set_turtle_prop(
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {state: turtle, current_fill_polygon, ..} = turtle;
+    // Only changes to the pen and fill color affect what gets drawn, so only those can be undone
+    if let TurtlePropValue::Pen(_) | TurtlePropValue::FillColor(_) = prop_value {
+        turtle.push_undo_record(display_list);
+    }
+
+    let TurtleDrawings {state: turtle, drawings, current_fill_polygon, ..} = turtle;
 
     use TurtlePropValue::*;
     use PenPropValue::*;
     match prop_value {
         Pen(IsEnabled(is_enabled)) => turtle.pen.is_enabled = is_enabled,
-        Pen(Thickness(thickness)) => turtle.pen.thickness = thickness,
-        Pen(Color(color)) => turtle.pen.color = color,
+        Pen(Thickness(thickness)) => {
+            turtle.pen.thickness = thickness;
+            turtle.pen.thickness_change = None;
+        },
+        Pen(ThicknessChange(thickness_change)) => turtle.pen.thickness_change = thickness_change,
+        Pen(Color(color)) => {
+            turtle.pen.color = color;
+            turtle.pen.end_color = None;
+        },
+        Pen(Gradient(gradient)) => match gradient {
+            Some((start_color, end_color)) => {
+                turtle.pen.color = start_color;
+                turtle.pen.end_color = Some(end_color);
+            },
+            None => turtle.pen.end_color = None,
+        },
+        Pen(Cap(cap)) => turtle.pen.cap = cap,
+        Pen(Join(join)) => turtle.pen.join = join,
+        Pen(State(state)) => turtle.pen = state::Pen::from(state),
 
         FillColor(fill_color) => {
             turtle.fill_color = fill_color;
 
             // Update the current fill polygon to the new color
             if let Some(poly_handle) = *current_fill_polygon {
-                display_list.polygon_set_fill_color(poly_handle, fill_color);
+                display_list.polygon_set_fill_color(poly_handle, turtle.drawing_fill_color());
+
+                // Signal the main thread that the image has changed
+                event_loop.request_redraw()?;
+            }
+        },
+
+        // The fill rule of the current fill polygon (if any) is left as is
+        FillRule(fill_rule) => turtle.fill_rule = fill_rule,
+        // Just like the fill rule, the pattern only applies to shapes filled after it is set. The
+        // client checks the pattern too, but drawing a pattern with no spacing would never finish,
+        // so an invalid pattern is ignored here rather than trusted.
+        FillPattern(fill_pattern) => if fill_pattern.is_valid() {
+            turtle.fill_pattern = fill_pattern;
+        },
+
+        GroupOpacity(group_opacity) => {
+            turtle.group_opacity = group_opacity;
+
+            // The shape being filled is still being drawn, so it uses the new opacity too
+            if let Some(poly_handle) = *current_fill_polygon {
+                display_list.polygon_set_fill_color(poly_handle, turtle.drawing_fill_color());
 
                 // Signal the main thread that the image has changed
                 event_loop.request_redraw()?;
@@ -82,7 +141,23 @@ pub(crate) fn set_turtle_prop(
         PositionY(_) => unreachable!("bug: should have used `MoveTo` instead"),
         Heading(_) => unreachable!("bug: should have used `RotateInPlace` instead"),
 
-        Speed(speed) => turtle.speed = speed,
+        Speed(speed) => {
+            turtle.speed = speed;
+            turtle.movement_rate = None;
+            turtle.rotation_rate = None;
+        },
+        MovementRate(rate) => turtle.movement_rate = rate,
+        RotationRate(rate) => turtle.rotation_rate = rate,
+
+        Tracer(tracer) => {
+            turtle.tracer = tracer;
+
+            // Show everything that was drawn while the tracer was disabled
+            if tracer {
+                display_list.show(drawings.iter().copied());
+                event_loop.request_redraw()?;
+            }
+        },
 
         IsVisible(is_visible) => {
             turtle.is_visible = is_visible;
@@ -90,6 +165,28 @@ pub(crate) fn set_turtle_prop(
             // Signal the main thread that the image has changed
             event_loop.request_redraw()?;
         },
+
+        Shape(shape) => {
+            turtle.shape = shape;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
+
+        ShapeScale(shape_scale) => {
+            turtle.shape_scale = shape_scale;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
+
+        ShapeColors(outline_color, fill_color) => {
+            turtle.shape_outline_color = outline_color;
+            turtle.shape_fill_color = fill_color;
+
+            // Signal the main thread that the image has changed
+            event_loop.request_redraw()?;
+        },
     }
 
     Ok(())
@@ -104,7 +201,7 @@ pub(crate) fn reset_turtle_prop(
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {state: turtle, current_fill_polygon, ..} = turtle;
+    let TurtleDrawings {state: turtle, drawings, current_fill_polygon, ..} = turtle;
 
     let mut drawing_changed = false;
 
@@ -112,15 +209,39 @@ pub(crate) fn reset_turtle_prop(
     use PenProp::*;
     match prop {
         Pen(IsEnabled) => turtle.pen.is_enabled = state::Pen::DEFAULT_IS_ENABLED,
-        Pen(Thickness) => turtle.pen.thickness = state::Pen::DEFAULT_THICKNESS,
-        Pen(Color) => turtle.pen.color = state::Pen::DEFAULT_COLOR,
+        Pen(Thickness) => {
+            turtle.pen.thickness = state::Pen::DEFAULT_THICKNESS;
+            turtle.pen.thickness_change = state::Pen::DEFAULT_THICKNESS_CHANGE;
+        },
+        Pen(ThicknessChange) => turtle.pen.thickness_change = state::Pen::DEFAULT_THICKNESS_CHANGE,
+        Pen(Color) => {
+            turtle.pen.color = state::Pen::DEFAULT_COLOR;
+            turtle.pen.end_color = state::Pen::DEFAULT_END_COLOR;
+        },
+        Pen(Gradient) => turtle.pen.end_color = state::Pen::DEFAULT_END_COLOR,
+        Pen(Cap) => turtle.pen.cap = state::Pen::DEFAULT_CAP,
+        Pen(Join) => turtle.pen.join = state::Pen::DEFAULT_JOIN,
+        Pen(State) => turtle.pen = state::Pen::default(),
 
         FillColor => {
             turtle.fill_color = TurtleState::DEFAULT_FILL_COLOR;
 
             // Update the current fill polygon to the new color
             if let Some(poly_handle) = *current_fill_polygon {
-                display_list.polygon_set_fill_color(poly_handle, TurtleState::DEFAULT_FILL_COLOR);
+                display_list.polygon_set_fill_color(poly_handle, turtle.drawing_fill_color());
+
+                drawing_changed = true;
+            }
+        },
+
+        FillRule => turtle.fill_rule = TurtleState::DEFAULT_FILL_RULE,
+        FillPattern => turtle.fill_pattern = TurtleState::DEFAULT_FILL_PATTERN,
+
+        GroupOpacity => {
+            turtle.group_opacity = TurtleState::DEFAULT_GROUP_OPACITY;
+
+            if let Some(poly_handle) = *current_fill_polygon {
+                display_list.polygon_set_fill_color(poly_handle, turtle.drawing_fill_color());
 
                 drawing_changed = true;
             }
@@ -146,12 +267,40 @@ pub(crate) fn reset_turtle_prop(
             drawing_changed = true;
         },
 
-        Speed => turtle.speed = crate::Speed::default(),
+        Speed => {
+            turtle.speed = crate::Speed::default();
+            turtle.movement_rate = None;
+            turtle.rotation_rate = None;
+        },
+        MovementRate => turtle.movement_rate = None,
+        RotationRate => turtle.rotation_rate = None,
+
+        Tracer => {
+            turtle.tracer = TurtleState::DEFAULT_TRACER;
+            display_list.show(drawings.iter().copied());
+            drawing_changed = true;
+        },
 
         IsVisible => {
             turtle.is_visible = TurtleState::DEFAULT_IS_VISIBLE;
             drawing_changed = true;
         },
+
+        Shape => {
+            turtle.shape = TurtleState::DEFAULT_SHAPE;
+            drawing_changed = true;
+        },
+
+        ShapeScale => {
+            turtle.shape_scale = TurtleState::DEFAULT_SHAPE_SCALE;
+            drawing_changed = true;
+        },
+
+        ShapeColors => {
+            turtle.shape_outline_color = TurtleState::DEFAULT_SHAPE_OUTLINE_COLOR;
+            turtle.shape_fill_color = TurtleState::DEFAULT_SHAPE_FILL_COLOR;
+            drawing_changed = true;
+        },
     }
 
     if drawing_changed {
@@ -170,9 +319,13 @@ pub(crate) fn reset_turtle(
 ) -> Result<(), HandlerError> {
     let turtle = app.turtle_mut(id);
 
-    let TurtleDrawings {state: turtle, current_fill_polygon, ..} = turtle;
+    let TurtleDrawings {state: turtle, drawings, current_fill_polygon, undo_history, ..} = turtle;
 
     *turtle = TurtleState::default();
+    // The tracer is enabled again, so everything it held back is shown
+    display_list.show(drawings.iter().copied());
+    // Undoing a command run before the reset would restore part of the old state
+    undo_history.clear();
 
     // Update the current fill polygon to the new color
     if let Some(poly_handle) = *current_fill_polygon {
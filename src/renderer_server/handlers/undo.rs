@@ -0,0 +1,75 @@
+use crate::{FillId, StampId};
+
+use super::HandlerError;
+use super::super::{
+    event_loop_notifier::EventLoopNotifier,
+    app::{TurtleId, TurtleDrawings, UndoRecord, UndoGroup, App},
+    renderer::display_list::DisplayList,
+};
+
+pub(crate) fn undo(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    let TurtleDrawings {state, drawings, current_fill_polygon, undo_history, fills, stamps, ..} = turtle;
+
+    // Nothing to do if there is no history left
+    let UndoRecord {state: prev_state, drawings_len, fill, line_end, next_fill_id, next_stamp_id} = match undo_history.pop_back() {
+        Some(record) => record,
+        None => return Ok(()),
+    };
+
+    // Only restore the parts of the state that can be changed by an undoable command. The speed
+    // and visibility of the turtle are left as they are.
+    state.position = prev_state.position;
+    state.heading = prev_state.heading;
+    state.pen = prev_state.pen;
+    state.fill_color = prev_state.fill_color;
+
+    // Remove any drawings that were created by the command, along with any fills that the
+    // command completed and any stamps that it created
+    if drawings.len() > drawings_len {
+        let removed: Vec<_> = drawings.drain(drawings_len..).collect();
+        fills.retain(|_, poly_handle| !removed.contains(poly_handle));
+        display_list.remove(removed.into_iter());
+    }
+    fills.split_off(&FillId(next_fill_id));
+    stamps.split_off(&StampId(next_stamp_id));
+
+    // Shorten the line that the command extended (if any) back to its original end point
+    if let Some((line_handle, end)) = line_end {
+        display_list.line_update_end(line_handle, end);
+    }
+
+    // Restore the fill polygon to the way it was before the command
+    *current_fill_polygon = fill.map(|(poly_handle, len)| {
+        display_list.polygon_truncate(poly_handle, len);
+        display_list.polygon_set_fill_color(poly_handle, state.drawing_fill_color());
+        poly_handle
+    });
+
+    // Signal the main thread that the image has changed
+    event_loop.request_redraw()?;
+
+    Ok(())
+}
+
+pub(crate) fn begin_undo_group(app: &mut App, id: TurtleId) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+    if turtle.undo_group == UndoGroup::None {
+        turtle.undo_group = UndoGroup::Started;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn end_undo_group(app: &mut App, id: TurtleId) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+    turtle.undo_group = UndoGroup::None;
+
+    Ok(())
+}
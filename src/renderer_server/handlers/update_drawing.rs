@@ -0,0 +1,51 @@
+use tokio::sync::mpsc::{self, error::TryRecvError};
+
+use crate::ipc_protocol::{
+    ServerOneshotSender,
+    ServerResponse,
+};
+use crate::renderer_client::ClientId;
+
+use super::HandlerError;
+use super::super::{
+    event_loop_notifier::EventLoopNotifier,
+    renderer::display_list::DisplayList,
+};
+
+pub(crate) fn update_drawing(
+    conn: ServerOneshotSender,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    frames_receiver: &mut mpsc::Receiver<()>,
+    frame_waiters: &mut Vec<ClientId>,
+) -> Result<(), HandlerError> {
+    // Everything drawn by turtles with their tracer disabled is shown in the next frame
+    display_list.show_all();
+
+    // Any frame that was already drawn may not include the latest changes to the drawing, so the
+    // client has to wait for the frame drawn after the redraw requested below
+    loop {
+        match frames_receiver.try_recv() {
+            Ok(()) => {},
+            Err(TryRecvError::Empty) => break,
+            // There is no window (or it has been closed), so no frame will ever be drawn
+            Err(TryRecvError::Closed) => {
+                conn.send(ServerResponse::DrawingUpdated)?;
+                return Ok(());
+            },
+        }
+    }
+
+    // The response will be sent by `send_drawing_updated` once the frame is drawn
+    frame_waiters.push(conn.client_id());
+    event_loop.request_redraw()?;
+
+    Ok(())
+}
+
+/// Responds to a client that was waiting in `update_drawing`
+pub(crate) fn send_drawing_updated(conn: ServerOneshotSender) -> Result<(), HandlerError> {
+    conn.send(ServerResponse::DrawingUpdated)?;
+
+    Ok(())
+}
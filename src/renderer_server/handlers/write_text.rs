@@ -0,0 +1,39 @@
+use crate::TextAlign;
+
+use super::HandlerError;
+use super::super::{
+    event_loop_notifier::EventLoopNotifier,
+    app::{TurtleId, TurtleDrawings, App},
+    renderer::{display_list::DisplayList, text::text_polygons},
+};
+
+pub(crate) fn write_text(
+    app: &mut App,
+    display_list: &mut DisplayList,
+    event_loop: &EventLoopNotifier,
+    id: TurtleId,
+    text: &str,
+    font_size: f64,
+    align: TextAlign,
+) -> Result<(), HandlerError> {
+    let turtle = app.turtle_mut(id);
+
+    turtle.push_undo_record(display_list);
+
+    let TurtleDrawings {state: turtle, drawings, ..} = turtle;
+
+    // Text is drawn regardless of whether the pen is up or down
+    for points in text_polygons(text, font_size, align, turtle.position) {
+        let handle = display_list.push_polygon(points, turtle.apply_group_opacity(turtle.pen.color));
+        drawings.push(handle);
+        if !turtle.tracer {
+            display_list.hide(handle);
+        }
+    }
+
+    if turtle.tracer {
+        event_loop.request_redraw()?;
+    }
+
+    Ok(())
+}
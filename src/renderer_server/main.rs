@@ -1,3 +1,5 @@
+use std::env;
+use std::sync::Arc;
 use std::time::{Instant, Duration};
 use std::future::Future;
 
@@ -7,8 +9,8 @@ use glutin::{
     ContextBuilder,
     WindowedContext,
     PossiblyCurrent,
-    dpi::{LogicalSize, PhysicalPosition},
-    window::{WindowBuilder, Fullscreen},
+    dpi::{LogicalSize, LogicalPosition, PhysicalPosition},
+    window::{WindowBuilder, Fullscreen, Icon},
     event::{
         Event as GlutinEvent,
         StartCause,
@@ -20,6 +22,7 @@ use glutin::{
     event_loop::{ControlFlow, EventLoop},
     platform::run_return::EventLoopExtRunReturn,
 };
+use parking_lot::RwLock;
 use tokio::{
     sync::mpsc,
     runtime::Handle,
@@ -29,11 +32,13 @@ use crate::Event;
 use crate::ipc_protocol::{ServerSender, ServerReceiver, ConnectionError};
 
 use super::{
+    state::WindowConfig,
     app::{SharedApp, App},
     coords::ScreenPoint,
     renderer::{
         Renderer,
         display_list::{SharedDisplayList, DisplayList},
+        export::RgbaImage,
     },
     event_loop_notifier::{EventLoopNotifier, MainThreadAction},
 };
@@ -48,6 +53,20 @@ const MAX_RENDERING_FPS: u64 = 60;
 // 1,000,000 us in 1 s
 const MICROS_PER_SEC: u64 = 1_000_000;
 
+/// The frame rate shown in the title of the window is measured over at least this much time
+const FPS_MEASUREMENT_PERIOD: Duration = Duration::from_secs(1);
+
+/// The environment variable that can be set to `1` or `true` to run without opening a window
+const HEADLESS_ENV_VAR: &str = "TURTLE_HEADLESS";
+
+/// Returns true if the renderer should run without opening a window
+pub fn is_headless() -> bool {
+    match env::var(HEADLESS_ENV_VAR) {
+        Ok(value) => value == "1" || value.eq_ignore_ascii_case("true"),
+        Err(_) => false,
+    }
+}
+
 fn new_event_loop<T>() -> EventLoop<T> {
     cfg_if::cfg_if! {
         if #[cfg(target_os = "macos")] {
@@ -76,11 +95,19 @@ pub fn run_main(
     // Necessary because this function is not run on a runtime thread in all backends
     handle: Handle,
 
+    // The settings to open the window with
+    config: WindowConfig,
+
     // Polled to establish the server connection
     establish_connection: impl Future<Output=Result<(ServerSender, ServerReceiver), ConnectionError>> + Send + 'static,
 ) {
+    if is_headless() {
+        run_headless(handle, config, establish_connection);
+        return;
+    }
+
     // The state of the drawing and the state/drawings associated with each turtle
-    let app = SharedApp::default();
+    let app: SharedApp = Arc::new(RwLock::new(App::with_drawing(config.initial_drawing())));
     // All of the drawing primitives in the order in which they wil be drawn
     //
     // Critical sections containing the display list should be as short as possible to avoid holding
@@ -96,6 +123,10 @@ pub fn run_main(
     // Put these variables in an Option so we can call `take()` in the event loop. Required
     // because borrow checker cannot verify which events only fire once.
     let mut events_receiver = Some(events_receiver);
+    // A channel for notifying the server each time a frame is drawn. Only the most recent frame
+    // matters, so there is no need to buffer more than one notification.
+    let (mut frames_sender, frames_receiver) = mpsc::channel(1);
+    let mut frames_receiver = Some(frames_receiver);
     let mut establish_connection = Some(establish_connection);
     // Using a bounded (size = 1) channel because a oneshot consumes self when awaited and this
     // needs to be polled multiple times
@@ -108,6 +139,8 @@ pub fn run_main(
         WindowBuilder::new()
             .with_title(&drawing.title)
             .with_inner_size(LogicalSize {width: drawing.width, height: drawing.height})
+            .with_maximized(drawing.is_maximized)
+            .with_resizable(config.is_resizable)
     };
 
     // Create an OpenGL 3.x context for Pathfinder to use
@@ -123,6 +156,13 @@ pub fn run_main(
     let gl_context = unsafe { gl_context.make_current().unwrap() };
     gl::load_with(|name| gl_context.get_proc_address(name) as *const _);
 
+    // The operating system decides where the window is placed, so that needs to be recorded before
+    // the position can be requested by the client
+    if let Ok(position) = gl_context.window().outer_position() {
+        let position: LogicalPosition<i32> = position.to_logical(gl_context.window().scale_factor());
+        app.write().drawing_mut().window_position = (position.x, position.y);
+    }
+
     // Need to draw using the physical size in pixels, not the logical size
     let draw_size = gl_context.window().inner_size();
     let mut renderer = Renderer::new(draw_size, gl_context.window().scale_factor());
@@ -131,6 +171,8 @@ pub fn run_main(
     let min_render_delay = Duration::from_micros(MICROS_PER_SEC / MAX_RENDERING_FPS);
     // Subtracting the delay so we do an initial render right away
     let mut last_render = Instant::now() - min_render_delay;
+    // For showing the frame rate in the title of the window
+    let mut fps_counter = FpsCounter::new(Instant::now());
     // Very important to use `run_return` here instead of `run` because `run` calls process::exit()
     // and that is not appropriate for the multithreaded backend as that would cause the entire
     // process to end when the window is closed.
@@ -149,6 +191,7 @@ pub fn run_main(
                 display_list.clone(),
                 event_loop_notifier.clone(),
                 events_receiver.take().expect("bug: init event should only occur once"),
+                frames_receiver.take().expect("bug: init event should only occur once"),
                 establish_connection.take().expect("bug: init event should only occur once"),
                 server_shutdown_receiver.take().expect("bug: init event should only occur once"),
             );
@@ -160,14 +203,15 @@ pub fn run_main(
             gl_context.window().request_redraw();
         },
 
-        // Quit if the window is closed or if Esc is pressed and then released
+        // Quit if the window is destroyed or if Esc is pressed and then released (unless that has
+        // been disabled)
         GlutinEvent::WindowEvent {
-            event: WindowEvent::CloseRequested,
-            ..
-        } | GlutinEvent::WindowEvent {
             event: WindowEvent::Destroyed,
             ..
-        } | GlutinEvent::WindowEvent {
+        } => {
+            *control_flow = ControlFlow::Exit;
+        },
+        GlutinEvent::WindowEvent {
             event: WindowEvent::KeyboardInput {
                 input: KeyboardInput {
                     state: ElementState::Released,
@@ -177,10 +221,24 @@ pub fn run_main(
                 ..
             },
             ..
-        } => {
+        } if app.read().drawing().exit_on_escape => {
             *control_flow = ControlFlow::Exit;
         },
 
+        // Quit if the window is closed, unless the program has chosen to handle that itself
+        GlutinEvent::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            ..
+        } => {
+            if app.read().drawing().exit_on_close {
+                *control_flow = ControlFlow::Exit;
+            } else {
+                // Sending may fail if the IPC thread has ended, which is fine since then no one
+                // could handle the event anyway
+                events_sender.send(Event::CloseRequested).unwrap_or(());
+            }
+        },
+
         GlutinEvent::WindowEvent {
             event: WindowEvent::ScaleFactorChanged {scale_factor, ..},
             ..
@@ -199,6 +257,11 @@ pub fn run_main(
                     drawing.height = size.height;
                 },
 
+                WindowEvent::Moved(position) => {
+                    let position: LogicalPosition<i32> = position.to_logical(scale_factor);
+                    app.write().drawing_mut().window_position = (position.x, position.y);
+                },
+
                 //TODO: There are currently no events for updating is_maximized, so that property
                 // should not be relied on. https://github.com/rust-windowing/glutin/issues/1298
 
@@ -211,19 +274,23 @@ pub fn run_main(
                 let drawing = app.drawing();
                 let center = drawing.center;
                 let draw_size = gl_context.window().inner_size();
+                // The view is zoomed about the center, so the zoom is undone along with the DPI
+                let scale = scale_factor * drawing.scale;
                 let fb_center = ScreenPoint {
                     x: draw_size.width as f64 / 2.0,
                     y: draw_size.height as f64 / 2.0,
                 };
 
                 let pos: ScreenPoint = pos.into();
-                pos.to_logical(scale_factor, center, fb_center)
+                pos.to_logical(scale, center, fb_center)
             };
 
             //TODO: There is no guarantee that sending this event here will actually allow a client
             // to receive it. After all, if the window closes and this process exits, there will be
             // no way to handle subsequent `NextEvent` requests.
             if let Some(event) = Event::from_window_event(event, scale_factor, to_logical) {
+                let event = app.write().input_mut().update(event, Instant::now());
+
                 // Sending may fail if the IPC thread has ended due to a disconnection when the
                 // main process ends. This is not a fatal error though so we just ignore it.
                 events_sender.send(event).unwrap_or(());
@@ -238,7 +305,8 @@ pub fn run_main(
         },
 
         GlutinEvent::UserEvent(MainThreadAction::SetTitle(title)) => {
-            gl_context.window().set_title(&title);
+            let show_fps = app.read().drawing().show_fps;
+            gl_context.window().set_title(&fps_counter.window_title(&title, show_fps));
         },
 
         GlutinEvent::UserEvent(MainThreadAction::SetSize(size)) => {
@@ -255,6 +323,18 @@ pub fn run_main(
             } else { None });
         },
 
+        GlutinEvent::UserEvent(MainThreadAction::SetPosition(position)) => {
+            // Not supported on some platforms (e.g. Wayland), in which case this does nothing
+            gl_context.window().set_outer_position(position);
+        },
+
+        GlutinEvent::UserEvent(MainThreadAction::SetIcon(icon)) => {
+            let icon = Icon::from_rgba(icon.rgba, icon.width, icon.height)
+                .expect("bug: icon should have 4 bytes for every pixel");
+            // Not supported on some platforms (e.g. macOS), in which case this does nothing
+            gl_context.window().set_window_icon(Some(icon));
+        },
+
         GlutinEvent::UserEvent(MainThreadAction::Exit) => {
             *control_flow = ControlFlow::Exit;
         }
@@ -268,15 +348,54 @@ pub fn run_main(
                 return;
             }
 
-            let app = app.read();
-            let display_list = display_list.lock();
-            redraw(&app, &display_list, &gl_context, &mut renderer);
+            let frame = {
+                let app = app.read();
+                let display_list = display_list.lock();
+                redraw(&app, &display_list, &gl_context, &mut renderer)
+            };
             last_render = Instant::now();
 
+            if fps_counter.record_frame(last_render) {
+                let app = app.read();
+                let drawing = app.drawing();
+                if drawing.show_fps {
+                    gl_context.window().set_title(&fps_counter.window_title(&drawing.title, true));
+                }
+            }
+
+            // Fails if a notification is already waiting to be received, or if the server has
+            // ended. Neither case is a problem.
+            frames_sender.try_send(()).unwrap_or(());
+
+            let tick_events = {
+                let mut app = app.write();
+                let tick_events = app.drawing().tick_events;
+                if tick_events {
+                    if let Some(tick) = app.input_mut().tick(last_render) {
+                        events_sender.send(tick).unwrap_or(());
+                    }
+                } else {
+                    app.input_mut().stop_ticks();
+                }
+                tick_events
+            };
+
+            // The recording may have been stopped while the frame was being rendered
+            if let Some(frame) = frame {
+                if let Some(recording) = app.write().gif_recording_mut() {
+                    recording.push_frame(last_render, frame);
+                }
+            }
+
             // Do not re-render unless there is a reason to
             //
-            // This is why the window has 0 CPU usage when nothing is happening
-            *control_flow = ControlFlow::Wait;
+            // This is why the window has 0 CPU usage when nothing is happening. Tick events are a
+            // reason to keep rendering, so the next frame is drawn as soon as it is allowed to be.
+            *control_flow = if tick_events {
+                ControlFlow::WaitUntil(last_render + min_render_delay)
+            } else {
+                ControlFlow::Wait
+            };
         },
 
         GlutinEvent::LoopDestroyed => {
@@ -289,26 +408,120 @@ pub fn run_main(
     });
 }
 
+/// Serve client requests in the current thread/task without opening a window
+///
+/// All of the state and drawings are still maintained, so exporting the drawing works exactly the
+/// same as it does with a window. Since there is no window, no events are ever produced. This
+/// returns once the client disconnects.
+fn run_headless(
+    handle: Handle,
+    config: WindowConfig,
+    establish_connection: impl Future<Output=Result<(ServerSender, ServerReceiver), ConnectionError>> + Send + 'static,
+) {
+    let app: SharedApp = Arc::new(RwLock::new(App::with_drawing(config.initial_drawing())));
+    let display_list = SharedDisplayList::default();
+
+    let event_loop_notifier = EventLoopNotifier::headless();
+    // No events are ever sent since there is no window to produce them, so the sender is dropped
+    // right away. That closes the channel so that waiting for an event ends instead of waiting
+    // forever.
+    let (_, events_receiver) = mpsc::unbounded_channel();
+    // No frames are ever drawn, so the sender is dropped right away
+    let (_, frames_receiver) = mpsc::channel(1);
+    // Nothing ever needs to shut down the server since there is no window that can be closed
+    let (_server_shutdown, server_shutdown_receiver) = mpsc::channel(1);
+
+    handle.block_on(async {
+        let (conn_sender, conn_receiver) = establish_connection.await
+            .expect("unable to establish turtle server connection");
+
+        super::serve(
+            conn_sender,
+            conn_receiver,
+            app,
+            display_list,
+            event_loop_notifier,
+            events_receiver,
+            frames_receiver,
+            server_shutdown_receiver,
+        ).await;
+    });
+}
+
+/// Renders the drawing to the window, returning the rendered image if a GIF is being recorded
 fn redraw(
     app: &App,
     display_list: &DisplayList,
     gl_context: &WindowedContext<PossiblyCurrent>,
     renderer: &mut Renderer,
-) {
+) -> Option<RgbaImage> {
     let draw_size = gl_context.window().inner_size();
     let drawing = app.drawing();
     let turtle_states = app.turtles().map(|(_, turtle)| &turtle.state);
 
     renderer.render(draw_size, display_list, drawing, turtle_states);
+
+    // The image must be read before the buffers are swapped. A minimized window has no pixels.
+    let has_pixels = draw_size.width > 0 && draw_size.height > 0;
+    let frame = if app.is_recording_gif() && has_pixels {
+        Some(renderer.read_pixels(draw_size))
+    } else {
+        None
+    };
+
     gl_context.swap_buffers().expect("unable to swap the buffer (for double buffering)");
+
+    frame
+}
+
+/// Measures the rate at which frames are rendered
+#[derive(Debug)]
+struct FpsCounter {
+    /// The time at which the current measurement started
+    start: Instant,
+    /// The number of frames rendered since the current measurement started
+    frames: u32,
+    /// The frame rate from the last completed measurement, if any
+    fps: Option<f64>,
+}
+
+impl FpsCounter {
+    fn new(start: Instant) -> Self {
+        Self {start, frames: 0, fps: None}
+    }
+
+    /// Records a frame rendered at the given time, returning true if a new frame rate was measured
+    fn record_frame(&mut self, time: Instant) -> bool {
+        self.frames += 1;
+
+        let elapsed = time.saturating_duration_since(self.start);
+        if elapsed < FPS_MEASUREMENT_PERIOD {
+            return false;
+        }
+
+        self.fps = Some(self.frames as f64 / elapsed.as_secs_f64());
+        self.start = time;
+        self.frames = 0;
+        true
+    }
+
+    /// Returns the title that the window should have given the title of the drawing
+    fn window_title(&self, title: &str, show_fps: bool) -> String {
+        match self.fps {
+            Some(fps) if show_fps => format!("{} - {:.1} FPS", title, fps),
+            _ => title.to_string(),
+        }
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_async_server(
     handle: &Handle,
     app: SharedApp,
     display_list: SharedDisplayList,
     event_loop: EventLoopNotifier,
     events_receiver: mpsc::UnboundedReceiver<Event>,
+    frames_receiver: mpsc::Receiver<()>,
     establish_connection: impl Future<Output=Result<(ServerSender, ServerReceiver), ConnectionError>> + Send + 'static,
     server_shutdown_receiver: mpsc::Receiver<()>,
 ) {
@@ -323,6 +536,7 @@ fn spawn_async_server(
             display_list,
             event_loop,
             events_receiver,
+            frames_receiver,
             server_shutdown_receiver,
         ).await;
     });
@@ -1,9 +1,13 @@
 pub mod display_list;
 pub mod export;
+pub mod gif_recording;
+pub mod grid;
+pub mod text;
 
 use glutin::dpi::PhysicalSize;
-use pathfinder_canvas::{Canvas, CanvasFontContext, Path2D, LineCap, LineJoin, FillRule};
+use pathfinder_canvas::{Canvas, CanvasFontContext, Path2D, LineCap, LineJoin, FillRule, FillStyle};
 use pathfinder_color::ColorU;
+use pathfinder_content::gradient::Gradient;
 use pathfinder_geometry::vector::{vec2f, vec2i};
 use pathfinder_gl::{GLDevice, GLVersion};
 use pathfinder_resources::embedded::EmbeddedResourceLoader;
@@ -17,15 +21,70 @@ use pathfinder_renderer::{
     },
 };
 
-use crate::{Point, Color};
+use crate::{Point, Color, TurtleShape, FillPattern};
+use crate::radians::Radians;
 
 use super::coords::ScreenPoint;
 use super::state::{DrawingState, TurtleState};
 
-use display_list::{DisplayList, DrawPrim, Line, Polygon};
+use display_list::{DisplayList, RenderPrim, Line, Polygon, PATTERN_LINE_THICKNESS};
+use export::RgbaImage;
+use grid::{GridCache, GridOverlay, GRID_COLOR, AXES_COLOR};
+
+/// The maximum ratio of the length of a miter join to the thickness of the line
+///
+/// Every renderer must use the same value so that miter joins look the same everywhere. This
+/// matches the default used by SVG.
+pub const MITER_LIMIT: f64 = 4.0;
+
+/// Returns the points of the turtle shell in logical coordinates for a turtle with the given
+/// shape, scale, position, and heading
+pub fn turtle_shell(shape: &TurtleShape, scale: f64, position: Point, heading: Radians) -> impl Iterator<Item=Point> {
+    let Point {x: turtle_x, y: turtle_y} = position;
+    let cos = heading.cos();
+    let sin = heading.sin();
+
+    shape.outline().into_iter().map(move |point| {
+        // Scale and rotate each point by the heading and add the current turtle position
+        let Point {x, y} = point * scale;
+        Point {
+            x: cos * x - sin * y + turtle_x,
+            y: sin * x + cos * y + turtle_y,
+        }
+    })
+}
+
+/// Converts a line cap from the representation in this crate to the one used in the renderer
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+fn convert_line_cap(cap: crate::LineCap) -> LineCap {
+    match cap {
+        crate::LineCap::Butt => LineCap::Butt,
+        crate::LineCap::Round => LineCap::Round,
+        crate::LineCap::Square => LineCap::Square,
+    }
+}
+
+/// Converts a line join from the representation in this crate to the one used in the renderer
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+fn convert_line_join(join: crate::LineJoin) -> LineJoin {
+    match join {
+        crate::LineJoin::Miter => LineJoin::Miter,
+        crate::LineJoin::Round => LineJoin::Round,
+        crate::LineJoin::Bevel => LineJoin::Bevel,
+    }
+}
+
+/// Converts a fill rule from the representation in this crate to the one used in the renderer
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+fn convert_fill_rule(rule: crate::FillRule) -> FillRule {
+    match rule {
+        crate::FillRule::NonZero => FillRule::Winding,
+        crate::FillRule::EvenOdd => FillRule::EvenOdd,
+    }
+}
 
 /// Converts a color from the representation in this crate to the one used in the renderer
-#[cfg_attr(any(feature = "test", test), allow(dead_code))]
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
 fn convert_color(color: Color) -> ColorU {
     let Color {red, green, blue, alpha} = color;
     ColorU {
@@ -37,16 +96,85 @@ fn convert_color(color: Color) -> ColorU {
 }
 
 /// A renderer that draws on the current OpenGL context
-#[cfg_attr(any(feature = "test", test), allow(dead_code))]
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
 pub struct Renderer {
     renderer: PathfinderRenderer<GLDevice>,
     font_context: CanvasFontContext,
     scene: SceneProxy,
     /// Information about DPI scaling: https://docs.rs/glutin/0.24.0/glutin/dpi/index.html
     dpi_scale: f64,
+    /// The grid and axes drawn during the last frame
+    grid_cache: GridCache,
+    /// Used to copy images to the window when antialiasing is disabled, created the first time
+    /// that happens
+    image_target: Option<ImageTarget>,
 }
 
-#[cfg_attr(any(feature = "test", test), allow(dead_code))]
+/// An OpenGL texture and a framebuffer that reads from it, used to copy an image to the window
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+struct ImageTarget {
+    texture: gl::types::GLuint,
+    framebuffer: gl::types::GLuint,
+}
+
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+impl ImageTarget {
+    fn new() -> Self {
+        let mut texture = 0;
+        let mut framebuffer = 0;
+        unsafe {
+            gl::GenTextures(1, &mut texture);
+            gl::GenFramebuffers(1, &mut framebuffer);
+        }
+
+        Self {texture, framebuffer}
+    }
+
+    /// Copies the given image to the window pixel for pixel, replacing everything in it
+    fn draw(&self, image: &RgbaImage) {
+        let &RgbaImage {ref rgba, width, height} = image;
+        let (width, height) = (width as i32, height as i32);
+        unsafe {
+            gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, 0);
+            gl::BindTexture(gl::TEXTURE_2D, self.texture);
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                gl::RGBA8 as i32,
+                width,
+                height,
+                0,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                rgba.as_ptr() as *const _,
+            );
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 4);
+            gl::BindTexture(gl::TEXTURE_2D, 0);
+
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.framebuffer);
+            gl::FramebufferTexture2D(gl::READ_FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, self.texture, 0);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, 0);
+            gl::Disable(gl::SCISSOR_TEST);
+            // The rows of the image go from top to bottom, but OpenGL stores them from bottom to
+            // top, so the image is flipped as it is copied. Nearest-neighbour sampling keeps every
+            // pixel exactly the color it was drawn with.
+            gl::BlitFramebuffer(0, 0, width, height, 0, height, width, 0, gl::COLOR_BUFFER_BIT, gl::NEAREST);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+    }
+}
+
+impl Drop for ImageTarget {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.framebuffer);
+            gl::DeleteTextures(1, &self.texture);
+        }
+    }
+}
+
+#[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
 impl Renderer {
     /// Creates a new renderer with the given physical size in pixels
     pub fn new(draw_size: PhysicalSize<u32>, dpi_scale: f64) -> Self {
@@ -66,6 +194,8 @@ impl Renderer {
             font_context: CanvasFontContext::from_system_source(),
             scene: SceneProxy::new(RayonExecutor),
             dpi_scale,
+            grid_cache: GridCache::default(),
+            image_target: None,
         }
     }
 
@@ -79,6 +209,39 @@ impl Renderer {
         self.dpi_scale = dpi_scale;
     }
 
+    /// Reads back the image that was just rendered, before the buffers are swapped
+    ///
+    /// This waits for the GPU to finish rendering and copies every pixel of the window, so it
+    /// should only be used when the image is actually needed.
+    pub fn read_pixels(&self, draw_size: PhysicalSize<u32>) -> RgbaImage {
+        let PhysicalSize {width, height} = draw_size;
+        let row_len = width as usize * 4;
+        let mut pixels = vec![0; row_len * height as usize];
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+            gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+            gl::ReadBuffer(gl::BACK);
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                pixels.as_mut_ptr() as *mut _,
+            );
+        }
+
+        // OpenGL stores the rows from bottom to top, and the alpha of the window is meaningless
+        // since nothing can be seen through it
+        let rgba = pixels.chunks_exact(row_len.max(1)).rev()
+            .flat_map(|row| row.chunks_exact(4).flat_map(|p| [p[0], p[1], p[2], u8::MAX]))
+            .collect();
+
+        RgbaImage {rgba, width, height}
+    }
+
     /// Draw the given primitives onto a canvas of the given size
     ///
     /// Size is passed in to ensure that it is up-to-date
@@ -89,6 +252,13 @@ impl Renderer {
         drawing: &DrawingState,
         turtles: impl Iterator<Item=&'a TurtleState>
     ) {
+        // Pathfinder always smooths the edges of shapes, so the drawing is rendered the same way
+        // it is exported instead
+        if !drawing.antialiasing {
+            self.render_crisp(draw_size, display_list, drawing, turtles);
+            return;
+        }
+
         // Set the current draw size
         self.renderer.replace_dest_framebuffer(
             DestFramebuffer::full_window(vec2i(draw_size.width as i32, draw_size.height as i32))
@@ -108,35 +278,129 @@ impl Renderer {
         // Set default options for all operations
         canvas.set_line_cap(LineCap::Round);
         canvas.set_line_join(LineJoin::Round);
+        canvas.set_miter_limit(MITER_LIMIT as f32);
 
         //TODO: Remove this line once servo/pathfinder#318 is fixed.
         //  Link: https://github.com/servo/pathfinder/issues/318
         // Need to render *something* every time to get pathfinder to even render a background
         canvas.stroke_rect(pathfinder_geometry::rect::RectF::new(vec2f(0.0, 0.0), vec2f(1.0, 1.0)));
 
-        // Draw each primitive
-        let dpi_scale = self.dpi_scale;
+        // Zooming scales everything drawn about the center, just like a higher DPI would
+        let dpi_scale = self.dpi_scale * drawing.scale;
         let center = drawing.center;
         let fb_center = (fb_size / 2.0).into();
-        for prim in display_list.iter() {
-            match prim {
-                &DrawPrim::Line(Line {start, end, thickness, color}) => {
-                    let mut path = Path2D::new();
 
+        // Draw the grid and axes beneath everything else. The grid lines stay the same thickness
+        // no matter how far the view is zoomed.
+        let grid_line_width = (grid::LINE_THICKNESS * self.dpi_scale) as f32;
+        if let Some(overlay) = self.grid_overlay(draw_size, drawing) {
+            canvas.set_line_width(grid_line_width);
+            for (lines, color) in [(&overlay.grid_lines, GRID_COLOR), (&overlay.axes, AXES_COLOR)] {
+                let mut path = Path2D::new();
+                for &(start, end) in lines {
                     path.move_to(ScreenPoint::from_logical(start, dpi_scale, center, fb_center).into());
                     path.line_to(ScreenPoint::from_logical(end, dpi_scale, center, fb_center).into());
+                }
+
+                canvas.set_stroke_style(convert_color(color));
+                canvas.stroke_path(path);
+            }
+
+            let mut path = Path2D::new();
+            for glyph in &overlay.labels {
+                path.move_to(ScreenPoint::from_logical(glyph[0], dpi_scale, center, fb_center).into());
+                for &point in &glyph[1..] {
+                    path.line_to(ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into());
+                }
+                path.close_path();
+            }
+            canvas.set_fill_style(convert_color(AXES_COLOR));
+            canvas.fill_path(path, FillRule::Winding);
+        }
+
+        // Draw each primitive
+        for prim in display_list.render_iter() {
+            match prim {
+                RenderPrim::Path(points, line) => {
+                    let &Line {thickness, color, end_color, cap, join, ..} = line;
+
+                    let start = ScreenPoint::from_logical(points[0], dpi_scale, center, fb_center);
+                    let style = if line.is_gradient() {
+                        // Gradient lines are always in a path on their own
+                        let end = ScreenPoint::from_logical(points[1], dpi_scale, center, fb_center);
+                        let mut gradient = Gradient::linear_from_points(start.into(), end.into());
+                        gradient.add_color_stop(convert_color(color), 0.0);
+                        gradient.add_color_stop(convert_color(end_color), 1.0);
+                        FillStyle::from(gradient)
+                    } else {
+                        FillStyle::from(convert_color(color))
+                    };
+
+                    // Tapered lines are also in a path on their own, and are filled instead of
+                    // stroked since their thickness changes
+                    if line.is_tapered() {
+                        let outline = line.taper_outline();
+                        if outline.is_empty() {
+                            continue;
+                        }
+
+                        let mut path = Path2D::new();
+                        path.move_to(ScreenPoint::from_logical(outline[0], dpi_scale, center, fb_center).into());
+                        for &point in &outline[1..] {
+                            path.line_to(ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into());
+                        }
+                        path.close_path();
+
+                        canvas.set_fill_style(style);
+                        canvas.fill_path(path, FillRule::Winding);
+                        continue;
+                    }
+
+                    let mut path = Path2D::new();
+                    path.move_to(start.into());
+                    for &point in &points[1..] {
+                        path.line_to(ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into());
+                    }
 
                     canvas.set_line_width((thickness * dpi_scale) as f32);
-                    canvas.set_stroke_style(convert_color(color));
+                    canvas.set_line_cap(convert_line_cap(cap));
+                    canvas.set_line_join(convert_line_join(join));
+                    canvas.set_stroke_style(style);
                     canvas.stroke_path(path);
                 },
 
-                &DrawPrim::Polygon(Polygon {ref points, fill_color}) => {
+                RenderPrim::Polygon(polygon) => {
+                    let &Polygon {ref points, fill_color, fill_rule, fill_pattern, ..} = polygon;
+
                     // Skip obviously degenerate polygons
                     if points.len() <= 2 {
                         continue;
                     }
 
+                    if fill_pattern != FillPattern::Solid {
+                        let mut path = Path2D::new();
+                        for &(start, end) in polygon.pattern_lines() {
+                            path.move_to(ScreenPoint::from_logical(start, dpi_scale, center, fb_center).into());
+                            path.line_to(ScreenPoint::from_logical(end, dpi_scale, center, fb_center).into());
+                        }
+                        canvas.set_line_width((PATTERN_LINE_THICKNESS * dpi_scale) as f32);
+                        canvas.set_line_cap(LineCap::Butt);
+                        canvas.set_stroke_style(convert_color(fill_color));
+                        canvas.stroke_path(path);
+
+                        let mut path = Path2D::new();
+                        for dot in polygon.pattern_dots() {
+                            path.move_to(ScreenPoint::from_logical(dot[0], dpi_scale, center, fb_center).into());
+                            for &point in &dot[1..] {
+                                path.line_to(ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into());
+                            }
+                            path.close_path();
+                        }
+                        canvas.set_fill_style(convert_color(fill_color));
+                        canvas.fill_path(path, FillRule::Winding);
+                        continue;
+                    }
+
                     let mut path = Path2D::new();
 
                     path.move_to(ScreenPoint::from_logical(points[0], dpi_scale, center, fb_center).into());
@@ -147,41 +411,44 @@ impl Renderer {
                     path.close_path();
 
                     canvas.set_fill_style(convert_color(fill_color));
-                    canvas.fill_path(path, FillRule::Winding);
+                    canvas.fill_path(path, convert_fill_rule(fill_rule));
                 },
             }
         }
 
-        // The turtle shell specified in logical coordinates relative to the turtle position
-        let shell = &[Point {x: 0.0, y: 15.0}, Point {x: 10.0, y: 0.0}, Point {x: 0.0, y: -15.0}];
+        // The turtle shell should not be affected by the styles of any of the lines drawn above
+        canvas.set_line_cap(LineCap::Round);
+        canvas.set_line_join(LineJoin::Round);
+
         for turtle in turtles {
-            let &TurtleState {position, heading, is_visible, ..} = turtle;
+            let &TurtleState {
+                ref shape,
+                shape_scale,
+                shape_outline_color,
+                shape_fill_color,
+                position,
+                heading,
+                is_visible,
+                ..
+            } = turtle;
             if !is_visible {
                 continue;
             }
 
-            let Point {x: turtle_x, y: turtle_y} = position;
-            let cos = heading.cos();
-            let sin = heading.sin();
-            let shell_screen_coord = |Point {x, y}| {
-                // Rotate each point by the heading and add the current turtle position
-                let point = Point {
-                    x: cos * x - sin * y + turtle_x,
-                    y: sin * x + cos * y + turtle_y,
-                };
-                ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into()
-            };
+            let mut shell = turtle_shell(shape, shape_scale, position, heading)
+                .map(|point| ScreenPoint::from_logical(point, dpi_scale, center, fb_center).into());
 
             let mut path = Path2D::new();
-            path.move_to(shell_screen_coord(shell[0]));
-            for &point in &shell[1..] {
-                path.line_to(shell_screen_coord(point));
+            // The shell always has at least one point
+            path.move_to(shell.next().unwrap());
+            for point in shell {
+                path.line_to(point);
             }
             path.close_path();
-            canvas.set_fill_style(ColorU::white());
+            canvas.set_fill_style(convert_color(shape_fill_color));
             canvas.fill_path(path.clone(), FillRule::Winding);
             canvas.set_line_width((1.0 * dpi_scale) as f32);
-            canvas.set_stroke_style(ColorU::black());
+            canvas.set_stroke_style(convert_color(shape_outline_color));
             canvas.stroke_path(path);
         }
 
@@ -189,4 +456,44 @@ impl Renderer {
         self.scene.replace_scene(canvas.into_canvas().into_scene());
         self.scene.build_and_render(&mut self.renderer, BuildOptions::default());
     }
+
+    /// Draws the given primitives without smoothing the edges of any shapes
+    ///
+    /// The image is drawn on the CPU and then copied to the window, so this is slower than
+    /// rendering with pathfinder for large drawings.
+    fn render_crisp<'a>(
+        &mut self,
+        draw_size: PhysicalSize<u32>,
+        display_list: &DisplayList,
+        drawing: &DrawingState,
+        turtles: impl Iterator<Item=&'a TurtleState>
+    ) {
+        let dpi_scale = self.dpi_scale;
+        let overlay = self.grid_overlay(draw_size, drawing).map(|overlay| (overlay, dpi_scale));
+        let PhysicalSize {width, height} = draw_size;
+        // A minimized window has no pixels, so there is nothing to draw
+        let image = match export::render_view(display_list, drawing, turtles, width, height, dpi_scale * drawing.scale, overlay) {
+            Ok(image) => image,
+            Err(_) => return,
+        };
+
+        self.image_target.get_or_insert_with(ImageTarget::new).draw(&image);
+    }
+
+    /// Returns the grid and axes that are visible in a window of the given physical size, or
+    /// `None` if neither of them are shown
+    fn grid_overlay(&mut self, draw_size: PhysicalSize<u32>, drawing: &DrawingState) -> Option<&GridOverlay> {
+        if drawing.grid.is_none() && !drawing.show_axes {
+            return None;
+        }
+
+        // Zooming scales everything drawn about the center, just like a higher DPI would
+        let dpi_scale = self.dpi_scale * drawing.scale;
+        let half_size = Point {
+            x: draw_size.width as f64 / (2.0 * dpi_scale),
+            y: draw_size.height as f64 / (2.0 * dpi_scale),
+        };
+        let center = drawing.center;
+        Some(self.grid_cache.overlay(drawing.grid, drawing.show_axes, center - half_size, center + half_size))
+    }
 }
@@ -1,12 +1,44 @@
-use std::{sync::Arc, collections::BTreeMap};
+use std::{sync::{Arc, OnceLock}, collections::{BTreeMap, BTreeSet}, f64::consts::PI};
 
 use parking_lot::Mutex;
+use serde::{Serialize, Deserialize};
 
-use crate::{Point, Color};
+use crate::{Point, Rect, Color, LineCap, LineJoin, FillRule, FillPattern};
 
 use super::super::state::Pen;
 
-#[derive(Debug, Clone)]
+/// The number of points used to approximate each round end of a tapered line
+const ROUND_CAP_POINTS: usize = 8;
+
+/// The thickness in (logical) pixels of the lines in a hatched fill pattern
+pub const PATTERN_LINE_THICKNESS: f64 = 1.0;
+/// The radius in (logical) pixels of the dots in a dotted fill pattern
+pub const PATTERN_DOT_RADIUS: f64 = 1.0;
+/// The number of points used to approximate each dot in a dotted fill pattern
+const PATTERN_DOT_POINTS: usize = 12;
+/// The largest number of rows of lines or dots in the fill pattern of a single polygon
+///
+/// The pattern of a polygon that is very large compared to its spacing is spread out so that it
+/// still has a bounded number of lines and dots to draw.
+const MAX_PATTERN_ROWS: f64 = 1000.0;
+/// The largest sine of the angle between two lines for which they are still considered collinear
+///
+/// This allows for the small rounding errors that occur when a turtle moves at an angle.
+const COLLINEAR_TOLERANCE: f64 = 1e-9;
+
+/// A gradual change in the thickness of a line
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Taper {
+    /// The thickness of the line once the taper is complete
+    pub end_thickness: f64,
+    /// The distance from the start of the line to the point where it reaches `end_thickness`
+    ///
+    /// The rest of the line is drawn with `end_thickness`. If this is longer than the line, the
+    /// line ends before its thickness reaches `end_thickness`.
+    pub length: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Line {
     /// The point where the line will begin when drawn
     pub start: Point,
@@ -14,12 +46,129 @@ pub struct Line {
     pub end: Point,
 
     /// The thickness of the line in (logical) pixels
+    ///
+    /// If the line is tapered, this is the thickness at the start of the line.
     pub thickness: f64,
+    /// The change in thickness along the line, or `None` if the line has the same thickness from
+    /// start to end
+    pub taper: Option<Taper>,
     /// The stroke color of the line
+    ///
+    /// If the line is a gradient, this is the color at the start of the line.
     pub color: Color,
+    /// The stroke color at the end of the line, the same as `color` unless the line is a gradient
+    pub end_color: Color,
+    /// The shape drawn at the ends of the line
+    pub cap: LineCap,
+    /// The shape drawn where this line meets another line that continues from its end point
+    pub join: LineJoin,
 }
 
-#[derive(Debug, Clone)]
+impl Line {
+    /// Returns true if the points of this line are finite, its colors are valid, and its
+    /// thicknesses and taper length are finite and not negative
+    pub fn is_valid(&self) -> bool {
+        let is_valid_size = |size: f64| size >= 0.0 && size.is_finite();
+
+        self.start.is_finite()
+            && self.end.is_finite()
+            && is_valid_size(self.thickness)
+            && self.taper.is_none_or(|taper| is_valid_size(taper.end_thickness) && is_valid_size(taper.length))
+            && self.color.is_valid()
+            && self.end_color.is_valid()
+    }
+
+    /// Returns true if the color of this line changes from its start to its end
+    pub fn is_gradient(&self) -> bool {
+        self.color != self.end_color
+    }
+
+    /// Returns true if the thickness of this line changes along its length
+    pub fn is_tapered(&self) -> bool {
+        self.taper.is_some()
+    }
+
+    /// Returns the thickness of the line at the given distance from its start
+    pub fn thickness_at(&self, distance: f64) -> f64 {
+        match self.taper {
+            Some(Taper {end_thickness, length}) if distance < length => {
+                self.thickness + (end_thickness - self.thickness) * distance / length
+            },
+            Some(Taper {end_thickness, ..}) => end_thickness,
+            None => self.thickness,
+        }
+    }
+
+    /// Returns the outline of this line (including its caps) as a polygon
+    ///
+    /// A stroke always has the same thickness along its entire length, so tapered lines are drawn
+    /// by filling this polygon instead. Lines with a length of zero have no outline.
+    pub fn taper_outline(&self) -> Vec<Point> {
+        let length = (self.end - self.start).len();
+        if length == 0.0 {
+            return Vec::new();
+        }
+
+        let direction = (self.end - self.start) / length;
+        let normal = Point {x: -direction.y, y: direction.x};
+
+        // The distances from the start of the line where the thickness may stop changing
+        let mut stations = vec![0.0];
+        if let Some(Taper {length: taper_length, ..}) = self.taper {
+            if taper_length > 0.0 && taper_length < length {
+                stations.push(taper_length);
+            }
+        }
+        stations.push(length);
+
+        let half_thickness = |distance| self.thickness_at(distance) / 2.0;
+        let side_point = |distance: f64, side: f64| {
+            self.start + direction * distance + normal * (side * half_thickness(distance))
+        };
+
+        // Go along one side of the line, around the end, back along the other side, and then
+        // around the start
+        let mut outline: Vec<_> = stations.iter().map(|&distance| side_point(distance, 1.0)).collect();
+        outline.extend(cap_points(self.cap, self.end, direction, normal, half_thickness(length)));
+        outline.extend(stations.iter().rev().map(|&distance| side_point(distance, -1.0)));
+        outline.extend(cap_points(self.cap, self.start, -direction, -normal, half_thickness(0.0)));
+        outline
+    }
+
+    /// Returns true if this line is drawn with the same stroke as the other line
+    fn same_stroke(&self, other: &Line) -> bool {
+        self.thickness == other.thickness
+            && self.color == other.color
+            && self.end_color == other.end_color
+            && self.taper == other.taper
+            && self.cap == other.cap
+            && self.join == other.join
+    }
+}
+
+/// Returns the points of the given cap at one end of a line, going from one side of the line to
+/// the other
+///
+/// The `direction` points away from the line and `normal` points towards the side of the line
+/// where the cap should start.
+fn cap_points(cap: LineCap, center: Point, direction: Point, normal: Point, half_thickness: f64) -> Vec<Point> {
+    match cap {
+        LineCap::Butt => Vec::new(),
+        LineCap::Square => {
+            let extension = direction * half_thickness;
+            vec![
+                center + normal * half_thickness + extension,
+                center - normal * half_thickness + extension,
+            ]
+        },
+        LineCap::Round => (1..ROUND_CAP_POINTS).map(|i| {
+            let angle = PI * i as f64 / ROUND_CAP_POINTS as f64;
+            center + normal * (half_thickness * angle.cos()) + direction * (half_thickness * angle.sin())
+        }).collect(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Polygon {
     /// The points on the polygon, guaranteed to be non-empty
     ///
@@ -28,16 +177,200 @@ pub struct Polygon {
 
     /// The fill color of the polygon
     pub fill_color: Color,
+    /// Decides which parts of the polygon are filled when its edges cross each other
+    pub fill_rule: FillRule,
+    /// The pattern drawn in the fill color, with the angle of a hatch pattern in radians
+    ///
+    /// Drawings exported before fill patterns were added are filled with a solid color.
+    #[serde(default)]
+    pub fill_pattern: FillPattern,
+
+    /// The lines and dots of the fill pattern, computed the first time they are needed
+    ///
+    /// This must be reset whenever the points of the polygon change.
+    #[serde(skip)]
+    pub pattern_cache: PatternCache,
+}
+
+/// The lines and dots of the fill pattern of a polygon, so they don't need to be computed again
+/// every time the polygon is drawn
+#[derive(Debug, Clone, Default)]
+pub struct PatternCache {
+    lines: OnceLock<Vec<(Point, Point)>>,
+    dots: OnceLock<Vec<Vec<Point>>>,
+}
+
+impl Polygon {
+    /// Returns true if the points of this polygon are finite and its fill color and fill pattern
+    /// are valid
+    ///
+    /// The angle of a hatch pattern is in radians here, but that doesn't affect whether it is
+    /// valid.
+    pub fn is_valid(&self) -> bool {
+        self.points.iter().all(|p| p.is_finite())
+            && self.fill_color.is_valid()
+            && self.fill_pattern.is_valid()
+    }
+
+    /// Returns the lines of the fill pattern of this polygon, cut off at the edges of the parts of
+    /// the polygon that are filled
+    ///
+    /// Only hatched patterns have lines.
+    pub fn pattern_lines(&self) -> &[(Point, Point)] {
+        self.pattern_cache.lines.get_or_init(|| match self.fill_pattern {
+            FillPattern::Solid | FillPattern::Dots {..} => Vec::new(),
+            FillPattern::Hatch {angle, spacing} => self.hatch_lines(angle, self.pattern_spacing(spacing)),
+            FillPattern::CrossHatch {spacing} => {
+                let spacing = self.pattern_spacing(spacing);
+                let mut lines = self.hatch_lines(PI / 4.0, spacing);
+                lines.extend(self.hatch_lines(-PI / 4.0, spacing));
+                lines
+            },
+        })
+    }
+
+    /// Returns the outline of each dot of the fill pattern of this polygon
+    ///
+    /// Only dotted patterns have dots. Every dot is centered at a point that is inside the filled
+    /// part of the polygon.
+    pub fn pattern_dots(&self) -> &[Vec<Point>] {
+        self.pattern_cache.dots.get_or_init(|| match self.fill_pattern {
+            FillPattern::Dots {spacing} => self.dots(self.pattern_spacing(spacing)),
+            _ => Vec::new(),
+        })
+    }
+
+    /// Forgets the lines and dots of the fill pattern so that they are computed again from the
+    /// current points of the polygon
+    fn reset_pattern(&mut self) {
+        self.pattern_cache = PatternCache::default();
+    }
+
+    /// Returns the spacing to use for the fill pattern of this polygon, given the spacing of the
+    /// pattern
+    ///
+    /// The spacing is widened if needed so that no line through the polygon crosses more than
+    /// `MAX_PATTERN_ROWS` rows of the pattern.
+    fn pattern_spacing(&self, spacing: f64) -> f64 {
+        let (min, max) = self.points.iter().fold(
+            (Point {x: f64::INFINITY, y: f64::INFINITY}, Point {x: f64::NEG_INFINITY, y: f64::NEG_INFINITY}),
+            |(min, max), &p| (
+                Point {x: min.x.min(p.x), y: min.y.min(p.y)},
+                Point {x: max.x.max(p.x), y: max.y.max(p.y)},
+            ),
+        );
+        let diagonal = (max.x - min.x).hypot(max.y - min.y);
+        if diagonal.is_finite() {
+            spacing.max(diagonal / MAX_PATTERN_ROWS)
+        } else {
+            spacing
+        }
+    }
+
+    /// Returns the outline of each dot in a grid of dots with the given spacing that are inside the
+    /// filled part of this polygon
+    fn dots(&self, spacing: f64) -> Vec<Vec<Point>> {
+        // Horizontal lines through each row of dots go from left to right, so the dots along each
+        // line are the ones between its ends
+        self.hatch_lines(0.0, spacing).into_iter().flat_map(|(start, end)| {
+            let first = (start.x / spacing).ceil() as i64;
+            let last = (end.x / spacing).floor() as i64;
+            (first..=last).map(move |i| Point {x: i as f64 * spacing, y: start.y})
+        }).map(|center| {
+            (0..PATTERN_DOT_POINTS).map(|i| {
+                let angle = 2.0 * PI * i as f64 / PATTERN_DOT_POINTS as f64;
+                center + Point {x: angle.cos(), y: angle.sin()} * PATTERN_DOT_RADIUS
+            }).collect()
+        }).collect()
+    }
+
+    /// Returns the parts of evenly spaced parallel lines at the given angle (in radians) that are
+    /// inside the filled part of this polygon
+    ///
+    /// One of the lines goes through the origin. Each returned line goes in the direction of the
+    /// angle.
+    fn hatch_lines(&self, angle: f64, spacing: f64) -> Vec<(Point, Point)> {
+        // Degenerate polygons are never drawn
+        if self.points.len() <= 2 {
+            return Vec::new();
+        }
+
+        let direction = Point {x: angle.cos(), y: angle.sin()};
+        let normal = Point {x: -direction.y, y: direction.x};
+        // The distance of each point along the lines and across the lines
+        let coords: Vec<_> = self.points.iter().map(|&p| (
+            p.x * direction.x + p.y * direction.y,
+            p.x * normal.x + p.y * normal.y,
+        )).collect();
+
+        let (min, max) = coords.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &(_, across)| {
+            (min.min(across), max.max(across))
+        });
+        let first = (min / spacing).ceil() as i64;
+        let last = (max / spacing).floor() as i64;
+
+        let mut lines = Vec::new();
+        let mut crossings = Vec::new();
+        for i in first..=last {
+            let offset = i as f64 * spacing;
+
+            // Find where the line crosses each edge and which way the edge goes at that point
+            crossings.clear();
+            for (j, &(along1, across1)) in coords.iter().enumerate() {
+                let (along2, across2) = coords[(j + 1) % coords.len()];
+                // Only one end of each edge counts as being on the line, so a line through a
+                // point where two edges meet only crosses one of them
+                if (across1 <= offset) != (across2 <= offset) {
+                    let t = (offset - across1) / (across2 - across1);
+                    let winding = if across2 > across1 { 1 } else { -1 };
+                    crossings.push((along1 + (along2 - along1) * t, winding));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                let is_inside = match self.fill_rule {
+                    FillRule::NonZero => winding != 0,
+                    FillRule::EvenOdd => winding % 2 != 0,
+                };
+
+                if is_inside && pair[1].0 > pair[0].0 {
+                    let point = |along: f64| direction * along + normal * offset;
+                    lines.push((point(pair[0].0), point(pair[1].0)));
+                }
+            }
+        }
+
+        lines
+    }
 }
 
 /// A drawing primitive
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DrawPrim {
     Line(Line),
     Polygon(Polygon),
 }
 
 impl DrawPrim {
+    /// Returns true if every value in this primitive can be drawn
+    pub fn is_valid(&self) -> bool {
+        match self {
+            DrawPrim::Line(line) => line.is_valid(),
+            DrawPrim::Polygon(polygon) => polygon.is_valid(),
+        }
+    }
+
+    pub fn as_line(&self) -> Option<&Line> {
+        use DrawPrim::*;
+        match self {
+            Line(line) => Some(line),
+            _ => None,
+        }
+    }
+
     pub fn as_line_mut(&mut self) -> Option<&mut Line> {
         use DrawPrim::*;
         match self {
@@ -46,6 +379,14 @@ impl DrawPrim {
         }
     }
 
+    pub fn as_polygon(&self) -> Option<&Polygon> {
+        use DrawPrim::*;
+        match self {
+            Polygon(polygon) => Some(polygon),
+            _ => None,
+        }
+    }
+
     pub fn as_polygon_mut(&mut self) -> Option<&mut Polygon> {
         use DrawPrim::*;
         match self {
@@ -55,6 +396,40 @@ impl DrawPrim {
     }
 }
 
+/// A drawing primitive prepared for rendering
+#[derive(Debug, Clone)]
+pub enum RenderPrim<'a> {
+    /// A path made up of one or more connected lines that all have the same stroke
+    ///
+    /// The path always has at least two points. The stroke (thickness, color, etc.) of every line
+    /// in the path is the same as the stroke of the given line. A line that is a gradient or that
+    /// is tapered is always in a path on its own.
+    Path(Vec<Point>, &'a Line),
+    Polygon(&'a Polygon),
+}
+
+/// The contents of a display list along with the background color that it is drawn on
+///
+/// This is everything needed to render the drawing again later, so it is the format used to
+/// export the drawing as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayListData {
+    /// The background color of the drawing
+    pub background: Color,
+    /// Every primitive in the display list, in the order that they are rendered
+    pub prims: Vec<DrawPrim>,
+}
+
+impl DisplayListData {
+    /// Returns true if the background color and every primitive can be drawn
+    ///
+    /// Data that was deserialized from JSON can contain any value, so it must be checked before it
+    /// is imported.
+    pub fn is_valid(&self) -> bool {
+        self.background.is_valid() && self.prims.iter().all(DrawPrim::is_valid)
+    }
+}
+
 /// A unique handle to one of the items in a display list
 ///
 /// Handles are guaranteed to be unique, even across removals from the display list.
@@ -72,6 +447,10 @@ pub struct DisplayList {
     items: BTreeMap<PrimHandle, DrawPrim>,
     /// The next ID inside `PrimHandle`, must be monotonic (even across removals/deletions)
     next_id: usize,
+    /// Items that are kept in the display list but are not rendered yet
+    ///
+    /// These were drawn by a turtle while its tracer was disabled.
+    hidden: BTreeSet<PrimHandle>,
 }
 
 impl DisplayList {
@@ -80,14 +459,16 @@ impl DisplayList {
     /// If a new line would not need to be drawn based on the pen configuration, `None` is
     /// returned. Otherwise, a handle to the line that will be drawn is returned.
     pub fn push_line(&mut self, start: Point, end: Point, pen: &Pen) -> Option<PrimHandle> {
-        let &Pen {is_enabled, thickness, color} = pen;
+        let &Pen {is_enabled, thickness, color, end_color, thickness_change, cap, join} = pen;
 
         // Do not draw lines for which the pen is disabled
         if !is_enabled {
             return None;
         }
 
-        let handle = self.insert(DrawPrim::Line(Line {start, end, thickness, color}));
+        let end_color = end_color.unwrap_or(color);
+        let taper = thickness_change.map(|(end_thickness, length)| Taper {end_thickness, length});
+        let handle = self.insert(DrawPrim::Line(Line {start, end, thickness, taper, color, end_color, cap, join}));
         Some(handle)
     }
 
@@ -101,9 +482,92 @@ impl DisplayList {
         line.end = end;
     }
 
+    /// Returns the `end` point of a line
+    ///
+    /// Panics if the given handle does not refer to a line primitive.
+    pub fn line_end(&self, handle: PrimHandle) -> Point {
+        let prim = self.items.get(&handle).expect("bug: invalid handle");
+        let line = prim.as_line()
+            .expect("bug: attempt to get the end of a draw primitive that was not a line");
+        line.end
+    }
+
+    /// Returns true if the line with the given handle can be extended to draw a line from `start`
+    /// to `end` with the given pen, instead of pushing a new line
+    ///
+    /// This is only possible if the new line would continue the existing line in a straight line
+    /// with exactly the same stroke, so extending it does not change what gets drawn. Only the
+    /// last item in the display list can be extended so that the draw order is preserved.
+    pub fn can_extend_line(&self, handle: PrimHandle, start: Point, end: Point, pen: &Pen) -> bool {
+        let &Pen {is_enabled, thickness, color, end_color, thickness_change, cap, join} = pen;
+        // Gradients and tapers depend on the length of the line, so they can never be extended
+        if !is_enabled || end_color.is_some() || thickness_change.is_some() {
+            return false;
+        }
+
+        let line = match self.items.iter().next_back() {
+            Some((&last, prim)) if last == handle => match prim.as_line() {
+                Some(line) => line,
+                None => return false,
+            },
+            _ => return false,
+        };
+
+        let new_line = Line {start, end, thickness, taper: None, color, end_color: color, cap, join};
+        if line.is_gradient() || !line.same_stroke(&new_line) || line.end != start {
+            return false;
+        }
+
+        // The lines must point in the same direction (the cross product is zero and the dot
+        // product is positive)
+        let prev = line.end - line.start;
+        let next = end - start;
+        let cross = prev.x * next.y - prev.y * next.x;
+        let dot = prev.x * next.x + prev.y * next.y;
+        dot > 0.0 && cross.abs() <= COLLINEAR_TOLERANCE * prev.len() * next.len()
+    }
+
+    /// Updates the stroke color at the `end` point of a line
+    ///
+    /// Panics if the given handle does not refer to a line primitive.
+    pub fn line_update_end_color(&mut self, handle: PrimHandle, end_color: Color) {
+        let prim = self.items.get_mut(&handle).expect("bug: invalid handle");
+        let line = prim.as_line_mut()
+            .expect("bug: attempt to update the end color of a draw primitive that was not a line");
+        line.end_color = end_color;
+    }
+
+    /// Pushes a new polygon with the given points into the display list
+    ///
+    /// The list of points must be non-empty. The polygon is filled with a solid color using the
+    /// nonzero fill rule.
+    pub fn push_polygon(&mut self, points: Vec<Point>, fill_color: Color) -> PrimHandle {
+        debug_assert!(!points.is_empty(), "bug: polygons must have at least one point");
+
+        self.insert(DrawPrim::Polygon(Polygon {
+            points,
+            fill_color,
+            fill_rule: FillRule::NonZero,
+            fill_pattern: FillPattern::Solid,
+            pattern_cache: PatternCache::default(),
+        }))
+    }
+
     /// Creates a polygon with one point, and pushes it into the display list
-    pub fn push_polygon_start(&mut self, start: Point, fill_color: Color) -> PrimHandle {
-        self.insert(DrawPrim::Polygon(Polygon {points: vec![start], fill_color}))
+    pub fn push_polygon_start(
+        &mut self,
+        start: Point,
+        fill_color: Color,
+        fill_rule: FillRule,
+        fill_pattern: FillPattern,
+    ) -> PrimHandle {
+        self.insert(DrawPrim::Polygon(Polygon {
+            points: vec![start],
+            fill_color,
+            fill_rule,
+            fill_pattern,
+            pattern_cache: PatternCache::default(),
+        }))
     }
 
     /// Pushes a point into a polygon with the given handle
@@ -119,6 +583,7 @@ impl DisplayList {
 
         let index = polygon.points.len();
         polygon.points.push(point);
+        polygon.reset_pattern();
         index
     }
 
@@ -136,6 +601,33 @@ impl DisplayList {
 
         // This will panic if the index is out of bounds
         polygon.points[index] = point;
+        polygon.reset_pattern();
+    }
+
+    /// Returns the number of points in the polygon with the given handle
+    ///
+    /// Panics if the given handle does not refer to a polygon primitive.
+    pub fn polygon_len(&self, handle: PrimHandle) -> usize {
+        let prim = self.items.get(&handle).expect("bug: invalid handle");
+        let polygon = prim.as_polygon()
+            .expect("bug: attempt to get the length of a draw primitive that was not a polygon");
+        polygon.points.len()
+    }
+
+    /// Removes all the points in a polygon after the given length
+    ///
+    /// Has no effect if the polygon already has `len` points or fewer. Note that the polygon must
+    /// always have at least one point, so `len` must be greater than zero.
+    ///
+    /// Panics if the given handle does not refer to a polygon primitive.
+    pub fn polygon_truncate(&mut self, handle: PrimHandle, len: usize) {
+        debug_assert!(len > 0, "bug: polygons must have at least one point");
+
+        let prim = self.items.get_mut(&handle).expect("bug: invalid handle");
+        let polygon = prim.as_polygon_mut()
+            .expect("bug: attempt to truncate a draw primitive that was not a polygon");
+        polygon.points.truncate(len);
+        polygon.reset_pattern();
     }
 
     /// Sets the fill color of a polygon to the given color
@@ -148,24 +640,136 @@ impl DisplayList {
         polygon.fill_color = fill_color;
     }
 
+    /// Pushes the given primitive into the display list
+    ///
+    /// Polygons with no points are never drawn, so they are not added to the display list and
+    /// `None` is returned.
+    pub fn push(&mut self, prim: DrawPrim) -> Option<PrimHandle> {
+        if let DrawPrim::Polygon(polygon) = &prim {
+            if polygon.points.is_empty() {
+                return None;
+            }
+        }
+
+        Some(self.insert(prim))
+    }
+
+    /// Iterates over the items in the display list in the order in which they are rendered
+    pub fn iter(&self) -> impl Iterator<Item=&DrawPrim> {
+        self.items.values()
+    }
+
+    /// Returns the smallest rectangle that contains everything in the display list, or `None` if
+    /// nothing has been drawn
+    ///
+    /// Lines are padded by half of their thickness on every side, which covers their caps but not
+    /// the tips of any sharp miter joins. Polygons with fewer than three points are not drawn, so
+    /// they are skipped.
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut bounds: Option<Rect> = None;
+        let mut include = |point: Point| {
+            bounds = Some(match bounds {
+                Some(rect) => rect.including(point),
+                None => Rect {min: point, max: point},
+            });
+        };
+
+        for prim in self.iter() {
+            match prim {
+                DrawPrim::Line(line) => {
+                    let end_thickness = line.taper.map(|taper| taper.end_thickness).unwrap_or(line.thickness);
+                    let half_thickness = line.thickness.max(end_thickness) / 2.0;
+                    let padding = Point {x: half_thickness, y: half_thickness};
+                    for &point in &[line.start, line.end] {
+                        include(point - padding);
+                        include(point + padding);
+                    }
+                },
+
+                DrawPrim::Polygon(polygon) => if polygon.points.len() >= 3 {
+                    for &point in &polygon.points {
+                        include(point);
+                    }
+                },
+            }
+        }
+
+        bounds
+    }
+
     /// Removes the given items from the display list
     pub fn remove<I: Iterator<Item=PrimHandle>>(&mut self, items: I) {
         for handle in items {
             self.items.remove(&handle);
+            self.hidden.remove(&handle);
         }
     }
 
+    /// Stops the given item from being rendered until it is shown again
+    pub fn hide(&mut self, handle: PrimHandle) {
+        self.hidden.insert(handle);
+    }
+
+    /// Returns true if the given item was hidden with `hide()` and has not been shown since
+    pub fn is_hidden(&self, handle: PrimHandle) -> bool {
+        self.hidden.contains(&handle)
+    }
+
+    /// Renders the given items again if they were hidden
+    pub fn show<I: Iterator<Item=PrimHandle>>(&mut self, items: I) {
+        for handle in items {
+            self.hidden.remove(&handle);
+        }
+    }
+
+    /// Renders every item that was hidden
+    pub fn show_all(&mut self) {
+        self.hidden.clear();
+    }
+
     /// Removes all items from the display list
     ///
     /// This invalidates all handles that have been returned so far. Continuing to use them will
     /// cause a panic. New handles created after this will still be unique.
     pub fn clear(&mut self) {
         self.items.clear();
+        self.hidden.clear();
     }
 
-    /// Iterates over the items in the display list in the order in which they should be rendered
-    pub fn iter(&self) -> impl Iterator<Item=&DrawPrim> {
-        self.items.values()
+    /// Iterates over the items in the display list in the order in which they should be rendered,
+    /// combining consecutive lines into a single path wherever they can be drawn as one
+    ///
+    /// Lines are combined if each line starts where the previous one ended and they all have the
+    /// same stroke. This is what allows the line join to be drawn at the corners between them.
+    /// Hidden items are skipped.
+    pub fn render_iter(&self) -> impl Iterator<Item=RenderPrim<'_>> {
+        let mut prims = self.items.iter()
+            .filter(move |(handle, _)| !self.hidden.contains(handle))
+            .map(|(_, prim)| prim)
+            .peekable();
+        std::iter::from_fn(move || {
+            let prim = match prims.next()? {
+                DrawPrim::Line(line) => {
+                    let mut points = vec![line.start, line.end];
+                    // Gradients and tapers are drawn along a single line, so those lines can't be
+                    // part of a longer path
+                    while let Some(DrawPrim::Line(next)) = prims.peek().copied() {
+                        if line.is_gradient() || line.is_tapered() || Some(&next.start) != points.last() || !line.same_stroke(next) {
+                            break;
+                        }
+
+                        points.push(next.end);
+                        prims.next();
+                    }
+
+                    RenderPrim::Path(points, line)
+                },
+
+                DrawPrim::Polygon(polygon) => RenderPrim::Polygon(polygon),
+            };
+
+            Some(prim)
+        })
     }
 
     /// Inserts a new primitive into the display list, returning its handle
@@ -1,16 +1,48 @@
+use std::fs::File;
 use std::fmt::Write;
+use std::io::{self, BufReader, BufWriter};
 use std::path::Path as FilePath;
 
 use thiserror::Error;
-use serde::{Serialize, Deserialize};
-use svg::node::element::{Line, Polygon, Rectangle};
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use svg::node::element::{Line, Polyline, Polygon, Rectangle, LinearGradient, Stop, Pattern, Circle};
+use tiny_skia::{
+    Pixmap,
+    Paint,
+    PathBuilder,
+    Stroke,
+    LineCap,
+    LineJoin,
+    FillRule,
+    Transform,
+    GradientStop,
+    SpreadMode,
+};
+use pdf_writer::{
+    Pdf,
+    Content,
+    Finish,
+    Name,
+    Rect,
+    Ref,
+    types::{LineCapStyle, LineJoinStyle, ColorSpaceOperand, FunctionShadingType},
+};
 
-use crate::Color;
+use crate::{Color, Point, FillPattern};
 
-use super::display_list::{DisplayList, DrawPrim, Line as DrawLine, Polygon as DrawPolygon};
+use super::{MITER_LIMIT, turtle_shell};
+use super::grid::{self, GridOverlay, GRID_COLOR, AXES_COLOR};
+use super::display_list::{
+    DisplayList,
+    RenderPrim,
+    Line as DrawLine,
+    Polygon as DrawPolygon,
+    PATTERN_LINE_THICKNESS,
+    PATTERN_DOT_RADIUS,
+};
 use super::super::{
     coords::ScreenPoint,
-    state::DrawingState,
+    state::{DrawingState, TurtleState},
 };
 
 /// Converts a color to its RGBA color string (suitable for SVG)
@@ -19,6 +51,144 @@ fn rgba(color: Color) -> String {
     format!("rgba({}, {}, {}, {})", red as u8, green as u8, blue as u8, alpha)
 }
 
+/// Converts a color to the equivalent color used for rasterization
+fn skia_color(color: Color) -> tiny_skia::Color {
+    let Color {red, green, blue, alpha} = color;
+    tiny_skia::Color::from_rgba8(red as u8, green as u8, blue as u8, (alpha * 255.0).round() as u8)
+}
+
+/// Converts a line cap to its name in SVG
+fn svg_line_cap(cap: crate::LineCap) -> &'static str {
+    match cap {
+        crate::LineCap::Butt => "butt",
+        crate::LineCap::Round => "round",
+        crate::LineCap::Square => "square",
+    }
+}
+
+/// Converts a line join to its name in SVG
+fn svg_line_join(join: crate::LineJoin) -> &'static str {
+    match join {
+        crate::LineJoin::Miter => "miter",
+        crate::LineJoin::Round => "round",
+        crate::LineJoin::Bevel => "bevel",
+    }
+}
+
+/// Converts a fill rule to its name in SVG
+fn svg_fill_rule(rule: crate::FillRule) -> &'static str {
+    match rule {
+        crate::FillRule::NonZero => "nonzero",
+        crate::FillRule::EvenOdd => "evenodd",
+    }
+}
+
+/// Creates the SVG pattern element used to fill a polygon with the given fill pattern
+///
+/// Each tile of the pattern is lined up with the given position of the origin of the drawing in
+/// the image. Lines and dots on the edge of a tile are cut off by the tile, so they are drawn on
+/// both opposite edges of the tile to make the tiles fit together.
+fn svg_fill_pattern(id: &str, pattern: FillPattern, color: Color, origin: ScreenPoint) -> Pattern {
+    let tile = |spacing: f64, degrees: f64| Pattern::new()
+        .set("id", id)
+        .set("patternUnits", "userSpaceOnUse")
+        .set("width", spacing)
+        .set("height", spacing)
+        // The y-axis of the image points down, so the angle is turned in the opposite direction
+        .set("patternTransform", format!("translate({} {}) rotate({})", origin.x, origin.y, -degrees));
+    let line = |x1: f64, y1: f64, x2: f64, y2: f64| Line::new()
+        .set("x1", x1)
+        .set("y1", y1)
+        .set("x2", x2)
+        .set("y2", y2)
+        .set("stroke", rgba(color))
+        .set("stroke-width", px(PATTERN_LINE_THICKNESS));
+    let dot = |cx: f64, cy: f64| Circle::new()
+        .set("cx", cx)
+        .set("cy", cy)
+        .set("r", PATTERN_DOT_RADIUS)
+        .set("fill", rgba(color));
+
+    match pattern {
+        FillPattern::Solid => unreachable!("bug: solid fills do not use an SVG pattern"),
+        FillPattern::Hatch {angle, spacing} => tile(spacing, angle.to_degrees())
+            .add(line(0.0, 0.0, spacing, 0.0))
+            .add(line(0.0, spacing, spacing, spacing)),
+        // Rotating the tile by 45 degrees turns its horizontal and vertical lines into diagonals
+        FillPattern::CrossHatch {spacing} => tile(spacing, 45.0)
+            .add(line(0.0, 0.0, spacing, 0.0))
+            .add(line(0.0, spacing, spacing, spacing))
+            .add(line(0.0, 0.0, 0.0, spacing))
+            .add(line(spacing, 0.0, spacing, spacing)),
+        FillPattern::Dots {spacing} => tile(spacing, 0.0)
+            .add(dot(0.0, 0.0))
+            .add(dot(spacing, 0.0))
+            .add(dot(0.0, spacing))
+            .add(dot(spacing, spacing)),
+    }
+}
+
+/// Converts a line cap to the equivalent line cap used for rasterization
+fn skia_line_cap(cap: crate::LineCap) -> LineCap {
+    match cap {
+        crate::LineCap::Butt => LineCap::Butt,
+        crate::LineCap::Round => LineCap::Round,
+        crate::LineCap::Square => LineCap::Square,
+    }
+}
+
+/// Converts a line join to the equivalent line join used for rasterization
+fn skia_line_join(join: crate::LineJoin) -> LineJoin {
+    match join {
+        crate::LineJoin::Miter => LineJoin::Miter,
+        crate::LineJoin::Round => LineJoin::Round,
+        crate::LineJoin::Bevel => LineJoin::Bevel,
+    }
+}
+
+/// Converts a fill rule to the equivalent fill rule used for rasterization
+fn skia_fill_rule(rule: crate::FillRule) -> FillRule {
+    match rule {
+        crate::FillRule::NonZero => FillRule::Winding,
+        crate::FillRule::EvenOdd => FillRule::EvenOdd,
+    }
+}
+
+/// Converts a color to the RGB components (between 0.0 and 1.0) used in PDF
+fn pdf_rgb(color: Color) -> [f32; 3] {
+    let Color {red, green, blue, ..} = color;
+    [(red / 255.0) as f32, (green / 255.0) as f32, (blue / 255.0) as f32]
+}
+
+/// Converts a line cap to the equivalent line cap style in PDF
+fn pdf_line_cap(cap: crate::LineCap) -> LineCapStyle {
+    match cap {
+        crate::LineCap::Butt => LineCapStyle::ButtCap,
+        crate::LineCap::Round => LineCapStyle::RoundCap,
+        crate::LineCap::Square => LineCapStyle::ProjectingSquareCap,
+    }
+}
+
+/// Converts a line join to the equivalent line join style in PDF
+fn pdf_line_join(join: crate::LineJoin) -> LineJoinStyle {
+    match join {
+        crate::LineJoin::Miter => LineJoinStyle::MiterJoin,
+        crate::LineJoin::Round => LineJoinStyle::RoundJoin,
+        crate::LineJoin::Bevel => LineJoinStyle::BevelJoin,
+    }
+}
+
+/// Adds the given points to the current path of the PDF content
+///
+/// The list must be non-empty
+fn pdf_path(content: &mut Content, mut points: impl Iterator<Item=ScreenPoint>) {
+    let first = points.next().expect("list must be non-empty");
+    content.move_to(first.x as f32, first.y as f32);
+    for pt in points {
+        content.line_to(pt.x as f32, pt.y as f32);
+    }
+}
+
 /// Converts a value into a string with the unit "px"
 fn px(value: f64) -> String {
     format!("{}px", value)
@@ -39,9 +209,89 @@ fn pairs(mut items: impl Iterator<Item=ScreenPoint>) -> String {
 }
 
 /// An error produced while exporting the drawing
-#[derive(Debug, Error, Serialize, Deserialize)]
-#[error("{0}")]
-pub struct ExportError(String);
+///
+/// This implements [`std::error::Error`], so it can be returned with `?` from functions that
+/// return `Result<_, Box<dyn Error>>`:
+///
+/// ```rust,no_run
+/// use std::error::Error;
+/// use turtle::Drawing;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let mut drawing = Drawing::new();
+///     let mut turtle = drawing.add_turtle();
+///     turtle.forward(100.0);
+///
+///     drawing.save_svg("line.svg")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Error)]
+pub enum ExportError {
+    /// The file could not be read or written
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The file is not in a format that can be read (e.g. an icon that is not a PNG image)
+    #[error("the file is not in a supported format")]
+    UnsupportedFormat,
+    /// The drawing could not be converted to the requested format
+    #[error("{0}")]
+    Render(String),
+}
+
+/// The form of `ExportError` sent between processes, since `io::Error` cannot be serialized
+///
+/// Only the OS error code (if any) and the message of an IO error are kept.
+#[derive(Serialize, Deserialize)]
+enum ExportErrorData {
+    Io {os_error: Option<i32>, message: String},
+    UnsupportedFormat,
+    Render(String),
+}
+
+impl Serialize for ExportError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = match self {
+            ExportError::Io(err) => ExportErrorData::Io {
+                os_error: err.raw_os_error(),
+                message: err.to_string(),
+            },
+            ExportError::UnsupportedFormat => ExportErrorData::UnsupportedFormat,
+            ExportError::Render(message) => ExportErrorData::Render(message.clone()),
+        };
+
+        data.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExportError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match ExportErrorData::deserialize(deserializer)? {
+            ExportErrorData::Io {os_error: Some(code), ..} => ExportError::Io(io::Error::from_raw_os_error(code)),
+            ExportErrorData::Io {os_error: None, message} => ExportError::Io(io::Error::other(message)),
+            ExportErrorData::UnsupportedFormat => ExportError::UnsupportedFormat,
+            ExportErrorData::Render(message) => ExportError::Render(message),
+        })
+    }
+}
+
+/// Keeps IO errors from the PNG encoder separate from errors in encoding the image
+fn png_encoding_error(err: png::EncodingError) -> ExportError {
+    match err {
+        png::EncodingError::IoError(err) => ExportError::Io(err),
+        err => ExportError::Render(err.to_string()),
+    }
+}
+
+/// Converts an error from the PNG decoder, where a format error means the file is not a PNG image
+fn png_decoding_error(err: png::DecodingError) -> ExportError {
+    match err {
+        png::DecodingError::IoError(err) => ExportError::Io(err),
+        png::DecodingError::Format(_) => ExportError::UnsupportedFormat,
+        err => ExportError::Render(err.to_string()),
+    }
+}
 
 pub fn save_svg(
     display_list: &DisplayList,
@@ -50,6 +300,10 @@ pub fn save_svg(
 ) -> Result<(), ExportError> {
     let mut document = svg::Document::new()
         .set("viewBox", (0, 0, drawing.width, drawing.height));
+    // Inherited by every shape in the image
+    if !drawing.antialiasing {
+        document = document.set("shape-rendering", "crispEdges");
+    }
 
     // set background color - https://stackoverflow.com/a/11293812/9276882
     let background = Rectangle::new()
@@ -63,42 +317,741 @@ pub fn save_svg(
         x: drawing.width as f64 / 2.0,
         y: drawing.height as f64 / 2.0,
     };
-    for prim in display_list.iter() {
+    // Used to give each gradient and fill pattern a unique ID
+    let mut gradients = 0;
+    let mut patterns = 0;
+    for prim in display_list.render_iter() {
         match prim {
-            &DrawPrim::Line(DrawLine {start, end, thickness, color}) => {
-                let start = ScreenPoint::from_logical(start, 1.0, center, image_center);
-                let end = ScreenPoint::from_logical(end, 1.0, center, image_center);
-
-                let line = Line::new()
-                    .set("x1", start.x)
-                    .set("y1", start.y)
-                    .set("x2", end.x)
-                    .set("y2", end.y)
-                    .set("stroke-linecap", "round")
-                    .set("stroke-linejoin", "round")
-                    .set("stroke", rgba(color))
-                    .set("stroke-width", px(thickness));
-
-                document = document.add(line);
+            RenderPrim::Path(points, line) => {
+                let &DrawLine {thickness, color, end_color, cap, join, ..} = line;
+
+                let mut points = points.into_iter()
+                    .map(|p| ScreenPoint::from_logical(p, 1.0, center, image_center));
+
+                // Paths are always made up of at least two points
+                if points.len() == 2 {
+                    let start = points.next().unwrap();
+                    let end = points.next().unwrap();
+
+                    // Gradient and tapered lines are always in a path on their own, so only this
+                    // case needs to handle them
+                    let paint = if line.is_gradient() {
+                        let id = format!("pen-gradient-{}", gradients);
+                        gradients += 1;
+
+                        let gradient = LinearGradient::new()
+                            .set("id", id.as_str())
+                            .set("gradientUnits", "userSpaceOnUse")
+                            .set("x1", start.x)
+                            .set("y1", start.y)
+                            .set("x2", end.x)
+                            .set("y2", end.y)
+                            .add(Stop::new().set("offset", 0).set("stop-color", rgba(color)))
+                            .add(Stop::new().set("offset", 1).set("stop-color", rgba(end_color)));
+                        document = document.add(gradient);
+
+                        format!("url(#{})", id)
+                    } else {
+                        rgba(color)
+                    };
+
+                    // A tapered line changes thickness, so it is drawn by filling its outline
+                    if line.is_tapered() {
+                        let outline = line.taper_outline();
+                        if outline.is_empty() {
+                            continue;
+                        }
+
+                        let outline = outline.into_iter()
+                            .map(|p| ScreenPoint::from_logical(p, 1.0, center, image_center));
+                        let polygon = Polygon::new()
+                            .set("points", pairs(outline))
+                            .set("fill", paint);
+
+                        document = document.add(polygon);
+                        continue;
+                    }
+
+                    let line = Line::new()
+                        .set("x1", start.x)
+                        .set("y1", start.y)
+                        .set("x2", end.x)
+                        .set("y2", end.y)
+                        .set("stroke-linecap", svg_line_cap(cap))
+                        .set("stroke-linejoin", svg_line_join(join))
+                        .set("stroke-miterlimit", MITER_LIMIT)
+                        .set("stroke", paint)
+                        .set("stroke-width", px(thickness));
+
+                    document = document.add(line);
+                } else {
+                    let polyline = Polyline::new()
+                        .set("points", pairs(points))
+                        .set("fill", "none")
+                        .set("stroke-linecap", svg_line_cap(cap))
+                        .set("stroke-linejoin", svg_line_join(join))
+                        .set("stroke-miterlimit", MITER_LIMIT)
+                        .set("stroke", rgba(color))
+                        .set("stroke-width", px(thickness));
+
+                    document = document.add(polyline);
+                }
             },
 
-            &DrawPrim::Polygon(DrawPolygon {ref points, fill_color}) => {
+            RenderPrim::Polygon(&DrawPolygon {ref points, fill_color, fill_rule, fill_pattern, ..}) => {
                 // Skip obviously degenerate polygons
                 if points.len() <= 2 {
                     continue;
                 }
 
+                let paint = if fill_pattern == FillPattern::Solid {
+                    rgba(fill_color)
+                } else {
+                    let id = format!("fill-pattern-{}", patterns);
+                    patterns += 1;
+
+                    let origin = ScreenPoint::from_logical(Point::origin(), 1.0, center, image_center);
+                    document = document.add(svg_fill_pattern(&id, fill_pattern, fill_color, origin));
+
+                    format!("url(#{})", id)
+                };
+
                 let points = points.iter()
                     .map(|&p| ScreenPoint::from_logical(p, 1.0, center, image_center));
                 let polygon = Polygon::new()
                     .set("points", pairs(points))
-                    .set("fill-rule", "nonzero")
-                    .set("fill", rgba(fill_color));
+                    .set("fill-rule", svg_fill_rule(fill_rule))
+                    .set("fill", paint);
 
                 document = document.add(polygon);
             },
         }
     }
 
-    svg::save(path, &document).map_err(|err| ExportError(err.to_string()))
+    Ok(svg::save(path, &document)?)
+}
+
+pub fn save_png(
+    display_list: &DisplayList,
+    drawing: &DrawingState,
+    path: &FilePath,
+    scale: f64,
+) -> Result<(), ExportError> {
+    let RgbaImage {rgba, width, height} = render_image(display_list, drawing, std::iter::empty(), scale)?;
+
+    let file = File::create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(png_encoding_error)?;
+    writer.write_image_data(&rgba).map_err(png_encoding_error)?;
+
+    Ok(())
+}
+
+/// Renders the drawing without a window, with the size of the image being the size of the
+/// drawing multiplied by `scale`
+///
+/// Only the given turtles are drawn on top of everything else, the same way that the window draws
+/// them. Exported images leave out the turtles and only contain what they have drawn.
+pub fn render_image<'a>(
+    display_list: &DisplayList,
+    drawing: &DrawingState,
+    turtles: impl Iterator<Item=&'a TurtleState>,
+    scale: f64,
+) -> Result<RgbaImage, ExportError> {
+    // The size of the drawing is in logical pixels, so the window's scale factor is deliberately
+    // not used here. This keeps the size of the image the same on every display.
+    let width = (drawing.width as f64 * scale).round() as u32;
+    let height = (drawing.height as f64 * scale).round() as u32;
+    render_view(display_list, drawing, turtles, width, height, scale, None)
+}
+
+/// Renders a `width` by `height` image of the part of the drawing around its center, with every
+/// logical pixel taking up `scale` pixels in the image
+///
+/// If `overlay` is given, its grid and axes are drawn beneath everything else with lines that are
+/// `line_scale` times as thick as usual. This is how the window draws itself when antialiasing is
+/// disabled, since its renderer always smooths the edges of shapes.
+pub fn render_view<'a>(
+    display_list: &DisplayList,
+    drawing: &DrawingState,
+    turtles: impl Iterator<Item=&'a TurtleState>,
+    width: u32,
+    height: u32,
+    scale: f64,
+    overlay: Option<(&GridOverlay, f64)>,
+) -> Result<RgbaImage, ExportError> {
+    let mut pixmap = Pixmap::new(width, height).ok_or_else(|| ExportError::Render(format!(
+        "unable to create a {}x{} image, try a smaller scale",
+        width,
+        height,
+    )))?;
+
+    pixmap.fill(skia_color(drawing.background));
+
+    let center = drawing.center;
+    let image_center = ScreenPoint {
+        x: width as f64 / 2.0,
+        y: height as f64 / 2.0,
+    };
+
+    if let Some((overlay, line_scale)) = overlay {
+        let mut paint = Paint {anti_alias: drawing.antialiasing, ..Paint::default()};
+        let stroke = Stroke {
+            width: (grid::LINE_THICKNESS * line_scale) as f32,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
+        for (lines, color) in [(&overlay.grid_lines, GRID_COLOR), (&overlay.axes, AXES_COLOR)] {
+            let mut path = PathBuilder::new();
+            for &(start, end) in lines {
+                let start = ScreenPoint::from_logical(start, scale, center, image_center);
+                let end = ScreenPoint::from_logical(end, scale, center, image_center);
+                path.move_to(start.x as f32, start.y as f32);
+                path.line_to(end.x as f32, end.y as f32);
+            }
+            if let Some(path) = path.finish() {
+                paint.set_color(skia_color(color));
+                pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+            }
+        }
+
+        let mut path = PathBuilder::new();
+        for glyph in &overlay.labels {
+            let mut glyph = glyph.iter()
+                .map(|&p| ScreenPoint::from_logical(p, scale, center, image_center));
+            // Every glyph has at least one point
+            let first = glyph.next().unwrap();
+            path.move_to(first.x as f32, first.y as f32);
+            for pt in glyph {
+                path.line_to(pt.x as f32, pt.y as f32);
+            }
+            path.close();
+        }
+        if let Some(path) = path.finish() {
+            paint.set_color(skia_color(AXES_COLOR));
+            pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+        }
+    }
+
+    for prim in display_list.render_iter() {
+        match prim {
+            RenderPrim::Path(points, line) => {
+                let &DrawLine {thickness, color, end_color, cap, join, ..} = line;
+
+                let points: Vec<_> = points.into_iter()
+                    .map(|p| ScreenPoint::from_logical(p, scale, center, image_center))
+                    .collect();
+
+                // Paths are always made up of at least two points
+                let start = points[0];
+
+                let mut paint = Paint::default();
+                paint.set_color(skia_color(color));
+                paint.anti_alias = drawing.antialiasing;
+
+                // Gradient lines are always in a path on their own
+                if line.is_gradient() {
+                    let end = points[1];
+                    let shader = tiny_skia::LinearGradient::new(
+                        tiny_skia::Point::from_xy(start.x as f32, start.y as f32),
+                        tiny_skia::Point::from_xy(end.x as f32, end.y as f32),
+                        vec![
+                            GradientStop::new(0.0, skia_color(color)),
+                            GradientStop::new(1.0, skia_color(end_color)),
+                        ],
+                        SpreadMode::Pad,
+                        Transform::identity(),
+                    );
+                    // No shader is created if the line is too short to have a gradient, in which
+                    // case the solid color is used instead
+                    if let Some(shader) = shader {
+                        paint.shader = shader;
+                    }
+                }
+
+                // Tapered lines are also in a path on their own, and are filled instead of stroked
+                // since their thickness changes
+                if line.is_tapered() {
+                    let mut outline = line.taper_outline().into_iter()
+                        .map(|p| ScreenPoint::from_logical(p, scale, center, image_center));
+
+                    let mut path = PathBuilder::new();
+                    // Lines of length zero have no outline
+                    let first = match outline.next() {
+                        Some(first) => first,
+                        None => continue,
+                    };
+                    path.move_to(first.x as f32, first.y as f32);
+                    for pt in outline {
+                        path.line_to(pt.x as f32, pt.y as f32);
+                    }
+                    path.close();
+                    let path = match path.finish() {
+                        Some(path) => path,
+                        None => continue,
+                    };
+
+                    pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+                    continue;
+                }
+
+                let mut path = PathBuilder::new();
+                path.move_to(start.x as f32, start.y as f32);
+                for pt in &points[1..] {
+                    path.line_to(pt.x as f32, pt.y as f32);
+                }
+                // Paths with no area (e.g. lines of length zero) cannot be drawn
+                let path = match path.finish() {
+                    Some(path) => path,
+                    None => continue,
+                };
+
+                let stroke = Stroke {
+                    width: (thickness * scale) as f32,
+                    miter_limit: MITER_LIMIT as f32,
+                    line_cap: skia_line_cap(cap),
+                    line_join: skia_line_join(join),
+                    ..Stroke::default()
+                };
+
+                pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+            },
+
+            RenderPrim::Polygon(polygon) => {
+                let &DrawPolygon {ref points, fill_color, fill_rule, fill_pattern, ..} = polygon;
+
+                // Skip obviously degenerate polygons
+                if points.len() <= 2 {
+                    continue;
+                }
+
+                let mut paint = Paint::default();
+                paint.set_color(skia_color(fill_color));
+                paint.anti_alias = drawing.antialiasing;
+
+                if fill_pattern != FillPattern::Solid {
+                    let mut path = PathBuilder::new();
+                    for &(start, end) in polygon.pattern_lines() {
+                        let start = ScreenPoint::from_logical(start, scale, center, image_center);
+                        let end = ScreenPoint::from_logical(end, scale, center, image_center);
+                        path.move_to(start.x as f32, start.y as f32);
+                        path.line_to(end.x as f32, end.y as f32);
+                    }
+                    if let Some(path) = path.finish() {
+                        let stroke = Stroke {
+                            width: (PATTERN_LINE_THICKNESS * scale) as f32,
+                            ..Stroke::default()
+                        };
+                        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+                    }
+
+                    let mut path = PathBuilder::new();
+                    for dot in polygon.pattern_dots() {
+                        let mut dot = dot.iter()
+                            .map(|&p| ScreenPoint::from_logical(p, scale, center, image_center));
+                        // Every dot has at least one point
+                        let first = dot.next().unwrap();
+                        path.move_to(first.x as f32, first.y as f32);
+                        for pt in dot {
+                            path.line_to(pt.x as f32, pt.y as f32);
+                        }
+                        path.close();
+                    }
+                    if let Some(path) = path.finish() {
+                        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+                    }
+
+                    continue;
+                }
+
+                let mut points = points.iter()
+                    .map(|&p| ScreenPoint::from_logical(p, scale, center, image_center));
+
+                let mut path = PathBuilder::new();
+                // This unwrap is safe because we checked the length of the polygon above
+                let first = points.next().unwrap();
+                path.move_to(first.x as f32, first.y as f32);
+                for pt in points {
+                    path.line_to(pt.x as f32, pt.y as f32);
+                }
+                path.close();
+                let path = match path.finish() {
+                    Some(path) => path,
+                    None => continue,
+                };
+
+                pixmap.fill_path(&path, &paint, skia_fill_rule(fill_rule), Transform::identity(), None);
+            },
+        }
+    }
+
+    for turtle in turtles {
+        let &TurtleState {
+            ref shape,
+            shape_scale,
+            shape_outline_color,
+            shape_fill_color,
+            position,
+            heading,
+            is_visible,
+            ..
+        } = turtle;
+        if !is_visible {
+            continue;
+        }
+
+        let mut shell = turtle_shell(shape, shape_scale, position, heading)
+            .map(|p| ScreenPoint::from_logical(p, scale, center, image_center));
+
+        let mut path = PathBuilder::new();
+        // The shell always has at least one point
+        let first = shell.next().unwrap();
+        path.move_to(first.x as f32, first.y as f32);
+        for pt in shell {
+            path.line_to(pt.x as f32, pt.y as f32);
+        }
+        path.close();
+        let path = match path.finish() {
+            Some(path) => path,
+            None => continue,
+        };
+
+        let mut paint = Paint::default();
+        paint.set_color(skia_color(shape_fill_color));
+        paint.anti_alias = drawing.antialiasing;
+        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+
+        // The shell is not affected by the styles of any of the lines drawn above
+        let stroke = Stroke {
+            width: scale as f32,
+            line_cap: LineCap::Round,
+            line_join: LineJoin::Round,
+            ..Stroke::default()
+        };
+        paint.set_color(skia_color(shape_outline_color));
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+    }
+
+    // The pixmap stores premultiplied colors, but images are expected to have colors that are
+    // not premultiplied
+    let rgba = pixmap.pixels().iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    Ok(RgbaImage {rgba, width, height})
+}
+
+/// A gradient drawn along a line in an exported PDF
+struct PdfGradient {
+    start: ScreenPoint,
+    end: ScreenPoint,
+    color: Color,
+    end_color: Color,
+}
+
+/// The name of the graphics state with the given index in the page resources of a PDF
+fn pdf_alpha_name(index: usize) -> String {
+    format!("A{}", index)
+}
+
+/// The name of the gradient pattern with the given index in the page resources of a PDF
+fn pdf_gradient_name(index: usize) -> String {
+    format!("G{}", index)
+}
+
+/// Sets the opacity used for both strokes and fills in the PDF content
+///
+/// PDF colors have no alpha channel, so each distinct opacity gets its own graphics state.
+fn pdf_set_alpha(content: &mut Content, alphas: &mut Vec<f32>, alpha: f64) {
+    let alpha = alpha as f32;
+    let index = match alphas.iter().position(|&other| other == alpha) {
+        Some(index) => index,
+        None => {
+            alphas.push(alpha);
+            alphas.len() - 1
+        },
+    };
+
+    content.set_parameters(Name(pdf_alpha_name(index).as_bytes()));
+}
+
+pub fn save_pdf(
+    display_list: &DisplayList,
+    drawing: &DrawingState,
+    path: &FilePath,
+) -> Result<(), ExportError> {
+    // PDF units are points, and one logical pixel is drawn as one point
+    let width = drawing.width as f32;
+    let height = drawing.height as f32;
+
+    let mut content = Content::new();
+    // The origin of a PDF page is its bottom-left corner, so the y-axis is flipped to draw in the
+    // same coordinates as the other image formats
+    content.transform([1.0, 0.0, 0.0, -1.0, 0.0, height]);
+
+    // The opacity of each graphics state and the gradients used on the page, in order
+    let mut alphas = Vec::new();
+    let mut gradients = Vec::new();
+
+    let [red, green, blue] = pdf_rgb(drawing.background);
+    content.save_state();
+    pdf_set_alpha(&mut content, &mut alphas, drawing.background.alpha);
+    content.set_fill_rgb(red, green, blue);
+    content.rect(0.0, 0.0, width, height);
+    content.fill_nonzero();
+    content.restore_state();
+
+    let center = drawing.center;
+    let image_center = ScreenPoint {
+        x: drawing.width as f64 / 2.0,
+        y: drawing.height as f64 / 2.0,
+    };
+    for prim in display_list.render_iter() {
+        // Each primitive is drawn in its own graphics state so that its opacity and line style
+        // do not affect the primitives drawn after it
+        content.save_state();
+
+        match prim {
+            RenderPrim::Path(points, line) => {
+                let &DrawLine {thickness, color, end_color, cap, join, ..} = line;
+
+                let points: Vec<_> = points.into_iter()
+                    .map(|p| ScreenPoint::from_logical(p, 1.0, center, image_center))
+                    .collect();
+
+                // A PDF gradient cannot change opacity, so the opacity at the start of the line
+                // is used for the entire line
+                pdf_set_alpha(&mut content, &mut alphas, color.alpha);
+
+                // Gradient and tapered lines are always in a path on their own
+                let gradient = if line.is_gradient() {
+                    gradients.push(PdfGradient {start: points[0], end: points[1], color, end_color});
+                    Some(pdf_gradient_name(gradients.len() - 1))
+                } else {
+                    None
+                };
+
+                // A tapered line changes thickness, so it is drawn by filling its outline
+                if line.is_tapered() {
+                    let outline = line.taper_outline();
+                    if !outline.is_empty() {
+                        match &gradient {
+                            Some(name) => {
+                                content.set_fill_color_space(ColorSpaceOperand::Pattern);
+                                content.set_fill_pattern(None, Name(name.as_bytes()));
+                            },
+                            None => {
+                                let [red, green, blue] = pdf_rgb(color);
+                                content.set_fill_rgb(red, green, blue);
+                            },
+                        }
+
+                        let outline = outline.into_iter()
+                            .map(|p| ScreenPoint::from_logical(p, 1.0, center, image_center));
+                        pdf_path(&mut content, outline);
+                        content.close_path();
+                        content.fill_nonzero();
+                    }
+
+                    content.restore_state();
+                    continue;
+                }
+
+                match &gradient {
+                    Some(name) => {
+                        content.set_stroke_color_space(ColorSpaceOperand::Pattern);
+                        content.set_stroke_pattern(None, Name(name.as_bytes()));
+                    },
+                    None => {
+                        let [red, green, blue] = pdf_rgb(color);
+                        content.set_stroke_rgb(red, green, blue);
+                    },
+                }
+
+                content.set_line_width(thickness as f32);
+                content.set_line_cap(pdf_line_cap(cap));
+                content.set_line_join(pdf_line_join(join));
+                content.set_miter_limit(MITER_LIMIT as f32);
+
+                // Paths are always made up of at least two points
+                pdf_path(&mut content, points.into_iter());
+                content.stroke();
+            },
+
+            RenderPrim::Polygon(polygon) => {
+                let &DrawPolygon {ref points, fill_color, fill_rule, fill_pattern, ..} = polygon;
+
+                // Skip obviously degenerate polygons
+                if points.len() > 2 && fill_pattern != FillPattern::Solid {
+                    pdf_set_alpha(&mut content, &mut alphas, fill_color.alpha);
+                    let [red, green, blue] = pdf_rgb(fill_color);
+                    content.set_stroke_rgb(red, green, blue);
+                    content.set_fill_rgb(red, green, blue);
+                    content.set_line_width(PATTERN_LINE_THICKNESS as f32);
+
+                    let lines = polygon.pattern_lines();
+                    for &(start, end) in lines {
+                        let start = ScreenPoint::from_logical(start, 1.0, center, image_center);
+                        let end = ScreenPoint::from_logical(end, 1.0, center, image_center);
+                        content.move_to(start.x as f32, start.y as f32);
+                        content.line_to(end.x as f32, end.y as f32);
+                    }
+                    if !lines.is_empty() {
+                        content.stroke();
+                    }
+
+                    let dots = polygon.pattern_dots();
+                    for dot in dots {
+                        let dot = dot.iter()
+                            .map(|&p| ScreenPoint::from_logical(p, 1.0, center, image_center));
+                        pdf_path(&mut content, dot);
+                        content.close_path();
+                    }
+                    if !dots.is_empty() {
+                        content.fill_nonzero();
+                    }
+                } else if points.len() > 2 {
+                    pdf_set_alpha(&mut content, &mut alphas, fill_color.alpha);
+                    let [red, green, blue] = pdf_rgb(fill_color);
+                    content.set_fill_rgb(red, green, blue);
+
+                    let points = points.iter()
+                        .map(|&p| ScreenPoint::from_logical(p, 1.0, center, image_center));
+                    pdf_path(&mut content, points);
+                    content.close_path();
+                    match fill_rule {
+                        crate::FillRule::NonZero => content.fill_nonzero(),
+                        crate::FillRule::EvenOdd => content.fill_even_odd(),
+                    };
+                }
+            },
+        }
+
+        content.restore_state();
+    }
+
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let content_id = Ref::new(4);
+    let mut next_id = Ref::new(5);
+    let alpha_ids: Vec<_> = alphas.iter().map(|_| next_id.bump()).collect();
+    let gradient_ids: Vec<_> = gradients.iter().map(|_| (next_id.bump(), next_id.bump())).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+
+    let mut page = pdf.page(page_id);
+    page.parent(page_tree_id)
+        .media_box(Rect::new(0.0, 0.0, width, height))
+        .contents(content_id);
+    let mut resources = page.resources();
+    let mut states = resources.ext_g_states();
+    for (i, &id) in alpha_ids.iter().enumerate() {
+        states.pair(Name(pdf_alpha_name(i).as_bytes()), id);
+    }
+    states.finish();
+    let mut patterns = resources.patterns();
+    for (i, &(pattern_id, _)) in gradient_ids.iter().enumerate() {
+        patterns.pair(Name(pdf_gradient_name(i).as_bytes()), pattern_id);
+    }
+    patterns.finish();
+    resources.finish();
+    page.finish();
+
+    pdf.stream(content_id, &content.finish());
+
+    for (&alpha, &id) in alphas.iter().zip(&alpha_ids) {
+        pdf.ext_graphics(id).stroking_alpha(alpha).non_stroking_alpha(alpha);
+    }
+
+    for (gradient, &(pattern_id, function_id)) in gradients.iter().zip(&gradient_ids) {
+        let &PdfGradient {start, end, color, end_color} = gradient;
+
+        let mut pattern = pdf.shading_pattern(pattern_id);
+        // Patterns are positioned relative to the page, not the flipped coordinates of the content
+        pattern.matrix([1.0, 0.0, 0.0, -1.0, 0.0, height]);
+        let mut shading = pattern.function_shading();
+        shading.shading_type(FunctionShadingType::Axial);
+        shading.color_space().device_rgb();
+        shading.function(function_id);
+        shading.coords([start.x as f32, start.y as f32, end.x as f32, end.y as f32]);
+        shading.extend([true, true]);
+        shading.finish();
+        pattern.finish();
+
+        pdf.exponential_function(function_id)
+            .domain([0.0, 1.0])
+            .c0(pdf_rgb(color))
+            .c1(pdf_rgb(end_color))
+            .n(1.0);
+    }
+
+    Ok(std::fs::write(path, pdf.finish())?)
+}
+
+/// An image with 4 bytes (red, green, blue, alpha) per pixel, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Loads a PNG image of any color type and bit depth, converting it to 8-bit RGBA
+pub fn load_png(path: &FilePath) -> Result<RgbaImage, ExportError> {
+    let file = File::open(path)?;
+    let mut decoder = png::Decoder::new(BufReader::new(file));
+    // Expands indexed colors and transparency chunks, and reduces 16-bit channels to 8 bits
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info().map_err(png_decoding_error)?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(png_decoding_error)?;
+    let pixels = &buffer[..info.buffer_size()];
+
+    use png::ColorType::*;
+    let rgba = match info.color_type {
+        Rgba => pixels.to_vec(),
+        Rgb => pixels.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], u8::MAX]).collect(),
+        GrayscaleAlpha => pixels.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        Grayscale => pixels.iter().flat_map(|&g| [g, g, g, u8::MAX]).collect(),
+        Indexed => unreachable!("bug: indexed colors should have been expanded while decoding"),
+    };
+
+    Ok(RgbaImage {rgba, width: info.width, height: info.height})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crisp_view_has_no_blended_grid_pixels() {
+        let display_list = DisplayList::default();
+        let mut drawing = DrawingState {width: 40, height: 40, ..DrawingState::default()};
+        let overlay = grid::grid_overlay(Some(10.0), false, Point {x: -20.0, y: -20.0}, Point {x: 20.0, y: 20.0});
+        let colors = |drawing: &DrawingState| {
+            let image = render_view(&display_list, drawing, std::iter::empty(), 40, 40, 1.0, Some((&overlay, 1.0))).unwrap();
+            let mut colors: Vec<_> = image.rgba.chunks_exact(4).map(|pixel| pixel.to_vec()).collect();
+            colors.sort();
+            colors.dedup();
+            colors
+        };
+
+        // The background and the grid lines, with nothing in between
+        drawing.antialiasing = false;
+        assert_eq!(colors(&drawing).len(), 2);
+
+        // Grid lines that fall between two pixels are blended into both of them
+        drawing.antialiasing = true;
+        assert!(colors(&drawing).len() > 2);
+    }
 }
@@ -0,0 +1,203 @@
+use std::fs::File;
+use std::convert::TryFrom;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::time::{Instant, Duration};
+
+use super::export::{ExportError, RgbaImage};
+
+/// Trades off the quality of the color palette of each frame for encoding speed, in the range
+/// 1 (best quality, slowest) to 30 (worst quality, fastest)
+const QUANTIZATION_SPEED: i32 = 10;
+
+/// The shortest frame delay that GIF viewers reliably respect, in hundredths of a second
+///
+/// Most browsers show frames with a shorter delay for 1/10th of a second instead.
+const MIN_FRAME_DELAY_CENTIS: u16 = 2;
+
+/// A frame of the recording along with the time at which it started being shown
+#[derive(Debug)]
+struct GifFrame {
+    start: Instant,
+    image: RgbaImage,
+}
+
+/// The frames of the window captured since the GIF recording started
+///
+/// The window is rendered far more often than a GIF can show frames, so frames are downsampled to
+/// keep at least the frame delay between the start of each frame. The most recent frame rendered
+/// too soon after the previous one is held back so that the final state of the drawing is never
+/// lost.
+#[derive(Debug)]
+pub struct GifRecording {
+    /// The path to save the GIF to when the recording stops
+    path: PathBuf,
+    /// The time between frames at the requested frame rate
+    frame_delay: Duration,
+    /// The frames that will be saved, in order
+    frames: Vec<GifFrame>,
+    /// The most recently rendered frame, if it came too soon after the last frame in `frames`
+    pending: Option<GifFrame>,
+}
+
+impl GifRecording {
+    /// Starts a recording that will be saved to the given path at (at most) the given frame rate
+    pub fn new(path: PathBuf, fps: f64) -> Self {
+        Self {
+            path,
+            frame_delay: Duration::from_secs_f64(1.0 / fps),
+            frames: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Adds a frame that was rendered at the given time
+    #[cfg_attr(any(feature = "test", feature = "embedded"), allow(dead_code))] // Used in main thread, but not for tests or embedded drawings
+    pub fn push_frame(&mut self, time: Instant, image: RgbaImage) {
+        let frame = GifFrame {start: time, image};
+
+        let last_start = match self.frames.last() {
+            Some(last) => last.start,
+            None => {
+                self.frames.push(frame);
+                return;
+            },
+        };
+
+        let next_start = last_start + self.frame_delay;
+        if time < next_start {
+            self.pending = Some(frame);
+            return;
+        }
+
+        // The pending frame was on screen until now, so it gets the next slot and this frame
+        // may have to wait for the slot after that
+        if let Some(mut pending) = self.pending.take() {
+            pending.start = next_start;
+            self.frames.push(pending);
+
+            if time < next_start + self.frame_delay {
+                self.pending = Some(frame);
+                return;
+            }
+        }
+
+        self.frames.push(frame);
+    }
+
+    /// Encodes every captured frame and saves the GIF to the path given when it was started
+    ///
+    /// Each frame is shown until the time at which the next frame was rendered, so pauses in the
+    /// drawing are kept. The GIF loops forever.
+    pub fn save(mut self) -> Result<(), ExportError> {
+        if let Some(mut pending) = self.pending.take() {
+            if let Some(last) = self.frames.last() {
+                pending.start = last.start + self.frame_delay;
+            }
+            self.frames.push(pending);
+        }
+
+        if self.frames.is_empty() {
+            return Err(ExportError::Render("no frames were captured, the window may not have been redrawn during the recording".to_string()));
+        }
+
+        // Frames may differ in size if the window was resized during the recording
+        let width = self.frames.iter().map(|frame| frame.image.width).max().unwrap_or(0);
+        let height = self.frames.iter().map(|frame| frame.image.height).max().unwrap_or(0);
+        let to_u16 = |size: u32| u16::try_from(size).map_err(|_| ExportError::Render(format!(
+            "unable to save a {}x{} GIF, the window is too large",
+            width,
+            height,
+        )));
+        let (width, height) = (to_u16(width)?, to_u16(height)?);
+
+        let file = File::create(&self.path)?;
+        let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, &[])
+            .map_err(gif_error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_error)?;
+
+        let starts: Vec<_> = self.frames.iter().map(|frame| frame.start).collect();
+        let final_delay = self.frame_delay;
+        for (i, frame) in self.frames.into_iter().enumerate() {
+            let delay = starts.get(i + 1).map(|&next| next - starts[i]).unwrap_or(final_delay);
+
+            let GifFrame {image: RgbaImage {mut rgba, width, height}, ..} = frame;
+            // Both sizes are at most the size of the GIF, which was already checked to fit
+            let mut gif_frame = gif::Frame::from_rgba_speed(width as u16, height as u16, &mut rgba, QUANTIZATION_SPEED);
+            gif_frame.delay = delay_centis(delay);
+            encoder.write_frame(&gif_frame).map_err(gif_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Keeps IO errors from the GIF encoder separate from errors in encoding the frames
+fn gif_error(err: gif::EncodingError) -> ExportError {
+    match err {
+        gif::EncodingError::Io(err) => ExportError::Io(err),
+        err => ExportError::Render(err.to_string()),
+    }
+}
+
+/// Converts a frame delay to the hundredths of a second used by GIF
+fn delay_centis(delay: Duration) -> u16 {
+    let centis = (delay.as_secs_f64() * 100.0).round();
+    centis.max(MIN_FRAME_DELAY_CENTIS as f64).min(u16::MAX as f64) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(value: u8) -> RgbaImage {
+        RgbaImage {rgba: vec![value; 2 * 2 * 4], width: 2, height: 2}
+    }
+
+    #[test]
+    fn frames_are_downsampled() {
+        let start = Instant::now();
+        let ms = |millis| start + Duration::from_millis(millis);
+
+        // 10 FPS is one frame every 100ms
+        let mut recording = GifRecording::new(PathBuf::new(), 10.0);
+        recording.push_frame(ms(0), image(0));
+        recording.push_frame(ms(16), image(1));
+        recording.push_frame(ms(33), image(2));
+        assert_eq!(recording.frames.len(), 1);
+
+        // The held back frame is kept until the drawing changes again after a pause
+        recording.push_frame(ms(2000), image(3));
+        let starts: Vec<_> = recording.frames.iter().map(|frame| frame.start - start).collect();
+        assert_eq!(starts, vec![Duration::from_millis(0), Duration::from_millis(100), Duration::from_millis(2000)]);
+        assert_eq!(recording.frames[1].image, image(2));
+        assert!(recording.pending.is_none());
+    }
+
+    #[test]
+    fn save_writes_every_frame() {
+        let path = std::env::temp_dir().join(format!("turtle-recording-{}.gif", std::process::id()));
+        let start = Instant::now();
+
+        let mut recording = GifRecording::new(path.clone(), 20.0);
+        recording.push_frame(start, image(0));
+        recording.push_frame(start + Duration::from_millis(10), image(255));
+        recording.save().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new().read_info(File::open(&path).unwrap()).unwrap();
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        // The last frame was held back and shown one frame delay (50ms) after the first
+        assert_eq!(delays, vec![5, 5]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn save_without_frames_fails() {
+        let recording = GifRecording::new(PathBuf::new(), 10.0);
+        assert!(recording.save().is_err());
+    }
+}
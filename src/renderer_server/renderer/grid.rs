@@ -0,0 +1,173 @@
+use crate::{Color, Point, TextAlign};
+
+use super::text::text_polygons;
+
+/// The color of the grid lines, faint enough to stay out of the way on most backgrounds
+pub const GRID_COLOR: Color = Color {red: 0.0, green: 0.0, blue: 0.0, alpha: 0.12};
+/// The color of the axes and their tick labels
+pub const AXES_COLOR: Color = Color {red: 0.0, green: 0.0, blue: 0.0, alpha: 0.5};
+/// The thickness of the grid lines and axes in logical pixels
+pub const LINE_THICKNESS: f64 = 1.0;
+
+/// The font size of the tick labels on the axes
+const LABEL_FONT_SIZE: f64 = 10.0;
+/// Grid lines any closer than this (in logical pixels) would just cover the whole window, so a
+/// grid that dense is not drawn at all
+const MIN_GRID_SPACING: f64 = 4.0;
+/// The minimum distance between two tick labels on the same axis in logical pixels
+const MIN_LABEL_SPACING: f64 = 50.0;
+/// The distance between tick labels when the axes are shown without a grid
+const DEFAULT_LABEL_SPACING: f64 = 100.0;
+
+/// The grid lines, axes, and tick labels that are visible in part of the drawing
+#[derive(Debug, Default)]
+pub struct GridOverlay {
+    /// The start and end of each grid line, in logical coordinates
+    pub grid_lines: Vec<(Point, Point)>,
+    /// The start and end of each visible axis, in logical coordinates
+    pub axes: Vec<(Point, Point)>,
+    /// The glyphs of every tick label as polygons, in logical coordinates
+    pub labels: Vec<Vec<Point>>,
+}
+
+/// Lays out the grid (if `grid` is the spacing between grid lines) and the axes (if `show_axes`
+/// is true) so that they cover the rectangle from `min` to `max` in logical coordinates
+///
+/// The tick labels are placed at multiples of the grid spacing, skipping lines as needed to keep
+/// the labels from overlapping.
+pub fn grid_overlay(grid: Option<f64>, show_axes: bool, min: Point, max: Point) -> GridOverlay {
+    let mut overlay = GridOverlay::default();
+
+    if let Some(spacing) = grid {
+        if spacing >= MIN_GRID_SPACING {
+            overlay.grid_lines.extend(multiples(spacing, min.x, max.x)
+                .map(|x| (Point {x, y: min.y}, Point {x, y: max.y})));
+            overlay.grid_lines.extend(multiples(spacing, min.y, max.y)
+                .map(|y| (Point {x: min.x, y}, Point {x: max.x, y})));
+        }
+    }
+
+    if !show_axes {
+        return overlay;
+    }
+
+    if min.y <= 0.0 && 0.0 <= max.y {
+        overlay.axes.push((Point {x: min.x, y: 0.0}, Point {x: max.x, y: 0.0}));
+    }
+    if min.x <= 0.0 && 0.0 <= max.x {
+        overlay.axes.push((Point {x: 0.0, y: min.y}, Point {x: 0.0, y: max.y}));
+    }
+
+    let spacing = grid.unwrap_or(DEFAULT_LABEL_SPACING);
+    let label_spacing = spacing * (MIN_LABEL_SPACING / spacing).ceil();
+
+    // Labels are placed just below the x-axis and just to the left of the y-axis. The origin is
+    // left unlabelled so that it doesn't get two labels.
+    for x in multiples(label_spacing, min.x, max.x).filter(|&x| x != 0.0) {
+        let position = Point {x, y: -(LABEL_FONT_SIZE + 2.0)};
+        overlay.labels.extend(text_polygons(&format_label(x), LABEL_FONT_SIZE, TextAlign::Center, position));
+    }
+    for y in multiples(label_spacing, min.y, max.y).filter(|&y| y != 0.0) {
+        // Move the baseline down so that the label is roughly centered on the tick
+        let position = Point {x: -4.0, y: y - LABEL_FONT_SIZE * 0.35};
+        overlay.labels.extend(text_polygons(&format_label(y), LABEL_FONT_SIZE, TextAlign::Right, position));
+    }
+
+    overlay
+}
+
+/// Remembers the most recently laid out grid overlay so that it is only laid out again when the
+/// part of the drawing that it covers changes
+///
+/// Laying out the tick labels is expensive, and most frames are drawn without the view changing.
+#[derive(Debug, Default)]
+pub struct GridCache {
+    /// The arguments that the cached overlay was laid out with
+    key: Option<(Option<f64>, bool, Point, Point)>,
+    overlay: GridOverlay,
+}
+
+impl GridCache {
+    /// Returns the same overlay as `grid_overlay`, reusing the previous overlay if the arguments
+    /// are the same as last time
+    pub fn overlay(&mut self, grid: Option<f64>, show_axes: bool, min: Point, max: Point) -> &GridOverlay {
+        let key = (grid, show_axes, min, max);
+        if self.key != Some(key) {
+            self.overlay = grid_overlay(grid, show_axes, min, max);
+            self.key = Some(key);
+        }
+
+        &self.overlay
+    }
+}
+
+/// Returns every multiple of `spacing` between `min` and `max` (inclusive)
+fn multiples(spacing: f64, min: f64, max: f64) -> impl Iterator<Item=f64> {
+    let first = (min / spacing).ceil() as i64;
+    let last = (max / spacing).floor() as i64;
+    (first..=last).map(move |i| i as f64 * spacing)
+}
+
+/// Formats the value of a tick label without the rounding errors that come from multiplying by a
+/// fractional spacing
+fn format_label(value: f64) -> String {
+    let value = (value * 1e6).round() / 1e6;
+    format!("{}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_covers_bounds() {
+        let min = Point {x: -100.0, y: -50.0};
+        let max = Point {x: 100.0, y: 75.0};
+
+        let overlay = grid_overlay(Some(25.0), false, min, max);
+        // 9 vertical lines from -100 to 100 and 6 horizontal lines from -50 to 75
+        assert_eq!(overlay.grid_lines.len(), 9 + 6);
+        assert!(overlay.axes.is_empty());
+        assert!(overlay.labels.is_empty());
+
+        // Grids that are too dense are not drawn
+        let overlay = grid_overlay(Some(0.5), false, min, max);
+        assert!(overlay.grid_lines.is_empty());
+    }
+
+    #[test]
+    fn axes_only_drawn_when_visible() {
+        let overlay = grid_overlay(None, true, Point {x: -100.0, y: -100.0}, Point {x: 100.0, y: 100.0});
+        assert_eq!(overlay.axes.len(), 2);
+        assert!(!overlay.labels.is_empty());
+
+        // The y-axis is off to the left of these bounds
+        let overlay = grid_overlay(None, true, Point {x: 10.0, y: -100.0}, Point {x: 200.0, y: 100.0});
+        assert_eq!(overlay.axes, vec![(Point {x: 10.0, y: 0.0}, Point {x: 200.0, y: 0.0})]);
+    }
+
+    #[test]
+    fn cache_lays_out_again_when_view_changes() {
+        let mut cache = GridCache::default();
+        let min = Point {x: -100.0, y: -100.0};
+        let max = Point {x: 100.0, y: 100.0};
+
+        let labels = cache.overlay(Some(25.0), true, min, max).labels.clone();
+        assert_eq!(cache.overlay(Some(25.0), true, min, max).labels, labels);
+
+        // Zooming out shows more labels
+        let (min, max) = (min * 2.0, max * 2.0);
+        let overlay = cache.overlay(Some(25.0), true, min, max);
+        assert_eq!(overlay.labels, grid_overlay(Some(25.0), true, min, max).labels);
+        assert!(overlay.labels.len() > labels.len());
+
+        let overlay = cache.overlay(None, false, min, max);
+        assert!(overlay.labels.is_empty() && overlay.grid_lines.is_empty());
+    }
+
+    #[test]
+    fn labels_are_rounded() {
+        assert_eq!(format_label(3.0 * 0.1), "0.3");
+        assert_eq!(format_label(-200.0), "-200");
+    }
+}
@@ -0,0 +1,161 @@
+use once_cell::sync::OnceCell;
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+use crate::{Point, TextAlign};
+
+/// The font used to draw all text (Roboto, licensed under the Apache License 2.0)
+static FONT_DATA: &[u8] = include_bytes!("fonts/Roboto-Regular.ttf");
+
+/// The parsed font, initialized the first time any text is drawn
+static FONT: OnceCell<Face<'static>> = OnceCell::new();
+
+/// The number of line segments used to approximate each curve in a glyph outline
+const CURVE_SEGMENTS: usize = 8;
+
+/// Converts glyph outlines from font units into a list of contours in logical coordinates
+struct ContourBuilder {
+    /// The position of the glyph origin in logical coordinates
+    origin: Point,
+    /// The number of logical pixels per font unit
+    scale: f64,
+    /// The contours that have been completed so far
+    contours: Vec<Vec<Point>>,
+    /// The contour currently being built
+    current: Vec<Point>,
+}
+
+impl ContourBuilder {
+    fn new(origin: Point, scale: f64) -> Self {
+        Self {
+            origin,
+            scale,
+            contours: Vec::new(),
+            current: Vec::new(),
+        }
+    }
+
+    fn to_logical(&self, x: f32, y: f32) -> Point {
+        // Font units and logical coordinates both have the y-axis pointing upwards
+        Point {
+            x: self.origin.x + x as f64 * self.scale,
+            y: self.origin.y + y as f64 * self.scale,
+        }
+    }
+
+    fn last_point(&self) -> Point {
+        *self.current.last().expect("bug: glyph outline drew a curve before moving to a point")
+    }
+
+    fn finish_contour(&mut self) {
+        if !self.current.is_empty() {
+            let contour = std::mem::take(&mut self.current);
+            self.contours.push(contour);
+        }
+    }
+}
+
+impl OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish_contour();
+        let point = self.to_logical(x, y);
+        self.current.push(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.to_logical(x, y);
+        self.current.push(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let start = self.last_point();
+        let control = self.to_logical(x1, y1);
+        let end = self.to_logical(x, y);
+
+        for i in 1..=CURVE_SEGMENTS {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let point = start.lerp(control, t).lerp(control.lerp(end, t), t);
+            self.current.push(point);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let start = self.last_point();
+        let control1 = self.to_logical(x1, y1);
+        let control2 = self.to_logical(x2, y2);
+        let end = self.to_logical(x, y);
+
+        for i in 1..=CURVE_SEGMENTS {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let a = start.lerp(control1, t);
+            let b = control1.lerp(control2, t);
+            let c = control2.lerp(end, t);
+            let point = a.lerp(b, t).lerp(b.lerp(c, t), t);
+            self.current.push(point);
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish_contour();
+    }
+}
+
+/// Joins the contours of a glyph into a single polygon
+///
+/// Each contour is connected to the start of the first contour by a pair of edges that go there
+/// and back again. Those edges cancel each other out when the polygon is filled with the nonzero
+/// winding rule, so the holes in glyphs like "o" are preserved.
+fn join_contours(contours: Vec<Vec<Point>>) -> Option<Vec<Point>> {
+    let mut contours = contours.into_iter();
+    let mut polygon = contours.next()?;
+    let anchor = polygon[0];
+    polygon.push(anchor);
+
+    for contour in contours {
+        let start = contour[0];
+        polygon.extend(contour);
+        polygon.push(start);
+        polygon.push(anchor);
+    }
+
+    Some(polygon)
+}
+
+/// Lays out the given text and returns a polygon for each glyph, in logical coordinates
+///
+/// The first line of text is drawn with its baseline at `position`. Each following line is drawn
+/// below the previous one. The `font_size` is the height of the font's em square in logical pixels.
+pub fn text_polygons(text: &str, font_size: f64, align: TextAlign, position: Point) -> Vec<Vec<Point>> {
+    let face = FONT.get_or_init(|| Face::from_slice(FONT_DATA, 0)
+        .expect("bug: the bundled font should always be valid"));
+
+    let scale = font_size / face.units_per_em() as f64;
+    let line_height = (face.ascender() as f64 - face.descender() as f64 + face.line_gap() as f64) * scale;
+    let glyph = |c| face.glyph_index(c).unwrap_or(GlyphId(0));
+    let advance = |glyph_id| face.glyph_hor_advance(glyph_id).unwrap_or(0) as f64 * scale;
+
+    let mut polygons = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let width: f64 = line.chars().map(|c| advance(glyph(c))).sum();
+        let mut x = match align {
+            TextAlign::Left => position.x,
+            TextAlign::Center => position.x - width / 2.0,
+            TextAlign::Right => position.x - width,
+        };
+        let y = position.y - i as f64 * line_height;
+
+        for c in line.chars() {
+            let glyph_id = glyph(c);
+
+            let mut builder = ContourBuilder::new(Point {x, y}, scale);
+            // Glyphs like spaces have no outline, but still take up space
+            if face.outline_glyph(glyph_id, &mut builder).is_some() {
+                builder.finish_contour();
+                polygons.extend(join_contours(builder.contours));
+            }
+
+            x += advance(glyph_id);
+        }
+    }
+
+    polygons
+}
@@ -1,11 +1,11 @@
-#[cfg(not(any(feature = "test", test)))]
+#[cfg(not(any(feature = "test", feature = "embedded", test)))]
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use super::backend::RendererServer;
 
 /// `start()` must be called once from the main thread, but it can be called after that any number
 /// of times. This flag helps ensure that the main thread check only executes the first time.
-#[cfg(not(any(feature = "test", test)))]
+#[cfg(not(any(feature = "test", feature = "embedded", test)))]
 static START_RAN_ONCE: AtomicBool = AtomicBool::new(false);
 
 /// Start the turtle window in advance
@@ -44,13 +44,27 @@ static START_RAN_ONCE: AtomicBool = AtomicBool::new(false);
 /// }
 /// ```
 ///
+/// # Headless Mode
+///
+/// Set the `TURTLE_HEADLESS` environment variable to `1` to run your program without opening a
+/// window. Everything else works the same way, so methods like
+/// [`save_svg()`](struct.Drawing.html#method.save_svg) and
+/// [`save_png()`](struct.Drawing.html#method.save_png) can be used to generate images on a
+/// machine with no display (e.g. in CI). No events (e.g. mouse clicks) ever occur in headless mode,
+/// so methods like [`wait_for_click()`](struct.Turtle.html#method.wait_for_click) will wait
+/// forever.
+///
+/// ```bash
+/// TURTLE_HEADLESS=1 cargo run --example squares
+/// ```
+///
 /// [`Turtle::new()`]: struct.Turtle.html#method.new
 pub fn start() {
     // This check is performed on all platforms to help avoid compatibility hazards that may
     // accidentally make it harder to run a turtle program on a different platform. The check is
     // not foolproof and there is no way to verify that start() is called at the beginning of
     // main() in all cases. This is just to help in the cases where we can detect something.
-    #[cfg(not(any(feature = "test", test)))]
+    #[cfg(not(any(feature = "test", feature = "embedded", test)))]
     if !START_RAN_ONCE.swap(true, Ordering::SeqCst) {
         assert_main_thread();
     }
@@ -58,7 +72,7 @@ pub fn start() {
     RendererServer::start();
 }
 
-#[cfg(not(any(feature = "test", test)))]
+#[cfg(not(any(feature = "test", feature = "embedded", test)))]
 fn assert_main_thread() {
     // This check isn't foolproof. Someone can always create a thread named "main".
     if std::thread::current().name().unwrap_or("") != "main" {
@@ -1,4 +1,5 @@
 use std::f64::consts::PI;
+use std::time::{Instant, Duration};
 
 use serde::{Serialize, Deserialize};
 
@@ -6,7 +7,16 @@ use crate::{
     Color,
     Point,
     Speed,
+    Distance,
+    LineCap,
+    LineJoin,
+    PenState,
+    TurtleShape,
+    FillRule,
+    FillPattern,
+    Event,
     debug,
+    event::{Key, PressedState},
     radians::Radians,
     colors::{WHITE, BLACK},
     async_turtle::AngleUnit,
@@ -21,6 +31,25 @@ pub struct DrawingState {
     pub height: u32,
     pub is_maximized: bool,
     pub is_fullscreen: bool,
+    /// The position of the top left corner of the window on the screen (in logical coordinates)
+    pub window_position: (i32, i32),
+    pub grid: Option<f64>,
+    pub show_axes: bool,
+    /// The zoom level of the view, multiplied with every logical coordinate (about the center)
+    /// before it is drawn in the window
+    pub scale: f64,
+    /// True if the window should close when the Esc key is pressed and released
+    pub exit_on_escape: bool,
+    /// True if the window should close when the user closes it (e.g. with its close button), false
+    /// if the program should be sent an event instead
+    pub exit_on_close: bool,
+    /// True if the measured frame rate should be shown after the title of the window
+    pub show_fps: bool,
+    /// True if the window should keep drawing frames and send an `Event::Tick` for each one
+    pub tick_events: bool,
+    /// True if the edges of shapes should be smoothed, both in the window and when the drawing is
+    /// exported as an image
+    pub antialiasing: bool,
 }
 
 impl DrawingState {
@@ -31,6 +60,17 @@ impl DrawingState {
     pub const DEFAULT_HEIGHT: u32 = 600;
     pub const DEFAULT_IS_MAXIMIZED: bool = false;
     pub const DEFAULT_IS_FULLSCREEN: bool = false;
+    /// Only used until the window is opened, since the window is placed wherever the operating
+    /// system decides
+    pub const DEFAULT_WINDOW_POSITION: (i32, i32) = (0, 0);
+    pub const DEFAULT_GRID: Option<f64> = None;
+    pub const DEFAULT_SHOW_AXES: bool = false;
+    pub const DEFAULT_SCALE: f64 = 1.0;
+    pub const DEFAULT_EXIT_ON_ESCAPE: bool = true;
+    pub const DEFAULT_EXIT_ON_CLOSE: bool = true;
+    pub const DEFAULT_SHOW_FPS: bool = false;
+    pub const DEFAULT_TICK_EVENTS: bool = false;
+    pub const DEFAULT_ANTIALIASING: bool = true;
 }
 
 impl Default for DrawingState {
@@ -43,6 +83,15 @@ impl Default for DrawingState {
             height: Self::DEFAULT_HEIGHT,
             is_maximized: Self::DEFAULT_IS_MAXIMIZED,
             is_fullscreen: Self::DEFAULT_IS_FULLSCREEN,
+            window_position: Self::DEFAULT_WINDOW_POSITION,
+            grid: Self::DEFAULT_GRID,
+            show_axes: Self::DEFAULT_SHOW_AXES,
+            scale: Self::DEFAULT_SCALE,
+            exit_on_escape: Self::DEFAULT_EXIT_ON_ESCAPE,
+            exit_on_close: Self::DEFAULT_EXIT_ON_CLOSE,
+            show_fps: Self::DEFAULT_SHOW_FPS,
+            tick_events: Self::DEFAULT_TICK_EVENTS,
+            antialiasing: Self::DEFAULT_ANTIALIASING,
         }
     }
 }
@@ -57,6 +106,15 @@ impl DrawingState {
             height,
             is_maximized,
             is_fullscreen,
+            window_position,
+            grid,
+            show_axes,
+            scale,
+            exit_on_escape,
+            exit_on_close,
+            show_fps,
+            tick_events,
+            antialiasing,
         } = self;
 
         let title = title.clone();
@@ -69,25 +127,101 @@ impl DrawingState {
             height,
             is_maximized,
             is_fullscreen,
+            window_position,
+            grid,
+            show_axes,
+            scale,
+            exit_on_escape,
+            exit_on_close,
+            show_fps,
+            tick_events,
+            antialiasing,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The settings that the window is opened with, before any requests are received from the client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowConfig {
+    pub title: String,
+    pub background: Color,
+    pub width: u32,
+    pub height: u32,
+    pub is_maximized: bool,
+    pub is_resizable: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: DrawingState::DEFAULT_TITLE.to_owned(),
+            background: DrawingState::DEFAULT_BACKGROUND,
+            width: DrawingState::DEFAULT_WIDTH,
+            height: DrawingState::DEFAULT_HEIGHT,
+            is_maximized: DrawingState::DEFAULT_IS_MAXIMIZED,
+            is_resizable: true,
+        }
+    }
+}
+
+impl WindowConfig {
+    /// Returns the state that the drawing should start with when the window is opened with these
+    /// settings
+    pub fn initial_drawing(&self) -> DrawingState {
+        let &Self {ref title, background, width, height, is_maximized, is_resizable: _} = self;
+
+        DrawingState {
+            title: title.clone(),
+            background,
+            width,
+            height,
+            is_maximized,
+            ..DrawingState::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TurtleState {
     pub pen: Pen,
     pub fill_color: Color,
+    pub fill_rule: FillRule,
+    /// The angle of a hatch pattern is always in radians
+    pub fill_pattern: FillPattern,
+    /// Multiplied with the alpha of the pen and fill colors of anything that the turtle draws
+    pub group_opacity: f64,
     pub position: Point,
     pub heading: Radians,
     pub speed: Speed,
+    /// The movement speed in pixels per second, or `None` if the rate given by `speed` is used
+    pub movement_rate: Option<Distance>,
+    /// The rotation speed in radians per second, or `None` if the rate given by `speed` is used
+    pub rotation_rate: Option<Radians>,
+    /// If false, the turtle moves instantly and does not redraw the window when it draws
+    pub tracer: bool,
     pub is_visible: bool,
+    pub shape: TurtleShape,
+    /// Multiplied with the size of the shape when the turtle is drawn
+    pub shape_scale: f64,
+    /// The outline color of the turtle shape, which does not affect anything the turtle draws
+    pub shape_outline_color: Color,
+    /// The fill color of the turtle shape, which does not affect anything the turtle draws
+    pub shape_fill_color: Color,
 }
 
 impl TurtleState {
     pub const DEFAULT_FILL_COLOR: Color = BLACK;
+    pub const DEFAULT_FILL_RULE: FillRule = FillRule::NonZero;
+    pub const DEFAULT_FILL_PATTERN: FillPattern = FillPattern::Solid;
+    pub const DEFAULT_GROUP_OPACITY: f64 = 1.0;
     pub const DEFAULT_POSITION: Point = Point::origin();
     pub const DEFAULT_HEADING: Radians = Radians::from_radians_value(PI / 2.0);
+    pub const DEFAULT_TRACER: bool = true;
     pub const DEFAULT_IS_VISIBLE: bool = true;
+    pub const DEFAULT_SHAPE: TurtleShape = TurtleShape::Triangle;
+    pub const DEFAULT_SHAPE_SCALE: f64 = 1.0;
+    pub const DEFAULT_SHAPE_OUTLINE_COLOR: Color = BLACK;
+    pub const DEFAULT_SHAPE_FILL_COLOR: Color = WHITE;
 }
 
 impl Default for TurtleState {
@@ -95,23 +229,73 @@ impl Default for TurtleState {
         Self {
             pen: Pen::default(),
             fill_color: Self::DEFAULT_FILL_COLOR,
+            fill_rule: Self::DEFAULT_FILL_RULE,
+            fill_pattern: Self::DEFAULT_FILL_PATTERN,
+            group_opacity: Self::DEFAULT_GROUP_OPACITY,
             position: Self::DEFAULT_POSITION,
             heading: Self::DEFAULT_HEADING,
             speed: Speed::default(),
+            movement_rate: None,
+            rotation_rate: None,
+            tracer: Self::DEFAULT_TRACER,
             is_visible: Self::DEFAULT_IS_VISIBLE,
+            shape: Self::DEFAULT_SHAPE,
+            shape_scale: Self::DEFAULT_SHAPE_SCALE,
+            shape_outline_color: Self::DEFAULT_SHAPE_OUTLINE_COLOR,
+            shape_fill_color: Self::DEFAULT_SHAPE_FILL_COLOR,
         }
     }
 }
 
 impl TurtleState {
+    /// Returns the given color with the group opacity of the turtle applied to it
+    pub fn apply_group_opacity(&self, color: Color) -> Color {
+        Color {alpha: color.alpha * self.group_opacity, ..color}
+    }
+
+    /// Returns the pen that new lines should be drawn with, with the group opacity applied to
+    /// its colors
+    pub fn drawing_pen(&self) -> Pen {
+        Pen {
+            color: self.apply_group_opacity(self.pen.color),
+            end_color: self.pen.end_color.map(|end_color| self.apply_group_opacity(end_color)),
+            ..self.pen.clone()
+        }
+    }
+
+    /// Returns the color that fills should be drawn with, with the group opacity applied to it
+    pub fn drawing_fill_color(&self) -> Color {
+        self.apply_group_opacity(self.fill_color)
+    }
+
+    /// Returns the rate at which the turtle moves, in pixels per second
+    pub fn px_per_sec(&self) -> Distance {
+        self.movement_rate.unwrap_or_else(|| self.speed.to_px_per_sec())
+    }
+
+    /// Returns the rate at which the turtle rotates, in radians per second
+    pub fn rad_per_sec(&self) -> Radians {
+        self.rotation_rate.unwrap_or_else(|| self.speed.to_rad_per_sec())
+    }
+
     pub(crate) fn to_debug(&self, angle_unit: AngleUnit) -> debug::Turtle {
         let &Self {
             position,
             heading,
             speed,
+            movement_rate,
+            rotation_rate,
+            tracer,
             ref pen,
             fill_color,
+            fill_rule,
+            fill_pattern,
+            group_opacity,
             is_visible,
+            ref shape,
+            shape_scale,
+            shape_outline_color,
+            shape_fill_color,
         } = self;
 
         let heading = match angle_unit {
@@ -119,29 +303,60 @@ impl TurtleState {
             AngleUnit::Radians => debug::DebugAngle::Radians(heading.to_radians()),
         };
         let pen = pen.to_debug();
+        let shape = shape.clone();
+        let fill_pattern = fill_pattern.map_angle(|angle| match angle_unit {
+            AngleUnit::Degrees => angle.to_degrees(),
+            AngleUnit::Radians => angle,
+        });
 
         debug::Turtle {
             position,
             heading,
             speed,
+            movement_rate,
+            rotation_rate: rotation_rate.map(|rate| match angle_unit {
+                AngleUnit::Degrees => debug::DebugAngle::Degrees(rate.to_degrees()),
+                AngleUnit::Radians => debug::DebugAngle::Radians(rate.to_radians()),
+            }),
+            tracer,
             pen,
             fill_color,
+            fill_rule,
+            fill_pattern,
+            group_opacity,
             is_visible,
+            shape,
+            shape_scale,
+            shape_outline_color,
+            shape_fill_color,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pen {
     pub is_enabled: bool,
     pub thickness: f64,
     pub color: Color,
+    /// The color at the end of each line if the pen draws a gradient, or `None` if every line is
+    /// drawn using `color` alone
+    pub end_color: Option<Color>,
+    /// The thickness that the pen is gradually changing to and the distance that the turtle has
+    /// left to travel before the pen reaches that thickness, or `None` if the thickness of the pen
+    /// stays the same as the turtle moves
+    pub thickness_change: Option<(f64, f64)>,
+    pub cap: LineCap,
+    pub join: LineJoin,
 }
 
 impl Pen {
     pub const DEFAULT_IS_ENABLED: bool = true;
     pub const DEFAULT_THICKNESS: f64 = 1.0;
     pub const DEFAULT_COLOR: Color = BLACK;
+    pub const DEFAULT_END_COLOR: Option<Color> = None;
+    pub const DEFAULT_THICKNESS_CHANGE: Option<(f64, f64)> = None;
+    pub const DEFAULT_CAP: LineCap = LineCap::Round;
+    pub const DEFAULT_JOIN: LineJoin = LineJoin::Round;
 }
 
 impl Default for Pen {
@@ -150,6 +365,10 @@ impl Default for Pen {
             is_enabled: Self::DEFAULT_IS_ENABLED,
             thickness: Self::DEFAULT_THICKNESS,
             color: Self::DEFAULT_COLOR,
+            end_color: Self::DEFAULT_END_COLOR,
+            thickness_change: Self::DEFAULT_THICKNESS_CHANGE,
+            cap: Self::DEFAULT_CAP,
+            join: Self::DEFAULT_JOIN,
         }
     }
 }
@@ -160,12 +379,196 @@ impl Pen {
             is_enabled,
             thickness,
             color,
+            end_color,
+            thickness_change,
+            cap,
+            join,
         } = self;
 
         debug::Pen {
             is_enabled,
             thickness,
             color,
+            end_color,
+            thickness_change,
+            cap,
+            join,
+        }
+    }
+
+    /// Returns the settings of the pen that can be restored with `Pen::from`
+    pub fn to_pen_state(&self) -> PenState {
+        let &Self {is_enabled, thickness, color, end_color, cap, join, ..} = self;
+        PenState {is_down: is_enabled, size: thickness, color, end_color, cap, join}
+    }
+}
+
+/// The thickness of the restored pen stays the same as the turtle moves
+impl From<PenState> for Pen {
+    fn from(state: PenState) -> Self {
+        let PenState {is_down, size, color, end_color, cap, join} = state;
+        Self {
+            is_enabled: is_down,
+            thickness: size,
+            color,
+            end_color,
+            thickness_change: None,
+            cap,
+            join,
         }
     }
 }
+
+/// The current state of the mouse and keyboard, based on the events received from the window
+#[derive(Debug, Default)]
+pub struct InputState {
+    /// The most recent position of the mouse in logical coordinates
+    pub mouse_position: Point,
+    /// The keys that are currently held down and the time at which each of them was pressed, in
+    /// the order in which they were pressed
+    pub pressed_keys: Vec<(Key, Instant)>,
+    /// The time of the most recent `Tick` event, or `None` if ticks are not being sent
+    pub last_tick: Option<Instant>,
+    /// True if a `Tick` event has been sent and has not been received by a client yet
+    pub tick_pending: bool,
+}
+
+impl InputState {
+    /// Updates the input state based on an event from the window that happened at the given time
+    ///
+    /// Returns the event that should be sent to clients. Holding down a key produces many pressed
+    /// events, so every pressed event after the first one is turned into a `KeyHeld` event.
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+    pub fn update(&mut self, event: Event, now: Instant) -> Event {
+        match event {
+            Event::MouseMove(position) => self.mouse_position = position,
+
+            Event::Key(key, PressedState::Pressed) => {
+                match self.pressed_keys.iter().find(|&&(pressed, _)| pressed == key) {
+                    Some(&(_, pressed_at)) => {
+                        let duration = now.saturating_duration_since(pressed_at);
+                        return Event::KeyHeld {key, duration};
+                    },
+                    None => self.pressed_keys.push((key, now)),
+                }
+            },
+            Event::Key(key, PressedState::Released) => {
+                self.pressed_keys.retain(|&(pressed, _)| pressed != key);
+            },
+
+            // Keys released while the window is not focused never produce a released event
+            Event::WindowFocused(false) => self.pressed_keys.clear(),
+
+            _ => {},
+        }
+
+        event
+    }
+
+    /// Returns the keys that are currently held down, in the order in which they were pressed
+    pub fn pressed_keys(&self) -> Vec<Key> {
+        self.pressed_keys.iter().map(|&(key, _)| key).collect()
+    }
+
+    /// Returns the `Tick` event that should be sent for a frame drawn at the given time
+    ///
+    /// Returns `None` if the previous tick has not been received yet. That time is then included
+    /// in the next tick instead.
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+    pub fn tick(&mut self, now: Instant) -> Option<Event> {
+        if self.tick_pending {
+            return None;
+        }
+
+        let dt = match self.last_tick {
+            Some(last_tick) => now.saturating_duration_since(last_tick),
+            None => Duration::from_secs(0),
+        };
+        self.last_tick = Some(now);
+        self.tick_pending = true;
+
+        Some(Event::Tick {dt})
+    }
+
+    /// Stops measuring time between ticks so that the next tick starts from zero again
+    ///
+    /// A tick that was sent but never received no longer holds back the ticks sent after tick
+    /// events are enabled again.
+    #[cfg_attr(any(feature = "test", feature = "embedded", test), allow(dead_code))]
+    pub fn stop_ticks(&mut self) {
+        self.last_tick = None;
+        self.tick_pending = false;
+    }
+
+    /// Records that the given event was received by a client
+    pub fn event_received(&mut self, event: &Event) {
+        if let Event::Tick {..} = event {
+            self.tick_pending = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_state_tracks_held_keys() {
+        let mut input = InputState::default();
+
+        let now = Instant::now();
+
+        input.update(Event::MouseMove(Point {x: 10.0, y: -20.0}), now);
+        assert_eq!(input.mouse_position, Point {x: 10.0, y: -20.0});
+
+        input.update(Event::Key(Key::LeftArrow, PressedState::Pressed), now);
+        input.update(Event::Key(Key::Space, PressedState::Pressed), now);
+        input.update(Event::Key(Key::LeftArrow, PressedState::Pressed), now);
+        assert_eq!(input.pressed_keys(), vec![Key::LeftArrow, Key::Space]);
+
+        input.update(Event::Key(Key::LeftArrow, PressedState::Released), now);
+        assert_eq!(input.pressed_keys(), vec![Key::Space]);
+
+        input.update(Event::WindowFocused(false), now);
+        assert!(input.pressed_keys().is_empty());
+    }
+
+    #[test]
+    fn repeated_key_presses_become_held_events() {
+        let mut input = InputState::default();
+        let start = Instant::now();
+        let pressed = Event::Key(Key::UpArrow, PressedState::Pressed);
+
+        assert_eq!(input.update(pressed.clone(), start), pressed);
+        let held = input.update(pressed.clone(), start + Duration::from_millis(500));
+        assert_eq!(held, Event::KeyHeld {key: Key::UpArrow, duration: Duration::from_millis(500)});
+
+        // Pressing the key again after releasing it is a new press
+        input.update(Event::Key(Key::UpArrow, PressedState::Released), start + Duration::from_secs(1));
+        assert_eq!(input.update(pressed.clone(), start + Duration::from_secs(2)), pressed);
+    }
+
+    #[test]
+    fn ticks_are_coalesced_until_received() {
+        let mut input = InputState::default();
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+
+        let tick = input.tick(start).unwrap();
+        assert_eq!(tick, Event::Tick {dt: ms(0)});
+        // The first tick has not been received, so these frames are included in the next tick
+        assert_eq!(input.tick(start + ms(16)), None);
+        assert_eq!(input.tick(start + ms(32)), None);
+
+        input.event_received(&tick);
+        assert_eq!(input.tick(start + ms(48)), Some(Event::Tick {dt: ms(48)}));
+
+        input.event_received(&Event::Tick {dt: ms(48)});
+        input.stop_ticks();
+        assert_eq!(input.tick(start + ms(1000)), Some(Event::Tick {dt: ms(0)}));
+
+        // Stopping while a tick is still pending starts over without waiting for it
+        input.stop_ticks();
+        assert_eq!(input.tick(start + ms(2000)), Some(Event::Tick {dt: ms(0)}));
+    }
+}
@@ -1,11 +1,14 @@
 use thiserror::Error;
-use glutin::dpi::LogicalSize;
+use glutin::dpi::{LogicalSize, LogicalPosition};
+
+use super::renderer::export::RgbaImage;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 #[error("event loop closed while messages were still being sent to it")]
 pub struct EventLoopClosed;
 
-/// An event loop notifier for use in tests that does nothing
+/// An event loop notifier that does nothing, for use in tests and when there is no window because
+/// the drawing is embedded in another application
 #[derive(Debug, Clone)]
 pub struct EventLoopNotifier {}
 
@@ -34,6 +37,14 @@ impl EventLoopNotifier {
         Ok(())
     }
 
+    pub fn set_position<P: Into<LogicalPosition<i32>>>(&self, _position: P) -> Result<(), EventLoopClosed> {
+        Ok(())
+    }
+
+    pub fn set_icon(&self, _icon: RgbaImage) -> Result<(), EventLoopClosed> {
+        Ok(())
+    }
+
     pub fn exit(&self) -> Result<(), EventLoopClosed> {
         Ok(())
     }
@@ -0,0 +1,119 @@
+use std::f64::consts::PI;
+
+use serde::{Serialize, Deserialize};
+
+use crate::Point;
+
+/// The number of points used to draw the `Circle` shape
+const CIRCLE_POINTS: usize = 24;
+
+/// The shape used to draw the turtle
+///
+/// See the [`set_shape()` method] for more information.
+///
+/// # Creating Shapes
+///
+/// The built-in shapes can also be created by converting one of the strings `"triangle"`,
+/// `"circle"`, `"square"`, or `"arrow"`. Any other string will cause a panic.
+///
+/// ```rust
+/// # use turtle::TurtleShape;
+/// let shape: TurtleShape = "circle".into();
+/// assert_eq!(shape, TurtleShape::Circle);
+/// ```
+///
+/// # Custom Shapes
+///
+/// The `Polygon` variant draws the turtle with any outline you want. The points of the polygon
+/// are relative to the turtle's position, **for a turtle facing to the right**. That means that
+/// the positive x-axis points in the direction the turtle is facing and the positive y-axis points
+/// to the turtle's left. The polygon is then rotated to match the turtle's actual heading.
+///
+/// ```rust
+/// # use turtle::{TurtleShape, Point};
+/// // A thin diamond that points in the direction the turtle is facing
+/// let diamond = TurtleShape::Polygon(vec![
+///     Point {x: 15.0, y: 0.0},
+///     Point {x: 0.0, y: 5.0},
+///     Point {x: -15.0, y: 0.0},
+///     Point {x: 0.0, y: -5.0},
+/// ]);
+/// ```
+///
+/// [`set_shape()` method]: struct.Turtle.html#method.set_shape
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TurtleShape {
+    /// A triangle that points in the direction the turtle is facing
+    Triangle,
+    /// A circle centered on the turtle's position
+    Circle,
+    /// A square centered on the turtle's position, with sides parallel and perpendicular to the
+    /// turtle's heading
+    Square,
+    /// An arrowhead with a notch cut out of its back
+    Arrow,
+    /// A custom outline made of at least three points
+    ///
+    /// See [Custom Shapes](#custom-shapes) for how these points are interpreted.
+    Polygon(Vec<Point>),
+}
+
+/// The default shape is a triangle
+impl Default for TurtleShape {
+    fn default() -> Self {
+        TurtleShape::Triangle
+    }
+}
+
+impl<'a> From<&'a str> for TurtleShape {
+    fn from(shape: &'a str) -> Self {
+        match shape {
+            "triangle" => TurtleShape::Triangle,
+            "circle" => TurtleShape::Circle,
+            "square" => TurtleShape::Square,
+            "arrow" => TurtleShape::Arrow,
+            _ => panic!("Invalid turtle shape specified, use one of the words: \"triangle\", \"circle\", \"square\", \"arrow\""),
+        }
+    }
+}
+
+impl From<Vec<Point>> for TurtleShape {
+    fn from(points: Vec<Point>) -> Self {
+        TurtleShape::Polygon(points)
+    }
+}
+
+impl TurtleShape {
+    /// Returns the outline of this shape for a turtle at the origin that is facing to the right
+    pub(crate) fn outline(&self) -> Vec<Point> {
+        use TurtleShape::*;
+        match self {
+            Triangle => vec![
+                Point {x: 0.0, y: 15.0},
+                Point {x: 10.0, y: 0.0},
+                Point {x: 0.0, y: -15.0},
+            ],
+
+            Circle => (0..CIRCLE_POINTS).map(|i| {
+                let angle = 2.0 * PI * i as f64 / CIRCLE_POINTS as f64;
+                Point {x: 10.0 * angle.cos(), y: 10.0 * angle.sin()}
+            }).collect(),
+
+            Square => vec![
+                Point {x: 10.0, y: 10.0},
+                Point {x: -10.0, y: 10.0},
+                Point {x: -10.0, y: -10.0},
+                Point {x: 10.0, y: -10.0},
+            ],
+
+            Arrow => vec![
+                Point {x: 12.0, y: 0.0},
+                Point {x: -8.0, y: 10.0},
+                Point {x: -3.0, y: 0.0},
+                Point {x: -8.0, y: -10.0},
+            ],
+
+            Polygon(points) => points.clone(),
+        }
+    }
+}
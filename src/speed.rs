@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
 
 use serde::{Serialize, Deserialize};
 use interpolation::lerp;
@@ -11,20 +13,45 @@ use crate::Distance;
 const MIN_SPEED: i32 = 1;
 const MAX_SPEED: i32 = 25;
 
-#[derive(Clone, Copy, PartialEq, Eq, Ord, Hash, Serialize, Deserialize)]
+/// The level of a speed value
+///
+/// Values are always finite and between `MIN_SPEED` and `MAX_SPEED`. That is what allows this
+/// type to implement `Eq`, `Ord`, and `Hash` even though it contains an `f64`.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub(crate) enum SpeedLevel {
-    Value(i32),
+    Value(f64),
     Instant,
 }
 
+impl Eq for SpeedLevel {}
+
 impl PartialOrd for SpeedLevel {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SpeedLevel {
+    fn cmp(&self, other: &Self) -> Ordering {
         use SpeedLevel::*;
         match (*self, *other) {
-            (Value(value), Value(ref other_value)) => value.partial_cmp(other_value),
-            (Instant, Instant) => Some(Ordering::Equal),
-            (Value(_), Instant) => Some(Ordering::Less),
-            (Instant, Value(_)) => Some(Ordering::Greater),
+            (Value(value), Value(ref other_value)) => value.total_cmp(other_value),
+            (Instant, Instant) => Ordering::Equal,
+            (Value(_), Instant) => Ordering::Less,
+            (Instant, Value(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl Hash for SpeedLevel {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        use SpeedLevel::*;
+        match *self {
+            Value(value) => {
+                state.write_u8(0);
+                value.to_bits().hash(state);
+            },
+            Instant => state.write_u8(1),
         }
     }
 }
@@ -67,6 +94,24 @@ impl PartialOrd for SpeedLevel {
 /// While the minimum speed will not change, the maximum speed may grow larger if the need arises.
 /// That is why we chose to panic for invalid speeds instead of defaulting to another value.
 ///
+/// ### Fractional Speeds
+///
+/// Speeds do not have to be whole numbers. Any `f64` value in the valid range can be used to
+/// fine-tune the speed of an animation. The turtle's speed changes smoothly between the whole
+/// number levels.
+///
+/// Values are still rounded before they are checked against the valid range, so a value that
+/// rounds to a valid level (e.g. `0.6` or `25.4`) is accepted and moved to the nearest end of the
+/// range.
+///
+/// ```rust
+/// # use turtle::{Turtle};
+/// let mut turtle = Turtle::new();
+/// // A little faster than 3, but not as fast as 4
+/// turtle.set_speed(3.5);
+/// assert_eq!(turtle.speed().to_level(), 3.5);
+/// ```
+///
 /// ### String Conversion
 ///
 /// Strings are converted as follows:
@@ -93,6 +138,22 @@ impl PartialOrd for SpeedLevel {
 /// turtle.set_speed(Speed::from("slower")); // Not recommended!
 /// ```
 ///
+/// ### Constants
+///
+/// Each of the strings above also has an associated constant, e.g. [`Speed::SLOWER`] for
+/// `"slower"`. A typo in a string only causes a panic once your program runs, but a typo in the
+/// name of a constant won't compile at all. [`Speed::FASTEST`] is the fastest speed that is still
+/// animated, which is `25`.
+///
+/// ```rust
+/// # use turtle::{Turtle, Speed};
+/// # let mut turtle = Turtle::new();
+/// turtle.set_speed(Speed::SLOWER);
+/// assert_eq!(turtle.speed(), 5);
+/// turtle.set_speed(Speed::INSTANT);
+/// assert!(turtle.speed().is_instant());
+/// ```
+///
 /// # Instant
 ///
 /// There is one special speed value `"instant"` which makes it so that movement and rotation
@@ -166,6 +227,8 @@ impl PartialOrd for SpeedLevel {
 /// ```
 ///
 /// [`set_speed` method]: struct.Turtle.html#method.set_speed
+/// [`Speed::SLOWER`]: struct.Speed.html#associatedconstant.SLOWER
+/// [`Speed::FASTEST`]: struct.Speed.html#associatedconstant.FASTEST
 /// [`Speed::instant()`]: struct.Speed.html#method.instant
 /// [`is_instant()` method]: struct.Speed.html#method.is_instant
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -174,11 +237,29 @@ pub struct Speed(SpeedLevel);
 /// The default speed is "normal"
 impl Default for Speed {
     fn default() -> Self {
-        "normal".into()
+        Speed::NORMAL
     }
 }
 
 impl Speed {
+    /// The slowest speed, level `1` (same as `"slowest"`)
+    pub const SLOWEST: Speed = Speed(SpeedLevel::Value(1.0));
+    /// Speed level `5` (same as `"slower"`)
+    pub const SLOWER: Speed = Speed(SpeedLevel::Value(5.0));
+    /// Speed level `8` (same as `"slow"`)
+    pub const SLOW: Speed = Speed(SpeedLevel::Value(8.0));
+    /// The default speed, level `10` (same as `"normal"`)
+    pub const NORMAL: Speed = Speed(SpeedLevel::Value(10.0));
+    /// Speed level `12` (same as `"fast"`)
+    pub const FAST: Speed = Speed(SpeedLevel::Value(12.0));
+    /// Speed level `15` (same as `"faster"`)
+    pub const FASTER: Speed = Speed(SpeedLevel::Value(15.0));
+    /// The fastest speed that is still animated, level `25`
+    pub const FASTEST: Speed = Speed(SpeedLevel::Value(MAX_SPEED as f64));
+    /// Moves and rotates the turtle without any animation (same as `"instant"` and
+    /// [`Speed::instant()`](struct.Speed.html#method.instant))
+    pub const INSTANT: Speed = Speed(SpeedLevel::Instant);
+
     /// Returns the speed value that will make the turtle move and rotate instantly. This means
     /// that instead of the turtle's movements being animated, it will directly move to wherever
     /// you direct it to go.
@@ -219,6 +300,123 @@ impl Speed {
         }
     }
 
+    /// Returns the numeric level of this speed
+    ///
+    /// For [`Speed::instant()`], this returns `f64::INFINITY`.
+    ///
+    /// ```rust
+    /// use turtle::Speed;
+    ///
+    /// assert_eq!(Speed::from(7).to_level(), 7.0);
+    /// assert_eq!(Speed::from(2.25).to_level(), 2.25);
+    /// assert_eq!(Speed::from("normal").to_level(), 10.0);
+    /// assert_eq!(Speed::instant().to_level(), f64::INFINITY);
+    /// ```
+    ///
+    /// [`Speed::instant()`]: struct.Speed.html#method.instant
+    pub fn to_level(self) -> f64 {
+        match self.0 {
+            SpeedLevel::Value(level) => level,
+            SpeedLevel::Instant => f64::INFINITY,
+        }
+    }
+
+    /// Returns the speed level rounded to the nearest whole level, or `None` for
+    /// [`Speed::instant()`]
+    ///
+    /// Speeds created from a fractional level (e.g. `2.25`) or by [`from_duration()`] may be
+    /// between two levels. Use [`to_level()`] to get the exact level.
+    ///
+    /// ```rust
+    /// use turtle::Speed;
+    ///
+    /// assert_eq!(Speed::from(7).level(), Some(7));
+    /// assert_eq!(Speed::from(2.25).level(), Some(2));
+    /// assert_eq!(Speed::from("normal").level(), Some(10));
+    /// assert_eq!(Speed::instant().level(), None);
+    /// ```
+    ///
+    /// [`Speed::instant()`]: struct.Speed.html#method.instant
+    /// [`from_duration()`]: struct.Speed.html#method.from_duration
+    /// [`to_level()`]: struct.Speed.html#method.to_level
+    pub fn level(self) -> Option<u8> {
+        match self.0 {
+            // Levels are always between MIN_SPEED and MAX_SPEED, so they fit in a u8
+            SpeedLevel::Value(level) => Some(level.round() as u8),
+            SpeedLevel::Instant => None,
+        }
+    }
+
+    /// Returns the speed that moves the turtle the given distance (in pixels) in the given amount
+    /// of time
+    ///
+    /// This is useful for planning how long an animation will take, but the range of possible
+    /// speeds is limited by the range of speed levels. To move the turtle in a specific amount of
+    /// time regardless of its speed, use the [`forward_in()` method] instead.
+    ///
+    /// A duration of zero returns [`Speed::instant()`].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use turtle::Speed;
+    ///
+    /// // The slowest speed moves 200 px in 2 seconds
+    /// assert_eq!(Speed::from_duration(200.0, Duration::from_secs(2)), 1);
+    ///
+    /// // This is a little slower than the normal speed
+    /// let speed = Speed::from_duration(300.0, Duration::from_secs(2));
+    /// assert!(speed > 8 && speed < 10);
+    ///
+    /// assert!(Speed::from_duration(100.0, Duration::from_secs(0)).is_instant());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `px` is zero or not finite, or if moving `px` pixels in the given duration would
+    /// be slower than the slowest speed or faster than the fastest speed. Moving zero pixels takes
+    /// no time at any speed, so there is no speed that takes the given duration.
+    ///
+    /// [`forward_in()` method]: struct.Turtle.html#method.forward_in
+    /// [`Speed::instant()`]: struct.Speed.html#method.instant
+    pub fn from_duration(px: Distance, duration: Duration) -> Self {
+        // This is the inverse of the calculation in `to_px_per_sec`
+        let distance = 200.0; // px
+        let speed_min_time = 2.0 * 1000.0; // ms
+        let speed_max_time = 5.0; // ms
+
+        if !px.is_finite() {
+            panic!("Invalid distance: {}. The distance must be a finite number of pixels", px);
+        }
+        if px == 0.0 {
+            panic!("Invalid distance: 0. The distance must not be zero since moving zero pixels takes no time at any speed");
+        }
+
+        let secs = duration.as_secs_f64();
+        if secs == 0.0 {
+            return Speed::instant();
+        }
+
+        // The time it would take to cross the fixed distance at this speed
+        let time = distance * secs * 1000.0 / px.abs(); // ms
+        let t = (speed_min_time - time) / (speed_min_time - speed_max_time);
+        let level = lerp(&(MIN_SPEED as f64), &(MAX_SPEED as f64), &t);
+
+        // Allow for a small amount of floating-point error at the ends of the range
+        let level = if (level - MIN_SPEED as f64).abs() < 1e-9 {
+            MIN_SPEED as f64
+        } else if (level - MAX_SPEED as f64).abs() < 1e-9 {
+            MAX_SPEED as f64
+        } else {
+            level
+        };
+
+        if (MIN_SPEED as f64..=MAX_SPEED as f64).contains(&level) {
+            Speed(SpeedLevel::Value(level))
+        } else {
+            panic!("Invalid speed: moving {} px in {} seconds is outside of the range of speed levels. Use `forward_in()` to move in a specific amount of time instead", px, secs)
+        }
+    }
+
     /// Converts a speed to its value as a movement speed in pixels per second
     pub(crate) fn to_px_per_sec(self) -> Distance {
         // Goal: increasing speed causes a linear decrease in the time it takes to draw a line
@@ -241,11 +439,12 @@ impl Speed {
             Instant => return f64::INFINITY,
         };
 
-        // Linearly interpolate the time to get the time it should take at this speed level
+        // Linearly interpolate the time to get the time it should take at this speed level. Since
+        // the level can be fractional, this also interpolates smoothly between whole levels.
         // Note:
         //   if level = MIN_SPEED then t = 0.0
         //   if level = MAX_SPEED then t = 1.0
-        let t = (level - MIN_SPEED) as f64 / (MAX_SPEED - MIN_SPEED) as f64;
+        let t = (level - MIN_SPEED as f64) / (MAX_SPEED - MIN_SPEED) as f64;
         let time = lerp(&speed_min_time, &speed_max_time, &t); // ms
 
         // Compute the final speed using the formula above (note: 1000.0 ms == 1.0 s)
@@ -268,7 +467,7 @@ impl Speed {
             Instant => return Radians::from_radians_value(f64::INFINITY),
         };
 
-        let t = (level - MIN_SPEED) as f64 / (MAX_SPEED - MIN_SPEED) as f64;
+        let t = (level - MIN_SPEED as f64) / (MAX_SPEED - MIN_SPEED) as f64;
         let time = lerp(&speed_min_time, &speed_max_time, &t); // ms
 
         distance * 1000.0 / time
@@ -306,12 +505,25 @@ impl PartialOrd<i32> for Speed {
 impl Random for Speed {
     /// Generates a random speed within the valid range of speed levels
     fn random() -> Self {
-        RandomRange::random_range(MIN_SPEED, MAX_SPEED)
+        RandomRange::random_range(MIN_SPEED as f64, MAX_SPEED as f64)
     }
 }
 
 impl<B: Into<Speed>> RandomRange<B> for Speed {
-    /// Generates a random difficulty level within the given range, not including instant.
+    /// Generates a random speed within the given range, not including instant.
+    ///
+    /// Both bounds are inclusive and may be anything that can be converted into a `Speed`, such as
+    /// a speed level number or one of the speed names.
+    ///
+    /// ```rust
+    /// use turtle::{Speed, rand::random_range};
+    ///
+    /// let speed: Speed = random_range("slow", "fast");
+    /// assert!(speed >= 8 && speed <= 12);
+    ///
+    /// let speed: Speed = random_range(3, 5);
+    /// assert!(speed >= 3 && speed <= 5);
+    /// ```
     ///
     /// # Panics
     ///
@@ -321,8 +533,8 @@ impl<B: Into<Speed>> RandomRange<B> for Speed {
         let low = low.into();
         let high = high.into();
         if let (Speed(SpeedLevel::Value(low)), Speed(SpeedLevel::Value(high))) = (low, high) {
-            if low < MIN_SPEED || high > MAX_SPEED {
-                panic!("The boundaries must be within the valid range of difficulties");
+            if low < MIN_SPEED as f64 || high > MAX_SPEED as f64 {
+                panic!("The boundaries must be within the valid range of speed levels");
             }
 
             Speed(SpeedLevel::Value(RandomRange::random_range(low, high)))
@@ -334,20 +546,18 @@ impl<B: Into<Speed>> RandomRange<B> for Speed {
 
 impl<'a> From<&'a str> for Speed {
     fn from(level_name: &'a str) -> Self {
-        use SpeedLevel::*;
-
-        Speed(match level_name {
-            "slowest" => Value(1),
-            "slower" => Value(5),
-            "slow" => Value(8),
-            "normal" => Value(10),
-            "fast" => Value(12),
-            "faster" => Value(15),
-            "instant" => Instant,
+        match level_name {
+            "slowest" => Speed::SLOWEST,
+            "slower" => Speed::SLOWER,
+            "slow" => Speed::SLOW,
+            "normal" => Speed::NORMAL,
+            "fast" => Speed::FAST,
+            "faster" => Speed::FASTER,
+            "instant" => Speed::INSTANT,
             _ => panic!(
                 "Invalid speed specified, use one of the words: \"slowest\", \"slower\", \"slow\", \"normal\", \"fast\", \"faster\", \"instant\""
             ),
-        })
+        }
     }
 }
 
@@ -358,18 +568,31 @@ impl From<i32> for Speed {
         Speed(match n {
             // Special error message for 0 because this used to be a valid speed
             0 => panic!("Invalid speed: 0. If you wanted to set the speed to instant, please use the string \"instant\" or Speed::instant()"),
-            n if n >= MIN_SPEED && n <= MAX_SPEED => Value(n),
+            n if (MIN_SPEED..=MAX_SPEED).contains(&n) => Value(n as f64),
             n => panic!("Invalid speed: {}. Must be a value between {} and {}", n, MIN_SPEED, MAX_SPEED),
         })
     }
 }
 
-// We include this implementation because we use f64 in the rest of the library.
-// It makes sense to implement this so that they don't get messed up if they accidentally use a
-// floating point literal with set_speed.
+// Fractional speeds allow for finer control over the speed of animations than whole levels do.
 impl From<f64> for Speed {
     fn from(n: f64) -> Self {
-        (n.round() as i32).into()
+        use SpeedLevel::*;
+
+        // Values have always been rounded before checking the range, so values that round to a
+        // valid level are still accepted
+        let level = n.round();
+
+        // Special error message for 0 because this used to be a valid speed
+        if level == 0.0 {
+            panic!("Invalid speed: 0. If you wanted to set the speed to instant, please use the string \"instant\" or Speed::instant()");
+        }
+
+        if (MIN_SPEED as f64..=MAX_SPEED as f64).contains(&level) {
+            Speed(Value(n.clamp(MIN_SPEED as f64, MAX_SPEED as f64)))
+        } else {
+            panic!("Invalid speed: {}. Must be a value between {} and {}", n, MIN_SPEED, MAX_SPEED)
+        }
     }
 }
 
@@ -388,6 +611,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn display_fractional() {
+        assert_eq!(format!("{}", Speed::from(2.25)), "2.25");
+        assert_eq!(format!("{:?}", Speed::from(2.25)), "Speed(2.25)");
+        assert_eq!(format!("{}", Speed::from(24.5)), "24.5");
+    }
+
     #[test]
     fn speed_strings() {
         let mut turtle = Turtle::new();
@@ -407,6 +637,19 @@ mod tests {
         assert_eq!(turtle.speed(), Speed::instant());
     }
 
+    #[test]
+    fn speed_constants_match_strings() {
+        assert_eq!(Speed::SLOWEST, Speed::from("slowest"));
+        assert_eq!(Speed::SLOWER, Speed::from("slower"));
+        assert_eq!(Speed::SLOW, Speed::from("slow"));
+        assert_eq!(Speed::NORMAL, Speed::from("normal"));
+        assert_eq!(Speed::FAST, Speed::from("fast"));
+        assert_eq!(Speed::FASTER, Speed::from("faster"));
+        assert_eq!(Speed::FASTEST, MAX_SPEED);
+        assert_eq!(Speed::INSTANT, Speed::instant());
+        assert_eq!(Speed::default(), Speed::NORMAL);
+    }
+
     #[test]
     #[should_panic(expected = "Invalid speed specified, use one of the words: \"slowest\", \"slower\", \"slow\", \"normal\", \"fast\", \"faster\", \"instant\"")]
     fn invalid_speed() {
@@ -428,10 +671,142 @@ mod tests {
         let mut turtle = Turtle::new();
         for speed in 1..MAX_SPEED {
             turtle.set_speed(speed as f64 + 0.4);
-            assert_eq!(turtle.speed(), speed);
+            assert_eq!(turtle.speed().to_level(), speed as f64 + 0.4);
+            assert!(turtle.speed() > speed && turtle.speed() < speed + 1);
+        }
+    }
+
+    #[test]
+    fn fractional_speeds_interpolate() {
+        for speed in 1..MAX_SPEED {
+            let low = Speed::from(speed);
+            let mid = Speed::from(speed as f64 + 0.5);
+            let high = Speed::from(speed + 1);
+
+            assert!(low.to_px_per_sec() < mid.to_px_per_sec());
+            assert!(mid.to_px_per_sec() < high.to_px_per_sec());
+            assert!(low.to_rad_per_sec() < mid.to_rad_per_sec());
+            assert!(mid.to_rad_per_sec() < high.to_rad_per_sec());
+        }
+    }
+
+    #[test]
+    fn fractional_speeds_interpolate_linearly() {
+        // The time taken to cross a fixed distance changes linearly with the level, so the time
+        // taken at a fractional level is between the times of the levels around it
+        let px_time = |speed: Speed| 200.0 / speed.to_px_per_sec();
+        let rad_time = |speed: Speed| (radians::TWO_PI / speed.to_rad_per_sec()).to_radians();
+        for speed in 1..MAX_SPEED {
+            let low = Speed::from(speed);
+            let quarter = Speed::from(speed as f64 + 0.25);
+            let high = Speed::from(speed + 1);
+
+            let expected = px_time(low) + (px_time(high) - px_time(low)) * 0.25;
+            assert!((px_time(quarter) - expected).abs() < 1e-12);
+            let expected = rad_time(low) + (rad_time(high) - rad_time(low)) * 0.25;
+            assert!((rad_time(quarter) - expected).abs() < 1e-12);
         }
     }
 
+    #[test]
+    fn to_level_round_trips() {
+        for &level in &[1.0, 1.5, 2.25, 9.99, 12.5, 24.75, 25.0] {
+            let speed = Speed::from(level);
+            assert_eq!(speed.to_level(), level);
+            assert_eq!(Speed::from(speed.to_level()), speed);
+        }
+
+        // Speeds that were not created from a level round trip too
+        let speed = Speed::from_duration(300.0, Duration::from_secs(2));
+        assert_eq!(Speed::from(speed.to_level()), speed);
+        assert_eq!(Speed::from(Speed::FAST.to_level()), Speed::FAST);
+
+        assert_eq!(Speed::instant().to_level(), f64::INFINITY);
+    }
+
+    #[test]
+    fn fractional_speeds_are_ordered() {
+        let mut speeds = vec![
+            Speed::instant(),
+            Speed::from(3),
+            Speed::from(2.5),
+            Speed::from(25),
+            Speed::from(2.25),
+            Speed::from(24.75),
+        ];
+        speeds.sort();
+        assert_eq!(speeds, vec![
+            Speed::from(2.25),
+            Speed::from(2.5),
+            Speed::from(3),
+            Speed::from(24.75),
+            Speed::from(25),
+            Speed::instant(),
+        ]);
+
+        assert!(Speed::from(2.25) > 2 && Speed::from(2.25) < 3);
+        assert_ne!(Speed::from(2.25), 2);
+        assert!(Speed::from(24.75) < 25);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid speed: 25.5. Must be a value between 1 and 25")]
+    fn speed_value_f64_out_of_range() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed(25.5);
+    }
+
+    #[test]
+    fn speed_value_f64_rounds_into_range() {
+        assert_eq!(Speed::from(0.6), 1);
+        assert_eq!(Speed::from(0.5).to_level(), 1.0);
+        assert_eq!(Speed::from(25.4), 25);
+    }
+
+    #[test]
+    fn from_duration_matches_px_per_sec() {
+        for &(px, millis) in &[(200.0, 2000), (300.0, 2000), (1000.0, 250), (-450.0, 1500)] {
+            let duration = Duration::from_millis(millis);
+            let speed = Speed::from_duration(px, duration);
+            let expected = f64::abs(px) / duration.as_secs_f64();
+            assert!((speed.to_px_per_sec() - expected).abs() < 1e-6,
+                "{} px in {:?} should be {} px/s, but got {:?}", px, duration, expected, speed);
+        }
+
+        assert_eq!(Speed::from_duration(200.0, Duration::from_millis(5)), MAX_SPEED);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid speed: moving 10 px in 10 seconds is outside of the range of speed levels")]
+    fn from_duration_too_slow() {
+        Speed::from_duration(10.0, Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid distance: 0. The distance must not be zero")]
+    fn from_duration_zero_distance() {
+        Speed::from_duration(0.0, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn random_range_within_bounds() {
+        use crate::rand::random_range;
+
+        for _ in 0..100 {
+            let speed: Speed = random_range("slower", "normal");
+            assert!(speed >= 5 && speed <= 10, "{:?} is not between 5 and 10", speed);
+        }
+
+        let speed: Speed = random_range(7, 7);
+        assert_eq!(speed, 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one of the bounds provided to random_range() was Speed::instant()")]
+    fn random_range_rejects_instant() {
+        let _: Speed = crate::rand::random_range("normal", "instant");
+    }
+
     #[test]
     #[should_panic(expected = "Invalid speed: 26. Must be a value between 1 and 25")]
     fn speed_value_out_of_range() {
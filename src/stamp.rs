@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+/// Identifies a stamp left on the drawing by the [`stamp()` method]
+///
+/// Pass this to the [`clear_stamp()` method] to remove that stamp from the drawing. A `StampId` can
+/// only be used with the turtle that created it.
+///
+/// [`stamp()` method]: struct.Turtle.html#method.stamp
+/// [`clear_stamp()` method]: struct.Turtle.html#method.clear_stamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct StampId(pub(crate) usize);
@@ -0,0 +1,53 @@
+use serde::{Serialize, Deserialize};
+
+/// The font size used by [`write()`] when no other size is provided
+///
+/// [`write()`]: struct.Turtle.html#method.write
+pub(crate) const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+/// Controls where text is drawn relative to the turtle's position
+///
+/// See the [`write_with()` method] for more information.
+///
+/// # Creating Alignments
+///
+/// Just like with [`Speed`], you can create a `TextAlign` value by converting a string.
+///
+/// ```rust
+/// # use turtle::TextAlign;
+/// let align: TextAlign = "center".into();
+/// assert_eq!(align, TextAlign::Center);
+/// ```
+///
+/// The strings `"left"`, `"center"`, and `"right"` are supported. Any other string will cause a
+/// panic.
+///
+/// [`write_with()` method]: struct.Turtle.html#method.write_with
+/// [`Speed`]: struct.Speed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextAlign {
+    /// The text starts at the turtle's position
+    Left,
+    /// The text is centered horizontally on the turtle's position
+    Center,
+    /// The text ends at the turtle's position
+    Right,
+}
+
+/// The default alignment is "left"
+impl Default for TextAlign {
+    fn default() -> Self {
+        TextAlign::Left
+    }
+}
+
+impl<'a> From<&'a str> for TextAlign {
+    fn from(align: &'a str) -> Self {
+        match align {
+            "left" => TextAlign::Left,
+            "center" => TextAlign::Center,
+            "right" => TextAlign::Right,
+            _ => panic!("Invalid text alignment specified, use one of the words: \"left\", \"center\", \"right\""),
+        }
+    }
+}
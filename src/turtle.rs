@@ -1,6 +1,7 @@
 use std::fmt::{self, Debug};
+use std::time::Duration;
 
-use crate::{Color, Point, Speed, Distance, Angle};
+use crate::{Color, Point, Size, Speed, Distance, Angle, TextAlign, LineCap, LineJoin, PenState, Pen, StampId, TurtleShape, FillRule, FillPattern, FillId, UnknownFillError, PathRecorder, TurtleBatch};
 use crate::async_turtle::AsyncTurtle;
 use crate::sync_runtime::block_on;
 
@@ -67,6 +68,42 @@ impl Turtle {
         }
     }
 
+    /// Creates a new turtle in the same drawing as this turtle, starting out exactly the same as
+    /// this turtle.
+    ///
+    /// The new turtle has the same position, heading, speed, pen, fill color, shape, and
+    /// visibility as this turtle. It also uses the same unit for angles (see [`use_degrees()`]).
+    /// Anything that this turtle has drawn still belongs to this turtle, so calling [`clear()`]
+    /// or [`undo()`] on the new turtle does not affect it.
+    ///
+    /// After it is created, the new turtle is completely independent of this one. It is not
+    /// filling a shape, even if this turtle is.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_pen_color("blue");
+    /// turtle.forward(50.0);
+    /// turtle.right(45.0);
+    ///
+    /// let mut mirror = turtle.clone_turtle();
+    /// assert_eq!(mirror.position(), turtle.position());
+    /// assert_eq!(mirror.heading(), turtle.heading());
+    /// assert_eq!(mirror.pen_color(), "blue".into());
+    ///
+    /// // Draw a "V" shape by turning each turtle in the opposite direction
+    /// turtle.forward(100.0);
+    /// mirror.left(90.0);
+    /// mirror.forward(100.0);
+    /// ```
+    ///
+    /// [`use_degrees()`]: struct.Turtle.html#method.use_degrees
+    /// [`clear()`]: struct.Turtle.html#method.clear
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    pub fn clone_turtle(&self) -> Turtle {
+        block_on(self.turtle.clone_turtle()).into()
+    }
+
     /// Move the turtle forward by the given amount of `distance`. If the pen is down, the turtle
     /// will draw a line as it moves.
     ///
@@ -94,6 +131,56 @@ impl Turtle {
         block_on(self.turtle.forward(distance))
     }
 
+    /// Move the turtle forward by the given amount of `distance` and return the position that it
+    /// ends up at.
+    ///
+    /// This works just like [`forward()`], but saves having to ask for the [`position()`]
+    /// afterwards. That is useful for logging where the turtle goes or for deciding what to do
+    /// next based on where it is, especially in a loop that moves the turtle many times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // The turtle starts at the origin, facing up
+    /// let position = turtle.forward_returning(100.0);
+    /// assert_eq!(position.round(), Point {x: 0.0, y: 100.0});
+    /// assert_eq!(position, turtle.position());
+    /// ```
+    ///
+    /// [`forward()`]: #method.forward
+    /// [`position()`]: #method.position
+    pub fn forward_returning(&mut self, distance: Distance) -> Point {
+        block_on(self.turtle.forward_returning(distance))
+    }
+
+    /// Move the turtle forward by the given amount of `distance`, taking exactly `duration` to
+    /// get there. If the pen is down, the turtle will draw a line as it moves.
+    ///
+    /// This works just like [`forward()`], except that the turtle's [speed] is ignored. A
+    /// duration of zero moves the turtle instantly. The speed of the turtle is not changed, so
+    /// later movements will go back to using it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// # use turtle::Turtle;
+    /// # let mut turtle = Turtle::new();
+    /// // Draw a square, taking exactly one second for each side
+    /// for _ in 0..4 {
+    ///     turtle.forward_in(100.0, Duration::from_secs(1));
+    ///     turtle.right(90.0);
+    /// }
+    /// ```
+    ///
+    /// [`forward()`]: #method.forward
+    /// [speed]: #method.speed
+    pub fn forward_in(&mut self, distance: Distance, duration: Duration) {
+        block_on(self.turtle.forward_in(distance, duration))
+    }
+
     /// Move the turtle backwards by the given amount of `distance`. If the pen is down, the turtle
     /// will draw a line as it moves.
     ///
@@ -121,6 +208,80 @@ impl Turtle {
         block_on(self.turtle.backward(distance))
     }
 
+    /// Move the turtle backwards by the given amount of `distance` and return the position that
+    /// it ends up at.
+    ///
+    /// This works just like [`backward()`]. See [`forward_returning()`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// let position = turtle.backward_returning(50.0);
+    /// assert_eq!(position.round(), Point {x: 0.0, y: -50.0});
+    /// ```
+    ///
+    /// [`backward()`]: #method.backward
+    /// [`forward_returning()`]: #method.forward_returning
+    pub fn backward_returning(&mut self, distance: Distance) -> Point {
+        block_on(self.turtle.backward_returning(distance))
+    }
+
+    /// Move the turtle forward by the given amount of `distance`, stopping early if it reaches the
+    /// edge of the visible area of the drawing. Returns the distance that the turtle actually
+    /// moved.
+    ///
+    /// The visible area is based on the current [`size()`], [`center()`] and [`scale()`] of the
+    /// drawing, so zooming in makes the turtle stop closer to the center. Just like with
+    /// [`forward()`], the `distance` can be negative to move backward, in which case the returned
+    /// distance is negative too. A turtle that is already outside of the visible area can
+    /// still move back into it, but it won't move any further away.
+    ///
+    /// This makes it easy to keep something inside the window, like a ball bouncing off of the
+    /// walls:
+    ///
+    /// ```rust,no_run
+    /// # use turtle::Drawing;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// let half_width = drawing.size().width as f64 / 2.0;
+    /// turtle.set_heading(30.0);
+    ///
+    /// for _ in 0..10 {
+    ///     turtle.forward_bounded(1000.0);
+    ///
+    ///     // Reflect the heading off of whichever wall the turtle hit
+    ///     let heading = turtle.heading();
+    ///     if turtle.position().x.abs() >= half_width - 0.5 {
+    ///         turtle.set_heading(180.0 - heading);
+    ///     } else {
+    ///         turtle.set_heading(-heading);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// # use turtle::Drawing;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// // The turtle starts in the middle of the 800x600 drawing facing up
+    /// assert_eq!(turtle.forward_bounded(100.0), 100.0);
+    /// assert_eq!(turtle.forward_bounded(500.0), 200.0);
+    /// // Already at the top edge, so it can't go any further
+    /// assert_eq!(turtle.forward_bounded(10.0), 0.0);
+    /// // Moving backward is fine though
+    /// assert_eq!(turtle.forward_bounded(-50.0), -50.0);
+    /// ```
+    ///
+    /// [`size()`]: struct.Drawing.html#method.size
+    /// [`center()`]: struct.Drawing.html#method.center
+    /// [`scale()`]: struct.Drawing.html#method.scale
+    /// [`forward()`]: #method.forward
+    pub fn forward_bounded(&mut self, distance: Distance) -> Distance {
+        block_on(self.turtle.forward_bounded(distance))
+    }
+
     /// Instruct the turtle to turn right (clockwise) by the given angle. Since the turtle rotates
     /// in place, its position will not change and it will not draw anything while it turns.
     ///
@@ -158,6 +319,27 @@ impl Turtle {
         block_on(self.turtle.right(angle))
     }
 
+    /// Instruct the turtle to turn right (clockwise) by the given angle and return the heading
+    /// that it ends up facing.
+    ///
+    /// This works just like [`right()`], but saves having to ask for the [`heading()`]
+    /// afterwards. The heading is in the same units as `angle`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // The turtle starts facing up, at 90 degrees
+    /// assert_eq!(turtle.right_returning(30.0).round(), 60.0);
+    /// ```
+    ///
+    /// [`right()`]: #method.right
+    /// [`heading()`]: #method.heading
+    pub fn right_returning(&mut self, angle: Angle) -> Angle {
+        block_on(self.turtle.right_returning(angle))
+    }
+
     /// Instruct the turtle to turn left (counterclockwise) by the given angle. Since the turtle
     /// rotates in place, its position will not change and it will not draw anything while it
     /// turns.
@@ -192,6 +374,25 @@ impl Turtle {
         block_on(self.turtle.left(angle))
     }
 
+    /// Instruct the turtle to turn left (counterclockwise) by the given angle and return the
+    /// heading that it ends up facing.
+    ///
+    /// This works just like [`left()`]. See [`right_returning()`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.left_returning(30.0).round(), 120.0);
+    /// ```
+    ///
+    /// [`left()`]: #method.left
+    /// [`right_returning()`]: #method.right_returning
+    pub fn left_returning(&mut self, angle: Angle) -> Angle {
+        block_on(self.turtle.left_returning(angle))
+    }
+
     /// Waits for the specified number of seconds before executing the next command.
     ///
     /// ```rust,no_run
@@ -206,6 +407,71 @@ impl Turtle {
         block_on(self.turtle.wait(secs))
     }
 
+    /// Runs all of the movements added to a [`TurtleBatch`] by the given closure at once.
+    ///
+    /// Every call to a method like [`forward()`] waits for the window to carry it out before
+    /// returning. When drawing thousands of short lines, e.g. in a fractal, most of the time is
+    /// spent on that back and forth rather than on the drawing itself. The closure passed to
+    /// `batch()` collects its commands without sending them anywhere. They are then all sent
+    /// together and this method returns once every one of them has been carried out.
+    ///
+    /// The commands in a batch are never animated, no matter what the [`speed()`] of the turtle
+    /// is. The turtle jumps straight to wherever the batch leaves it, drawing every line along
+    /// the way, just as if its speed was `"instant"`. Each command can still be undone on its own
+    /// with [`undo()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // Draw a circle out of 360 tiny lines all at once
+    /// turtle.batch(|batch| {
+    ///     for _ in 0..360 {
+    ///         batch.forward(1.0);
+    ///         batch.right(1.0);
+    ///     }
+    /// });
+    /// # assert_eq!(turtle.position().round(), Point::origin());
+    /// # assert_eq!(turtle.heading().round(), 90.0);
+    /// ```
+    ///
+    /// [`TurtleBatch`]: struct.TurtleBatch.html
+    /// [`forward()`]: #method.forward
+    /// [`speed()`]: #method.speed
+    /// [`undo()`]: #method.undo
+    pub fn batch<F: FnOnce(&mut TurtleBatch)>(&mut self, commands: F) {
+        block_on(self.turtle.batch(commands))
+    }
+
+    /// Waits until the animation that the turtle is currently playing (if any) has completed,
+    /// without any extra delay.
+    ///
+    /// The methods that move or rotate the turtle already wait for their animation to complete
+    /// before returning, so there is usually no animation left to wait for. What `sync()` adds is
+    /// a guarantee: once it returns, the window has carried out every command given to this
+    /// turtle beforehand, including the ones that return right away, like
+    /// [`set_pen_color()`]. That makes it useful for coordinating turtles that are controlled
+    /// from different threads.
+    ///
+    /// With [`AsyncTurtle`], an animation can still be playing if the future that started it was
+    /// dropped before it completed. Awaiting `sync()` waits for that animation to complete.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.forward(100.0);
+    /// turtle.set_pen_color("blue");
+    /// // Returns right away since the turtle isn't moving anymore
+    /// turtle.sync();
+    /// ```
+    ///
+    /// [`set_pen_color()`]: struct.Turtle.html#method.set_pen_color
+    /// [`AsyncTurtle`]: struct.AsyncTurtle.html
+    pub fn sync(&mut self) {
+        block_on(self.turtle.sync())
+    }
+
     /// Draw a circular arc starting at the current position and going to the left of the turtle,
     /// thus globally turning counterclockwise.
     ///
@@ -318,10 +584,230 @@ impl Turtle {
         block_on(self.turtle.arc_right(radius, extent))
     }
 
+    /// Move the turtle along a circle around the given `center` point, drawing an arc if the pen
+    /// is down.
+    ///
+    /// The radius of the circle is the distance between the turtle and `center`, so unlike with
+    /// [`arc_left()`] and [`arc_right()`], the center stays in the same place no matter which
+    /// way the turtle is facing. The turtle first turns to face along the circle, then moves
+    /// around `center` by `angle`: counterclockwise if `angle` is positive, and clockwise if it is
+    /// negative. When it stops, the turtle is still facing along the circle. Calling this method
+    /// repeatedly keeps the turtle on the same circle, which makes it useful for orbits and gears.
+    ///
+    /// Nothing happens if the turtle is already at `center` or if `center` is infinite or NaN.
+    ///
+    /// The unit of `angle` is degrees by default, but can be set using the
+    /// [`use_degrees()`] or [`use_radians()`] methods. It can be more than a full turn, in which
+    /// case the turtle goes around more than once.
+    ///
+    /// Calling [`undo()`] once removes the entire arc.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::Turtle;
+    /// let mut turtle = Turtle::new();
+    /// turtle.pen_up();
+    /// turtle.go_to([100.0, 0.0]);
+    /// turtle.pen_down();
+    ///
+    /// // A quarter of the way around the origin
+    /// turtle.arc_around([0.0, 0.0], 90.0);
+    /// assert!((turtle.position() - [0.0, 100.0].into()).len() < 1e-9);
+    /// // Now facing to the left, along the circle
+    /// assert!((turtle.heading() - 180.0).abs() < 1e-9);
+    ///
+    /// // Go back the other way, which turns the turtle around first
+    /// turtle.arc_around([0.0, 0.0], -90.0);
+    /// assert!((turtle.position() - [100.0, 0.0].into()).len() < 1e-9);
+    /// // Facing down, along the circle in the clockwise direction
+    /// assert!((turtle.heading() - 270.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// [`arc_left()`]: struct.Turtle.html#method.arc_left
+    /// [`arc_right()`]: struct.Turtle.html#method.arc_right
+    /// [`use_degrees()`]: struct.Turtle.html#method.use_degrees
+    /// [`use_radians()`]: struct.Turtle.html#method.use_radians
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn arc_around<P: Into<Point>>(&mut self, center: P, angle: Angle) {
+        block_on(self.turtle.arc_around(center, angle))
+    }
+
+    /// Draws a smooth spiral that winds around a center point, getting wider (or narrower) as it
+    /// goes.
+    ///
+    /// The spiral starts at the turtle's current position, going in the direction that the turtle
+    /// is facing. It winds counterclockwise (to the left) if `turns` is positive and clockwise
+    /// (to the right) if it is negative. `turns` is the number of times to go around the center,
+    /// and it does not need to be a whole number.
+    ///
+    /// The center of the spiral is `start_radius` away from the turtle. With each full turn, the
+    /// distance from the center changes by `growth`: a positive `growth` makes the spiral wind
+    /// outward and a negative `growth` makes it wind inward. If `start_radius` is zero, the turtle
+    /// starts right at the center. A spiral with a `growth` of zero is just a circle, like the
+    /// ones drawn by [`arc_left()`] and [`arc_right()`].
+    ///
+    /// If `growth` is negative and the spiral reaches its center before it is done, it passes
+    /// straight through the center and winds outward again on the other side, still going around
+    /// in the same direction.
+    ///
+    /// The spiral is made of many short lines, so it looks smooth at any size. When it is done,
+    /// the turtle faces along the spiral. Calling [`undo()`] once removes the entire spiral.
+    ///
+    /// This method will panic if `start_radius` is negative, if `turns` is more than 100 turns
+    /// in either direction, or if any of the arguments are infinite or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::Turtle;
+    /// let mut turtle = Turtle::new();
+    /// // Starting from the middle, go around 5 times, moving 20 steps further out each time
+    /// turtle.spiral(0.0, 20.0, 5.0);
+    /// // Whole turns always end on the same side of the center as the start
+    /// // (the rounding is to account for floating-point error)
+    /// assert_eq!(turtle.position().round(), [0.0, 100.0].into());
+    /// ```
+    ///
+    /// [`arc_left()`]: struct.Turtle.html#method.arc_left
+    /// [`arc_right()`]: struct.Turtle.html#method.arc_right
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    pub fn spiral(&mut self, start_radius: Distance, growth: Distance, turns: f64) {
+        block_on(self.turtle.spiral(start_radius, growth, turns))
+    }
+
+    /// Draws a smooth curve from the turtle's current position to `end`, bending toward the two
+    /// control points along the way.
+    ///
+    /// This is a [cubic Bézier curve]. The curve leaves the turtle's position heading toward
+    /// `control1` and arrives at `end` coming from the direction of `control2`, but it usually
+    /// does not pass through either control point. The further away a control point is, the more
+    /// the curve is pulled toward it. Joining curves where the second control point of one curve,
+    /// the shared end point, and the first control point of the next curve are all on a straight
+    /// line gives a path without any sharp corners.
+    ///
+    /// The curve is drawn as many short lines, with more lines for larger curves. The number of
+    /// lines does not depend on the speed of the turtle, so the curve has exactly the same shape
+    /// at every speed (including in saved images). The speed only changes how long it takes to
+    /// draw the curve. The turtle turns to follow the curve as it moves, so when it is done, it
+    /// faces (approximately) the direction from `control2` to `end`. Calling [`undo()`] once
+    /// removes the entire curve. Nothing happens if any of the points are infinite or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::Turtle;
+    /// let mut turtle = Turtle::new();
+    /// // An "S" shape that starts and ends facing to the right
+    /// turtle.pen_up();
+    /// turtle.go_to([-200.0, -100.0]);
+    /// turtle.pen_down();
+    /// turtle.bezier([0.0, -100.0], [0.0, 100.0], [200.0, 100.0]);
+    /// assert_eq!(turtle.position(), [200.0, 100.0].into());
+    /// ```
+    ///
+    /// To draw a curve with only one control point, use [`quadratic_bezier()`].
+    ///
+    /// [cubic Bézier curve]: https://en.wikipedia.org/wiki/B%C3%A9zier_curve#Cubic_B%C3%A9zier_curves
+    /// [`quadratic_bezier()`]: struct.Turtle.html#method.quadratic_bezier
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    pub fn bezier<C1, C2, E>(&mut self, control1: C1, control2: C2, end: E)
+        where C1: Into<Point>,
+              C2: Into<Point>,
+              E: Into<Point>,
+    {
+        block_on(self.turtle.bezier(control1, control2, end))
+    }
+
+    /// Draws a smooth curve from the turtle's current position to `end`, bending toward the
+    /// `control` point along the way.
+    ///
+    /// This is a [quadratic Bézier curve]. The curve leaves the turtle's position heading toward
+    /// `control` and arrives at `end` coming from the direction of `control`. Otherwise, it works
+    /// just like [`bezier()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::Turtle;
+    /// let mut turtle = Turtle::new();
+    /// // An arch from the left to the right that reaches halfway up to its control point
+    /// turtle.pen_up();
+    /// turtle.go_to([-100.0, 0.0]);
+    /// turtle.pen_down();
+    /// turtle.quadratic_bezier([0.0, 200.0], [100.0, 0.0]);
+    /// assert_eq!(turtle.position(), [100.0, 0.0].into());
+    /// ```
+    ///
+    /// [quadratic Bézier curve]: https://en.wikipedia.org/wiki/B%C3%A9zier_curve#Quadratic_B%C3%A9zier_curves
+    /// [`bezier()`]: struct.Turtle.html#method.bezier
+    pub fn quadratic_bezier<C, E>(&mut self, control: C, end: E)
+        where C: Into<Point>,
+              E: Into<Point>,
+    {
+        block_on(self.turtle.quadratic_bezier(control, end))
+    }
+
+    /// Draws a regular polygon with the given number of sides, each with the given length.
+    ///
+    /// The turtle draws the first side in the direction it is facing and turns right after each
+    /// side, the same way you would draw a square with `forward()` and `right(90.0)`. Once the
+    /// polygon is complete, the turtle is back where it started, facing the same direction. Each
+    /// side is drawn at the turtle's current speed, and only if its pen is down.
+    ///
+    /// The number of sides must be at least 3, otherwise this method will panic.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // A hexagon
+    /// turtle.polygon(6, 50.0);
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(turtle.position().round(), Point::origin());
+    /// assert_eq!(turtle.heading().round(), 90.0);
+    /// ```
+    ///
+    /// To fill the polygon as well, use [`filled_polygon()`].
+    ///
+    /// [`filled_polygon()`]: struct.Turtle.html#method.filled_polygon
+    pub fn polygon(&mut self, sides: u32, side_length: Distance) {
+        block_on(self.turtle.polygon(sides, side_length))
+    }
+
+    /// Draws a regular polygon like [`polygon()`] and fills it with the given color.
+    ///
+    /// The fill color of the turtle is only changed while the polygon is drawn. Afterwards, it is
+    /// set back to what it was before. Since this uses [`begin_fill()`] and [`end_fill()`], it
+    /// should not be called while the turtle is already filling another shape.
+    ///
+    /// The number of sides must be at least 3, otherwise this method will panic.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_fill_color("blue");
+    /// // A green triangle
+    /// turtle.filled_polygon(3, 100.0, "green");
+    /// assert_eq!(turtle.fill_color(), "blue".into());
+    /// ```
+    ///
+    /// [`polygon()`]: struct.Turtle.html#method.polygon
+    /// [`begin_fill()`]: struct.Turtle.html#method.begin_fill
+    /// [`end_fill()`]: struct.Turtle.html#method.end_fill
+    pub fn filled_polygon<C: Into<Color> + Copy + Debug>(&mut self, sides: u32, side_length: Distance, color: C) {
+        block_on(self.turtle.filled_polygon(sides, side_length, color))
+    }
+
     pub(crate) fn into_async(self) -> AsyncTurtle {
         self.turtle
     }
 
+    pub(crate) fn as_async(&self) -> &AsyncTurtle {
+        &self.turtle
+    }
+
     /// Returns the current speed of the turtle.
     ///
     /// ```rust
@@ -382,69 +868,305 @@ impl Turtle {
         self.turtle.set_speed(speed)
     }
 
-    /// Returns the turtle's current location (x, y)
+    /// Returns the number of pixels per second that the turtle moves.
+    ///
+    /// This is the rate set by [`set_speed_px_per_sec()`], or the rate given by the turtle's
+    /// [speed] if no rate was set. For the instant speed, this is `f64::INFINITY`.
     ///
     /// ```rust
     /// # use turtle::*;
-    /// # let mut turtle = Turtle::new();
-    /// turtle.forward(100.0);
-    /// let pos = turtle.position();
-    /// assert_eq!(pos.round(), Point {x: 0.0, y: 100.0});
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_speed_px_per_sec(150.0);
+    /// assert_eq!(turtle.speed_px_per_sec(), 150.0);
+    ///
+    /// turtle.set_speed("instant");
+    /// assert_eq!(turtle.speed_px_per_sec(), f64::INFINITY);
     /// ```
-    pub fn position(&self) -> Point {
-        block_on(self.turtle.position())
+    ///
+    /// [`set_speed_px_per_sec()`]: struct.Turtle.html#method.set_speed_px_per_sec
+    /// [speed]: struct.Turtle.html#method.speed
+    pub fn speed_px_per_sec(&self) -> Distance {
+        block_on(self.turtle.speed_px_per_sec())
     }
 
-    /// Moves the turtle directly to the given position. See the [`Point` struct](struct.Point.html)
-    /// documentation for more information.
+    /// Sets the number of pixels per second that the turtle moves, instead of using the rate
+    /// given by its [speed].
     ///
-    /// If the pen is down, this will draw a line. The turtle will not turn to face the direction
-    /// in which it is moving. It's heading will stay the same.
-    /// Use [`set_speed()`](struct.Turtle.html#method.set_speed) to control the animation speed.
+    /// The rates used for each [`Speed`] level are an implementation detail that may change. Use
+    /// this method when an animation needs to take a precise amount of time. Only movement is
+    /// affected. To set the rate at which the turtle turns, use [`set_turn_speed_deg_per_sec()`].
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use turtle::*;
-    /// # let mut turtle = Turtle::new();
-    /// let heading = turtle.heading();
-    /// assert_eq!(turtle.position(), Point {x: 0.0, y: 0.0});
-    /// turtle.go_to([100.0, -150.0]);
-    /// // The heading has not changed, but the turtle has moved to the new position
-    /// assert_eq!(turtle.heading(), heading);
-    /// assert_eq!(turtle.position(), Point {x: 100.0, y: -150.0});
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_speed_px_per_sec(100.0);
+    /// // Takes exactly 2 seconds
+    /// turtle.forward(200.0);
     /// ```
-    pub fn go_to<P: Into<Point>>(&mut self, position: P) {
-        block_on(self.turtle.go_to(position))
-    }
-
-    /// Goes to the given x-coordinate, keeping the y-coordinate and heading of the turtle the
-    /// same. See [`go_to()`](struct.Turtle.html#method.go_to) for more information.
-    pub fn set_x(&mut self, x: f64) {
-        block_on(self.turtle.set_x(x))
-    }
-
-    /// Goes to the given y-coordinate, keeping the x-coordinate and heading of the turtle the
-    /// same. See [`go_to()`](struct.Turtle.html#method.go_to) for more information.
-    pub fn set_y(&mut self, y: f64) {
-        block_on(self.turtle.set_y(y))
+    ///
+    /// Calling [`set_speed()`] goes back to using the rates given by the new speed for both
+    /// movement and rotation. The value returned by [`speed()`] does not change when this method
+    /// is called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `px` is not a finite number greater than zero. To move instantly, set the speed
+    /// to `"instant"` instead.
+    ///
+    /// [speed]: struct.Turtle.html#method.set_speed
+    /// [`Speed`]: struct.Speed.html
+    /// [`set_turn_speed_deg_per_sec()`]: struct.Turtle.html#method.set_turn_speed_deg_per_sec
+    /// [`set_speed()`]: struct.Turtle.html#method.set_speed
+    /// [`speed()`]: struct.Turtle.html#method.speed
+    pub fn set_speed_px_per_sec(&mut self, px: Distance) {
+        self.turtle.set_speed_px_per_sec(px)
     }
 
-    /// Moves the turtle to the origin and resets its heading to face north. If the pen is down, the
-    /// turtle will draw a line as it moves.
+    /// Returns the number of degrees per second that the turtle turns.
+    ///
+    /// This is the rate set by [`set_turn_speed_deg_per_sec()`], or the rate given by the
+    /// turtle's [speed] if no rate was set. The rate is always in degrees, even if the turtle is
+    /// [using radians]. For the instant speed, this is `f64::INFINITY`.
     ///
-    //TODO: We could have a more illustrative doctest here
     /// ```rust
     /// # use turtle::*;
     /// let mut turtle = Turtle::new();
-    /// let start_position = turtle.position().round();
-    /// let start_heading = turtle.heading().round();
+    /// turtle.set_turn_speed_deg_per_sec(90.0);
+    /// assert_eq!(turtle.turn_speed_deg_per_sec().round(), 90.0);
+    /// ```
     ///
-    /// turtle.right(55.0);
-    /// turtle.forward(127.0);
-    /// assert_ne!(turtle.position().round(), start_position);
-    /// assert_ne!(turtle.heading().round(), start_heading);
+    /// [`set_turn_speed_deg_per_sec()`]: struct.Turtle.html#method.set_turn_speed_deg_per_sec
+    /// [speed]: struct.Turtle.html#method.speed
+    /// [using radians]: struct.Turtle.html#method.use_radians
+    pub fn turn_speed_deg_per_sec(&self) -> f64 {
+        block_on(self.turtle.turn_speed_deg_per_sec())
+    }
+
+    /// Sets the number of degrees per second that the turtle turns, instead of using the rate
+    /// given by its [speed].
     ///
-    /// // The turtle will draw a line as it moves
-    /// turtle.home();
+    /// This works just like [`set_speed_px_per_sec()`], except that only rotation is affected.
+    /// The rate is always in degrees, even if the turtle is [using radians].
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_turn_speed_deg_per_sec(45.0);
+    /// // Takes exactly 2 seconds
+    /// turtle.right(90.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `deg` is not a finite number greater than zero.
+    ///
+    /// [speed]: struct.Turtle.html#method.set_speed
+    /// [`set_speed_px_per_sec()`]: struct.Turtle.html#method.set_speed_px_per_sec
+    /// [using radians]: struct.Turtle.html#method.use_radians
+    pub fn set_turn_speed_deg_per_sec(&mut self, deg: f64) {
+        self.turtle.set_turn_speed_deg_per_sec(deg)
+    }
+
+    /// Returns true if the turtle's tracer is enabled. The tracer is enabled by default.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert!(turtle.is_tracer_enabled());
+    /// turtle.set_tracer(false);
+    /// assert!(!turtle.is_tracer_enabled());
+    /// ```
+    ///
+    /// See [`set_tracer()`] for more information.
+    ///
+    /// [`set_tracer()`]: struct.Turtle.html#method.set_tracer
+    pub fn is_tracer_enabled(&self) -> bool {
+        block_on(self.turtle.is_tracer_enabled())
+    }
+
+    /// Enables or disables the turtle's tracer, similar to `tracer` in Python's turtle module.
+    ///
+    /// While the tracer is disabled, the turtle moves and turns instantly regardless of its
+    /// [speed] and the window is not redrawn as it draws. Everything drawn in the meantime is
+    /// shown all at once when the tracer is enabled again. Until then, it is left out of the
+    /// window (even if the window is redrawn for some other reason), screenshots and exported
+    /// images. This makes drawings with thousands of lines much faster, since the window no
+    /// longer has to be redrawn after every line.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_tracer(false);
+    /// for i in 0..2000 {
+    ///     turtle.forward(i as f64 * 0.2);
+    ///     turtle.right(59.0);
+    /// }
+    /// // The entire spiral appears at once
+    /// turtle.set_tracer(true);
+    /// ```
+    ///
+    /// To show the progress of the drawing while the tracer is disabled, call
+    /// [`Drawing::update()`]. The speed of the turtle is not changed, so it goes back to animating
+    /// its movements at the same speed as before once the tracer is enabled.
+    ///
+    /// [speed]: struct.Turtle.html#method.set_speed
+    /// [`Drawing::update()`]: struct.Drawing.html#method.update
+    pub fn set_tracer(&mut self, enabled: bool) {
+        self.turtle.set_tracer(enabled)
+    }
+
+    /// Returns the turtle's current location (x, y)
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// turtle.forward(100.0);
+    /// let pos = turtle.position();
+    /// assert_eq!(pos.round(), Point {x: 0.0, y: 100.0});
+    /// ```
+    pub fn position(&self) -> Point {
+        block_on(self.turtle.position())
+    }
+
+    /// Moves the turtle directly to the given position. See the [`Point` struct](struct.Point.html)
+    /// documentation for more information.
+    ///
+    /// If the pen is down, this will draw a line. The turtle will not turn to face the direction
+    /// in which it is moving. It's heading will stay the same.
+    /// Use [`set_speed()`](struct.Turtle.html#method.set_speed) to control the animation speed.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// let heading = turtle.heading();
+    /// assert_eq!(turtle.position(), Point {x: 0.0, y: 0.0});
+    /// turtle.go_to([100.0, -150.0]);
+    /// // The heading has not changed, but the turtle has moved to the new position
+    /// assert_eq!(turtle.heading(), heading);
+    /// assert_eq!(turtle.position(), Point {x: 100.0, y: -150.0});
+    /// ```
+    pub fn go_to<P: Into<Point>>(&mut self, position: P) {
+        block_on(self.turtle.go_to(position))
+    }
+
+    /// Moves the turtle by the given offset from its current position, keeping its heading the
+    /// same.
+    ///
+    /// This is the same as calling [`go_to()`] with `position() + offset`, so it draws a line if
+    /// the pen is down. Unlike [`forward()`], the direction the turtle moves in does not depend on
+    /// its heading. That makes it convenient for drawing on a grid.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// let heading = turtle.heading();
+    /// turtle.go_to([10.0, 20.0]);
+    ///
+    /// // Move 30 steps right and 40 steps down
+    /// turtle.move_by([30.0, -40.0]);
+    /// assert_eq!(turtle.position(), Point {x: 40.0, y: -20.0});
+    /// assert_eq!(turtle.heading(), heading);
+    /// ```
+    ///
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    /// [`forward()`]: struct.Turtle.html#method.forward
+    pub fn move_by<P: Into<Point>>(&mut self, offset: P) {
+        block_on(self.turtle.move_by(offset))
+    }
+
+    /// Moves the turtle directly to the point that is `radius` steps away from the origin in the
+    /// direction of `angle`, keeping its heading the same.
+    ///
+    /// These are [polar coordinates](https://en.wikipedia.org/wiki/Polar_coordinate_system). The
+    /// angle is measured the same way as the [`heading()`]: `0.0` points to the right and angles
+    /// increase counterclockwise. It is in degrees or radians depending on whether the turtle is
+    /// [using degrees] or [using radians]. Just like with [`go_to()`], a line is drawn if the pen is
+    /// down.
+    ///
+    /// This is convenient for drawings that are naturally described by angles, like flowers:
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// for i in 0..=360 {
+    ///     let angle = i as f64;
+    ///     // A flower with 5 petals
+    ///     let radius = 200.0 * (5.0 * angle.to_radians()).sin().abs();
+    ///     turtle.go_to_polar(radius, angle);
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.go_to_polar(100.0, 90.0);
+    /// assert_eq!(turtle.position().round(), Point {x: 0.0, y: 100.0});
+    /// turtle.go_to_polar(50.0, 180.0);
+    /// assert_eq!(turtle.position().round(), Point {x: -50.0, y: 0.0});
+    /// ```
+    ///
+    /// [`heading()`]: struct.Turtle.html#method.heading
+    /// [using degrees]: struct.Turtle.html#method.use_degrees
+    /// [using radians]: struct.Turtle.html#method.use_radians
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    pub fn go_to_polar(&mut self, radius: Distance, angle: Angle) {
+        block_on(self.turtle.go_to_polar(radius, angle))
+    }
+
+    /// Moves the turtle `radius` steps from its current position in the direction of `angle`,
+    /// keeping its heading the same.
+    ///
+    /// This is the relative version of [`go_to_polar()`], just like [`move_by()`] is the relative
+    /// version of [`go_to()`]. Unlike [`forward()`], the direction the turtle moves in does not
+    /// depend on its heading.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.go_to([10.0, 10.0]);
+    /// turtle.move_by_polar(20.0, 0.0);
+    /// assert_eq!(turtle.position().round(), Point {x: 30.0, y: 10.0});
+    /// // The turtle is still facing up
+    /// assert_eq!(turtle.heading(), 90.0);
+    /// ```
+    ///
+    /// [`go_to_polar()`]: struct.Turtle.html#method.go_to_polar
+    /// [`move_by()`]: struct.Turtle.html#method.move_by
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    /// [`forward()`]: struct.Turtle.html#method.forward
+    pub fn move_by_polar(&mut self, radius: Distance, angle: Angle) {
+        block_on(self.turtle.move_by_polar(radius, angle))
+    }
+
+    /// Goes to the given x-coordinate, keeping the y-coordinate and heading of the turtle the
+    /// same. See [`go_to()`](struct.Turtle.html#method.go_to) for more information.
+    pub fn set_x(&mut self, x: f64) {
+        block_on(self.turtle.set_x(x))
+    }
+
+    /// Goes to the given y-coordinate, keeping the x-coordinate and heading of the turtle the
+    /// same. See [`go_to()`](struct.Turtle.html#method.go_to) for more information.
+    pub fn set_y(&mut self, y: f64) {
+        block_on(self.turtle.set_y(y))
+    }
+
+    /// Moves the turtle to the origin and resets its heading to face north. If the pen is down, the
+    /// turtle will draw a line as it moves.
+    ///
+    //TODO: We could have a more illustrative doctest here
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// let start_position = turtle.position().round();
+    /// let start_heading = turtle.heading().round();
+    ///
+    /// turtle.right(55.0);
+    /// turtle.forward(127.0);
+    /// assert_ne!(turtle.position().round(), start_position);
+    /// assert_ne!(turtle.heading().round(), start_heading);
+    ///
+    /// // The turtle will draw a line as it moves
+    /// turtle.home();
     /// assert_eq!(turtle.position().round(), start_position);
     /// assert_eq!(turtle.heading().round(), start_heading);
     /// ```
@@ -533,6 +1255,125 @@ impl Turtle {
         block_on(self.turtle.set_heading(angle))
     }
 
+    /// Instantly moves the turtle to the given position and rotates it to face the given heading.
+    ///
+    /// Unlike [`go_to()`] and [`set_heading()`], there is no animation and no line is drawn, no
+    /// matter what the turtle's speed is or whether its pen is down. This is useful for moving the
+    /// turtle to where the next part of a drawing should start. The pen itself is not changed, so
+    /// if it was down before, the turtle will continue drawing from its new position.
+    ///
+    /// If the turtle is [filling a shape], the new position is still added to the shape as if
+    /// the turtle had moved there with its pen up.
+    ///
+    /// The unit of `heading` is degrees by default, but can be set using the
+    /// [`use_degrees()`] or [`use_radians()`] methods. Calling `teleport()` with a position or
+    /// heading that is infinite or NaN does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.forward(100.0);
+    ///
+    /// turtle.teleport([-50.0, 20.0], 0.0);
+    /// assert_eq!(turtle.position(), Point {x: -50.0, y: 20.0});
+    /// assert_eq!(turtle.heading(), 0.0);
+    /// // The pen is still down, so this draws a line starting at (-50, 20)
+    /// assert!(turtle.is_pen_down());
+    /// turtle.forward(100.0);
+    /// ```
+    ///
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    /// [`set_heading()`]: struct.Turtle.html#method.set_heading
+    /// [filling a shape]: struct.Turtle.html#method.begin_fill
+    /// [`use_degrees()`]: struct.Turtle.html#method.use_degrees
+    /// [`use_radians()`]: struct.Turtle.html#method.use_radians
+    pub fn teleport<P: Into<Point>>(&mut self, position: P, heading: Angle) {
+        self.turtle.teleport(position, heading)
+    }
+
+    /// Saves the turtle's position, heading, pen, and fill color so that they can be restored
+    /// later with [`restore()`].
+    ///
+    /// Each call to `save()` pushes the state onto a stack, so calls can be nested. Each call to
+    /// [`restore()`] goes back to the state from the most recent call to `save()` that has not
+    /// been restored yet. This makes it easy to draw something like a branch of a tree and then
+    /// return to where the branch started.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.save();
+    ///
+    /// turtle.set_pen_color("green");
+    /// turtle.right(45.0);
+    /// turtle.forward(100.0);
+    ///
+    /// turtle.restore();
+    /// assert_eq!(turtle.position(), Point::origin());
+    /// assert_eq!(turtle.heading(), 90.0);
+    /// assert_eq!(turtle.pen_color(), "black".into());
+    /// ```
+    ///
+    /// [`restore()`]: #method.restore
+    pub fn save(&mut self) {
+        block_on(self.turtle.save())
+    }
+
+    /// Restores the state saved by the most recent call to [`save()`] that has not been restored
+    /// yet.
+    ///
+    /// The turtle [teleports] back to the saved position and heading, so nothing is drawn between
+    /// its current position and the saved position, even if the pen is down. The pen (see
+    /// [`pen_state()`]) and fill color are then set back to what they were when the state was
+    /// saved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no saved state left to restore.
+    ///
+    /// # Example
+    ///
+    /// In a [bracketed L-system](https://en.wikipedia.org/wiki/L-system#Example_2:_fractal_(binary)_tree),
+    /// `[` and `]` map directly to `save()` and `restore()`:
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.left(90.0);
+    ///
+    ///     let mut commands = String::from("X");
+    ///     for _ in 0..4 {
+    ///         commands = commands.chars().map(|c| match c {
+    ///             'X' => "F[-X][+X]FX".to_string(),
+    ///             'F' => "FF".to_string(),
+    ///             c => c.to_string(),
+    ///         }).collect();
+    ///     }
+    ///
+    ///     for c in commands.chars() {
+    ///         match c {
+    ///             'F' => turtle.forward(8.0),
+    ///             '-' => turtle.left(25.0),
+    ///             '+' => turtle.right(25.0),
+    ///             '[' => turtle.save(),
+    ///             ']' => turtle.restore(),
+    ///             _ => {},
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`save()`]: #method.save
+    /// [teleports]: #method.teleport
+    /// [`pen_state()`]: #method.pen_state
+    pub fn restore(&mut self) {
+        self.turtle.restore()
+    }
+
     /// Returns true if `Angle` values will be interpreted as degrees.
     ///
     /// See [`use_degrees()`](struct.Turtle.html#method.use_degrees) for more information.
@@ -580,6 +1421,72 @@ impl Turtle {
         self.turtle.use_radians()
     }
 
+    /// Returns the angle equivalent to `angle` that is at least zero and less than one full turn.
+    ///
+    /// In degrees, the result is between 0.0 (inclusive) and 360.0 (exclusive). In radians, it is
+    /// between 0.0 (inclusive) and 2π (exclusive). This is the same range as the angles returned by
+    /// [`heading()`], so it is useful for comparing a heading with an angle you calculated.
+    ///
+    /// The unit of `angle` is degrees by default, but can be set using the
+    /// [`use_degrees()`] or [`use_radians()`] methods.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.normalize_angle(450.0), 90.0);
+    /// assert_eq!(turtle.normalize_angle(-90.0), 270.0);
+    /// assert_eq!(turtle.normalize_angle(360.0), 0.0);
+    ///
+    /// turtle.left(450.0);
+    /// assert_eq!(turtle.heading(), turtle.normalize_angle(90.0 + 450.0));
+    /// ```
+    ///
+    /// [`heading()`]: struct.Turtle.html#method.heading
+    /// [`use_degrees()`]: struct.Turtle.html#method.use_degrees
+    /// [`use_radians()`]: struct.Turtle.html#method.use_radians
+    pub fn normalize_angle(&self, angle: Angle) -> Angle {
+        self.turtle.normalize_angle(angle)
+    }
+
+    /// Converts an `Angle` in the turtle's current angle unit to radians.
+    ///
+    /// If the turtle is already [using radians], `angle` is returned unchanged. This is useful for
+    /// working with functions that expect radians, like [`f64::sin()`], without checking which
+    /// unit the turtle is using.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.to_radians(180.0), std::f64::consts::PI);
+    ///
+    /// turtle.use_radians();
+    /// assert_eq!(turtle.to_radians(1.5), 1.5);
+    /// ```
+    ///
+    /// [using radians]: struct.Turtle.html#method.use_radians
+    /// [`f64::sin()`]: https://doc.rust-lang.org/std/primitive.f64.html#method.sin
+    pub fn to_radians(&self, angle: Angle) -> f64 {
+        self.turtle.to_radians(angle)
+    }
+
+    /// Converts an `Angle` in the turtle's current angle unit to degrees.
+    ///
+    /// If the turtle is already [using degrees], `angle` is returned unchanged.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.to_degrees(45.0), 45.0);
+    ///
+    /// turtle.use_radians();
+    /// assert_eq!(turtle.to_degrees(std::f64::consts::PI), 180.0);
+    /// ```
+    ///
+    /// [using degrees]: struct.Turtle.html#method.use_degrees
+    pub fn to_degrees(&self, angle: Angle) -> f64 {
+        self.turtle.to_degrees(angle)
+    }
+
     /// Return true if pen is down, false if it’s up.
     ///
     /// ```rust
@@ -687,37 +1594,174 @@ impl Turtle {
         self.turtle.set_pen_size(thickness)
     }
 
-    /// Returns the color of the pen.
+    /// Gradually changes the thickness of the pen to the given size over the next `over_distance`
+    /// pixels that the turtle travels.
+    ///
+    /// The thickness changes at a steady rate as the turtle moves forward or backward, so the
+    /// lines it draws get thicker or thinner along their length. This is great for drawing strokes
+    /// that taper off like a brush or a calligraphy pen. Any distance travelled while the pen is
+    /// up still counts, but turning the turtle does not. Once the turtle has travelled
+    /// `over_distance` pixels, the pen stays at the new size.
+    ///
+    /// [`pen_size()`] always returns the current thickness of the pen, so it will change as the
+    /// turtle moves. Calling [`set_pen_size()`] stops the pen from changing size any further.
+    /// Setting `over_distance` to zero is the same as calling [`set_pen_size()`].
+    ///
+    /// Lines that change in thickness are always drawn with the pen's [line cap] at each end,
+    /// instead of being joined to the lines before and after them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `thickness` or `over_distance` is negative, infinite, or NaN.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// # use turtle::*;
-    /// # let mut turtle = Turtle::new();
-    /// turtle.set_pen_color("blue");
-    /// assert_eq!(turtle.pen_color(), "blue".into());
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_pen_size(2.0);
+    ///
+    /// // Get thicker over the next 100 pixels
+    /// turtle.set_pen_size_animated(20.0, 100.0);
+    /// turtle.forward(50.0);
+    /// assert_eq!(turtle.pen_size(), 11.0);
+    /// turtle.forward(100.0);
+    /// assert_eq!(turtle.pen_size(), 20.0);
+    ///
+    /// // Taper off to a point
+    /// turtle.set_pen_size_animated(0.0, 80.0);
+    /// turtle.forward(80.0);
+    /// assert_eq!(turtle.pen_size(), 0.0);
     /// ```
     ///
-    /// See the [`Color` struct](struct.Color.html) for more information about colors.
-    pub fn pen_color(&self) -> Color {
-        block_on(self.turtle.pen_color())
+    /// [`pen_size()`]: struct.Turtle.html#method.pen_size
+    /// [`set_pen_size()`]: struct.Turtle.html#method.set_pen_size
+    /// [line cap]: struct.Turtle.html#method.set_pen_cap
+    pub fn set_pen_size_animated(&mut self, thickness: f64, over_distance: Distance) {
+        self.turtle.set_pen_size_animated(thickness, over_distance)
     }
 
-    /// Sets the color of the pen to the given color.
+    /// Returns the shape drawn at the ends of the lines drawn by the pen.
     ///
-    /// Any type that can be converted into a color can be passed into this function.
-    /// See the [`Color` struct](struct.Color.html) for more information.
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// // Lines have round ends by default
+    /// assert_eq!(turtle.pen_cap(), LineCap::Round);
+    /// turtle.set_pen_cap("butt");
+    /// assert_eq!(turtle.pen_cap(), LineCap::Butt);
+    /// ```
     ///
-    /// # Example
+    /// See [`set_pen_cap()`](struct.Turtle.html#method.set_pen_cap) for more details.
+    pub fn pen_cap(&self) -> LineCap {
+        block_on(self.turtle.pen_cap())
+    }
+
+    /// Sets the shape drawn at the ends of the lines drawn by the pen.
+    ///
+    /// You can pass either a [`LineCap`] value or one of the strings `"butt"`, `"round"`, or
+    /// `"square"`. Lines have round ends (`"round"`) by default. The cap makes the most difference
+    /// when the [pen size] is large.
     ///
     /// ```rust,no_run
-    /// use turtle::Drawing;
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_pen_size(50.0);
     ///
-    /// fn main() {
-    ///     let mut drawing = Drawing::new();
-    ///     let mut turtle = drawing.add_turtle();
-    ///     drawing.set_background_color("light grey");
-    ///     turtle.set_pen_size(3.0);
+    /// // Ends exactly where the turtle stops
+    /// turtle.set_pen_cap("butt");
+    /// turtle.forward(100.0);
     ///
-    ///     let colors = ["red", "green", "blue"];
+    /// // Extends past where the turtle stops by half the pen size
+    /// turtle.set_pen_cap(LineCap::Square);
+    /// turtle.forward(100.0);
+    /// ```
+    ///
+    /// [`LineCap`]: enum.LineCap.html
+    /// [pen size]: struct.Turtle.html#method.set_pen_size
+    pub fn set_pen_cap<C: Into<LineCap>>(&mut self, cap: C) {
+        self.turtle.set_pen_cap(cap)
+    }
+
+    /// Returns the shape drawn at the corners where the lines drawn by the pen meet.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// // Corners are round by default
+    /// assert_eq!(turtle.pen_join(), LineJoin::Round);
+    /// turtle.set_pen_join("miter");
+    /// assert_eq!(turtle.pen_join(), LineJoin::Miter);
+    /// ```
+    ///
+    /// See [`set_pen_join()`](struct.Turtle.html#method.set_pen_join) for more details.
+    pub fn pen_join(&self) -> LineJoin {
+        block_on(self.turtle.pen_join())
+    }
+
+    /// Sets the shape drawn at the corners where the lines drawn by the pen meet.
+    ///
+    /// You can pass either a [`LineJoin`] value or one of the strings `"miter"`, `"round"`, or
+    /// `"bevel"`. Corners are round (`"round"`) by default.
+    ///
+    /// A corner is only drawn between two lines if the second line starts where the first line
+    /// ended and both lines were drawn with the same pen color, size, cap, and join. Otherwise,
+    /// each line is drawn on its own with the [line cap] at both of its ends.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_pen_size(30.0);
+    /// turtle.set_pen_join("miter");
+    ///
+    /// // Draws a square with sharp corners
+    /// for _ in 0..4 {
+    ///     turtle.forward(200.0);
+    ///     turtle.right(90.0);
+    /// }
+    /// ```
+    ///
+    /// [`LineJoin`]: enum.LineJoin.html
+    /// [line cap]: struct.Turtle.html#method.set_pen_cap
+    pub fn set_pen_join<J: Into<LineJoin>>(&mut self, join: J) {
+        self.turtle.set_pen_join(join)
+    }
+
+    /// Returns the color of the pen.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// turtle.set_pen_color("blue");
+    /// assert_eq!(turtle.pen_color(), "blue".into());
+    /// ```
+    ///
+    /// If the pen is drawing a [gradient], this is the color at the start of each line.
+    ///
+    /// See the [`Color` struct](struct.Color.html) for more information about colors.
+    ///
+    /// [gradient]: #method.set_pen_gradient
+    pub fn pen_color(&self) -> Color {
+        block_on(self.turtle.pen_color())
+    }
+
+    /// Sets the color of the pen to the given color.
+    ///
+    /// Any type that can be converted into a color can be passed into this function.
+    /// See the [`Color` struct](struct.Color.html) for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Drawing;
+    ///
+    /// fn main() {
+    ///     let mut drawing = Drawing::new();
+    ///     let mut turtle = drawing.add_turtle();
+    ///     drawing.set_background_color("light grey");
+    ///     turtle.set_pen_size(3.0);
+    ///
+    ///     let colors = ["red", "green", "blue"];
     ///
     ///     for i in 0..36 {
     ///         turtle.set_pen_color(colors[i % colors.len()]);
@@ -730,10 +1774,162 @@ impl Turtle {
     /// This will produce the following:
     ///
     /// ![turtle pen color](https://github.com/sunjay/turtle/raw/9240f8890d1032a0033ec5c5338a10ffa942dc21/docs/assets/images/docs/colored_circle.png)
+    ///
+    /// If the pen was drawing a [gradient], this switches it back to drawing in a single color.
+    ///
+    /// [gradient]: #method.set_pen_gradient
     pub fn set_pen_color<C: Into<Color> + Copy + Debug>(&mut self, color: C) {
         self.turtle.set_pen_color(color)
     }
 
+    /// Returns the start and end colors of the gradient drawn by the pen, or `None` if the pen is
+    /// drawing in a single color.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.pen_gradient(), None);
+    ///
+    /// turtle.set_pen_gradient("red", "blue");
+    /// assert_eq!(turtle.pen_gradient(), Some(("red".into(), "blue".into())));
+    ///
+    /// turtle.set_pen_color("green");
+    /// assert_eq!(turtle.pen_gradient(), None);
+    /// ```
+    ///
+    /// See [`set_pen_gradient()`] for more information.
+    ///
+    /// [`set_pen_gradient()`]: #method.set_pen_gradient
+    pub fn pen_gradient(&self) -> Option<(Color, Color)> {
+        block_on(self.turtle.pen_gradient())
+    }
+
+    /// Sets the pen to draw each line as a gradient that fades smoothly from the `start` color at
+    /// the beginning of the line to the `end` color at the end of the line.
+    ///
+    /// Each call to [`forward()`], [`go_to()`], etc. draws a separate line, so the gradient
+    /// starts over with every movement. Use [`set_pen_color()`] to go back to drawing lines with
+    /// a single color. While the pen is drawing a gradient, [`pen_color()`] returns the `start`
+    /// color.
+    ///
+    /// Any type that can be converted into a color can be passed into this function.
+    /// See the [`Color` struct](struct.Color.html) for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.set_pen_size(10.0);
+    ///
+    ///     // A single line that fades from red to blue
+    ///     turtle.set_pen_gradient("red", "blue");
+    ///     turtle.forward(300.0);
+    /// }
+    /// ```
+    ///
+    /// [`forward()`]: #method.forward
+    /// [`go_to()`]: #method.go_to
+    /// [`set_pen_color()`]: #method.set_pen_color
+    /// [`pen_color()`]: #method.pen_color
+    pub fn set_pen_gradient<S, E>(&mut self, start: S, end: E)
+        where S: Into<Color> + Copy + Debug,
+              E: Into<Color> + Copy + Debug,
+    {
+        self.turtle.set_pen_gradient(start, end)
+    }
+
+    /// Returns a snapshot of every setting of the pen: whether it is down, its size, color (or
+    /// gradient), line cap, and line join.
+    ///
+    /// Pass the returned value to [`set_pen_state()`] to put the pen back the way it was. See
+    /// [`PenState`] for an example.
+    ///
+    /// [`set_pen_state()`]: #method.set_pen_state
+    /// [`PenState`]: struct.PenState.html
+    pub fn pen_state(&self) -> PenState {
+        block_on(self.turtle.pen_state())
+    }
+
+    /// Restores every setting of the pen at once from a snapshot taken with [`pen_state()`].
+    ///
+    /// Restoring the pen counts as a single change when it is [undone]. If the pen size was
+    /// changing because of [`set_pen_size_animated()`], it stops changing and stays at the size
+    /// in `state`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the pen is negative or if either of its colors is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.left(90.0);
+    ///     tree(&mut turtle, 100.0, 8.0);
+    /// }
+    ///
+    /// fn tree(turtle: &mut Turtle, length: f64, size: f64) {
+    ///     if length < 10.0 {
+    ///         return;
+    ///     }
+    ///
+    ///     let pen = turtle.pen_state();
+    ///     turtle.set_pen_size(size);
+    ///     turtle.forward(length);
+    ///
+    ///     // Each branch is thinner than its parent...
+    ///     turtle.left(30.0);
+    ///     tree(turtle, length * 0.7, size * 0.6);
+    ///     turtle.right(60.0);
+    ///     tree(turtle, length * 0.7, size * 0.6);
+    ///     turtle.left(30.0);
+    ///
+    ///     // ...but the parent's pen is used to draw back down the trunk
+    ///     turtle.set_pen_state(pen);
+    ///     turtle.backward(length);
+    /// }
+    /// ```
+    ///
+    /// [`pen_state()`]: #method.pen_state
+    /// [undone]: #method.undo
+    /// [`set_pen_size_animated()`]: #method.set_pen_size_animated
+    pub fn set_pen_state(&mut self, state: PenState) {
+        self.turtle.set_pen_state(state)
+    }
+
+    /// Returns a [`Pen`] for changing several settings of the pen at once.
+    ///
+    /// The settings start out the same as the current settings of the pen. Chain together the
+    /// methods for each setting that you want to change, then call [`apply()`] to change all of
+    /// them at once. This is shorter than calling a separate method for each setting and only
+    /// sends a single request.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.pen().gradient("red", "blue").size(5.0).join("bevel").apply();
+    /// assert_eq!(turtle.pen_size(), 5.0);
+    /// assert_eq!(turtle.pen_join(), LineJoin::Bevel);
+    ///
+    /// // Settings that are not changed stay the same
+    /// turtle.pen().up().apply();
+    /// assert!(!turtle.is_pen_down());
+    /// assert_eq!(turtle.pen_size(), 5.0);
+    /// ```
+    ///
+    /// [`Pen`]: struct.Pen.html
+    /// [`apply()`]: struct.Pen.html#method.apply
+    pub fn pen(&mut self) -> Pen<'_> {
+        let state = self.pen_state();
+        Pen::new(self, state)
+    }
+
     /// Returns the current fill color.
     ///
     /// This will be used to fill the shape when
@@ -767,6 +1963,174 @@ impl Turtle {
         self.turtle.set_fill_color(color)
     }
 
+    /// Returns the rule used to decide which parts of a filled shape are inside the shape.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// // Shapes are filled with the nonzero rule by default
+    /// assert_eq!(turtle.fill_rule(), FillRule::NonZero);
+    /// turtle.set_fill_rule("evenodd");
+    /// assert_eq!(turtle.fill_rule(), FillRule::EvenOdd);
+    /// ```
+    ///
+    /// See [`set_fill_rule()`](struct.Turtle.html#method.set_fill_rule) for more details.
+    pub fn fill_rule(&self) -> FillRule {
+        block_on(self.turtle.fill_rule())
+    }
+
+    /// Sets the rule used to decide which parts of a filled shape are inside the shape when its
+    /// outline crosses over itself.
+    ///
+    /// You can pass either a [`FillRule`] value or one of the strings `"nonzero"` or
+    /// `"evenodd"`. The default is `"nonzero"`, which fills in every part of a shape like a star,
+    /// including its center. With `"evenodd"`, the center of the star is left empty instead.
+    ///
+    /// The fill rule is used when [`begin_fill()`] is called. Changing it while the turtle is
+    /// already filling a shape only affects the shapes filled after that.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_fill_color("gold");
+    /// turtle.set_fill_rule("evenodd");
+    ///
+    /// // Draws a star with a hole in its center
+    /// turtle.begin_fill();
+    /// for _ in 0..5 {
+    ///     turtle.forward(300.0);
+    ///     turtle.right(144.0);
+    /// }
+    /// turtle.end_fill();
+    /// ```
+    ///
+    /// [`FillRule`]: enum.FillRule.html
+    /// [`begin_fill()`]: struct.Turtle.html#method.begin_fill
+    pub fn set_fill_rule<R: Into<FillRule>>(&mut self, rule: R) {
+        self.turtle.set_fill_rule(rule)
+    }
+
+    /// Returns the pattern that filled shapes are painted with.
+    ///
+    /// The angle of a [`FillPattern::Hatch`] pattern is returned in the angle unit that the
+    /// turtle is currently using.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // Shapes are filled with a solid color by default
+    /// assert_eq!(turtle.fill_pattern(), FillPattern::Solid);
+    /// turtle.set_fill_pattern(FillPattern::Dots {spacing: 8.0});
+    /// assert_eq!(turtle.fill_pattern(), FillPattern::Dots {spacing: 8.0});
+    /// ```
+    ///
+    /// See [`set_fill_pattern()`](struct.Turtle.html#method.set_fill_pattern) for more details.
+    ///
+    /// [`FillPattern::Hatch`]: enum.FillPattern.html#variant.Hatch
+    pub fn fill_pattern(&self) -> FillPattern {
+        block_on(self.turtle.fill_pattern())
+    }
+
+    /// Sets the pattern that filled shapes are painted with.
+    ///
+    /// By default, shapes are completely filled in with the fill color
+    /// ([`FillPattern::Solid`]). The other patterns fill a shape with lines or dots drawn in the
+    /// fill color, leaving the rest of the shape empty so that anything beneath it shows through.
+    /// This is useful for shading areas the way it is done in technical drawings.
+    ///
+    /// The angle of a [`FillPattern::Hatch`] pattern is interpreted in the angle unit that the
+    /// turtle is currently using (degrees by default).
+    ///
+    /// The fill pattern is used when [`begin_fill()`] is called. Changing it while the turtle is
+    /// already filling a shape only affects the shapes filled after that.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_fill_color("steel blue");
+    /// // Fill the square with lines going up and to the right
+    /// turtle.set_fill_pattern(FillPattern::Hatch {angle: 45.0, spacing: 10.0});
+    ///
+    /// turtle.begin_fill();
+    /// for _ in 0..4 {
+    ///     turtle.forward(200.0);
+    ///     turtle.right(90.0);
+    /// }
+    /// turtle.end_fill();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spacing of the pattern is less than `2.0` or not a finite number, or if its
+    /// angle is not a finite number.
+    ///
+    /// [`FillPattern::Solid`]: enum.FillPattern.html#variant.Solid
+    /// [`FillPattern::Hatch`]: enum.FillPattern.html#variant.Hatch
+    /// [`begin_fill()`]: struct.Turtle.html#method.begin_fill
+    pub fn set_fill_pattern(&mut self, pattern: FillPattern) {
+        self.turtle.set_fill_pattern(pattern)
+    }
+
+    /// Returns the opacity that is applied to everything the turtle draws.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.group_opacity(), 1.0);
+    /// turtle.set_group_opacity(0.25);
+    /// assert_eq!(turtle.group_opacity(), 0.25);
+    /// ```
+    ///
+    /// See [`set_group_opacity()`](struct.Turtle.html#method.set_group_opacity) for more details.
+    pub fn group_opacity(&self) -> f64 {
+        block_on(self.turtle.group_opacity())
+    }
+
+    /// Sets the opacity that is applied to everything the turtle draws, from `0.0` (completely
+    /// transparent) to `1.0` (the default).
+    ///
+    /// The alpha of the pen color and the fill color is multiplied by this value whenever the
+    /// turtle draws a line, fills a shape, writes text, or leaves a stamp. The colors themselves
+    /// are not changed, so [`pen_color()`] and [`fill_color()`] return the same colors as
+    /// before. This makes it easy to dim a whole shape at once, e.g. to fade it in or out.
+    ///
+    /// Anything that was already drawn keeps its opacity. The exception is a shape that is
+    /// currently being filled, which is updated to use the new opacity right away. The opacity
+    /// goes back to `1.0` when the turtle is [reset].
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_pen_size(4.0);
+    /// turtle.set_fill_color("blue");
+    ///
+    /// // Draw the same square several times, each one fainter than the last
+    /// for i in 0..5 {
+    ///     turtle.set_group_opacity(1.0 - i as f64 * 0.2);
+    ///     turtle.begin_fill();
+    ///     for _ in 0..4 {
+    ///         turtle.forward(60.0);
+    ///         turtle.right(90.0);
+    ///     }
+    ///     turtle.end_fill();
+    ///
+    ///     turtle.pen_up();
+    ///     turtle.forward(80.0);
+    ///     turtle.pen_down();
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not between `0.0` and `1.0`.
+    ///
+    /// [`pen_color()`]: struct.Turtle.html#method.pen_color
+    /// [`fill_color()`]: struct.Turtle.html#method.fill_color
+    /// [reset]: struct.Turtle.html#method.reset
+    pub fn set_group_opacity(&mut self, alpha: f64) {
+        self.turtle.set_group_opacity(alpha)
+    }
+
     /// Return true if the turtle is currently filling the shape drawn
     /// by its movements.
     ///
@@ -839,55 +2203,431 @@ impl Turtle {
     /// **Rule of thumb:** For every call to [`begin_fill()`](struct.Turtle.html#method.begin_fill),
     /// there should be a corresponding call to [`end_fill()`](struct.Turtle.html#method.end_fill).
     ///
+    /// Returns a [`FillId`] that can be passed to [`set_fill_color_of()`] to change the color of
+    /// the filled shape later on. If the turtle was not filling, the returned ID does not refer to
+    /// any shape.
+    ///
     /// See [`begin_fill()`](struct.Turtle.html#method.begin_fill) for more information.
-    pub fn end_fill(&mut self) {
-        self.turtle.end_fill()
+    ///
+    /// [`FillId`]: struct.FillId.html
+    /// [`set_fill_color_of()`]: struct.Turtle.html#method.set_fill_color_of
+    pub fn end_fill(&mut self) -> FillId {
+        block_on(self.turtle.end_fill())
     }
 
-    /// Returns true if the turtle is visible.
+    /// Changes the color of a shape that was filled by this turtle
+    ///
+    /// The `fill_id` is the value returned by [`end_fill()`] when the shape was completed. The
+    /// turtle's own [fill color] stays the same, so fills drawn later are not affected.
+    ///
+    /// Returns an [`UnknownFillError`] if the shape is no longer on the drawing (e.g. after
+    /// [`clear()`] or [`undo()`]) or if the `fill_id` came from a call to [`end_fill()`] that didn't
+    /// fill anything.
+    ///
+    /// This makes it possible to draw a shape once and change its color later, for example to
+    /// highlight a region of a map when it is clicked.
     ///
     /// ```rust
     /// # use turtle::*;
     /// let mut turtle = Turtle::new();
-    /// assert!(turtle.is_visible());
-    /// turtle.hide();
-    /// assert!(!turtle.is_visible());
-    /// turtle.show();
-    /// assert!(turtle.is_visible());
-    /// ```
-    pub fn is_visible(&self) -> bool {
-        block_on(self.turtle.is_visible())
-    }
-
-    /// Makes the turtle invisible. The shell will not be shown, but drawings will continue.
     ///
-    /// Useful for some complex drawings.
+    /// let mut squares = Vec::new();
+    /// for _ in 0..2 {
+    ///     turtle.set_fill_color("light grey");
+    ///     turtle.begin_fill();
+    ///     for _ in 0..4 {
+    ///         turtle.forward(50.0);
+    ///         turtle.right(90.0);
+    ///     }
+    ///     squares.push(turtle.end_fill());
     ///
-    /// ```rust
-    /// # use turtle::*;
-    /// # let mut turtle = Turtle::new();
-    /// assert!(turtle.is_visible());
-    /// turtle.hide();
-    /// assert!(!turtle.is_visible());
+    ///     turtle.pen_up();
+    ///     turtle.right(90.0);
+    ///     turtle.forward(60.0);
+    ///     turtle.left(90.0);
+    ///     turtle.pen_down();
+    /// }
+    ///
+    /// // Only the first square turns red
+    /// turtle.set_fill_color_of(squares[0], "red").unwrap();
+    ///
+    /// // The squares can no longer be changed once they are cleared
+    /// turtle.clear();
+    /// assert_eq!(turtle.set_fill_color_of(squares[1], "red"), Err(UnknownFillError(squares[1])));
     /// ```
-    pub fn hide(&mut self) {
-        self.turtle.hide()
+    ///
+    /// # Panics
+    ///
+    /// Panics if the color is invalid.
+    ///
+    /// [`end_fill()`]: struct.Turtle.html#method.end_fill
+    /// [fill color]: struct.Turtle.html#method.set_fill_color
+    /// [`clear()`]: struct.Turtle.html#method.clear
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    /// [`UnknownFillError`]: struct.UnknownFillError.html
+    pub fn set_fill_color_of<C: Into<Color> + Copy + Debug>(&mut self, fill_id: FillId, color: C) -> Result<(), UnknownFillError> {
+        block_on(self.turtle.set_fill_color_of(fill_id, color))
     }
 
-    /// Makes the turtle visible.
+    /// Gradually changes the color of the most recently completed fill from the `from` color to
+    /// the `to` color over `secs` seconds.
     ///
-    /// ```rust
-    /// # use turtle::*;
-    /// # let mut turtle = Turtle::new();
-    /// # turtle.hide();
-    /// assert!(!turtle.is_visible());
-    /// turtle.show();
-    /// assert!(turtle.is_visible());
-    /// ```
-    pub fn show(&mut self) {
+    /// The fill switches to the `from` color right away and then fades smoothly to the `to`
+    /// color. Only the most recent fill finished with [`end_fill()`] that is still drawn changes.
+    /// The turtle's [fill color] stays the same, so it does not affect any fills drawn later. If
+    /// the turtle has no finished fills left (e.g. they have all been cleared), nothing happens.
+    /// Changing the color of the fill with [`set_fill_color_of()`] stops the animation from
+    /// changing it any further.
+    ///
+    /// Just like moving the turtle, this method does not return until the animation has finished.
+    /// If `secs` is zero or negative, or if the turtle's tracer is disabled, the fill changes to
+    /// the `to` color right away.
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.hide();
+    ///
+    ///     turtle.set_fill_color("red");
+    ///     turtle.begin_fill();
+    ///     for _ in 0..36 {
+    ///         turtle.forward(10.0);
+    ///         turtle.right(10.0);
+    ///     }
+    ///     turtle.end_fill();
+    ///
+    ///     // Make the circle "breathe" by fading it between two colors forever
+    ///     loop {
+    ///         turtle.animate_fill_color("red", "pink", 1.0);
+    ///         turtle.animate_fill_color("pink", "red", 1.0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either color is invalid or if `secs` is NaN or infinite.
+    ///
+    /// [`end_fill()`]: struct.Turtle.html#method.end_fill
+    /// [`set_fill_color_of()`]: struct.Turtle.html#method.set_fill_color_of
+    /// [fill color]: struct.Turtle.html#method.set_fill_color
+    pub fn animate_fill_color<F, T>(&mut self, from: F, to: T, secs: f64)
+        where F: Into<Color> + Copy + Debug,
+              T: Into<Color> + Copy + Debug,
+    {
+        block_on(self.turtle.animate_fill_color(from, to, secs))
+    }
+
+    /// Start recording the positions that the turtle moves to.
+    ///
+    /// The turtle's current position is the first point. After that, a point is added every time
+    /// the turtle finishes moving, no matter whether its pen is up or down. That includes methods
+    /// like [`go_to()`] and [`teleport()`], as well as every small step the turtle takes while
+    /// drawing an arc. Turning in place does not add a point. Call [`end_poly()`] to stop
+    /// recording and get the recorded points.
+    ///
+    /// This is useful for getting the exact shape that the turtle traced, for example to use it
+    /// in another program or to check whether a point is inside of it.
+    ///
+    /// If the turtle is already recording, the points recorded so far are discarded and recording
+    /// starts over from the turtle's current position.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.begin_poly();
+    /// for _ in 0..3 {
+    ///     turtle.forward(100.0);
+    ///     turtle.right(120.0);
+    /// }
+    /// let triangle = turtle.end_poly();
+    ///
+    /// // The starting point, then one point for each side of the triangle
+    /// assert_eq!(triangle.len(), 4);
+    /// assert_eq!(triangle[0], Point::origin());
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(triangle[1].round(), Point {x: 0.0, y: 100.0});
+    /// ```
+    ///
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    /// [`teleport()`]: struct.Turtle.html#method.teleport
+    /// [`end_poly()`]: struct.Turtle.html#method.end_poly
+    pub fn begin_poly(&mut self) {
+        self.turtle.begin_poly()
+    }
+
+    /// Stop recording the positions that the turtle moves to and return the recorded points.
+    ///
+    /// The points are in the order in which the turtle visited them, starting with its position
+    /// when [`begin_poly()`] was called. The last point is not connected back to the first, so
+    /// the turtle must return to where it started if you want a closed shape.
+    ///
+    /// If [`begin_poly()`] was not called first, this returns an empty `Vec`.
+    ///
+    /// See [`begin_poly()`] for more information.
+    ///
+    /// [`begin_poly()`]: struct.Turtle.html#method.begin_poly
+    pub fn end_poly(&mut self) -> Vec<Point> {
+        block_on(self.turtle.end_poly())
+    }
+
+    /// Start recording the path of the turtle, returning a [`PathRecorder`] that collects every
+    /// position that the turtle moves to.
+    ///
+    /// The recorder works the same way as [`begin_poly()`], starting from the turtle's current
+    /// position and adding a point every time the turtle finishes moving. The difference is that
+    /// the points are kept by the recorder instead of the turtle, so several paths can be
+    /// recorded at the same time without interfering with each other or with [`begin_poly()`].
+    /// Recording stops when the recorder is stopped or dropped.
+    ///
+    /// Pass the recorded points to [`draw_path()`] to make a turtle follow the same path again.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// let recorder = turtle.record_path();
+    /// turtle.forward(100.0);
+    /// turtle.right(90.0);
+    /// turtle.forward(50.0);
+    /// let path = recorder.stop();
+    ///
+    /// // The starting point, then one point for each line
+    /// assert_eq!(path.len(), 3);
+    /// assert_eq!(path[0], Point::origin());
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(path[2].round(), Point {x: 50.0, y: 100.0});
+    /// ```
+    ///
+    /// [`PathRecorder`]: struct.PathRecorder.html
+    /// [`begin_poly()`]: struct.Turtle.html#method.begin_poly
+    /// [`draw_path()`]: struct.Turtle.html#method.draw_path
+    pub fn record_path(&mut self) -> PathRecorder {
+        block_on(self.turtle.record_path())
+    }
+
+    /// Moves the turtle to each of the given points in order.
+    ///
+    /// This is the same as calling [`go_to()`] with each point, so a line is drawn to every point
+    /// while the pen is down and the heading of the turtle stays the same. Nothing happens if
+    /// the path is empty.
+    ///
+    /// Since the turtle moves from wherever it is to the first point, lift the pen and move to
+    /// the first point beforehand if you don't want a line leading up to the path. This makes it
+    /// easy to retrace a path recorded with [`record_path()`]:
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle = drawing.add_turtle();
+    /// let recorder = turtle.record_path();
+    /// for _ in 0..4 {
+    ///     turtle.forward(100.0);
+    ///     turtle.right(90.0);
+    /// }
+    /// let path = recorder.stop();
+    ///
+    /// // A second turtle traces over the same square in a different color
+    /// let mut ghost = drawing.add_turtle();
+    /// ghost.set_pen_color("light grey");
+    /// ghost.pen_up();
+    /// ghost.go_to(path[0]);
+    /// ghost.pen_down();
+    /// ghost.draw_path(&path);
+    /// ```
+    ///
+    /// [`go_to()`]: struct.Turtle.html#method.go_to
+    /// [`record_path()`]: struct.Turtle.html#method.record_path
+    pub fn draw_path(&mut self, path: &[Point]) {
+        block_on(self.turtle.draw_path(path))
+    }
+
+    /// Returns true if the turtle is visible.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert!(turtle.is_visible());
+    /// turtle.hide();
+    /// assert!(!turtle.is_visible());
+    /// turtle.show();
+    /// assert!(turtle.is_visible());
+    /// ```
+    pub fn is_visible(&self) -> bool {
+        block_on(self.turtle.is_visible())
+    }
+
+    /// Makes the turtle invisible. The shell will not be shown, but drawings will continue.
+    ///
+    /// Useful for some complex drawings.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// assert!(turtle.is_visible());
+    /// turtle.hide();
+    /// assert!(!turtle.is_visible());
+    /// ```
+    pub fn hide(&mut self) {
+        self.turtle.hide()
+    }
+
+    /// Makes the turtle visible.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// # turtle.hide();
+    /// assert!(!turtle.is_visible());
+    /// turtle.show();
+    /// assert!(turtle.is_visible());
+    /// ```
+    pub fn show(&mut self) {
         self.turtle.show()
     }
 
+    /// Returns the shape used to draw the turtle.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// # let mut turtle = Turtle::new();
+    /// // The turtle is drawn as a triangle by default
+    /// assert_eq!(turtle.shape(), TurtleShape::Triangle);
+    /// turtle.set_shape("circle");
+    /// assert_eq!(turtle.shape(), TurtleShape::Circle);
+    /// ```
+    pub fn shape(&self) -> TurtleShape {
+        block_on(self.turtle.shape())
+    }
+
+    /// Sets the shape used to draw the turtle.
+    ///
+    /// You can pass any [`TurtleShape`] value, or one of the strings `"triangle"`, `"circle"`,
+    /// `"square"`, or `"arrow"`. A list of points (`Vec<Point>`) is converted into a custom
+    /// `TurtleShape::Polygon`. See the [`TurtleShape`] documentation for how those points are
+    /// positioned relative to the turtle.
+    ///
+    /// Giving each turtle its own shape makes it easier to tell turtles apart when there is more
+    /// than one in a drawing. The shape is also used by [`stamp()`].
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut turtle1 = drawing.add_turtle();
+    /// let mut turtle2 = drawing.add_turtle();
+    ///
+    /// turtle1.set_shape("arrow");
+    /// turtle2.set_shape(vec![
+    ///     Point {x: 15.0, y: 0.0},
+    ///     Point {x: 0.0, y: 5.0},
+    ///     Point {x: -15.0, y: 0.0},
+    ///     Point {x: 0.0, y: -5.0},
+    /// ]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a custom polygon has fewer than 3 points or if any of its points are not finite.
+    ///
+    /// [`TurtleShape`]: enum.TurtleShape.html
+    /// [`stamp()`]: struct.Turtle.html#method.stamp
+    pub fn set_shape<S: Into<TurtleShape>>(&mut self, shape: S) {
+        self.turtle.set_shape(shape)
+    }
+
+    /// Returns the amount that the shape of the turtle is scaled by when it is drawn.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // The turtle is drawn at its normal size by default
+    /// assert_eq!(turtle.shape_scale(), 1.0);
+    /// turtle.set_shape_scale(3.0);
+    /// assert_eq!(turtle.shape_scale(), 3.0);
+    /// ```
+    ///
+    /// See [`set_shape_scale()`](struct.Turtle.html#method.set_shape_scale) for more details.
+    pub fn shape_scale(&self) -> f64 {
+        block_on(self.turtle.shape_scale())
+    }
+
+    /// Sets the amount that the shape of the turtle is scaled by when it is drawn.
+    ///
+    /// A scale of `2.0` draws the turtle twice as large as usual, and a scale of `0.5` draws it
+    /// half as large. This makes the turtle easier to see in large drawings. Only the turtle
+    /// itself is affected: the lines that it draws keep their size, and the outline of the turtle
+    /// is always as thin as usual. Stamps left by [`stamp()`] are drawn at the same scale as the
+    /// turtle.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_shape("arrow");
+    /// turtle.set_shape_scale(4.0);
+    /// turtle.forward(200.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is not greater than zero or is not a finite number.
+    ///
+    /// [`stamp()`]: struct.Turtle.html#method.stamp
+    pub fn set_shape_scale(&mut self, scale: f64) {
+        self.turtle.set_shape_scale(scale)
+    }
+
+    /// Returns the outline and fill colors of the turtle shape, in that order.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // The turtle is drawn with a black outline and a white fill by default
+    /// assert_eq!(turtle.turtle_color(), ("black".into(), "white".into()));
+    /// turtle.set_turtle_color("blue", "yellow");
+    /// assert_eq!(turtle.turtle_color(), ("blue".into(), "yellow".into()));
+    /// ```
+    ///
+    /// See [`set_turtle_color()`](struct.Turtle.html#method.set_turtle_color) for more details.
+    pub fn turtle_color(&self) -> (Color, Color) {
+        block_on(self.turtle.turtle_color())
+    }
+
+    /// Sets the outline and fill colors that the turtle shape is drawn with.
+    ///
+    /// These colors only change how the turtle itself looks. They have nothing to do with the
+    /// [`pen_color()`] or the [`fill_color()`] used by [`begin_fill()`], so nothing that the turtle
+    /// draws is affected, including stamps left by [`stamp()`]. This makes it easy to tell several
+    /// turtles apart.
+    ///
+    /// ```rust,no_run
+    /// # use turtle::*;
+    /// let mut drawing = Drawing::new();
+    /// let mut red = drawing.add_turtle();
+    /// let mut blue = drawing.add_turtle();
+    /// red.set_turtle_color("dark red", "red");
+    /// blue.set_turtle_color("navy", "blue");
+    ///
+    /// // Both turtles still draw with the default black pen
+    /// red.forward(100.0);
+    /// blue.backward(100.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the colors is not valid.
+    ///
+    /// [`pen_color()`]: struct.Turtle.html#method.pen_color
+    /// [`fill_color()`]: struct.Turtle.html#method.fill_color
+    /// [`begin_fill()`]: struct.Turtle.html#method.begin_fill
+    /// [`stamp()`]: struct.Turtle.html#method.stamp
+    pub fn set_turtle_color<O, F>(&mut self, outline: O, fill: F)
+        where O: Into<Color> + Copy + Debug,
+              F: Into<Color> + Copy + Debug,
+    {
+        self.turtle.set_turtle_color(outline, fill)
+    }
+
     /// Delete the turtle's drawings from the screen, re-center the turtle and reset all of the
     /// turtle's state (speed, color, etc.) back to the default.
     ///
@@ -943,89 +2683,960 @@ impl Turtle {
         self.turtle.clear()
     }
 
-    /// Rotates the turtle to face the given point. See the [`Point` struct](struct.Point.html)
-    /// documentation for more information.
-    ///
-    /// If the coordinates are the same as the turtle's current position, no rotation takes place.
-    /// Always rotates the least amount necessary in order to face the given point.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run
-    /// use turtle::Turtle;
-    ///
-    /// fn main() {
-    ///     let mut turtle = Turtle::new();
-    ///     // moving the turtle to the bottom on the screen in the middle
-    ///     turtle.pen_up();
-    ///     turtle.go_to([0.0, -300.0]);
-    ///     turtle.set_heading(90.0);
-    ///     turtle.pen_down();
-    ///
-    ///     // the turtle will go up following an oscillating point
-    ///     let mut i: f64 = 0.0;
-    ///     // choosing an arbitrary constant to multiply
-    ///     // the cos function, result between -5000 and 5000
-    ///     let c = 5000.0;
-    ///     // just draw a few full cicles
-    ///     while i < 15.0 {
-    ///         let f = (i).cos()*c;
-    ///         // following the oscillating point above at y=1000
-    ///         turtle.turn_towards([f, 1000.0]);
-    ///         // going forward for a small amount
-    ///         turtle.forward(1.0);
-    ///         // incrementing the angle
-    ///         i = i + 0.01;
-    ///     }
-    /// }
-    /// ```
-    pub fn turn_towards<P: Into<Point>>(&mut self, target: P) {
-        block_on(self.turtle.turn_towards(target))
+    /// Undo the most recent command run by the turtle.
+    ///
+    /// The turtle's position, heading, pen (color, size, up or down) and fill color are put back
+    /// the way they were before the command, and anything drawn by that command is removed from
+    /// the screen. Commands that only change the turtle's speed or visibility cannot be undone.
+    ///
+    /// Calling this repeatedly steps further and further back. Up to 1000 commands can be undone.
+    /// If there is nothing left to undo, this method does nothing. Calling [`clear()`] or
+    /// [`reset()`] forgets the history, so commands run before either of those cannot be undone.
+    ///
+    /// Arcs (e.g. [`arc_left()`]) are made up of many small movements and rotations, each of which
+    /// is undone separately.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.forward(100.0);
+    /// turtle.right(90.0);
+    /// turtle.set_pen_color("red");
+    /// turtle.forward(50.0);
+    ///
+    /// // Remove the red line and go back to where we were
+    /// turtle.undo();
+    /// assert_eq!(turtle.position().round(), Point {x: 0.0, y: 100.0});
+    ///
+    /// // Go back to the original pen color
+    /// turtle.undo();
+    /// assert_ne!(turtle.pen_color(), "red".into());
+    ///
+    /// // Undo the right turn
+    /// turtle.undo();
+    /// assert_eq!(turtle.heading(), 90.0);
+    /// ```
+    ///
+    /// [`clear()`]: struct.Turtle.html#method.clear
+    /// [`reset()`]: struct.Turtle.html#method.reset
+    /// [`arc_left()`]: struct.Turtle.html#method.arc_left
+    pub fn undo(&mut self) {
+        self.turtle.undo()
+    }
+
+    /// Leaves a copy of the turtle's shape on the drawing at its current position and heading.
+    ///
+    /// The stamp is filled with the turtle's [fill color] and outlined with its [pen color]. It
+    /// stays on the drawing after the turtle moves away, which makes stamps useful for tracing the
+    /// path that the turtle took. Stamps are drawn even if the pen is up, but a hidden turtle leaves
+    /// a stamp that doesn't draw anything.
+    ///
+    /// The returned [`StampId`] can be passed to [`clear_stamp()`] to remove the stamp later.
+    /// Stamps are also removed by [`clear_stamps()`], [`clear()`], and [`undo()`].
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.set_fill_color("green");
+    /// turtle.pen_up();
+    ///
+    /// let mut stamps = Vec::new();
+    /// for _ in 0..5 {
+    ///     stamps.push(turtle.stamp());
+    ///     turtle.forward(40.0);
+    /// }
+    ///
+    /// // Remove the first stamp and keep the others
+    /// turtle.clear_stamp(stamps[0]);
+    /// ```
+    ///
+    /// Like everything else that is drawn, stamps are included when the drawing is saved with
+    /// [`Drawing::save_svg()`] or [`Drawing::save_png()`].
+    ///
+    /// [fill color]: struct.Turtle.html#method.set_fill_color
+    /// [pen color]: struct.Turtle.html#method.set_pen_color
+    /// [`StampId`]: struct.StampId.html
+    /// [`clear_stamp()`]: struct.Turtle.html#method.clear_stamp
+    /// [`clear_stamps()`]: struct.Turtle.html#method.clear_stamps
+    /// [`clear()`]: struct.Turtle.html#method.clear
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    /// [`Drawing::save_svg()`]: struct.Drawing.html#method.save_svg
+    /// [`Drawing::save_png()`]: struct.Drawing.html#method.save_png
+    pub fn stamp(&mut self) -> StampId {
+        block_on(self.turtle.stamp())
+    }
+
+    /// Removes a stamp created by the [`stamp()`] method of this turtle.
+    ///
+    /// Nothing happens if the stamp was already removed.
+    ///
+    /// See [`stamp()`] for an example.
+    ///
+    /// [`stamp()`]: struct.Turtle.html#method.stamp
+    pub fn clear_stamp(&mut self, stamp_id: StampId) {
+        self.turtle.clear_stamp(stamp_id)
+    }
+
+    /// Removes every stamp created by the [`stamp()`] method of this turtle.
+    ///
+    /// Anything else drawn by the turtle stays on the drawing.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// for _ in 0..4 {
+    ///     turtle.stamp();
+    ///     turtle.forward(50.0);
+    ///     turtle.right(90.0);
+    /// }
+    ///
+    /// // The square is still drawn, but the stamps at each corner are gone
+    /// turtle.clear_stamps();
+    /// ```
+    ///
+    /// [`stamp()`]: struct.Turtle.html#method.stamp
+    pub fn clear_stamps(&mut self) {
+        self.turtle.clear_stamps()
+    }
+
+    /// Writes the given text at the turtle's current position using the turtle's pen color.
+    ///
+    /// The text is drawn to the right of the turtle with its baseline at the turtle's position.
+    /// Each line of a string containing `\n` is drawn below the previous one. The turtle itself
+    /// does not move or turn, and the text is drawn even if the pen is up.
+    ///
+    /// This uses a font size of 16. To choose a different size or to change how the text is
+    /// positioned, use [`write_with()`].
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.pen_up();
+    /// turtle.go_to([-100.0, 0.0]);
+    /// turtle.set_pen_color("blue");
+    /// turtle.write("Hello, world!");
+    /// // Writing does not move the turtle
+    /// assert_eq!(turtle.position(), Point {x: -100.0, y: 0.0});
+    /// ```
+    ///
+    /// Text is made up of filled shapes, so it is included when the drawing is saved with
+    /// [`Drawing::save_svg()`]. Like any other command, writing can be reversed with [`undo()`].
+    ///
+    /// [`write_with()`]: struct.Turtle.html#method.write_with
+    /// [`Drawing::save_svg()`]: struct.Drawing.html#method.save_svg
+    /// [`undo()`]: struct.Turtle.html#method.undo
+    pub fn write(&mut self, text: &str) {
+        self.turtle.write(text)
+    }
+
+    /// Writes the given text at the turtle's current position with the given font size and
+    /// alignment.
+    ///
+    /// The `font_size` is in pixels and must be greater than zero. The alignment controls whether
+    /// the text starts at (`"left"`), is centered on (`"center"`), or ends at (`"right"`) the
+    /// turtle's position. See [`TextAlign`] for more information.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.write_with("Title", 32.0, "center");
+    /// turtle.forward(-40.0);
+    /// turtle.write_with("A smaller subtitle", 12.0, TextAlign::Center);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the font size is not greater than zero or if the alignment is an invalid string.
+    ///
+    /// [`TextAlign`]: enum.TextAlign.html
+    pub fn write_with<A: Into<TextAlign>>(&mut self, text: &str, font_size: f64, align: A) {
+        self.turtle.write_with(text, font_size, align)
+    }
+
+    /// Rotates the turtle to face the given point. See the [`Point` struct](struct.Point.html)
+    /// documentation for more information.
+    ///
+    /// If the coordinates are the same as the turtle's current position, no rotation takes place.
+    /// Always rotates the least amount necessary in order to face the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     // moving the turtle to the bottom on the screen in the middle
+    ///     turtle.pen_up();
+    ///     turtle.go_to([0.0, -300.0]);
+    ///     turtle.set_heading(90.0);
+    ///     turtle.pen_down();
+    ///
+    ///     // the turtle will go up following an oscillating point
+    ///     let mut i: f64 = 0.0;
+    ///     // choosing an arbitrary constant to multiply
+    ///     // the cos function, result between -5000 and 5000
+    ///     let c = 5000.0;
+    ///     // just draw a few full cicles
+    ///     while i < 15.0 {
+    ///         let f = (i).cos()*c;
+    ///         // following the oscillating point above at y=1000
+    ///         turtle.turn_towards([f, 1000.0]);
+    ///         // going forward for a small amount
+    ///         turtle.forward(1.0);
+    ///         // incrementing the angle
+    ///         i = i + 0.01;
+    ///     }
+    /// }
+    /// ```
+    pub fn turn_towards<P: Into<Point>>(&mut self, target: P) {
+        block_on(self.turtle.turn_towards(target))
+    }
+
+    /// Returns the distance from the turtle's current position to the given point.
+    ///
+    /// The turtle does not move or turn.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// assert_eq!(turtle.distance([30.0, 40.0]), 50.0);
+    ///
+    /// turtle.forward(40.0);
+    /// assert_eq!(turtle.distance([30.0, 40.0]).round(), 30.0);
+    /// ```
+    pub fn distance<P: Into<Point>>(&self, target: P) -> Distance {
+        block_on(self.turtle.distance(target))
+    }
+
+    /// Returns true if the turtle is inside the rectangle with the given top left corner and
+    /// size, or on one of its edges.
+    ///
+    /// The rectangle uses the same coordinates as [`position()`]. Since the y-axis points up, the
+    /// rectangle extends `size.width` steps to the right of `top_left` and `size.height` steps
+    /// *below* it. This is different from the coordinates of many other graphics libraries, where
+    /// the y-axis points down.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// // A 200x100 rectangle centered at the origin
+    /// assert!(turtle.within_rect([-100.0, 50.0], (200, 100)));
+    ///
+    /// turtle.go_to([90.0, -60.0]);
+    /// assert!(!turtle.within_rect([-100.0, 50.0], (200, 100)));
+    /// ```
+    ///
+    /// [`position()`]: struct.Turtle.html#method.position
+    pub fn within_rect<P: Into<Point>, S: Into<Size>>(&self, top_left: P, size: S) -> bool {
+        block_on(self.turtle.within_rect(top_left, size))
+    }
+
+    /// Returns true if the turtle is at most `radius` steps away from `center`.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.forward(50.0);
+    /// assert!(turtle.within_circle([0.0, 0.0], 50.0));
+    /// assert!(!turtle.within_circle([0.0, 0.0], 49.0));
+    /// assert!(turtle.within_circle([10.0, 40.0], 20.0));
+    /// ```
+    pub fn within_circle<P: Into<Point>>(&self, center: P, radius: Distance) -> bool {
+        block_on(self.turtle.within_circle(center, radius))
+    }
+
+    /// Returns the heading that the turtle would need to have in order to face the given point.
+    ///
+    /// The turtle does not turn. Use [`turn_towards()`] to actually face the point, or pass the
+    /// returned angle to [`set_heading()`]. Just like [`heading()`], the returned angle is
+    /// relative to the positive x axis (east), is in the angle unit that the turtle is currently
+    /// using, and is always between 0.0 and 360.0 degrees (or 2&pi; radians).
+    ///
+    /// If the given point is the turtle's current position (or is not finite), the turtle's
+    /// current heading is returned.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let turtle = Turtle::new();
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(turtle.towards([100.0, 100.0]).round(), 45.0);
+    /// assert_eq!(turtle.towards([-100.0, 0.0]).round(), 180.0);
+    /// assert_eq!(turtle.towards([0.0, -100.0]).round(), 270.0);
+    ///
+    /// // The heading of the turtle is unchanged
+    /// assert_eq!(turtle.heading().round(), 90.0);
+    /// ```
+    ///
+    /// [`turn_towards()`]: struct.Turtle.html#method.turn_towards
+    /// [`set_heading()`]: struct.Turtle.html#method.set_heading
+    /// [`heading()`]: struct.Turtle.html#method.heading
+    pub fn towards<P: Into<Point>>(&self, target: P) -> Angle {
+        block_on(self.turtle.towards(target))
+    }
+
+    /// Returns the heading that the turtle would need to have in order to face the origin (0, 0).
+    ///
+    /// This is the same as calling [`towards()`] with the origin. The turtle does not turn. If the
+    /// turtle is already at the origin, its current heading is returned.
+    ///
+    /// ```rust
+    /// # use turtle::*;
+    /// let mut turtle = Turtle::new();
+    /// turtle.forward(100.0);
+    /// // The rounding is to account for floating-point error
+    /// assert_eq!(turtle.heading_to_home().round(), 270.0);
+    /// ```
+    ///
+    /// [`towards()`]: struct.Turtle.html#method.towards
+    pub fn heading_to_home(&self) -> Angle {
+        block_on(self.turtle.heading_to_home())
+    }
+
+    /// Rotates the turtle to face the origin (0, 0) without moving it.
+    ///
+    /// This is the same as calling [`turn_towards()`] with the origin, so the turtle turns the
+    /// least amount necessary. Unlike [`home()`], the turtle stays where it is and does not draw
+    /// anything. If the turtle is already at the origin, it does not turn.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     // Wander off somewhere
+    ///     turtle.right(60.0);
+    ///     turtle.forward(150.0);
+    ///     turtle.left(100.0);
+    ///     turtle.forward(80.0);
+    ///
+    ///     // Come back home
+    ///     turtle.face_home();
+    ///     let distance = turtle.distance([0.0, 0.0]);
+    ///     turtle.forward(distance);
+    /// }
+    /// ```
+    ///
+    /// [`turn_towards()`]: struct.Turtle.html#method.turn_towards
+    /// [`home()`]: struct.Turtle.html#method.home
+    pub fn face_home(&mut self) {
+        block_on(self.turtle.face_home())
+    }
+
+    /// Convenience function that waits for a click to occur before returning.
+    ///
+    /// Useful for when you want the turtle to wait for the user to click before continuing. Use
+    /// this to force the turtle to wait before it starts drawing at the beginning of your program.
+    ///
+    /// This method uses [`poll_event()`](struct.Drawing.html#method.poll_event) internally and
+    /// ignores any other events that take place before the click is received.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::Turtle;
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.wait_for_click();
+    ///     // The turtle will wait for the screen to be clicked before continuing
+    ///     turtle.forward(100.0);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With the "embedded" feature, events can only reach the drawing through
+    /// [`Drawing::send_event()`], which requires the "unstable" feature. This method panics
+    /// instead of waiting forever if the "embedded" feature is enabled without the "unstable"
+    /// feature.
+    ///
+    /// [`Drawing::send_event()`]: struct.Drawing.html#method.send_event
+    pub fn wait_for_click(&mut self) {
+        block_on(self.turtle.wait_for_click())
+    }
+
+    /// Convenience function that waits for a key to be pressed and then returns that key.
+    ///
+    /// Just like [`wait_for_click()`], this method uses
+    /// [`poll_event()`](struct.Drawing.html#method.poll_event) internally and ignores any other
+    /// events that take place before the key is pressed. Releasing a key does not count as
+    /// pressing it.
+    ///
+    /// # Unstable
+    ///
+    /// Just like the rest of the events API, this method may change or be completely removed in
+    /// the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Turtle, event::Key};
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     // Keep turning until the user presses a key other than the arrow keys
+    ///     loop {
+    ///         match turtle.wait_for_key() {
+    ///             Key::LeftArrow => turtle.left(45.0),
+    ///             Key::RightArrow => turtle.right(45.0),
+    ///             _ => break,
+    ///         }
+    ///     }
+    ///     turtle.forward(100.0);
+    /// }
+    /// ```
+    ///
+    /// [`wait_for_click()`]: #method.wait_for_click
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn wait_for_key(&mut self) -> crate::event::Key {
+        block_on(self.turtle.wait_for_key())
+    }
+
+    /// Convenience function that waits for the given key to be pressed before returning.
+    ///
+    /// Any other keys that are pressed in the meantime are ignored. See [`wait_for_key()`] for
+    /// more information.
+    ///
+    /// # Unstable
+    ///
+    /// Just like the rest of the events API, this method may change or be completely removed in
+    /// the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turtle::{Turtle, event::Key};
+    ///
+    /// fn main() {
+    ///     let mut turtle = Turtle::new();
+    ///     turtle.forward(100.0);
+    ///     // Press space to continue
+    ///     turtle.wait_for_key_pressed(Key::Space);
+    ///     turtle.right(90.0);
+    ///     turtle.forward(100.0);
+    /// }
+    /// ```
+    ///
+    /// [`wait_for_key()`]: #method.wait_for_key
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn wait_for_key_pressed(&mut self, key: crate::event::Key) {
+        block_on(self.turtle.wait_for_key_pressed(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::radians;
+
+    #[test]
+    fn is_using_radians_degrees() {
+        // is_using_radians and is_using_degrees should be inverses of each other
+        let mut turtle = Turtle::new();
+        assert!(!turtle.is_using_radians());
+        assert!(turtle.is_using_degrees());
+        turtle.use_radians();
+        assert!(turtle.is_using_radians());
+        assert!(!turtle.is_using_degrees());
+        turtle.use_degrees();
+        assert!(!turtle.is_using_radians());
+        assert!(turtle.is_using_degrees());
+    }
+
+    #[test]
+    fn normalize_angle_in_current_unit() {
+        let mut turtle = Turtle::new();
+        assert_eq!(turtle.normalize_angle(720.0 + 45.0), 45.0);
+        assert_eq!(turtle.normalize_angle(-45.0), 315.0);
+        // Must never round up to a full turn
+        assert_eq!(turtle.normalize_angle(-1e-20), 0.0);
+
+        turtle.use_radians();
+        let two_pi = radians::TWO_PI.to_radians();
+        assert!((turtle.normalize_angle(-1.0) - (two_pi - 1.0)).abs() < f64::EPSILON);
+        assert!((turtle.normalize_angle(3.0 * two_pi + 1.0) - 1.0).abs() < 1e-12);
+        assert_eq!(turtle.normalize_angle(-1e-20), 0.0);
+    }
+
+    #[test]
+    fn forward_in_ignores_speed() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("slowest");
+        turtle.forward_in(100.0, Duration::from_secs(0));
+        turtle.forward_in(-50.0, Duration::from_millis(500));
+        // The rounding is to account for floating-point error
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 50.0});
+        assert_eq!(turtle.speed(), 1);
+    }
+
+    #[test]
+    fn speed_rates_override_speed_level() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed(3);
+        let level_rate = turtle.speed_px_per_sec();
+
+        turtle.set_speed_px_per_sec(42.0);
+        turtle.set_turn_speed_deg_per_sec(30.0);
+        assert_eq!(turtle.speed_px_per_sec(), 42.0);
+        assert!((turtle.turn_speed_deg_per_sec() - 30.0).abs() < 1e-9);
+        assert_eq!(turtle.speed(), 3);
+
+        // Setting the speed goes back to the rates of the speed level
+        turtle.set_speed(3);
+        assert_eq!(turtle.speed_px_per_sec(), level_rate);
+        assert!(turtle.turn_speed_deg_per_sec() > 30.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid speed: 0 px/sec. The speed must be a finite number greater than zero")]
+    fn speed_px_per_sec_rejects_zero() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed_px_per_sec(0.0);
+    }
+
+    #[test]
+    fn tracer_keeps_drawings_and_speed() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed(4);
+        turtle.set_tracer(false);
+        turtle.forward(100.0);
+        turtle.right(90.0);
+        turtle.forward(50.0);
+        assert!(!turtle.is_tracer_enabled());
+        assert_eq!(turtle.position().round(), Point {x: 50.0, y: 100.0});
+        assert_eq!(turtle.speed(), 4);
+
+        turtle.set_tracer(true);
+        assert!(turtle.is_tracer_enabled());
+        turtle.reset();
+        assert!(turtle.is_tracer_enabled());
+    }
+
+    #[test]
+    fn clone_turtle_copies_state_only() {
+        let mut turtle = Turtle::new();
+        turtle.use_radians();
+        turtle.set_speed(3);
+        turtle.set_pen_size(5.0);
+        turtle.set_fill_color("red");
+        turtle.forward(40.0);
+        turtle.left(radians::PI.to_radians() / 2.0);
+
+        let mut clone = turtle.clone_turtle();
+        assert_eq!(clone.position(), turtle.position());
+        assert_eq!(clone.heading(), turtle.heading());
+        assert_eq!(clone.speed(), 3);
+        assert_eq!(clone.pen_size(), 5.0);
+        assert_eq!(clone.fill_color(), "red".into());
+        assert!(clone.is_using_radians());
+
+        // The clone has nothing to undo since it hasn't done anything yet
+        clone.undo();
+        assert_eq!(clone.position(), turtle.position());
+
+        clone.forward(10.0);
+        assert_ne!(clone.position(), turtle.position());
+    }
+
+    #[test]
+    fn poly_records_every_position() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+
+        // Nothing to return when no polygon was started
+        assert!(turtle.end_poly().is_empty());
+
+        turtle.go_to([10.0, 10.0]);
+        turtle.begin_poly();
+        turtle.pen_up();
+        turtle.go_to([20.0, 10.0]);
+        // Zero distance movements and rotations don't add points
+        turtle.forward(0.0);
+        turtle.left(90.0);
+        turtle.teleport([20.0, 20.0], 0.0);
+        turtle.pen_down();
+        turtle.go_to([10.0, 10.0]);
+        assert_eq!(turtle.end_poly(), vec![
+            Point {x: 10.0, y: 10.0},
+            Point {x: 20.0, y: 10.0},
+            Point {x: 20.0, y: 20.0},
+            Point {x: 10.0, y: 10.0},
+        ]);
+
+        // Recording stops after end_poly()
+        turtle.forward(10.0);
+        assert!(turtle.end_poly().is_empty());
+
+        // Starting again discards the points recorded so far
+        turtle.begin_poly();
+        turtle.go_to([0.0, 0.0]);
+        turtle.begin_poly();
+        turtle.go_to([5.0, 0.0]);
+        assert_eq!(turtle.end_poly(), vec![Point::origin(), Point {x: 5.0, y: 0.0}]);
+    }
+
+    #[test]
+    fn sync_gets_exactly_one_response() {
+        let mut turtle = Turtle::new();
+        // Nothing to wait for
+        turtle.sync();
+        turtle.forward(30.0);
+        turtle.sync();
+        turtle.sync();
+
+        // Any extra response would be received by these requests instead
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 30.0});
+        turtle.right(90.0);
+        assert_eq!(turtle.heading().round(), 0.0);
+    }
+
+    #[test]
+    fn path_recorders_are_independent() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+
+        let first = turtle.record_path();
+        turtle.begin_poly();
+        turtle.go_to([10.0, 0.0]);
+        let second = turtle.record_path();
+        turtle.go_to([10.0, 10.0]);
+        // Looking at the points doesn't stop the recording
+        assert_eq!(first.points().len(), 3);
+        turtle.go_to([0.0, 10.0]);
+
+        assert_eq!(turtle.end_poly().len(), 4);
+        assert_eq!(second.stop(), vec![
+            Point {x: 10.0, y: 0.0},
+            Point {x: 10.0, y: 10.0},
+            Point {x: 0.0, y: 10.0},
+        ]);
+        turtle.go_to([0.0, 0.0]);
+        let path = first.stop();
+        assert_eq!(path.len(), 5);
+
+        let mut ghost = Turtle::new();
+        ghost.set_speed("instant");
+        let recorder = ghost.record_path();
+        ghost.draw_path(&path);
+        // The ghost started at the origin, so going to the first point didn't add a point
+        assert_eq!(recorder.stop(), path);
+        ghost.draw_path(&[]);
+        assert_eq!(ghost.position(), Point::origin());
+    }
+
+    #[test]
+    fn group_opacity_applies_to_new_drawings() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_group_opacity(0.5);
+        turtle.begin_fill();
+        turtle.forward(10.0);
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.end_fill();
+
+        // Only the background is left fully opaque
+        let json = drawing.export_json();
+        assert_eq!(json.matches(r#""alpha":1.0"#).count(), 1);
+        assert!(json.contains(r#""alpha":0.5"#));
+        assert_eq!(turtle.pen_color(), "black".into());
+
+        turtle.reset();
+        assert_eq!(turtle.group_opacity(), 1.0);
+    }
+
+    #[test]
+    fn animate_fill_color_changes_last_fill() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let green = r#""fill_color":{"red":0.0,"green":255.0,"blue":0.0,"alpha":1.0}"#;
+
+        // Nothing to change before any fill is completed
+        turtle.animate_fill_color("red", "#00ff00", 0.5);
+        turtle.set_fill_color("red");
+        turtle.begin_fill();
+        turtle.forward(10.0);
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.animate_fill_color("red", "#00ff00", 0.5);
+        assert!(!drawing.export_json().contains(green));
+
+        turtle.end_fill();
+        turtle.animate_fill_color("red", "#00ff00", 0.5);
+        assert!(drawing.export_json().contains(green));
+        assert_eq!(turtle.fill_color(), "red".into());
+
+        // The fill no longer exists once it is cleared
+        turtle.clear();
+        turtle.animate_fill_color("#00ff00", "blue", 0.5);
+    }
+
+    #[test]
+    fn set_fill_color_of_changes_only_that_fill() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let fill_json = |color: &str| {
+            let color: Color = color.into();
+            format!(r#""fill_color":{}"#, serde_json::to_string(&color).unwrap())
+        };
+
+        let mut fills = Vec::new();
+        for &color in &["red", "blue"] {
+            turtle.set_fill_color(color);
+            turtle.begin_fill();
+            turtle.forward(10.0);
+            turtle.right(90.0);
+            turtle.forward(10.0);
+            fills.push(turtle.end_fill());
+        }
+        assert_ne!(fills[0], fills[1]);
+
+        turtle.set_fill_color_of(fills[0], "#00ff00").unwrap();
+        let json = drawing.export_json();
+        assert!(json.contains(&fill_json("#00ff00")));
+        assert!(!json.contains(&fill_json("red")));
+        assert!(json.contains(&fill_json("blue")));
+        assert_eq!(turtle.fill_color(), "blue".into());
+
+        // Ending a fill without filling gives an ID that doesn't refer to anything
+        let nothing = turtle.end_fill();
+        assert_eq!(turtle.set_fill_color_of(nothing, "yellow"), Err(UnknownFillError(nothing)));
+        assert!(!drawing.export_json().contains(&fill_json("yellow")));
+
+        // Undoing `end_fill` forgets the fill even though its shape is still being filled
+        turtle.undo();
+        assert_eq!(turtle.set_fill_color_of(fills[1], "yellow"), Err(UnknownFillError(fills[1])));
+        fills[1] = turtle.end_fill();
+        turtle.set_fill_color_of(fills[1], "yellow").unwrap();
+
+        // The fills no longer exist once they are undone or cleared
+        for _ in 0..4 {
+            turtle.undo();
+        }
+        assert_eq!(turtle.set_fill_color_of(fills[1], "blue"), Err(UnknownFillError(fills[1])));
+        turtle.clear();
+        assert_eq!(turtle.set_fill_color_of(fills[0], "blue"), Err(UnknownFillError(fills[0])));
+    }
+
+    #[test]
+    fn straight_movements_extend_the_same_line() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let line_count = |json: &str| json.matches(r#"{"Line":"#).count();
+
+        for _ in 0..3 {
+            turtle.forward(10.0);
+        }
+        let json = drawing.export_json();
+        assert_eq!(line_count(&json), 1);
+        assert!(json.contains(r#""y":30.0}"#));
+
+        // Undoing shortens the line back to where it was
+        turtle.undo();
+        let json = drawing.export_json();
+        assert_eq!(line_count(&json), 1);
+        assert!(json.contains(r#""y":20.0}"#) && !json.contains(r#""y":30.0}"#));
+
+        // Turning, changing the pen, or going backwards starts a new line
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.set_pen_color("red");
+        turtle.forward(10.0);
+        turtle.backward(5.0);
+        assert_eq!(line_count(&drawing.export_json()), 4);
+
+        // Lines are never extended past the drawings of another turtle
+        let mut other = drawing.add_turtle();
+        other.stamp();
+        let stamped = line_count(&drawing.export_json());
+        turtle.backward(5.0);
+        assert_eq!(line_count(&drawing.export_json()), stamped + 1);
+    }
+
+    #[test]
+    fn clear_stamp_forgets_its_drawings() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let line_count = |json: &str| json.matches(r#"{"Line":"#).count();
+
+        turtle.forward(10.0);
+        let stamp = turtle.stamp();
+        turtle.clear_stamp(stamp);
+        // The stamp is gone, so the line drawn before it is the last drawing again
+        turtle.forward(10.0);
+        assert_eq!(line_count(&drawing.export_json()), 1);
+
+        let stamp = turtle.stamp();
+        turtle.right(90.0);
+        turtle.forward(10.0);
+        turtle.clear_stamp(stamp);
+        assert_eq!(line_count(&drawing.export_json()), 2);
+
+        // Undoing still only removes what each command drew
+        turtle.undo();
+        assert_eq!(line_count(&drawing.export_json()), 1);
+        for _ in 0..4 {
+            turtle.undo();
+        }
+        assert_eq!(line_count(&drawing.export_json()), 1);
+        turtle.undo();
+        assert_eq!(line_count(&drawing.export_json()), 0);
+    }
+
+    #[test]
+    fn undo_forgets_stamps() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        let line_count = |json: &str| json.matches(r#"{"Line":"#).count();
+
+        let stamp = turtle.stamp();
+        turtle.undo();
+        turtle.forward(10.0);
+        // The undone stamp is gone, so clearing it must not remove anything drawn after it
+        turtle.clear_stamp(stamp);
+        turtle.clear_stamps();
+        assert_eq!(line_count(&drawing.export_json()), 1);
+
+        // Undoing the line still removes it
+        turtle.undo();
+        assert_eq!(line_count(&drawing.export_json()), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid opacity: 1.5. The opacity must be between 0.0 and 1.0")]
+    fn group_opacity_rejects_out_of_range() {
+        let mut turtle = Turtle::new();
+        turtle.set_group_opacity(1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid shape scale: 0. The scale must be greater than zero")]
+    fn shape_scale_rejects_zero() {
+        let mut turtle = Turtle::new();
+        turtle.set_shape_scale(0.0);
+    }
+
+    #[test]
+    fn fill_pattern_angle_uses_the_angle_unit() {
+        let mut turtle = Turtle::new();
+        turtle.set_fill_pattern(FillPattern::Hatch {angle: 90.0, spacing: 5.0});
+
+        turtle.use_radians();
+        match turtle.fill_pattern() {
+            FillPattern::Hatch {angle, spacing} => {
+                assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-10);
+                assert_eq!(spacing, 5.0);
+            },
+            pattern => panic!("expected a hatch pattern, got {:?}", pattern),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid spacing: 0. The spacing of a fill pattern must be a finite number of at least 2")]
+    fn fill_pattern_rejects_zero_spacing() {
+        let mut turtle = Turtle::new();
+        turtle.set_fill_pattern(FillPattern::CrossHatch {spacing: 0.0});
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid spacing: 0.000000001. The spacing of a fill pattern must be a finite number of at least 2")]
+    fn fill_pattern_rejects_tiny_spacing() {
+        let mut turtle = Turtle::new();
+        turtle.set_fill_pattern(FillPattern::Dots {spacing: 1e-9});
+    }
+
+    #[test]
+    fn set_pen_state_restores_the_whole_pen() {
+        let mut turtle = Turtle::new();
+        turtle.set_pen_gradient("red", "blue");
+        turtle.set_pen_cap("butt");
+        let state = turtle.pen_state();
+        assert_eq!(state.end_color, Some("blue".into()));
+
+        turtle.set_pen_color("green");
+        turtle.set_pen_size(4.0);
+        turtle.set_pen_join("bevel");
+        turtle.pen_up();
+        turtle.set_pen_state(state);
+        assert_eq!(turtle.pen_state(), state);
+        assert_eq!(turtle.pen_gradient(), Some(("red".into(), "blue".into())));
+        assert_eq!(turtle.pen_cap(), LineCap::Butt);
+
+        // Restoring the pen is undone in a single step
+        turtle.undo();
+        assert_eq!(turtle.pen_color(), "green".into());
+        assert_eq!(turtle.pen_size(), 4.0);
+        assert!(!turtle.is_pen_down());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid thickness: -1. The pen thickness must be greater than or equal to zero")]
+    fn set_pen_state_rejects_negative_size() {
+        let mut turtle = Turtle::new();
+        let state = turtle.pen_state();
+        turtle.set_pen_state(PenState {size: -1.0, ..state});
     }
 
-    /// Convenience function that waits for a click to occur before returning.
-    ///
-    /// Useful for when you want the turtle to wait for the user to click before continuing. Use
-    /// this to force the turtle to wait before it starts drawing at the beginning of your program.
-    ///
-    /// This method uses [`poll_event()`](struct.Drawing.html#method.poll_event) internally and
-    /// ignores any other events that take place before the click is received.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run
-    /// use turtle::Turtle;
-    ///
-    /// fn main() {
-    ///     let mut turtle = Turtle::new();
-    ///     turtle.wait_for_click();
-    ///     // The turtle will wait for the screen to be clicked before continuing
-    ///     turtle.forward(100.0);
-    /// }
-    /// ```
-    pub fn wait_for_click(&mut self) {
-        block_on(self.turtle.wait_for_click())
+    #[test]
+    fn teleport_draws_nothing() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("slowest");
+        turtle.teleport([30.0, -40.0], 370.0);
+        assert_eq!(turtle.position(), Point {x: 30.0, y: -40.0});
+        // The rounding is to account for floating-point error
+        assert_eq!(turtle.heading().round(), 10.0);
+        assert!(turtle.is_pen_down());
+        assert!(!drawing.export_json().contains("Line"));
+
+        turtle.undo();
+        assert_eq!(turtle.position(), Point::origin());
+        assert_eq!(turtle.heading().round(), 90.0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn restore_returns_to_nested_saves_without_drawing() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.pen_up();
+        turtle.save();
 
-    use crate::radians;
+        turtle.pen_down();
+        turtle.set_fill_color("red");
+        turtle.forward(10.0);
+        turtle.save();
+
+        turtle.set_pen_size(3.0);
+        turtle.right(90.0);
+        turtle.forward(20.0);
+        let lines = drawing.export_json().matches("Line").count();
+
+        turtle.restore();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+        assert_eq!(turtle.heading(), 90.0);
+        assert_eq!(turtle.pen_size(), 1.0);
+        assert!(turtle.is_pen_down());
+        // The turtle teleported back even though its pen was down
+        assert_eq!(drawing.export_json().matches("Line").count(), lines);
+
+        turtle.restore();
+        assert_eq!(turtle.position(), Point::origin());
+        assert!(!turtle.is_pen_down());
+        assert_eq!(turtle.fill_color(), crate::colors::BLACK);
+    }
 
     #[test]
-    fn is_using_radians_degrees() {
-        // is_using_radians and is_using_degrees should be inverses of each other
+    #[should_panic(expected = "Unable to restore the turtle: no state was saved")]
+    fn restore_without_save() {
         let mut turtle = Turtle::new();
-        assert!(!turtle.is_using_radians());
-        assert!(turtle.is_using_degrees());
-        turtle.use_radians();
-        assert!(turtle.is_using_radians());
-        assert!(!turtle.is_using_degrees());
-        turtle.use_degrees();
-        assert!(!turtle.is_using_radians());
-        assert!(turtle.is_using_degrees());
+        turtle.restore();
     }
 
     #[test]
@@ -1041,6 +3652,42 @@ mod tests {
         assert!((turtle.heading() - 51.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn undo_restores_state() {
+        let mut turtle = Turtle::new();
+        turtle.pen_up();
+        turtle.go_to([30.0, 40.0]);
+        turtle.begin_fill();
+        turtle.forward(10.0);
+        turtle.end_fill();
+
+        turtle.undo();
+        assert!(turtle.is_filling());
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 30.0, y: 40.0});
+        assert!(turtle.is_filling());
+        turtle.undo();
+        assert!(!turtle.is_filling());
+        turtle.undo();
+        assert_eq!(turtle.position(), Point::origin());
+        turtle.undo();
+        assert!(turtle.is_pen_down());
+
+        // Nothing left to undo
+        turtle.undo();
+        assert_eq!(turtle.position(), Point::origin());
+        assert!(turtle.is_pen_down());
+    }
+
+    #[test]
+    fn clear_forgets_undo_history() {
+        let mut turtle = Turtle::new();
+        turtle.forward(100.0);
+        turtle.clear();
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 100.0});
+    }
+
     #[test]
     fn turn_towards() {
         let mut turtle = Turtle::new();
@@ -1059,6 +3706,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn towards_matches_turn_towards() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+        turtle.go_to([10.0, -20.0]);
+
+        for &target in &[[50.0, 30.0], [-40.0, -20.0], [10.0, -80.0], [11.0, 200.0]] {
+            let angle = turtle.towards(target);
+            assert!((0.0..360.0).contains(&angle));
+            turtle.turn_towards(target);
+            assert!((turtle.heading() - angle).abs() < 1e-9);
+        }
+
+        turtle.use_radians();
+        assert!((turtle.towards([10.0, 0.0]) - radians::PI.to_radians() / 2.0).abs() < 1e-9);
+        // A point on the turtle leaves its heading as is
+        assert_eq!(turtle.towards([10.0, -20.0]), turtle.heading());
+        assert_eq!(turtle.distance([13.0, -16.0]), 5.0);
+    }
+
+    #[test]
+    fn face_home_turns_without_moving() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+        turtle.go_to([30.0, 30.0]);
+
+        let heading = turtle.heading_to_home();
+        assert!((heading - 225.0).abs() < 1e-9);
+        turtle.face_home();
+        assert!((turtle.heading() - heading).abs() < 1e-9);
+        assert_eq!(turtle.position(), Point {x: 30.0, y: 30.0});
+
+        // At the origin, the turtle keeps its heading
+        turtle.go_to([0.0, 0.0]);
+        turtle.face_home();
+        assert!((turtle.heading() - heading).abs() < 1e-9);
+    }
+
     #[test]
     #[should_panic(expected = "Invalid thickness: -10. The pen thickness must be greater than or equal to zero")]
     fn set_pen_size_rejects_negative() {
@@ -1087,6 +3772,20 @@ mod tests {
         turtle.set_pen_size(-::std::f64::INFINITY);
     }
 
+    #[test]
+    #[should_panic(expected = "Invalid turtle shape: a polygon must have at least 3 points, but 2 were given")]
+    fn set_shape_rejects_short_polygon() {
+        let mut turtle = Turtle::new();
+        turtle.set_shape(vec![Point {x: 0.0, y: 0.0}, Point {x: 10.0, y: 0.0}]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid turtle shape: all of the points in a polygon must be finite")]
+    fn set_shape_rejects_nan_polygon() {
+        let mut turtle = Turtle::new();
+        turtle.set_shape(vec![Point {x: 0.0, y: 0.0}, Point {x: 10.0, y: 0.0}, Point {x: 0.0, y: ::std::f64::NAN}]);
+    }
+
     #[test]
     #[should_panic(expected = "Invalid color: Color { red: NaN, green: 0.0, blue: 0.0, alpha: 0.0 }. See the color module documentation for more information.")]
     fn rejects_invalid_pen_color() {
@@ -1111,6 +3810,86 @@ mod tests {
         });
     }
 
+    #[test]
+    fn arc_around_stays_on_circle() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+        let center = Point {x: 30.0, y: -20.0};
+        turtle.teleport([80.0, -20.0], 123.0);
+
+        // Many small steps, like in a simulation, must not drift away from the circle
+        for _ in 0..36 {
+            turtle.arc_around(center, 10.0);
+        }
+        assert!((turtle.position() - Point {x: 80.0, y: -20.0}).len() < 1e-9);
+        assert!((turtle.heading() - 90.0).abs() < 1e-9);
+
+        // More than a full turn clockwise
+        turtle.arc_around(center, -450.0);
+        assert!((turtle.position() - Point {x: 30.0, y: -70.0}).len() < 1e-9);
+        assert!((turtle.heading() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_around_is_undone_at_once() {
+        let mut turtle = Turtle::new();
+        turtle.set_speed("instant");
+        turtle.forward(10.0);
+        // Going around twice takes more steps than there are undo records
+        turtle.arc_around([0.0, -90.0], 720.0);
+
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+        assert_eq!(turtle.heading().round(), 90.0);
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 0.0});
+    }
+
+    #[test]
+    fn forward_bounded_stops_at_edge() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        drawing.set_size([200, 100]);
+        drawing.set_center([50.0, 0.0]);
+
+        // Heading diagonally up and to the left hits the top edge at y = 50 first
+        turtle.set_heading(135.0);
+        let traveled = turtle.forward_bounded(1000.0);
+        assert!((traveled - 50.0 * 2f64.sqrt()).abs() < 1e-9);
+        assert!((turtle.position() - Point {x: -50.0, y: 50.0}).len() < 1e-9);
+
+        // Backward goes the other way, to the right edge at x = 150
+        turtle.set_heading(180.0);
+        let traveled = turtle.forward_bounded(-1000.0);
+        assert!((traveled + 200.0).abs() < 1e-9);
+        assert!((turtle.position().x - 150.0).abs() < 1e-9);
+
+        // A turtle outside of the drawing can't move further away from it
+        turtle.teleport([500.0, 0.0], 0.0);
+        assert_eq!(turtle.forward_bounded(10.0), 0.0);
+        assert_eq!(turtle.position(), Point {x: 500.0, y: 0.0});
+        assert_eq!(turtle.forward_bounded(-10.0), -10.0);
+
+        assert_eq!(turtle.forward_bounded(f64::NAN), 0.0);
+    }
+
+    #[test]
+    fn forward_bounded_uses_scale() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        drawing.set_size([200, 100]);
+
+        // Zooming in by 2 only shows 100x50 of the drawing, so the top edge is at y = 25
+        drawing.set_scale(2.0);
+        assert!((turtle.forward_bounded(1000.0) - 25.0).abs() < 1e-9);
+        assert!((turtle.position().y - 25.0).abs() < 1e-9);
+
+        // Zooming out by 2 shows 400x200 of the drawing, so the top edge is at y = 100
+        drawing.set_scale(0.5);
+        assert!((turtle.forward_bounded(1000.0) - 75.0).abs() < 1e-9);
+        assert!((turtle.position().y - 100.0).abs() < 1e-9);
+    }
+
     #[test]
     fn ignores_nan_inf_zero() {
         let mut turtle = Turtle::new();
@@ -1132,6 +3911,14 @@ mod tests {
         turtle.forward(::std::f64::INFINITY);
         turtle.forward(-::std::f64::INFINITY);
 
+        turtle.forward_in(::std::f64::NAN, Duration::from_secs(1));
+        turtle.forward_in(::std::f64::INFINITY, Duration::from_secs(1));
+
+        turtle.teleport([f64::NAN, 0.0], 0.0);
+        turtle.teleport([0.0, f64::INFINITY], 0.0);
+        turtle.teleport([0.0, 0.0], f64::NAN);
+        turtle.teleport([0.0, 0.0], -f64::INFINITY);
+
         turtle.backward(0.0);
         turtle.backward(::std::f64::NAN);
         turtle.backward(::std::f64::INFINITY);
@@ -1186,6 +3973,15 @@ mod tests {
         turtle.arc_right(-f64::INFINITY, f64::INFINITY);
         turtle.arc_right(-f64::INFINITY, -f64::INFINITY);
 
+        // The turtle is on the center, so there is no circle to go around
+        turtle.arc_around(position, 90.0);
+        turtle.arc_around([f64::NAN, 0.0], 90.0);
+        turtle.arc_around([0.0, f64::INFINITY], 90.0);
+        turtle.arc_around([10.0, 0.0], 0.0);
+        turtle.arc_around([10.0, 0.0], f64::NAN);
+        turtle.arc_around([10.0, 0.0], f64::INFINITY);
+        turtle.arc_around([10.0, 0.0], -f64::INFINITY);
+
         assert_eq!(turtle.position(), position);
         assert!((turtle.heading() - heading).abs() < f64::EPSILON);
     }
@@ -1226,6 +4022,10 @@ mod tests {
         turtle.go_to([0.0, -::std::f64::INFINITY]);
         turtle.go_to([-::std::f64::INFINITY, -::std::f64::INFINITY]);
 
+        turtle.move_by([::std::f64::NAN, 0.0]);
+        turtle.move_by([0.0, ::std::f64::INFINITY]);
+        turtle.move_by([-::std::f64::INFINITY, -::std::f64::INFINITY]);
+
         turtle.set_x(::std::f64::NAN);
         turtle.set_x(::std::f64::INFINITY);
         turtle.set_x(-::std::f64::INFINITY);
@@ -1241,4 +4041,281 @@ mod tests {
         assert_eq!(turtle.position(), position);
         assert!((turtle.heading() - heading).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn polygon_draws_each_side() {
+        let mut turtle = Turtle::new();
+        turtle.begin_poly();
+        turtle.polygon(4, 10.0);
+        let square: Vec<_> = turtle.end_poly().into_iter().map(|point| point.round()).collect();
+        assert_eq!(square, vec![
+            Point::origin(),
+            Point {x: 0.0, y: 10.0},
+            Point {x: 10.0, y: 10.0},
+            Point {x: 10.0, y: 0.0},
+            Point::origin(),
+        ]);
+        assert_eq!(turtle.heading().round(), 90.0);
+
+        turtle.set_fill_color("red");
+        turtle.filled_polygon(5, 10.0, "blue");
+        assert_eq!(turtle.fill_color(), "red".into());
+        assert!(!turtle.is_filling());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of sides: 2. A polygon must have at least 3 sides")]
+    fn polygon_rejects_two_sides() {
+        let mut turtle = Turtle::new();
+        turtle.polygon(2, 10.0);
+    }
+
+    #[test]
+    fn returning_movements_match_turtle_state() {
+        let mut turtle = Turtle::new();
+        turtle.right(45.0);
+        let position = turtle.forward_returning(10.0);
+        assert_eq!(position, turtle.position());
+        assert_eq!(turtle.backward_returning(10.0).round(), Point::origin());
+        // Ignored distances do not move the turtle, but the position is still returned
+        assert_eq!(turtle.forward_returning(f64::NAN).round(), Point::origin());
+
+        let heading = turtle.left_returning(100.0);
+        assert_eq!(heading, turtle.heading());
+        assert_eq!(turtle.right_returning(0.0), heading);
+    }
+
+    #[test]
+    fn batch_matches_separate_commands() {
+        let mut drawing = crate::Drawing::new();
+        let mut batched = drawing.add_turtle();
+        let mut separate = drawing.add_turtle();
+
+        batched.batch(|batch| {
+            batch.forward(30.0);
+            batch.right(90.0);
+            batch.pen_up();
+            batch.backward(10.0);
+            batch.pen_down();
+            batch.left(45.0);
+            batch.go_to([5.0, -5.0]);
+        });
+
+        separate.forward(30.0);
+        separate.right(90.0);
+        separate.pen_up();
+        separate.backward(10.0);
+        separate.pen_down();
+        separate.left(45.0);
+        separate.go_to([5.0, -5.0]);
+
+        assert_eq!(batched.position(), separate.position());
+        assert_eq!(batched.heading(), separate.heading());
+        assert!(batched.is_pen_down());
+
+        // Every command in the batch is undone separately
+        batched.undo();
+        assert_eq!(batched.position().round(), Point {x: -10.0, y: 30.0});
+    }
+
+    #[test]
+    fn pen_builder_is_undone_at_once() {
+        let mut turtle = Turtle::new();
+        let original = turtle.pen_state();
+
+        turtle.pen().color("blue").size(3.0).up().apply();
+        assert_eq!(turtle.pen_color(), "blue".into());
+        assert_eq!(turtle.pen_size(), 3.0);
+        assert!(!turtle.is_pen_down());
+
+        turtle.undo();
+        assert_eq!(turtle.pen_state(), original);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid thickness: -1. The pen thickness must be greater than or equal to zero")]
+    fn pen_builder_invalid_size() {
+        let mut turtle = Turtle::new();
+        turtle.pen().size(-1.0).apply();
+    }
+
+    #[test]
+    fn region_edges_are_inside() {
+        let mut turtle = Turtle::new();
+        turtle.go_to([10.0, -20.0]);
+
+        // Each edge of the rectangle passes through the turtle
+        assert!(!turtle.within_rect([10.0, 0.0], (5, 5)));
+        assert!(turtle.within_rect([10.0, 0.0], (5, 20)));
+        assert!(turtle.within_rect([-10.0, 0.0], (20, 20)));
+        assert!(turtle.within_rect([0.0, -20.0], (10, 0)));
+        assert!(!turtle.within_rect([0.0, -20.0], (9, 0)));
+
+        assert!(turtle.within_circle([10.0, 10.0], 30.0));
+        assert!(!turtle.within_circle([10.0, 10.0], 29.9));
+    }
+
+    #[test]
+    fn polar_movement_uses_angle_unit() {
+        let mut turtle = Turtle::new();
+        turtle.use_radians();
+        turtle.go_to_polar(10.0, std::f64::consts::PI);
+        assert_eq!(turtle.position().round(), Point {x: -10.0, y: 0.0});
+
+        turtle.use_degrees();
+        turtle.move_by_polar(10.0, -90.0);
+        assert_eq!(turtle.position().round(), Point {x: -10.0, y: -10.0});
+    }
+
+    #[test]
+    fn spiral_without_growth_is_a_circle() {
+        let mut turtle = Turtle::new();
+        // A quarter of a circle to the left, ending up facing left
+        turtle.spiral(100.0, 0.0, 0.25);
+        assert_eq!(turtle.position().round(), Point {x: -100.0, y: 100.0});
+        assert!((turtle.heading() - 180.0).abs() < 1.0);
+
+        // A negative number of turns goes to the right instead
+        turtle.reset();
+        turtle.spiral(100.0, 0.0, -0.25);
+        assert_eq!(turtle.position().round(), Point {x: 100.0, y: 100.0});
+        assert!(turtle.heading() < 1.0 || turtle.heading() > 359.0);
+    }
+
+    #[test]
+    fn spiral_from_center_grows_each_turn() {
+        let mut turtle = Turtle::new();
+        // Starting at the center, the turtle first moves straight ahead and is 20 away from the
+        // center on the opposite side after half a turn
+        turtle.spiral(0.0, 40.0, 0.5);
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: -20.0});
+
+        // A full turn ends up straight ahead of the center, 40 away from it
+        turtle.reset();
+        turtle.spiral(0.0, 40.0, 1.0);
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 40.0});
+    }
+
+    #[test]
+    fn bezier_curves_end_facing_away_from_last_control_point() {
+        let mut turtle = Turtle::new();
+        // Each point can be given in a different form
+        turtle.bezier([0.0, 100.0], (200.0, 100.0), Point {x: 200.0, y: 0.0});
+        assert_eq!(turtle.position(), Point {x: 200.0, y: 0.0});
+        assert!((turtle.heading() - 270.0).abs() < 2.0);
+
+        turtle.reset();
+        turtle.quadratic_bezier([100.0, 100.0], [200.0, 0.0]);
+        assert_eq!(turtle.position(), Point {x: 200.0, y: 0.0});
+        assert!((turtle.heading() - 315.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn bezier_curves_are_undone_at_once() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.forward(10.0);
+        // These curves are long enough to be made of more lines than there are undo records
+        turtle.bezier([0.0, 2000.0], [2000.0, 2000.0], [2000.0, 10.0]);
+        turtle.quadratic_bezier([4000.0, 2000.0], [4000.0, 10.0]);
+
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 2000.0, y: 10.0});
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+        assert_eq!(turtle.heading().round(), 90.0);
+        // Only the first line is left, which is half of the pen size thicker at its end
+        assert_eq!(drawing.bounds().unwrap().max.y, 10.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid start radius: -1. The start radius must be greater than or equal to zero")]
+    fn spiral_negative_start_radius() {
+        let mut turtle = Turtle::new();
+        turtle.spiral(-1.0, 10.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of turns: 101. The number of turns must be between -100 and 100")]
+    fn spiral_too_many_turns() {
+        let mut turtle = Turtle::new();
+        turtle.spiral(0.0, 10.0, 101.0);
+    }
+
+    #[test]
+    fn spiral_passes_through_center() {
+        let mut turtle = Turtle::new();
+        // The spiral reaches the center after one turn, then winds outward again on the other side,
+        // so after another turn it is 40 away from the center, opposite to where it started
+        turtle.spiral(40.0, -40.0, 2.0);
+        assert!((turtle.position().len() - 80.0).abs() < 1e-9, "{:?}", turtle.position());
+    }
+
+    #[test]
+    fn spiral_is_undone_at_once() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        turtle.set_speed("instant");
+        turtle.forward(10.0);
+        // This spiral has more steps than there are undo records
+        turtle.spiral(0.0, 10.0, 5.0);
+
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+        assert_eq!(turtle.heading().round(), 90.0);
+        // Only the first line is left, which is half of the pen size thicker at its end
+        assert_eq!(drawing.bounds().unwrap().max.y, 10.5);
+
+        // Commands after the spiral are undone on their own again
+        turtle.forward(10.0);
+        turtle.forward(10.0);
+        turtle.undo();
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 20.0});
+    }
+
+    #[test]
+    fn top_left_coordinate_system_keeps_headings() {
+        let mut drawing = crate::Drawing::new();
+        let mut turtle = drawing.add_turtle();
+        drawing.set_size((400, 300));
+        drawing.set_coordinate_system(crate::CoordinateSystem::TopLeftYDown);
+
+        // Facing up still means moving towards the top of the drawing, so y decreases
+        turtle.forward(100.0);
+        assert_eq!(turtle.position().round(), Point {x: 200.0, y: 50.0});
+
+        turtle.go_to([0.0, 0.0]);
+        assert!(turtle.within_rect([-5.0, -5.0], (10, 10)));
+        assert!((turtle.heading_to_home() - 360.0 + 36.87).abs() < 0.01);
+        assert!((turtle.towards([10.0, 10.0]) - 315.0).abs() < 0.01);
+
+        turtle.go_to_polar(10.0, 270.0);
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+
+        // The origin stays where it was when the coordinate system was set
+        drawing.set_size((800, 600));
+        drawing.set_center([300.0, 150.0]);
+        assert_eq!(turtle.position().round(), Point {x: 0.0, y: 10.0});
+
+        drawing.set_coordinate_system(crate::CoordinateSystem::CenterYUp);
+        assert_eq!(turtle.position().round(), Point {x: -200.0, y: 140.0});
+        assert_eq!(drawing.center().round(), Point {x: 100.0, y: 0.0});
+
+        // Setting the coordinate system again moves the origin to the new top-left corner
+        drawing.set_coordinate_system(crate::CoordinateSystem::TopLeftYDown);
+        assert_eq!(turtle.position().round(), Point {x: 100.0, y: 160.0});
+    }
+
+    #[test]
+    fn turtle_color_does_not_affect_drawing_colors() {
+        let mut turtle = Turtle::new();
+        turtle.set_turtle_color("navy", "yellow");
+        assert_eq!(turtle.turtle_color(), ("navy".into(), "yellow".into()));
+        assert_eq!(turtle.pen_color(), "black".into());
+        assert_eq!(turtle.fill_color(), "black".into());
+
+        turtle.reset();
+        assert_eq!(turtle.turtle_color(), ("black".into(), "white".into()));
+    }
 }